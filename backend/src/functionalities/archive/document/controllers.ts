@@ -6,7 +6,7 @@ import {
     disableArchiveDocument,
     setTagsForArchiveDocument,
     getTagsForArchiveDocument,
-    archiveDocumentSignatureSearchHandler,
+    archiveDocumentSearchHandlers,
     getArchiveDocumentByIdInternal,
     getTagsForArchiveDocumentByIds,
     getMatchingDocumentIds,
@@ -190,6 +190,9 @@ export const updateArchiveDocumentController = async (req: BunRequest<":id">) =>
             await setTagsForArchiveDocument(id, validatedTagIds.filter((tid): tid is number => typeof tid === 'number'));
         }
 
+        if (updateData.active === true && !existingDoc.active) {
+            await Log.info(`Archive document re-enabled: ${updatedDocData?.title} (ID: ${id}) by ${updatedByLogin}`, sessionAndUser.user.login, AREA);
+        }
         await Log.info(`Archive document updated: ${updatedDocData?.title} (ID: ${id}) by ${updatedByLogin}`, sessionAndUser.user.login, AREA);
 
         const finalDocument = await getArchiveDocumentByIdInternal(id);
@@ -327,22 +330,7 @@ export const searchArchiveDocumentsController = async (req: BunRequest) => {
             'archive_documents',
             finalSearchRequest,
             allowedDirectFields,
-            {
-                // Tag handler remains the same
-                'tags': (element, tableAlias) => {
-                    if (element.field === 'tags' && element.condition === 'ANY_OF' && Array.isArray(element.value)) {
-                        const tagIds = element.value.filter((id): id is number => typeof id === 'number' && Number.isInteger(id) && id > 0);
-                        if (tagIds.length === 0) return { whereCondition: element.not ? '1=1' : '1=0', params: [] };
-                        const placeholders = tagIds.map(() => '?').join(', ');
-                        const whereCondition = `${element.not ? 'NOT ' : ''}EXISTS ( SELECT 1 FROM archive_document_tags adt WHERE adt.archiveDocumentId = ${tableAlias}.archiveDocumentId AND adt.tagId IN (${placeholders}) )`;
-                        return { whereCondition, params: tagIds };
-                    }
-                    return null;
-                },
-                // Signature handler remains the same
-                'descriptiveSignature': archiveDocumentSignatureSearchHandler,
-                // No special handler needed for createdBy/updatedBy (handled by default text search)
-            },
+            archiveDocumentSearchHandlers, // tags, descriptiveSignature, text
             primaryKey
         );
 
@@ -392,7 +380,7 @@ export const batchTagArchiveDocumentsController = async (req: BunRequest) => {
             return new Response(JSON.stringify({ message: "Invalid input", errors: validation.error.format() }), { status: 400 });
         }
 
-        const { searchQuery, tagIds, action } = validation.data;
+        const { searchQuery, searchGroups, tagIds, action } = validation.data;
         let finalQuery = [...searchQuery];
         const isAdmin = isAllowedRole(sessionAndUser, 'admin');
         const isEmployee = isAllowedRole(sessionAndUser, 'employee');
@@ -424,7 +412,7 @@ export const batchTagArchiveDocumentsController = async (req: BunRequest) => {
         }
 
         // Get IDs using the potentially updated search fields
-        const matchingIds = await getMatchingDocumentIds({ query: finalQuery, page: 1, pageSize: -1 });
+        const matchingIds = await getMatchingDocumentIds({ query: finalQuery, groups: searchGroups, page: 1, pageSize: -1 });
 
         if (matchingIds.length === 0) {
             await Log.info('No documents found matching batch tag criteria.', sessionAndUser.user.login, AREA, { finalQuery });
@@ -3,7 +3,7 @@ import { db } from '../../../initialization/db';
 import type { ArchiveDocument, UpdateArchiveDocumentInput, ArchiveDocumentSearchResult } from './models';
 import { Log } from '../../log/db';
 import { sqliteNow } from '../../../utils/sqlite';
-import { SearchQueryElement, SearchOnCustomFieldHandlerResult, SearchRequest, buildSearchQueries } from '../../../utils/search';
+import { SearchQueryElement, SearchOnCustomFieldHandler, SearchOnCustomFieldHandlerResult, SearchRequest, buildSearchQueries } from '../../../utils/search';
 import { Tag } from '../../tag/models';
 // Removed getUserByUserId import
 
@@ -195,7 +195,7 @@ export async function updateArchiveDocument(
         if (value === undefined || key === 'tagIds' || key === 'descriptiveSignatureElementIds') return;
 
         let dbKey = key; let dbValue = value;
-        if (key === 'isDigitized') dbValue = value ? 1 : 0;
+        if (key === 'isDigitized' || key === 'active') dbValue = value ? 1 : 0;
         else if (value === null) dbValue = null;
         // Add key to update list
         fieldsToUpdate.push(`${dbKey} = ?`);
@@ -366,6 +366,29 @@ export const archiveDocumentSignatureSearchHandler: (element: SearchQueryElement
     return { whereCondition: `(${whereCondition})`, params };
 };
 
+// Custom search fields of archive documents - shared by the search endpoint and batch tagging,
+// so a query that lists documents also selects the same documents for tagging
+export const archiveDocumentSearchHandlers: Record<string, SearchOnCustomFieldHandler<ArchiveDocumentSearchResult>> = {
+    'tags': (element, tableAlias) => {
+        if (element.field === 'tags' && element.condition === 'ANY_OF' && Array.isArray(element.value)) {
+            const tagIds = element.value.filter((id): id is number => typeof id === 'number' && Number.isInteger(id) && id > 0);
+            if (tagIds.length === 0) return { whereCondition: element.not ? '1=1' : '1=0', params: [] };
+            const placeholders = tagIds.map(() => '?').join(', ');
+            const whereCondition = `${element.not ? 'NOT ' : ''}EXISTS ( SELECT 1 FROM archive_document_tags adt WHERE adt.archiveDocumentId = ${tableAlias}.archiveDocumentId AND adt.tagId IN (${placeholders}) )`;
+            return { whereCondition, params: tagIds };
+        }
+        return null;
+    },
+    'descriptiveSignature': archiveDocumentSignatureSearchHandler,
+    // Free-text quick search: fragment of the title or the creator
+    'text': (element, tableAlias) => {
+        if (element.field === 'text' && element.condition === 'FRAGMENT' && typeof element.value === 'string') {
+            const condition = `(${tableAlias}.title LIKE ? OR ${tableAlias}.creator LIKE ?)`;
+            return { whereCondition: element.not ? `NOT ${condition}` : condition, params: [`%${element.value}%`, `%${element.value}%`] };
+        }
+        return null;
+    },
+};
 
 // --- Batch Tagging DB Functions ---
 // Updated allowedFields
@@ -384,20 +407,7 @@ export async function getMatchingDocumentIds(searchRequest: SearchRequest): Prom
             'archive_documents',
             { ...searchRequest, page: 1, pageSize: -1 },
             allowedDirectFields,
-            {
-                'tags': (element, tableAlias) => {
-                    if (element.field === 'tags' && element.condition === 'ANY_OF' && Array.isArray(element.value)) {
-                        const tagIds = element.value.filter((id): id is number => typeof id === 'number' && Number.isInteger(id) && id > 0);
-                        if (tagIds.length === 0) return { whereCondition: element.not ? '1=1' : '1=0', params: [] };
-                        const placeholders = tagIds.map(() => '?').join(', ');
-                        const whereCondition = `${element.not ? 'NOT ' : ''}EXISTS ( SELECT 1 FROM archive_document_tags adt WHERE adt.archiveDocumentId = ${tableAlias}.archiveDocumentId AND adt.tagId IN (${placeholders}) )`;
-                        return { whereCondition, params: tagIds };
-                    }
-                    return null;
-                },
-                'descriptiveSignature': archiveDocumentSignatureSearchHandler,
-                // No handler needed for createdBy/updatedBy as they are direct fields
-            },
+            archiveDocumentSearchHandlers,
             primaryKey
         );
         const idSelectQuery = countQuery.sql.replace(`SELECT COUNT(DISTINCT ${alias}.${primaryKey}) as total`, `SELECT DISTINCT ${alias}.${primaryKey} as id`);
@@ -432,4 +442,42 @@ export async function removeTagsFromDocuments(documentIds: number[], tagIds: num
     });
     try { transaction(); return changes; }
     catch (error) { await Log.error('Failed to batch remove tags from documents', 'system', 'database', { documentIds, tagIds, error }); throw error; }
-}
\ No newline at end of file
+}
+// Number of documents (incl. disabled ones) whose descriptive signatures reference each of the given elements.
+// descriptiveSignatureElementIds is a JSON array of signature paths (arrays of element IDs).
+export async function countDocumentsBySignatureElementIds(elementIds: number[]): Promise<Map<number, number>> {
+    const counts = new Map<number, number>(elementIds.map(id => [id, 0]));
+    if (elementIds.length === 0) return counts;
+    const placeholders = elementIds.map(() => '?').join(',');
+    try {
+        const statement = db.prepare(`
+            SELECT pathElement.value AS elementId, COUNT(DISTINCT d.archiveDocumentId) AS documentCount
+            FROM archive_documents d, json_each(d.descriptiveSignatureElementIds) AS signaturePath, json_each(signaturePath.value) AS pathElement
+            WHERE pathElement.value IN (${placeholders})
+            GROUP BY pathElement.value
+        `);
+        const rows = statement.all(...elementIds) as { elementId: number, documentCount: number }[];
+        rows.forEach(row => counts.set(row.elementId, row.documentCount));
+        return counts;
+    } catch (error) {
+        await Log.error('Failed to count documents by signature elements', 'system', 'database', { elementIds, error });
+        throw error;
+    }
+}
+
+// Replaces an element in the descriptive signatures of all documents (incl. disabled ones) referencing it.
+// Synchronous so it can run inside the caller's transaction (e.g. merging duplicate elements). Returns the number of updated documents.
+export function replaceSignatureElementInDocuments(fromElementId: number, toElementId: number, updatedBy: string): number {
+    const rows = db.prepare(`
+        SELECT DISTINCT d.archiveDocumentId, d.descriptiveSignatureElementIds
+        FROM archive_documents d, json_each(d.descriptiveSignatureElementIds) AS signaturePath, json_each(signaturePath.value) AS pathElement
+        WHERE pathElement.value = ?
+    `).all(fromElementId) as { archiveDocumentId: number, descriptiveSignatureElementIds: string }[];
+    const updateStmt = db.prepare(`UPDATE archive_documents SET descriptiveSignatureElementIds = ?, modifiedOn = ?, updatedBy = ? WHERE archiveDocumentId = ?`);
+    for (const row of rows) {
+        const paths = JSON.parse(row.descriptiveSignatureElementIds || '[]') as number[][];
+        const updatedPaths = paths.map(path => path.map(id => id === fromElementId ? toElementId : id));
+        updateStmt.run(JSON.stringify(updatedPaths), sqliteNow(), updatedBy, row.archiveDocumentId);
+    }
+    return rows.length;
+}
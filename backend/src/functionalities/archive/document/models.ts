@@ -81,7 +81,10 @@ const archiveDocumentBaseSchema = z.object({
     relatedDocumentsReferences: z.string().max(500).optional().nullable(),
     recordChangeHistory: z.string().optional().nullable(),
     isDigitized: z.boolean().optional().default(false),
-    digitizedVersionLink: z.string().url("Invalid URL format").optional().nullable(),
+    // http(s) only - z.string().url() alone also accepts e.g. javascript: URLs, which clients render as links
+    digitizedVersionLink: z.string().url("Invalid URL format")
+        .refine(link => /^https?:\/\//i.test(link), "Link must start with http:// or https://")
+        .optional().nullable(),
     tagIds: z.array(z.number().int().positive()).optional().default([]),
 });
 
@@ -89,7 +92,10 @@ const archiveDocumentBaseSchema = z.object({
 export const createArchiveDocumentSchema = archiveDocumentBaseSchema;
 
 // Schema for updating an existing document (no ownerUserId)
-export const updateArchiveDocumentSchema = archiveDocumentBaseSchema.partial();
+// 'active' allows re-enabling a previously disabled document/unit
+export const updateArchiveDocumentSchema = archiveDocumentBaseSchema.partial().extend({
+    active: z.boolean().optional(),
+});
 
 // Type definitions for input data based on the Zod schemas
 export type CreateArchiveDocumentInput = z.infer<typeof createArchiveDocumentSchema>;
@@ -105,6 +111,7 @@ export interface ArchiveDocumentSearchResult extends ArchiveDocument {
 // --- Schema and Type for Batch Tagging (remains the same internally) ---
 export const batchTagDocumentsSchema = z.object({
     searchQuery: searchRequestSchema.shape.query, // Get the 'query' shape
+    searchGroups: searchRequestSchema.shape.groups, // Optional AND/OR groups of the listing's search
     tagIds: z.array(z.number().int().positive()).min(1, "At least one tag ID must be selected"),
     action: z.enum(['add', 'remove']),
 });
@@ -7,7 +7,8 @@ import { Log } from '../log/db';
 import { reloadTlsConfiguration, stopHttpsServer } from '../../initialization/server';
 // ------------------------------------
 import { AppParams } from '../../initialization/app_params'; // Import AppParams to update runtime values
-import { existsSync } from 'node:fs'; // To check file existence before setting
+import { existsSync, readFileSync } from 'node:fs'; // To check file existence before setting
+import { validatePem, PRIVATE_KEY_PEM_LABELS, CERTIFICATE_PEM_LABELS } from '../../utils/pem';
 
 
 // --- NEW: Public Controller for Default Language ---
@@ -196,6 +197,19 @@ export const setConfigController = async (req: BunRequest<":key">) => {
                          await Log.warn(`Admin tried to set non-existent path for ${key}: ${value}`, sessionAndUser.user.login, 'config');
                          return new Response(JSON.stringify({ message: `Path does not exist on server: ${value}` }), { status: 400 });
                      }
+                     // Reject files that aren't usable PEM now, rather than having the TLS reload fail after a 200
+                     let pemError: string | null;
+                     try {
+                         pemError = key === AppConfigKeys.HTTPS_KEY_PATH
+                             ? validatePem(readFileSync(value, 'utf8'), PRIVATE_KEY_PEM_LABELS, 'HTTPS key file')
+                             : validatePem(readFileSync(value, 'utf8'), CERTIFICATE_PEM_LABELS, key === AppConfigKeys.HTTPS_CERT_PATH ? 'HTTPS cert file' : 'CA certificate file');
+                     } catch (readError) {
+                         pemError = `File cannot be read: ${readError instanceof Error ? readError.message : String(readError)}`;
+                     }
+                     if (pemError) {
+                         await Log.warn(`Admin tried to set an invalid PEM file for ${key}: ${value} (${pemError})`, sessionAndUser.user.login, 'config');
+                         return new Response(JSON.stringify({ message: pemError }), { status: 400 });
+                     }
                      processedValue = value;
                  }
                  break;
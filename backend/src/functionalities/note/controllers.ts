@@ -1,11 +1,11 @@
 import { BunRequest } from 'bun';
 import { createNote, getAllNotesByOwnerUserId, getNoteById, updateNote, deleteNote, getNotesForUser } from './db';
-import { Note, NoteInput, NoteWithDetails } from './models'; // Added NoteWithDetails
+import { Note, NoteInput, NoteWithDetails, NOTE_CONTENT_MAX_LENGTH } from './models'; // Added NoteWithDetails
 import { getSessionAndUser, isAllowedRole, isOwner } from '../session/controllers';
 import { Log } from '../log/db';
 import { getUserByLogin } from '../user/db';
 // Import buildSearchQueries and executeSearch explicitly
-import { SearchOnCustomFieldHandler, SearchOnCustomFieldHandlerResult, SearchQueryElement, SearchRequest, SearchResponse, buildSearchQueries, executeSearch } from "../../utils/search";
+import { SearchOnCustomFieldHandler, SearchOnCustomFieldHandlerResult, SearchQueryElement, SearchRequest, SearchResponse, buildSearchQueries, executeSearch, searchTableAlias } from "../../utils/search";
 import { getTagsForNote, setTagsForNote } from './tag/db'; // Use note-specific tag functions
 
 
@@ -167,9 +167,19 @@ export const searchNotesController = async (req: BunRequest) => {
             }
         };
 
+        // ownerLogin comes from the users JOIN, which is always added below
+        const notesAlias = searchTableAlias('notes');
+        const sortableFields: Record<string, string> = {
+            title: `${notesAlias}.title COLLATE NOCASE`,
+            ownerLogin: `users.login COLLATE NOCASE`,
+            createdOn: `${notesAlias}.createdOn`,
+            modifiedOn: `${notesAlias}.modifiedOn`,
+            shared: `${notesAlias}.shared`,
+        };
+
         // --- Build base search queries ---
         const { dataQuery, countQuery, alias: notesTableAlias } = await buildSearchQueries<NoteWithDetails>(
-            'notes', searchRequest, allowedDirectFields, customHandlers, primaryKey
+            'notes', searchRequest, allowedDirectFields, customHandlers, primaryKey, sortableFields
         );
 
         // --- Modify queries to enforce visibility rules (own OR shared) ---
@@ -270,6 +280,11 @@ export const createNoteController = async (req: BunRequest) => {
         const { title, content, shared, tagIds } = body;
         const ownerUserId = sessionAndUser.user.userId;
 
+        if (content && content.length > NOTE_CONTENT_MAX_LENGTH) {
+            const message = `Note content cannot exceed ${NOTE_CONTENT_MAX_LENGTH} characters`;
+            return new Response(JSON.stringify({ message, errors: { content: { _errors: [message] } } }), { status: 400 });
+        }
+
         // Create note core data
         const noteId = await createNote(title, content ?? '', ownerUserId, shared); // Pass empty string for null content
 
@@ -318,6 +333,10 @@ export const updateNoteController = async (req: BunRequest<":noteId">) => {
         }
 
         const body = await req.json() as NoteInput;
+        if (body.content && body.content.length > NOTE_CONTENT_MAX_LENGTH) {
+            const message = `Note content cannot exceed ${NOTE_CONTENT_MAX_LENGTH} characters`;
+            return new Response(JSON.stringify({ message, errors: { content: { _errors: [message] } } }), { status: 400 });
+        }
 
         // Determine which fields have actually changed
         const updatePayload: Partial<NoteInput> = {};
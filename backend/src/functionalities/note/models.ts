@@ -26,10 +26,13 @@ export interface NoteInput {
     tagIds?: number[]; // Array of tag IDs to associate
 }
 
+// Upper bound for note content, keeps huge bodies out of the database and list responses
+export const NOTE_CONTENT_MAX_LENGTH = 100_000;
+
 // Updated Zod schema for Note input validation
 export const noteInputSchema = z.object({
     title: z.string().min(1, "Title cannot be empty"),
-    content: z.string().nullable().optional(), // Allow null or undefined content
+    content: z.string().max(NOTE_CONTENT_MAX_LENGTH, `Content cannot exceed ${NOTE_CONTENT_MAX_LENGTH} characters`).nullable().optional(), // Allow null or undefined content
     shared: z.boolean().optional().default(false),
     tagIds: z.array(z.number().int().positive()).optional(),
 });
\ No newline at end of file
@@ -15,6 +15,18 @@ export async function initializeSessionTable() {
             FOREIGN KEY (userId) REFERENCES users(userId)
         )
     `);
+    // Refresh tokens outlive their session and are exchanged (once) for a new session
+    await db.exec(`
+        CREATE TABLE IF NOT EXISTS session_refresh_tokens (
+            refreshTokenId INTEGER PRIMARY KEY AUTOINCREMENT,
+            userId INTEGER NOT NULL,
+            token TEXT UNIQUE NOT NULL,
+            sessionToken TEXT NOT NULL,
+            createdOn DATETIME DEFAULT CURRENT_TIMESTAMP,
+            expiresOn DATETIME NOT NULL,
+            FOREIGN KEY (userId) REFERENCES users(userId)
+        )
+    `);
 }
 
 const dbToSession = (data: any) => {
@@ -49,4 +61,31 @@ export async function getSessionByToken(token: string) {
 export async function deleteSession(token: string) {
     const statement = db.prepare(`DELETE FROM sessions WHERE token = ?`);
     await statement.run(token);
+    // Logging out also invalidates the refresh token issued with this session
+    const refreshStatement = db.prepare(`DELETE FROM session_refresh_tokens WHERE sessionToken = ?`);
+    await refreshStatement.run(token);
+}
+
+export async function createRefreshToken(userId: number, sessionToken: string) {
+    const token = crypto.randomUUID();
+    const timestampExpiresOn = Date.now() + 30 * 24 * 60 * 60 * 1000; // Refresh token expires in 30 days
+
+    const statement = db.prepare(`INSERT INTO session_refresh_tokens (userId, token, sessionToken, expiresOn) VALUES (?, ?, ?, ?)`);
+    statement.run(userId, token, sessionToken, sqliteDate(timestampExpiresOn) as string);
+
+    return token;
+}
+
+// Refresh tokens are single use - a valid one is deleted and its owner's userId and old session token returned.
+// Lookup and delete are one statement, so concurrent refreshes with the same token can't both succeed.
+export async function consumeRefreshToken(token: string): Promise<{ userId: number, sessionToken: string } | undefined> {
+    const statement = db.prepare(`DELETE FROM session_refresh_tokens WHERE token = ? AND expiresOn > DATETIME('now') RETURNING userId, sessionToken`);
+    const row = await statement.get(token) as { userId: number, sessionToken: string } | null;
+    return row ?? undefined;
+}
+
+// After a password change no refresh token issued under the old password may start a new session
+export async function deleteRefreshTokensByUserId(userId: number) {
+    const statement = db.prepare(`DELETE FROM session_refresh_tokens WHERE userId = ?`);
+    await statement.run(userId);
 }
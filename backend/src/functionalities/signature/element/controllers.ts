@@ -9,9 +9,11 @@ import {
     setParentElementIds,
     elementParentSearchHandler, // Import the handler
     getParentElements,
+    mergeElementInto,
     // updateElementIndex // Not directly used here, part of re-index
 } from './db';
 import { getComponentById, incrementComponentIndexCount } from '../component/db'; // Need component DB access + incrementer
+import { countDocumentsBySignatureElementIds } from '../../archive/document/db';
 import { getSessionAndUser, isAllowedRole } from '../../session/controllers';
 import { Log } from '../../log/db';
 import { formatIndex } from '../../../utils/formatIndex'; // Import the formatter
@@ -21,13 +23,15 @@ import {
     CreateSignatureElementInput,
     UpdateSignatureElementInput,
     SignatureElement,
-    SignatureElementSearchResult
+    SignatureElementSearchResult,
+    SignatureElementUsage
 } from './models';
 // Import search utilities, including SearchQueryElement
 import { SearchQueryElement, SearchOnCustomFieldHandlerResult, SearchRequest, buildSearchQueries, executeSearch } from '../../../utils/search';
 
 
 const ELEMENT_AREA = 'signature_element';
+const MAX_ELEMENT_IDS_PER_REQUEST = 200;
 
 // --- Create ---
 export const createElementController = async (req: BunRequest) => {
@@ -109,6 +113,13 @@ export const getElementsByComponentController = async (req: BunRequest<":compone
          }
 
         const elements = await getElementsByComponentId(componentId); // Already sorted by name
+        // Parents on request only - used when exporting a whole component
+        const populateParams = new URL(req.url).searchParams.get('populate')?.split(',') ?? [];
+        if (populateParams.includes('parents')) {
+            for (const element of elements) {
+                element.parentElements = await getParentElements(element.signatureElementId!);
+            }
+        }
         return new Response(JSON.stringify(elements), { status: 200 });
     } catch (error) {
         await Log.error('Failed to fetch elements by component', sessionAndUser.user.login, ELEMENT_AREA, error);
@@ -173,6 +184,14 @@ export const updateElementController = async (req: BunRequest<":id">) => {
              return new Response(JSON.stringify({ message: 'Element not found' }), { status: 404 });
         }
 
+        // Moving to another component: make sure the target exists
+        if (updateData.signatureComponentId !== undefined && updateData.signatureComponentId !== existingElement.signatureComponentId) {
+            const targetComponent = await getComponentById(updateData.signatureComponentId);
+            if (!targetComponent) {
+                return new Response(JSON.stringify({ message: `Component with ID ${updateData.signatureComponentId} not found` }), { status: 400 });
+            }
+        }
+
         const updatedElementData = await updateElement(id, updateData);
         if (!updatedElementData) {
              return new Response(JSON.stringify({ message: 'Element not found or update failed' }), { status: 404 });
@@ -182,6 +201,9 @@ export const updateElementController = async (req: BunRequest<":id">) => {
             await setParentElementIds(id, parentIds);
         }
 
+        if (updateData.signatureComponentId !== undefined && updateData.signatureComponentId !== existingElement.signatureComponentId) {
+            await Log.info(`Element moved: ${updatedElementData?.name} (ID: ${id}) from component ${existingElement.signatureComponentId} to ${updateData.signatureComponentId}`, sessionAndUser.user.login, ELEMENT_AREA);
+        }
         await Log.info(`Element updated: ${updatedElementData?.name} (ID: ${id})`, sessionAndUser.user.login, ELEMENT_AREA);
         const updatedElementWithDetails = await getElementById(id, ['parents', 'component']); // Fetch component too
         return new Response(JSON.stringify(updatedElementWithDetails), { status: 200 });
@@ -225,6 +247,85 @@ export const deleteElementController = async (req: BunRequest<":id">) => {
     }
 };
 
+// --- Merge ---
+// Merges element :id (a duplicate) into body.targetElementId of the same component, see mergeElementInto
+export const mergeElementController = async (req: BunRequest<":id">) => {
+    const sessionAndUser = await getSessionAndUser(req);
+    if (!sessionAndUser) return new Response("Unauthorized", { status: 401 });
+    if (!isAllowedRole(sessionAndUser, 'admin')) return new Response("Forbidden", { status: 403 });
+
+    try {
+        const id = parseInt(req.params.id);
+        const body = await req.json() as { targetElementId?: unknown };
+        const targetId = Number(body?.targetElementId);
+        if (isNaN(id) || !Number.isInteger(targetId) || targetId <= 0) {
+            return new Response(JSON.stringify({ message: 'Invalid element ID' }), { status: 400 });
+        }
+        if (id === targetId) {
+            return new Response(JSON.stringify({ message: 'An element cannot be merged into itself' }), { status: 400 });
+        }
+
+        const [duplicate, target] = await Promise.all([getElementById(id), getElementById(targetId)]);
+        if (!duplicate || !target) {
+            return new Response(JSON.stringify({ message: 'Element not found' }), { status: 404 });
+        }
+        if (duplicate.signatureComponentId !== target.signatureComponentId) {
+            return new Response(JSON.stringify({ message: 'Only elements of the same component can be merged' }), { status: 400 });
+        }
+
+        const updatedDocumentCount = await mergeElementInto(id, targetId, sessionAndUser.user.login);
+        await Log.info(`Element merged: ID ${id} into ID ${targetId} (${updatedDocumentCount} documents updated)`, sessionAndUser.user.login, ELEMENT_AREA);
+        return new Response(JSON.stringify({ updatedDocumentCount }), { status: 200 });
+    } catch (error) {
+        await Log.error('Failed to merge elements', sessionAndUser.user.login, ELEMENT_AREA, error);
+        return new Response(JSON.stringify({ message: 'Failed to merge elements' }), { status: 500 });
+    }
+};
+
+// --- Usage ---
+// Number of archive documents referencing each element (in descriptive signatures), e.g. before deleting
+export const getElementsUsageController = async (req: BunRequest) => {
+    const sessionAndUser = await getSessionAndUser(req);
+    if (!sessionAndUser) return new Response("Unauthorized", { status: 401 });
+    if (!isAllowedRole(sessionAndUser, 'admin', 'employee')) return new Response("Forbidden", { status: 403 });
+
+    try {
+        const idsParam = new URL(req.url).searchParams.get('ids') || '';
+        const elementIds = idsParam.split(',').map(id => parseInt(id.trim(), 10));
+        if (elementIds.length === 0 || elementIds.length > MAX_ELEMENT_IDS_PER_REQUEST || elementIds.some(id => isNaN(id) || id <= 0)) {
+            return new Response(JSON.stringify({ message: `Provide 1-${MAX_ELEMENT_IDS_PER_REQUEST} valid element IDs in the "ids" parameter` }), { status: 400 });
+        }
+
+        const counts = await countDocumentsBySignatureElementIds(elementIds);
+        const usage: SignatureElementUsage[] = elementIds.map(id => ({ signatureElementId: id, documentCount: counts.get(id) ?? 0 }));
+        return new Response(JSON.stringify(usage), { status: 200 });
+    } catch (error) {
+        await Log.error('Failed to get element usage', sessionAndUser.user.login, ELEMENT_AREA, error);
+        return new Response(JSON.stringify({ message: 'Failed to get element usage' }), { status: 500 });
+    }
+};
+
+// Batch lookup (?ids=1,2,3) - lets clients resolve whole signature paths in one request
+export const getElementsByIdsController = async (req: BunRequest) => {
+    const sessionAndUser = await getSessionAndUser(req);
+    if (!sessionAndUser) return new Response("Unauthorized", { status: 401 });
+    if (!isAllowedRole(sessionAndUser, 'admin', 'employee')) return new Response("Forbidden", { status: 403 });
+
+    try {
+        const idsParam = new URL(req.url).searchParams.get('ids') || '';
+        const elementIds = idsParam.split(',').map(id => parseInt(id.trim(), 10));
+        if (elementIds.length === 0 || elementIds.length > MAX_ELEMENT_IDS_PER_REQUEST || elementIds.some(id => isNaN(id) || id <= 0)) {
+            return new Response(JSON.stringify({ message: `Provide 1-${MAX_ELEMENT_IDS_PER_REQUEST} valid element IDs in the "ids" parameter` }), { status: 400 });
+        }
+
+        const elements = await getElementsByIds([...new Set(elementIds)]);
+        return new Response(JSON.stringify(elements), { status: 200 });
+    } catch (error) {
+        await Log.error('Failed to get elements by IDs', sessionAndUser.user.login, ELEMENT_AREA, error);
+        return new Response(JSON.stringify({ message: 'Failed to get elements' }), { status: 500 });
+    }
+};
+
 // --- Search ---
 export const searchElementsController = async (req: BunRequest) => {
     const sessionAndUser = await getSessionAndUser(req);
@@ -244,6 +345,7 @@ export const searchElementsController = async (req: BunRequest) => {
         const customHandlers: Record<string, (el: SearchQueryElement, alias: string) => SearchOnCustomFieldHandlerResult> = {
             'parentIds': elementParentSearchHandler,
             'hasParents': elementParentSearchHandler,
+            'hasParentsInComponent': elementParentSearchHandler,
             'componentName': (element, tableAlias): SearchOnCustomFieldHandlerResult => {
                 const joinClause = `LEFT JOIN signature_components sc ON ${tableAlias}.signatureComponentId = sc.signatureComponentId`;
                 if (element.condition === 'FRAGMENT' && typeof element.value === 'string') {
@@ -6,6 +6,7 @@ import { sqliteNow } from '../../../utils/sqlite';
 import { SearchOnCustomFieldHandlerResult, SearchQueryElement } from '../../../utils/search';
 import { dbToComponent } from '../component/db';
 import type { ArchiveDocumentSearchResult } from '../../archive/document/models'; // IMPORT CORRECT TYPE
+import { replaceSignatureElementInDocuments } from '../../archive/document/db';
 
 // Initialization function (called in initializeDatabase)
 export async function initializeSignatureElementTable() {
@@ -106,6 +107,15 @@ export async function getElementById(id: number, populate: ('component' | 'paren
     return element;
 }
 
+// Fetches many elements in one query (missing IDs are simply absent from the result)
+export async function getElementsByIds(ids: number[]): Promise<SignatureElement[]> {
+    if (ids.length === 0) return [];
+    const placeholders = ids.map(() => '?').join(', ');
+    const statement = db.prepare(`SELECT * FROM signature_elements WHERE signatureElementId IN (${placeholders})`);
+    const results = statement.all(...ids);
+    return results.map(dbToElement).filter(e => e !== undefined) as SignatureElement[];
+}
+
 // Ensure sorting by name for re-indexing
 export async function getElementsByComponentId(componentId: number): Promise<SignatureElement[]> {
      // Add "AND active = TRUE" if using soft deletes
@@ -125,11 +135,16 @@ async function getComponentForElement(componentId: number): Promise<SignatureCom
 
 export async function updateElement(
     id: number,
-    data: Partial<{ name: string; description: string | null; index: string | null /*; active: boolean*/ }>
+    data: Partial<{ signatureComponentId: number; name: string; description: string | null; index: string | null /*; active: boolean*/ }>
 ): Promise<SignatureElement | undefined> {
     const fieldsToUpdate: string[] = [];
     const params: any[] = [];
 
+    if (data.signatureComponentId !== undefined) {
+        fieldsToUpdate.push('signatureComponentId = ?');
+        params.push(data.signatureComponentId);
+    }
+
     if (data.name !== undefined) {
         fieldsToUpdate.push('name = ?');
         params.push(data.name);
@@ -212,6 +227,35 @@ export async function deleteElement(id: number): Promise<boolean> {
     }
 }
 
+// Merges a duplicate element into another one of the same component: documents referencing the duplicate
+// get the kept element instead, the duplicate's children and parents are linked to the kept element,
+// then the duplicate is deleted. All in one transaction. Returns the number of updated documents.
+export async function mergeElementInto(duplicateId: number, keptId: number, mergedBy: string): Promise<number> {
+    const transaction = db.transaction(() => {
+        const updatedDocuments = replaceSignatureElementInDocuments(duplicateId, keptId, mergedBy);
+        // Children of the duplicate become children of the kept element
+        db.prepare(`
+            INSERT OR IGNORE INTO signature_element_parents (childElementId, parentElementId)
+            SELECT childElementId, ? FROM signature_element_parents WHERE parentElementId = ? AND childElementId != ?
+        `).run(keptId, duplicateId, keptId);
+        // The kept element also gets the duplicate's parents
+        db.prepare(`
+            INSERT OR IGNORE INTO signature_element_parents (childElementId, parentElementId)
+            SELECT ?, parentElementId FROM signature_element_parents WHERE childElementId = ? AND parentElementId != ?
+        `).run(keptId, duplicateId, keptId);
+        // Cascade removes the duplicate's remaining parent links
+        db.prepare(`DELETE FROM signature_elements WHERE signatureElementId = ?`).run(duplicateId);
+        db.prepare(`UPDATE signature_elements SET modifiedOn = ? WHERE signatureElementId = ?`).run(sqliteNow(), keptId);
+        return updatedDocuments;
+    });
+    try {
+        return transaction();
+    } catch (error) {
+        await Log.error('Failed to merge signature elements', 'system', 'database', { duplicateId, keptId, error });
+        throw error;
+    }
+}
+
 // --- Parent Relationship Management ---
 
 export async function setParentElementIds(childElementId: number, parentElementIds: number[]): Promise<void> {
@@ -325,6 +369,21 @@ export const elementParentSearchHandler: (element: SearchQueryElement, tableAlia
          return { whereCondition: condition, params: [] };
      }
 
+    // 'hasParentsInComponent' only counts parents from the element's own component
+    // (roots of the per-component tree may still have parents elsewhere)
+    if (element.field === 'hasParentsInComponent' && element.condition === 'EQ' && typeof element.value === 'boolean') {
+        const subQuery = `
+            EXISTS (
+                SELECT 1 FROM signature_element_parents sep
+                JOIN signature_elements pe ON pe.signatureElementId = sep.parentElementId
+                WHERE sep.childElementId = ${tableAlias}.signatureElementId
+                  AND pe.signatureComponentId = ${tableAlias}.signatureComponentId
+            )
+        `;
+        const condition = (element.value !== element.not) ? subQuery : `NOT (${subQuery})`;
+        return { whereCondition: condition, params: [] };
+    }
+
 
     // This handler doesn't handle other fields or conditions for 'parentIds' / 'hasParents'
     return null;
@@ -344,12 +403,12 @@ export type ElementSearchResult = SignatureElement & { parentIds?: number[] };
  * Resolves a single path of element IDs to a display string.
  * Example: [1, 5] -> "[CompA-Idx1] ElementName1 / [CompB-Idx2] ElementName2"
  */
-export async function resolveSignaturePathToString(idPath: number[]): Promise<string | null> {
+export async function resolveSignaturePathToString(idPath: number[], preloadedElements?: Map<number, SignatureElement>): Promise<string | null> {
     if (!idPath || idPath.length === 0) return null;
     try {
-        const elementsInPath: (SignatureElement | undefined)[] = await Promise.all(
-            idPath.map(id => getElementById(id, [])) // No need to populate further here
-        );
+        const elementsInPath: (SignatureElement | undefined)[] = preloadedElements
+            ? idPath.map(id => preloadedElements.get(id))
+            : await Promise.all(idPath.map(id => getElementById(id, []))); // No need to populate further here
 
         const displayParts = elementsInPath.map((el, index) => {
             if (el) {
@@ -357,7 +416,7 @@ export async function resolveSignaturePathToString(idPath: number[]): Promise<st
                 return `${el.index ? `[${el.index}] ` : ''}${el.name}`;
             }
             // Fallback if an element ID in the path is not found
-            return `[ID:${idPath[index]} not found]`;
+            return `[ErrID:${idPath[index]}]`;
         });
         return displayParts.join(' / ');
     } catch (error) {
@@ -372,6 +431,7 @@ export async function resolveSignaturePathToString(idPath: number[]): Promise<st
  * Modifies the documents in place.
  */
 export async function populateResolvedDescriptiveSignatures(documents: ArchiveDocumentSearchResult[]): Promise<void> {
+    const parsedPaths = new Map<ArchiveDocumentSearchResult, number[][]>();
     for (const doc of documents) {
         let descriptiveIds: number[][] = [];
         // Check if descriptiveSignatureElementIds is a string (from direct search result)
@@ -386,14 +446,21 @@ export async function populateResolvedDescriptiveSignatures(documents: ArchiveDo
         } else if (Array.isArray((doc as any).descriptiveSignatureElementIds)) {
             descriptiveIds = (doc as any).descriptiveSignatureElementIds;
         }
+        parsedPaths.set(doc, Array.isArray(descriptiveIds) ? descriptiveIds : []);
+    }
 
+    // Load every element referenced on this page in a single query instead of one per path element
+    const allElementIds = new Set<number>();
+    parsedPaths.forEach(paths => paths.forEach(idPath => { if (Array.isArray(idPath)) idPath.forEach(id => { if (typeof id === 'number') allElementIds.add(id); }); }));
+    const preloadedElements = new Map((await getElementsByIds([...allElementIds])).map(el => [el.signatureElementId!, el]));
 
-        if (descriptiveIds && Array.isArray(descriptiveIds) && descriptiveIds.length > 0) {
+    for (const [doc, descriptiveIds] of parsedPaths) {
+        if (descriptiveIds.length > 0) {
             const resolvedSignatures: (string | null)[] = await Promise.all(
                 descriptiveIds.map(idPath => {
                     // Ensure idPath itself is an array before passing to resolveSignaturePathToString
                     if (Array.isArray(idPath)) {
-                        return resolveSignaturePathToString(idPath);
+                        return resolveSignaturePathToString(idPath, preloadedElements);
                     }
                     Log.warn(`Invalid idPath found in descriptiveSignatureElementIds for doc ${doc.archiveDocumentId}`, 'system', 'signature_resolver', { idPath });
                     return Promise.resolve(null); // Return null for invalid paths
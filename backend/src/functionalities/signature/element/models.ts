@@ -27,8 +27,9 @@ export const createSignatureElementSchema = z.object({
     parentIds: z.array(z.number().int().positive()).optional().default([]), // Array of parent element IDs
 });
 
-// Schema for update input (componentId usually shouldn't change, parents can)
+// Schema for update input (componentId only changes when moving a misfiled element, parents can)
 export const updateSignatureElementSchema = z.object({
+    signatureComponentId: z.number().int().positive("Invalid Component ID").optional(), // Move element to another component
     name: z.string().min(1, "Name cannot be empty").max(100, "Name too long").optional(),
     description: z.string().max(500, "Description too long").optional().nullable(), // Allow setting to null
     index: z.string().max(255, "Index too long").optional().nullable(), // Allow setting index to null
@@ -40,6 +41,12 @@ export const updateSignatureElementSchema = z.object({
 export type CreateSignatureElementInput = z.infer<typeof createSignatureElementSchema>;
 export type UpdateSignatureElementInput = z.infer<typeof updateSignatureElementSchema>;
 
+// Number of archive documents referencing an element
+export interface SignatureElementUsage {
+    signatureElementId: number;
+    documentCount: number;
+}
+
 // Interface for search results potentially including parents
 export interface SignatureElementSearchResult extends SignatureElement {
      parentIds?: number[]; // Include raw parent IDs in search results if helpful
@@ -4,7 +4,10 @@ import {
     getElementByIdController,
     updateElementController,
     deleteElementController,
-    searchElementsController
+    searchElementsController,
+    getElementsUsageController,
+    getElementsByIdsController,
+    mergeElementController
 } from './controllers';
 
 export const signatureElementRoutes = {
@@ -20,6 +23,21 @@ export const signatureElementRoutes = {
         DELETE: deleteElementController,  // Delete element
     },
     
+    // Merge a duplicate element into another one of the same component (admin)
+    '/api/signature/element/:id/merge': {
+        POST: mergeElementController,
+    },
+
+    // Batch fetch of elements (?ids=1,2,3), used to resolve signature paths
+    '/api/signature/elements/by-ids': {
+        GET: getElementsByIdsController,
+    },
+
+    // Document usage counts for elements (?ids=1,2,3)
+    '/api/signature/elements/usage': {
+        GET: getElementsUsageController,
+    },
+
     // Search elements
     '/api/signature/elements/search': {
         POST: searchElementsController,
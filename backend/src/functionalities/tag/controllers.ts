@@ -16,10 +16,10 @@ export const createTagController = async (req: BunRequest) => {
         const description = body.description;
 
         if (!name) {
-            return new Response(JSON.stringify({ message: 'Tag name is required' }), { status: 400 });
+            return new Response(JSON.stringify({ message: 'Tag name is required', errors: { name: { _errors: ['Tag name is required'] } } }), { status: 400 });
         }
 
-        const newTag = await createTag(name, description);
+        const newTag = await createTag(name, description, sessionAndUser.user.login);
          if (!newTag) {
              const existing = await getTagByName(name);
              if (existing) return new Response(JSON.stringify(existing), { status: 200 });
@@ -89,7 +89,7 @@ export const updateTagController = async (req: BunRequest<":tagId">) => {
         const description = body.description; // Allow setting description to empty string or null implicitly
 
         if (name === "" ) { // Check for empty string explicitly if name is provided
-             return new Response(JSON.stringify({ message: 'Tag name cannot be empty' }), { status: 400 });
+             return new Response(JSON.stringify({ message: 'Tag name cannot be empty', errors: { name: { _errors: ['Tag name cannot be empty'] } } }), { status: 400 });
         }
 
         const existingTag = await getTagById(tagId);
@@ -2,6 +2,7 @@
 import { db } from '../../initialization/db';
 import type { Tag } from './models';
 import { Log } from '../log/db';
+import { sqliteNow } from '../../utils/sqlite';
 
 // initialization function
 export async function initializeTagTable() {
@@ -9,20 +10,29 @@ export async function initializeTagTable() {
         CREATE TABLE IF NOT EXISTS tags (
             tagId INTEGER PRIMARY KEY AUTOINCREMENT,
             name TEXT NOT NULL,
-            description TEXT
+            description TEXT,
+            createdBy TEXT,
+            createdOn DATETIME
         )
     `);
 
+    // Databases created before tag authorship was recorded lack the columns - existing tags keep NULLs
+    const columns = (db.prepare(`PRAGMA table_info(tags)`).all() as { name: string }[]).map(column => column.name);
+    if (!columns.includes('createdBy')) await db.exec(`ALTER TABLE tags ADD COLUMN createdBy TEXT`);
+    if (!columns.includes('createdOn')) await db.exec(`ALTER TABLE tags ADD COLUMN createdOn DATETIME`);
+    // Tags created while createdOn was written as an ISO string ("...T...Z") get the "YYYY-MM-DD HH:MM:SS" form used elsewhere
+    await db.exec(`UPDATE tags SET createdOn = DATETIME(createdOn) WHERE createdOn LIKE '%T%' AND DATETIME(createdOn) IS NOT NULL`);
+
     // await db.exec(`CREATE UNIQUE INDEX IF NOT EXISTS idx_tag_name ON tags (name);`);
 }
 
 // operation functions
-export async function createTag(name: string, description: string = ""): Promise<Tag | undefined> {
+export async function createTag(name: string, description: string = "", createdBy: string | null = null): Promise<Tag | undefined> {
     try {
         const statement = db.prepare(
-            `INSERT INTO tags (name, description) VALUES (?, ?) RETURNING *`
+            `INSERT INTO tags (name, description, createdBy, createdOn) VALUES (?, ?, ?, ?) RETURNING *`
         );
-        const newTag = statement.get(name, description) as Tag;
+        const newTag = statement.get(name, description, createdBy, sqliteNow()) as Tag;
         return newTag;
     } catch (error: any) {
         await Log.error('Failed to create tag', 'system', 'database', error);
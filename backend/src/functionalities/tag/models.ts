@@ -4,6 +4,8 @@ export interface Tag {
     tagId?: number;
     name: string;
     description?: string; // Optional description
+    createdBy?: string | null; // Login of the creator (null for tags created before this was recorded)
+    createdOn?: string | null; // SQLite "YYYY-MM-DD HH:MM:SS" (UTC), same caveat as createdBy
 }
 
 // Zod schema for creating/updating a tag
@@ -24,11 +24,14 @@ import {
     setPasswordSchema, // Import set password schema
     updatePreferredLanguageSchema, // Import preferred language schema
     SupportedLanguage, // Import SupportedLanguage type
+    supportedLanguages,
+    defaultLanguage,
 } from './models';
-import { createSession, deleteSession } from '../session/db';
+import { createSession, deleteSession, createRefreshToken, consumeRefreshToken, deleteRefreshTokensByUserId } from '../session/db';
 import { getSessionAndUser, isAllowedRole, isOwner } from '../session/controllers';
 import { Log } from '../log/db';
 import { z } from 'zod'; // Import z
+import { AppParams } from '../../initialization/app_params';
 
 
 export const createUserController = async (req: BunRequest) => {
@@ -41,9 +44,12 @@ export const createUserController = async (req: BunRequest) => {
             return new Response(JSON.stringify({ message: "Username already exists" }), { status: 409, headers: { 'Content-Type': 'application/json' } });
         }
 
-        // Backend decides default role (null). Pass preferredLanguage from validated data.
-        await createUser(validatedData.login, validatedData.password, null, validatedData.preferredLanguage);
-        await Log.info(`User created: ${validatedData.login} with lang ${validatedData.preferredLanguage}`, 'system', 'user');
+        // Backend decides default role (null). Users created without a language (e.g. by an admin) get the configured default.
+        const configuredLanguage = AppParams.defaultLanguage as SupportedLanguage;
+        const preferredLanguage = validatedData.preferredLanguage
+            ?? (supportedLanguages.includes(configuredLanguage) ? configuredLanguage : defaultLanguage);
+        await createUser(validatedData.login, validatedData.password, null, preferredLanguage);
+        await Log.info(`User created: ${validatedData.login} with lang ${preferredLanguage}`, 'system', 'user');
 
         const newUser = await getUserByLoginSafe(validatedData.login); // Fetches user without password
 
@@ -252,6 +258,7 @@ export const updateUserPasswordController = async (req: BunRequest) => {
         }
 
         await updateUserPassword(login, newPassword);
+        await deleteRefreshTokensByUserId(sessionAndUser.user.userId);
         await Log.info('User password updated successfully', sessionAndUser.user.login, 'user');
         return new Response(null, { status: 204 });
 
@@ -288,6 +295,7 @@ export const adminSetUserPasswordController = async (req: BunRequest<":login">)
         }
 
         await adminSetUserPassword(targetLogin, newPassword);
+        await deleteRefreshTokensByUserId(userExists.userId);
         await Log.info(`Admin set password for user ${targetLogin}`, sessionAndUser.user.login, 'user');
         return new Response(null, { status: 204 });
 
@@ -323,12 +331,14 @@ export const loginController = async (req: BunRequest) => {
              return new Response(JSON.stringify({ message: "Account is disabled or has no assigned role." }), { status: 403, headers: { 'Content-Type': 'application/json' } });
         }
         const token = await createSession(user.userId);
+        const refreshToken = await createRefreshToken(user.userId, token);
 
         // Return user details WITHOUT password, but include assigned tags and preferredLanguage
         const { password: _pwd, ...userResponseData } = user;
 
         return new Response(JSON.stringify({
             token,
+            refreshToken,
             ...userResponseData // Includes userId, login, role, assignedTags, preferredLanguage
         }), {
             status: 200,
@@ -341,6 +351,40 @@ export const loginController = async (req: BunRequest) => {
     }
 };
 
+// Exchanges a refresh token for a new session (and a new refresh token), without asking for the password again
+export const refreshSessionController = async (req: BunRequest) => {
+    try {
+        const body = await req.json() as { refreshToken?: string };
+        if (!body.refreshToken) {
+            return new Response(JSON.stringify({ message: "Refresh token is required" }), { status: 400, headers: { 'Content-Type': 'application/json' } });
+        }
+
+        const refreshed = await consumeRefreshToken(body.refreshToken);
+        if (!refreshed) {
+            return new Response(JSON.stringify({ message: "Invalid or expired refresh token" }), { status: 401, headers: { 'Content-Type': 'application/json' } });
+        }
+
+        const user = await getUserByUserId(refreshed.userId);
+        if (!user || user.role === null) {
+            await Log.warn(`Session refresh denied for disabled or missing user ID ${refreshed.userId}`, user?.login, 'auth');
+            return new Response(JSON.stringify({ message: "Account is disabled or has no assigned role." }), { status: 401, headers: { 'Content-Type': 'application/json' } });
+        }
+
+        // The old session may still be valid - replace it rather than leaving two sessions around
+        await deleteSession(refreshed.sessionToken);
+        const token = await createSession(user.userId);
+        const refreshToken = await createRefreshToken(user.userId, token);
+
+        return new Response(JSON.stringify({ token, refreshToken }), {
+            status: 200,
+            headers: { 'Content-Type': 'application/json' }
+        });
+    } catch (error) {
+        await Log.error('Session refresh error', undefined, 'auth', error);
+        return new Response(JSON.stringify({ message: 'Failed to refresh session', error: error instanceof Error ? error.message : String(error) }), { status: 500, headers: { 'Content-Type': 'application/json' } });
+    }
+};
+
 export const logoutController = async (req: BunRequest) => {
     try {
         const token = req.headers.get('Authorization');
@@ -37,9 +37,8 @@ export const userSchema = z.object({
     // Role is optional on creation, backend handles default assignment
     // Role field removed from frontend schema, assigned by backend/admin
     // role: z.enum(['admin', 'employee', 'user']).optional(),
-    // --- NEW: Allow preferredLanguage on creation, defaults to 'en' if not provided ---
-    // Ensure the default in the schema matches the exported constant
-    preferredLanguage: z.enum(supportedLanguages).optional().default(defaultLanguage),
+    // --- NEW: Allow preferredLanguage on creation; when omitted the server's default_language setting is used ---
+    preferredLanguage: z.enum(supportedLanguages).optional(),
 });
 
 // Schema for role update allows specific roles or null
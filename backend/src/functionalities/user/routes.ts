@@ -6,6 +6,7 @@ import {
     getUserByLoginController,
     loginController,
     logoutController,
+    refreshSessionController,
     updateUserPasswordController,
     updateUserRoleController,
     adminSetUserPasswordController,
@@ -51,6 +52,10 @@ export const userRoutes = {
     '/api/user/login': {
         POST: loginController
     },
+    // Exchange a refresh token for a new session
+    '/api/user/refresh': {
+        POST: refreshSessionController
+    },
     // Logout
     '/api/user/logout': {
         POST: logoutController
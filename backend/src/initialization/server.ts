@@ -3,9 +3,10 @@ import { routes, Routes } from "./routes";
 import { AppParams } from "./app_params";
 import { Log } from '../functionalities/log/db';
 import path from 'node:path';
-import { existsSync, watch } from 'node:fs'; // Added watch
+import { existsSync, readFileSync, watch } from 'node:fs'; // Added watch
 import type { ServeOptions, Server, TLSServeOptions, WebSocketServeOptions, FileBlob } from 'bun';
 import { EventEmitter } from 'node:events'; // Added EventEmitter
+import { validatePem, PRIVATE_KEY_PEM_LABELS, CERTIFICATE_PEM_LABELS } from '../utils/pem';
 
 // --- Global Variables ---
 let httpServer: Server | null = null;
@@ -95,9 +96,20 @@ function loadTlsFiles(keyPath: string, certPath: string, caPath?: string | null)
              return null;
         }
 
+        // Catch a half-written or wrongly pasted file here with a clear message, rather than a TLS error from Bun
+        const pemError = validatePem(readFileSync(keyPath, 'utf8'), PRIVATE_KEY_PEM_LABELS, 'HTTPS key file')
+            ?? validatePem(readFileSync(certPath, 'utf8'), CERTIFICATE_PEM_LABELS, 'HTTPS cert file');
+        if (pemError) {
+            Log.error(`Invalid TLS files: ${pemError}`, 'system', 'startup', { keyPath, certPath });
+            return null;
+        }
+
         if (caPath) {
             const caFile = Bun.file(caPath);
-            if (caFile.size) {
+            const caError = caFile.size ? validatePem(readFileSync(caPath, 'utf8'), CERTIFICATE_PEM_LABELS, 'CA certificate file') : null;
+            if (caError) {
+                Log.warn(`Ignoring CA certificate: ${caError}`, 'system', 'startup', { caPath });
+            } else if (caFile.size) {
                 ca = caFile;
             } else {
                 Log.warn("CA certificate path provided, but file is empty.", 'system', 'startup', { caPath });
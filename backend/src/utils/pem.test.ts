@@ -0,0 +1,45 @@
+import { describe, expect, test } from 'bun:test';
+import { CERTIFICATE_PEM_LABELS, PRIVATE_KEY_PEM_LABELS, validatePem } from './pem';
+
+// Bodies only need to be structurally valid base64 - the DER content isn't parsed
+const BODY = 'MIIBVQIBADANBgkqhkiG9w0BAQEFAASCAT8wggE7AgEAAkEAu1SU1LfVLPHCozMx\nH2Mo4lgOEePzNm0tRgeLezV6ffAt0gunVTLw7onLRnrq0/IzW7yWR7QkrmBL7jTK\n';
+const pem = (label: string, body = BODY, endLabel = label) => `-----BEGIN ${label}-----\n${body}-----END ${endLabel}-----\n`;
+
+describe('validatePem', () => {
+    test('a private key is accepted', () => {
+        expect(validatePem(pem('PRIVATE KEY'), PRIVATE_KEY_PEM_LABELS, 'HTTPS key file')).toBeNull();
+        expect(validatePem(pem('RSA PRIVATE KEY'), PRIVATE_KEY_PEM_LABELS, 'HTTPS key file')).toBeNull();
+    });
+
+    test('a certificate chain is accepted', () => {
+        expect(validatePem(pem('CERTIFICATE') + pem('CERTIFICATE'), CERTIFICATE_PEM_LABELS, 'HTTPS cert file')).toBeNull();
+    });
+
+    test('a block with another label is rejected', () => {
+        expect(validatePem(pem('CERTIFICATE'), PRIVATE_KEY_PEM_LABELS, 'HTTPS key file'))
+            .toBe(`HTTPS key file contains CERTIFICATE but ${PRIVATE_KEY_PEM_LABELS.join(' or ')} was expected.`);
+    });
+
+    test('a body that is not base64 is rejected', () => {
+        expect(validatePem(pem('CERTIFICATE', 'not base64 at all!\n'), CERTIFICATE_PEM_LABELS, 'HTTPS cert file'))
+            .toBe('HTTPS cert file block "CERTIFICATE" does not contain valid base64 data.');
+        expect(validatePem(pem('CERTIFICATE', 'QUJD\nRA\n'), CERTIFICATE_PEM_LABELS, 'HTTPS cert file'))
+            .toBe('HTTPS cert file block "CERTIFICATE" does not contain valid base64 data.');
+    });
+
+    test('mismatched BEGIN and END markers are rejected', () => {
+        expect(validatePem(pem('PRIVATE KEY', BODY, 'CERTIFICATE'), PRIVATE_KEY_PEM_LABELS, 'HTTPS key file'))
+            .toBe('HTTPS key file has mismatched markers: BEGIN PRIVATE KEY / END CERTIFICATE.');
+    });
+
+    test('a BEGIN marker without an END marker is rejected', () => {
+        expect(validatePem(`-----BEGIN CERTIFICATE-----\n${BODY}`, CERTIFICATE_PEM_LABELS, 'HTTPS cert file'))
+            .toBe('HTTPS cert file has a BEGIN marker without a matching END marker.');
+    });
+
+    test('empty content and non-PEM text get their own messages', () => {
+        expect(validatePem('  \n', CERTIFICATE_PEM_LABELS, 'CA certificate file')).toBe('CA certificate file is empty.');
+        expect(validatePem('just some text', CERTIFICATE_PEM_LABELS, 'CA certificate file'))
+            .toBe('CA certificate file has no "-----BEGIN ...-----" marker - is it a PEM file?');
+    });
+});
@@ -0,0 +1,35 @@
+// Structural sanity check for PEM files (BEGIN/END markers + base64 body). It doesn't parse the DER
+// content - the point is to catch obvious paste/copy mistakes with a clear message before TLS setup fails.
+
+const PEM_BLOCK_REGEX = /-----BEGIN ([A-Z0-9 ]+)-----\r?\n([\s\S]*?)-----END ([A-Z0-9 ]+)-----/g;
+const BASE64_BODY_REGEX = /^[A-Za-z0-9+/]+={0,2}$/;
+
+/**
+ * Validates that `text` contains at least one well-formed PEM block with one of the accepted labels.
+ * @returns An error message describing the first problem found, or null when the content looks valid.
+ */
+export function validatePem(text: string, acceptedLabels: string[], what: string): string | null {
+    if (!text.trim()) return `${what} is empty.`;
+    if (!text.includes('-----BEGIN ')) return `${what} has no "-----BEGIN ...-----" marker - is it a PEM file?`;
+
+    const blocks = [...text.matchAll(PEM_BLOCK_REGEX)];
+    const beginCount = text.split('-----BEGIN ').length - 1;
+    if (blocks.length === 0 || blocks.length !== beginCount) return `${what} has a BEGIN marker without a matching END marker.`;
+
+    for (const [, beginLabel, body, endLabel] of blocks) {
+        if (beginLabel !== endLabel) return `${what} has mismatched markers: BEGIN ${beginLabel} / END ${endLabel}.`;
+        // Legacy encrypted keys carry "Proc-Type:"/"DEK-Info:" headers before an empty line
+        const base64 = (body!.includes(':') ? body!.split(/\r?\n\r?\n/).slice(1).join('') : body!).replace(/\s+/g, '');
+        if (!base64 || !BASE64_BODY_REGEX.test(base64) || base64.length % 4 !== 0) {
+            return `${what} block "${beginLabel}" does not contain valid base64 data.`;
+        }
+    }
+
+    if (!blocks.some(([, label]) => acceptedLabels.includes(label!))) {
+        return `${what} contains ${blocks.map(([, label]) => label).join(', ')} but ${acceptedLabels.join(' or ')} was expected.`;
+    }
+    return null;
+}
+
+export const PRIVATE_KEY_PEM_LABELS = ['PRIVATE KEY', 'RSA PRIVATE KEY', 'EC PRIVATE KEY', 'ENCRYPTED PRIVATE KEY'];
+export const CERTIFICATE_PEM_LABELS = ['CERTIFICATE'];
@@ -0,0 +1,58 @@
+import { describe, expect, mock, test } from 'bun:test';
+import type { SearchRequest } from './search';
+
+// buildSearchQueries only builds SQL - keep the database and the log out of it
+mock.module('../initialization/db', () => ({ db: {} }));
+mock.module('../functionalities/log/db', () => ({ Log: { warn: async () => {}, error: async () => {} } }));
+const { buildSearchQueries } = await import('./search');
+
+const FIELDS = ['title', 'creator', 'active'];
+const whereOf = (sql: string) => sql.slice(sql.indexOf('WHERE'), sql.indexOf('ORDER BY')).trim();
+
+const build = (request: Partial<SearchRequest>) =>
+    buildSearchQueries('notes', { query: [], page: 1, pageSize: 10, ...request }, FIELDS);
+
+describe('buildSearchQueries groups', () => {
+    test('a flat query is ANDed', async () => {
+        const { dataQuery } = await build({
+            query: [
+                { field: 'title', condition: 'FRAGMENT', value: 'X', not: false },
+                { field: 'creator', condition: 'EQ', value: 'Y', not: false },
+            ],
+        });
+        expect(whereOf(dataQuery.sql)).toBe('WHERE notes_main.title LIKE ? AND notes_main.creator = ?');
+        expect(dataQuery.params).toEqual(['%X%', 'Y', 10, 0]);
+    });
+
+    test('an OR group is parenthesized and ANDed with the query', async () => {
+        const { dataQuery, countQuery } = await build({
+            query: [{ field: 'active', condition: 'EQ', value: true, not: false }],
+            groups: [{
+                logic: 'OR',
+                query: [
+                    { field: 'title', condition: 'FRAGMENT', value: 'X', not: false },
+                    { field: 'creator', condition: 'FRAGMENT', value: 'Y', not: false },
+                ],
+            }],
+        });
+        expect(whereOf(dataQuery.sql)).toBe('WHERE notes_main.active = ? AND ((notes_main.title LIKE ?) OR (notes_main.creator LIKE ?))');
+        expect(dataQuery.params).toEqual([1, '%X%', '%Y%', 10, 0]);
+        expect(countQuery.params).toEqual([1, '%X%', '%Y%']);
+    });
+
+    test('groups are ANDed with each other', async () => {
+        const { dataQuery } = await build({
+            groups: [
+                { logic: 'OR', query: [{ field: 'title', condition: 'EQ', value: 'A', not: false }, { field: 'title', condition: 'EQ', value: 'B', not: false }] },
+                { logic: 'AND', query: [{ field: 'creator', condition: 'EQ', value: 'C', not: true }] },
+            ],
+        });
+        expect(whereOf(dataQuery.sql)).toBe('WHERE ((notes_main.title = ?) OR (notes_main.title = ?)) AND ((NOT (notes_main.creator = ?)))');
+        expect(dataQuery.params).toEqual(['A', 'B', 'C', 10, 0]);
+    });
+
+    test('an empty group adds no condition', async () => {
+        const { dataQuery } = await build({ groups: [{ logic: 'OR', query: [] }] });
+        expect(dataQuery.sql).not.toContain('WHERE');
+    });
+});
@@ -35,6 +35,14 @@ interface SearchQueryElement_Contains { // For text fragment
     value: string;
 }
 
+// Explicit null checks (EQ with a null value also maps to IS NULL, kept for older clients)
+interface SearchQueryElement_NullCheck {
+    field: string;
+    not: boolean;
+    condition: "IS_NULL" | "IS_NOT_NULL";
+    value?: null; // Not used
+}
+
 // New conditions for signature path search
 interface SearchQueryElement_SignatureStartsWith {
     field: string; // e.g., "descriptiveSignature"
@@ -55,15 +63,31 @@ export type SearchQueryElement =
     | SearchQueryElement_Primitive
     | SearchQueryElement_AnyOf
     | SearchQueryElement_Contains
+    | SearchQueryElement_NullCheck
     | SearchQueryElement_SignatureStartsWith // Added new type
     | SearchQueryElement_SignatureContainsSequence; // Added new type
 
 export type SearchQuery = SearchQueryElement[];
 
+export type SearchLogic = "AND" | "OR";
+
+// Conditions combined with their own operator; the group as a whole is ANDed with `query` and the other groups
+export interface SearchQueryGroup {
+    logic: SearchLogic;
+    query: SearchQuery;
+}
+
+export interface SortElement {
+    field: string;
+    direction: "ASC" | "DESC";
+}
+
 export interface SearchRequest {
     query: SearchQuery;
     page: number;
     pageSize: number;
+    sort?: SortElement[]; // Applied in order; fields must be listed in the table's sortableFields
+    groups?: SearchQueryGroup[]; // Optional AND/OR groups - a request with only `query` keeps its plain AND meaning
 }
 
 export interface SearchResponse<T> {
@@ -82,14 +106,18 @@ interface BuildSearchQueriesResult {
     alias: string;
 }
 
+// Alias of the main table in the generated queries
+export const searchTableAlias = (table: string) => `${table}_main`;
+
 export async function buildSearchQueries<T extends Record<string, any>>(
     table: string,
     searchRequest: SearchRequest,
     allowedFields: (keyof T | string)[], // Allow string for potential JOINed fields like ownerLogin
     fieldHandlers?: Record<string, SearchOnCustomFieldHandler<T>>,
-    primaryKeyField: string = `${table.slice(0, -1)}Id`
+    primaryKeyField: string = `${table.slice(0, -1)}Id`,
+    sortableFields: Record<string, string> = {} // Sortable field -> SQL expression to order by
 ): Promise<BuildSearchQueriesResult> {
-    const mainTableAlias = `${table}_main`;
+    const mainTableAlias = searchTableAlias(table);
     const whereConditions: string[] = [];
     const allParams: any[] = [];
     const joinClauses = new Set<string>();
@@ -97,17 +125,15 @@ export async function buildSearchQueries<T extends Record<string, any>>(
     const pageSize = Math.max(1, searchRequest.pageSize || 10);
     const offset = (page - 1) * pageSize;
 
-    for (const element of searchRequest.query) {
+    // SQL condition for one query element (custom handler or the default per-condition logic), null when it's skipped
+    const buildElementCondition = async (element: SearchQueryElement): Promise<{ condition: string; params: any[] } | null> => {
         const field = element.field;
 
         if (fieldHandlers?.[field]) {
             const handlerResult = await fieldHandlers[field](element, mainTableAlias);
-            if (handlerResult) {
-                if (handlerResult.joinClause) joinClauses.add(handlerResult.joinClause);
-                if (handlerResult.whereCondition) whereConditions.push(handlerResult.whereCondition);
-                allParams.push(...handlerResult.params);
-            }
-            continue;
+            if (!handlerResult) return null;
+            if (handlerResult.joinClause) joinClauses.add(handlerResult.joinClause);
+            return handlerResult.whereCondition ? { condition: handlerResult.whereCondition, params: handlerResult.params } : null;
         }
 
         // Allow strings in allowedFields for fields like 'ownerLogin' that might come from a JOIN handled elsewhere
@@ -151,6 +177,10 @@ export async function buildSearchQueries<T extends Record<string, any>>(
                  baseCondition = `${qualifiedField} LIKE ?`;
                  elementParams.push(`%${element.value}%`);
                  break;
+            case "IS_NULL":
+            case "IS_NOT_NULL":
+                 baseCondition = `${qualifiedField} ${element.condition === "IS_NULL" ? "IS NULL" : "IS NOT NULL"}`;
+                 break;
             // STARTS_WITH and CONTAINS_SEQUENCE are expected to be handled by custom field handlers
             case "STARTS_WITH":
             case "CONTAINS_SEQUENCE":
@@ -164,16 +194,45 @@ export async function buildSearchQueries<T extends Record<string, any>>(
                  break;
         }
 
-        if (needsHandling && baseCondition) {
-            if (element.not) baseCondition = `NOT (${baseCondition})`;
-            whereConditions.push(baseCondition);
-            allParams.push(...elementParams);
+        if (!needsHandling || !baseCondition) return null;
+        if (element.not) baseCondition = `NOT (${baseCondition})`;
+        return { condition: baseCondition, params: elementParams };
+    };
+
+    for (const element of searchRequest.query) {
+        const built = await buildElementCondition(element);
+        if (!built) continue;
+        whereConditions.push(built.condition);
+        allParams.push(...built.params);
+    }
+
+    // Each group becomes one parenthesized condition joined by its own operator
+    for (const group of searchRequest.groups ?? []) {
+        const operator = group.logic === 'OR' ? ' OR ' : ' AND ';
+        const groupConditions: string[] = [];
+        for (const element of group.query) {
+            const built = await buildElementCondition(element);
+            if (!built) continue;
+            groupConditions.push(`(${built.condition})`);
+            allParams.push(...built.params);
         }
+        if (groupConditions.length > 0) whereConditions.push(`(${groupConditions.join(operator)})`);
     }
 
     const joins = Array.from(joinClauses).join('\n');
     const whereClause = whereConditions.length > 0 ? `WHERE ${whereConditions.join(' AND ')}` : '';
-    const orderBy = `ORDER BY ${mainTableAlias}.${primaryKeyField} DESC`; // Consider making ORDER BY configurable
+    // Requested sort first (only whitelisted fields - the expressions go into the SQL), primary key as tiebreaker for stable pages
+    const orderByTerms: string[] = [];
+    for (const sort of searchRequest.sort ?? []) {
+        const expression = sortableFields[sort.field];
+        if (!expression) {
+            await Log.warn(`Sort field '${sort.field}' is not sortable, ignoring.`, 'system', 'search', { field: sort.field, table });
+            continue;
+        }
+        orderByTerms.push(`${expression} ${sort.direction === 'ASC' ? 'ASC' : 'DESC'}`);
+    }
+    orderByTerms.push(`${mainTableAlias}.${primaryKeyField} DESC`);
+    const orderBy = `ORDER BY ${orderByTerms.join(', ')}`;
 
     // Adjust SELECT columns based on potential JOINs (only ownerLogin handled explicitly for now)
     // createdBy/updatedBy are now direct fields, no JOIN needed for them.
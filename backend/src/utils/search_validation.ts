@@ -30,6 +30,14 @@ const fragmentSearchSchema = z.object({
     value: z.string(),
 });
 
+// Schema for explicit null checks (no value)
+const nullCheckSearchSchema = z.object({
+    field: z.string().min(1),
+    not: z.boolean().optional().default(false),
+    condition: z.enum(["IS_NULL", "IS_NOT_NULL"]),
+    value: z.null().optional(),
+});
+
 // --- Custom Field Conditions for Signature Paths ---
 // This schema now ONLY handles conditions specific to signature paths that primitiveSearchSchema doesn't cover.
 const signaturePathSpecificSearchSchema = z.object({
@@ -45,15 +53,27 @@ export const searchQueryElementSchema = z.discriminatedUnion("condition", [
     primitiveSearchSchema, // Handles basic EQ, GT, LT etc.
     anyOfSearchSchema,     // Handles ANY_OF
     fragmentSearchSchema,  // Handles FRAGMENT (LIKE)
+    nullCheckSearchSchema, // Handles IS_NULL, IS_NOT_NULL
     signaturePathSpecificSearchSchema, // Handles path-specific STARTS_WITH, CONTAINS_SEQUENCE
 ]);
 // Note: An 'EQ' condition with an array value (for exact path match) will be validated
 // by primitiveSearchSchema (due to value: z.any()). The backend handler MUST check
 // the type of `value` when processing an 'EQ' condition on the 'descriptiveSignature' field.
 
+// AND/OR group of conditions, ANDed with the top-level query
+export const searchQueryGroupSchema = z.object({
+    logic: z.enum(["AND", "OR"]),
+    query: z.array(searchQueryElementSchema),
+});
+
 // Full Search Request Schema
 export const searchRequestSchema = z.object({
     query: z.array(searchQueryElementSchema),
     page: z.number().int().positive().optional().default(1),
     pageSize: z.number().int().min(-1).optional().default(10),
+    sort: z.array(z.object({
+        field: z.string().min(1),
+        direction: z.enum(["ASC", "DESC"]),
+    })).optional(),
+    groups: z.array(searchQueryGroupSchema).optional(),
 });
\ No newline at end of file
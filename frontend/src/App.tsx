@@ -1,6 +1,7 @@
 import React from 'react';
 import { Routes, Route, Navigate, useLocation, useNavigate } from 'react-router-dom';
 import { AuthProvider } from '@/context/AuthContext';
+import { ConfirmDialogProvider } from '@/context/ConfirmDialogContext';
 import { useAuth } from '@/hooks/useAuth';
 import Layout from '@/components/layout/Layout';
 import LoginForm from '@/components/auth/LoginForm';
@@ -14,6 +15,7 @@ import ArchivePage from '@/components/archive/ArchivePage';
 import AdminPage from '@/components/admin/AdminPage';
 import ProtectedRoute from '@/components/auth/ProtectedRoute';
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import SingleLinePasteGuard from '@/components/shared/SingleLinePasteGuard';
 // UserRole includes 'employee', 'user'
 import type { UserRole } from '../../backend/src/functionalities/user/models';
 import { t } from '@/translations/utils'; // Import translation utility
@@ -100,7 +102,10 @@ function AppContent() {
 function App() {
     return (
         <AuthProvider>
-            <AppContent />
+            <ConfirmDialogProvider>
+                <SingleLinePasteGuard />
+                <AppContent />
+            </ConfirmDialogProvider>
         </AuthProvider>
     );
 }
@@ -1,4 +1,4 @@
-import React from 'react';
+import React, { useState } from 'react';
 import { Tabs, TabsContent, TabsList, TabsTrigger } from "@/components/ui/tabs";
 import UserManagement from './UserManagement';
 import SettingsForm from './SettingsForm';
@@ -6,27 +6,31 @@ import SettingsForm from './SettingsForm';
 import LogViewer from './LogViewer';
 import DatabaseManagement from './DatabaseManagement';
 import { useAuth } from '@/hooks/useAuth';
-import { Card, CardContent, CardDescription, CardHeader, CardTitle } from '@/components/ui/card';
+import AccessDenied from '@/components/shared/AccessDenied';
+import { loadViewState, saveViewState } from '@/lib/viewState';
 import { t } from '@/translations/utils'; // Import translation utility
 
+const ADMIN_TABS = ['users', 'settings', 'database', 'logs'] as const;
+type AdminTab = typeof ADMIN_TABS[number];
+
+// The last opened tab is restored; an unknown persisted value falls back to the Users tab
+const loadAdminTab = (): AdminTab => {
+  const stored = loadViewState().adminTab;
+  return ADMIN_TABS.includes(stored as AdminTab) ? stored as AdminTab : 'users';
+};
+
 const AdminPage: React.FC = () => {
   const { user, preferredLanguage } = useAuth(); // Get preferredLanguage from context
+  const [activeTab, setActiveTab] = useState<AdminTab>(loadAdminTab);
+
+  const handleTabChange = (tab: string) => {
+    setActiveTab(tab as AdminTab);
+    saveViewState({ adminTab: tab });
+  };
 
+  // Guard in case the page is reached without the route guard (e.g. a role change mid-session)
   if (user?.role !== 'admin') {
-      return (
-          <div className='p-4 md:p-6'>
-              <Card className='border-destructive'>
-                  <CardHeader>
-                      {/* Use translated title */}
-                      <CardTitle className='text-destructive'>{t('accessDeniedTitle', preferredLanguage)}</CardTitle>
-                  </CardHeader>
-                  <CardContent>
-                      {/* Use translated message */}
-                      <p>{t('accessDeniedMessage', preferredLanguage)}</p>
-                  </CardContent>
-              </Card>
-          </div>
-      );
+      return <AccessDenied />;
   }
 
   return (
@@ -39,11 +43,11 @@ const AdminPage: React.FC = () => {
         </div>
       </div>
 
-      <Tabs defaultValue="users" className="w-full">
+      <Tabs value={activeTab} onValueChange={handleTabChange} className="w-full">
         <div className="overflow-x-auto pb-1 border-b">
             {/* Removed SSL Tab */}
             {/* Use translated tab labels */}
-            <TabsList className='inline-flex w-auto min-w-full'>
+            <TabsList className='inline-flex w-auto min-w-full' data-help="admin-tabs">
                 <TabsTrigger value="users">{t('userManagementTab', preferredLanguage)}</TabsTrigger>
                 <TabsTrigger value="settings">{t('appSettingsTab', preferredLanguage)}</TabsTrigger>
                 <TabsTrigger value="database">{t('databaseTab', preferredLanguage)}</TabsTrigger>
@@ -6,7 +6,7 @@ import LoadingSpinner from '@/components/shared/LoadingSpinner';
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
 import { useAuth } from '@/hooks/useAuth';
 import api from '@/lib/api';
-import { toast } from "sonner";
+import { toast } from '@/lib/toast';
 // Removed Upload and AlertTriangle icons, kept Download
 import { Download } from 'lucide-react';
 // Removed Alert related imports
@@ -1,33 +1,70 @@
-import React, { useState, useEffect, useCallback } from 'react';
+import React, { useState, useEffect, useCallback, useRef, useMemo } from 'react';
 import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from "@/components/ui/table";
 import { Badge } from '@/components/ui/badge';
 import type { VariantProps } from 'class-variance-authority'; // Import directly from cva
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import LoadingStatus from '@/components/shared/LoadingStatus';
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
 import SearchBar from '@/components/shared/SearchBar';
-import { Pagination } from '@/components/shared/Pagination';
+import { Pagination, isPastLastPage } from '@/components/shared/Pagination';
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card"; // Import Card components
 import { useAuth } from '@/hooks/useAuth';
+import { useConfirm } from '@/hooks/useConfirm';
 import api from '@/lib/api';
+import { toCsv, downloadTextFile } from '@/lib/csv';
 import type { LogEntry } from '../../../../backend/src/functionalities/log/models';
 import type { SearchRequest, SearchResponse } from '../../../../backend/src/utils/search';
+import { LOG_ACTIONS, logActionGroup, parseLogActivity, type LogAction } from '@/lib/logActivity';
 import { cn } from '@/lib/utils'; // Import cn
 import { Button } from '@/components/ui/button'; // Added Button
 import { Input } from '@/components/ui/input'; // Added Input
 import { AlertDialog, AlertDialogAction, AlertDialogCancel, AlertDialogContent, AlertDialogDescription, AlertDialogFooter, AlertDialogHeader, AlertDialogTitle, AlertDialogTrigger } from "@/components/ui/alert-dialog"; // Added AlertDialog
-import { toast } from "sonner"; // Added toast
-import { Trash2, Info } from 'lucide-react'; // Removed ChevronsDownUp, kept others
+import { toast } from '@/lib/toast'; // Added toast
+import { Trash2, Info, Download, RefreshCw } from 'lucide-react'; // Removed ChevronsDownUp, kept others
+import { DropdownMenu, DropdownMenuContent, DropdownMenuItem, DropdownMenuTrigger } from '@/components/ui/dropdown-menu';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/ui/select';
+import { ToggleGroup, ToggleGroupItem } from '@/components/ui/toggle-group';
+import type { AppTranslationKey } from '@/translations/models';
 // --- Import ScrollArea ---
 import { ScrollArea } from "@/components/ui/scroll-area";
 // -------------------------
 import { Dialog, DialogTrigger, DialogContent, DialogHeader, DialogTitle as DataDialogTitle, DialogFooter } from '@/components/ui/dialog'; // Added DialogFooter
+import Timestamp from '@/components/shared/Timestamp';
 import { t } from '@/translations/utils'; // Import translation utility
 
 // Define the type alias for badge variants
 type BadgeVariant = VariantProps<typeof Badge>['variant'];
 
+// Export fetches the filtered logs page by page; large exports need confirming and are capped
+const LOG_EXPORT_PAGE_SIZE = 500;
+const LOG_EXPORT_CONFIRM_THRESHOLD = 5000;
+const LOG_EXPORT_MAX_ENTRIES = 50000;
+
+type LevelFilter = 'all' | LogEntry['level'];
+type ActionFilter = 'all' | LogAction;
+type LogViewMode = 'entries' | 'activity'; // Activity: only changes, as timestamp / user / action / target
+const LOG_LEVELS: LogEntry['level'][] = ['info', 'warn', 'error'];
+const AUTO_REFRESH_INTERVALS = [0, 10, 30, 60]; // Seconds, 0 = off
+const MESSAGE_FILTER_DEBOUNCE_MS = 400;
+
+const actionLabelKeys: Record<LogAction, AppTranslationKey> = {
+    create: 'logActionCreate',
+    update: 'logActionUpdate',
+    delete: 'logActionDelete',
+    move: 'logActionMove',
+    reindex: 'logActionReindex',
+};
+const actionBadgeVariant: Record<LogAction, BadgeVariant> = {
+    create: 'default',
+    update: 'secondary',
+    delete: 'destructive',
+    move: 'outline',
+    reindex: 'outline',
+};
+
 const LogViewer: React.FC = () => {
     const { token, preferredLanguage } = useAuth(); // Get preferredLanguage
+    const confirm = useConfirm();
     const [logs, setLogs] = useState<LogEntry[]>([]);
     const [isLoading, setIsLoading] = useState(false);
     const [error, setError] = useState<string | null>(null);
@@ -39,6 +76,17 @@ const LogViewer: React.FC = () => {
     const [totalLogs, setTotalLogs] = useState(0);
     const [totalPages, setTotalPages] = useState(1);
 
+    // Quick filters, combined with the search bar query
+    const [levelFilter, setLevelFilter] = useState<LevelFilter>('all');
+    const [messageInput, setMessageInput] = useState('');
+    const [messageFilter, setMessageFilter] = useState(''); // Debounced messageInput
+    const [userInput, setUserInput] = useState('');
+    const [userFilter, setUserFilter] = useState(''); // Debounced userInput, exact login
+    const [actionFilter, setActionFilter] = useState<ActionFilter>('all');
+    const [viewMode, setViewMode] = useState<LogViewMode>('entries');
+    const [autoRefreshSeconds, setAutoRefreshSeconds] = useState(0);
+    const [lastRefreshedAt, setLastRefreshedAt] = useState<Date | null>(null);
+
     // --- NEW: State for Purge ---
     const [purgeDays, setPurgeDays] = useState<number>(7);
     const [isPurging, setIsPurging] = useState(false);
@@ -51,36 +99,82 @@ const LogViewer: React.FC = () => {
     const [isDataDialogOpen, setIsDataDialogOpen] = useState(false);
     // -------------------------------
 
-    // Fetch/Search Logs function
-    const fetchLogs = useCallback(async (page = currentPage, query = searchQuery) => {
+    // --- Export state ---
+    const [exportProgress, setExportProgress] = useState<{ done: number, total: number } | null>(null);
+    const exportCancelledRef = useRef(false);
+
+    const effectiveQuery = useMemo<SearchRequest['query']>(() => [
+        ...searchQuery,
+        ...(levelFilter !== 'all' ? [{ field: 'level', condition: 'EQ' as const, value: levelFilter, not: false }] : []),
+        ...(messageFilter ? [{ field: 'message', condition: 'FRAGMENT' as const, value: messageFilter, not: false }] : []),
+        ...(userFilter ? [{ field: 'userId', condition: 'EQ' as const, value: userFilter, not: false }] : []),
+    ], [searchQuery, levelFilter, messageFilter, userFilter]);
+
+    // An action matches any of its message fragments; the activity view shows entries of any action
+    const effectiveGroups = useMemo<SearchRequest['groups']>(() => {
+        if (actionFilter !== 'all') return [logActionGroup([actionFilter])];
+        return viewMode === 'activity' ? [logActionGroup(LOG_ACTIONS)] : undefined;
+    }, [actionFilter, viewMode]);
+
+    // Fetch/Search Logs function (a background refresh keeps the table on screen)
+    const fetchLogs = useCallback(async (page = currentPage, query = effectiveQuery, background = false) => {
         if (!token) return;
-        setIsLoading(true);
+        if (!background) setIsLoading(true);
         setError(null);
         try {
             const searchRequest: SearchRequest = {
                 query: query,
+                groups: effectiveGroups,
                 page: page,
                 pageSize: pageSize,
             };
             const response = await api.searchLogs(searchRequest, token);
+            if (isPastLastPage(response)) { setCurrentPage(response.totalPages); return; } // Refetches the last page
             setLogs(response.data);
             setTotalLogs(response.totalSize);
             setTotalPages(response.totalPages);
             setCurrentPage(response.page); // Update current page from response
+            setLastRefreshedAt(new Date());
         } catch (err: any) {
             setError(err.message || 'Failed to fetch logs');
             setLogs([]); // Clear logs on error
             setTotalLogs(0);
             setTotalPages(1);
         } finally {
-            setIsLoading(false);
+            if (!background) setIsLoading(false);
         }
-    }, [token, pageSize, currentPage, searchQuery]); // Ensure dependencies are correct
+    }, [token, pageSize, currentPage, effectiveQuery, effectiveGroups]); // Ensure dependencies are correct
 
     // Fetch logs on mount and when dependencies change
     useEffect(() => {
-        fetchLogs(currentPage, searchQuery);
-    }, [fetchLogs, currentPage, searchQuery]);
+        fetchLogs(currentPage, effectiveQuery);
+    }, [fetchLogs, currentPage, effectiveQuery]);
+
+    useEffect(() => {
+        const timer = setTimeout(() => {
+            const trimmedMessage = messageInput.trim();
+            const trimmedUser = userInput.trim();
+            if (trimmedMessage !== messageFilter || trimmedUser !== userFilter) {
+                setMessageFilter(trimmedMessage);
+                setUserFilter(trimmedUser);
+                setCurrentPage(1);
+            }
+        }, MESSAGE_FILTER_DEBOUNCE_MS);
+        return () => clearTimeout(timer);
+    }, [messageInput, messageFilter, userInput, userFilter]);
+
+    // Auto-refresh: re-runs the current search once the interval has passed since the last refresh
+    // (skipped while the tab is hidden or another request/export is running)
+    const isBusy = isLoading || isPurging || !!exportProgress;
+    useEffect(() => {
+        if (autoRefreshSeconds <= 0 || isBusy) return;
+        const timer = setInterval(() => {
+            if (document.hidden) return;
+            if (lastRefreshedAt && Date.now() - lastRefreshedAt.getTime() < autoRefreshSeconds * 1000) return;
+            fetchLogs(currentPage, effectiveQuery, true);
+        }, 1000);
+        return () => clearInterval(timer);
+    }, [autoRefreshSeconds, isBusy, lastRefreshedAt, fetchLogs, currentPage, effectiveQuery]);
 
 
    const handleSearch = (newQuery: SearchRequest['query']) => {
@@ -88,6 +182,22 @@ const LogViewer: React.FC = () => {
        setCurrentPage(1); // Reset page on new search
    };
 
+   const handleLevelFilterChange = (value: string) => {
+       setLevelFilter(value as LevelFilter);
+       setCurrentPage(1);
+   };
+
+   const handleActionFilterChange = (value: string) => {
+       setActionFilter(value as ActionFilter);
+       setCurrentPage(1);
+   };
+
+   const handleViewModeChange = (value: string) => {
+       if (!value) return; // Keep one mode selected
+       setViewMode(value as LogViewMode);
+       setCurrentPage(1);
+   };
+
    const handlePageChange = (newPage: number) => {
        setCurrentPage(newPage);
    };
@@ -103,7 +213,7 @@ const LogViewer: React.FC = () => {
        try {
            const result = await api.purgeLogs(purgeDays, token);
            toast.success(t('logPurgeSuccessMessage', preferredLanguage, { count: result.deletedCount, days: purgeDays }));
-           await fetchLogs(1, searchQuery); // Refresh logs on page 1 after purge
+           await fetchLogs(1, effectiveQuery); // Refresh logs on page 1 after purge
            if (currentPage !== 1) setCurrentPage(1); // Go to page 1
        } catch (err: any) {
            const msg = err.message || "Failed to purge logs.";
@@ -116,6 +226,54 @@ const LogViewer: React.FC = () => {
    };
    // -------------------------
 
+   // Exports every entry matching the active filters (not just the visible page)
+   const handleExport = async (format: 'csv' | 'json') => {
+       if (!token || totalLogs === 0) return;
+       const total = Math.min(totalLogs, LOG_EXPORT_MAX_ENTRIES);
+       if (totalLogs > LOG_EXPORT_CONFIRM_THRESHOLD) {
+           const message = totalLogs > LOG_EXPORT_MAX_ENTRIES
+               ? t('logExportCappedConfirm', preferredLanguage, { count: totalLogs, max: LOG_EXPORT_MAX_ENTRIES })
+               : t('logExportLargeConfirm', preferredLanguage, { count: totalLogs });
+           if (!await confirm({ message, confirmLabel: t('logExportButton', preferredLanguage), destructive: false })) return;
+       }
+       exportCancelledRef.current = false;
+       setExportProgress({ done: 0, total });
+       try {
+           const entries: LogEntry[] = [];
+           for (let page = 1; entries.length < total; page++) {
+               const response = await api.searchLogs({ query: effectiveQuery, groups: effectiveGroups, page, pageSize: LOG_EXPORT_PAGE_SIZE }, token);
+               if (exportCancelledRef.current) { toast.info(t('logExportCancelled', preferredLanguage)); return; }
+               entries.push(...response.data.slice(0, total - entries.length));
+               setExportProgress({ done: entries.length, total });
+               if (page >= response.totalPages || response.data.length === 0) break;
+           }
+           const fileName = `logs-${new Date().toISOString().slice(0, 10)}.${format}`;
+           if (format === 'json') {
+               const parsed = entries.map(entry => ({ ...entry, data: parseLogData(entry.data) }));
+               downloadTextFile(JSON.stringify(parsed, null, 2), fileName, 'application/json;charset=utf-8');
+           } else {
+               const rows = entries.map(entry => [
+                   new Date(entry.createdOn).toISOString(), entry.level, entry.userId, entry.category, entry.message,
+                   typeof entry.data === 'string' ? entry.data : entry.data == null ? '' : JSON.stringify(entry.data),
+               ]);
+               downloadTextFile(toCsv([['createdOn', 'level', 'userId', 'category', 'message', 'data'], ...rows]), fileName);
+           }
+           toast.success(t('logExportSuccess', preferredLanguage, { count: entries.length, fileName }));
+       } catch (err: any) {
+           const msg = err.message || t('logExportFailed', preferredLanguage);
+           toast.error(t('errorMessageTemplate', preferredLanguage, { message: msg }));
+           console.error("Export Logs Error:", err);
+       } finally {
+           setExportProgress(null);
+       }
+   };
+
+   // Stored data is usually a JSON string - export it as structured data when possible
+   const parseLogData = (data: any) => {
+       if (typeof data !== 'string') return data ?? null;
+       try { return JSON.parse(data); } catch { return data; }
+   };
+
    // --- NEW: Show Data Handler ---
    const handleShowData = (dataString: string | null | undefined) => {
        if (dataString) {
@@ -180,8 +338,96 @@ const LogViewer: React.FC = () => {
                      isLoading={isLoading || isPurging}
                  />
 
+                 {/* Quick filters & auto-refresh */}
+                 <div className="flex flex-wrap items-center gap-2">
+                     <Select value={levelFilter} onValueChange={handleLevelFilterChange}>
+                         <SelectTrigger className="w-[160px] h-9" aria-label={t('logsLevelColumn', preferredLanguage)}>
+                             <SelectValue />
+                         </SelectTrigger>
+                         <SelectContent>
+                             <SelectItem value="all">{t('logLevelFilterAll', preferredLanguage)}</SelectItem>
+                             {LOG_LEVELS.map(level => <SelectItem key={level} value={level}>{getLogLevelText(level)}</SelectItem>)}
+                         </SelectContent>
+                     </Select>
+                     <Input
+                         type="search"
+                         value={messageInput}
+                         onChange={(e) => setMessageInput(e.target.value)}
+                         placeholder={t('logMessageFilterPlaceholder', preferredLanguage)}
+                         aria-label={t('logMessageFilterPlaceholder', preferredLanguage)}
+                         className="h-9 max-w-xs"
+                     />
+                     <Input
+                         type="search"
+                         value={userInput}
+                         onChange={(e) => setUserInput(e.target.value)}
+                         placeholder={t('logUserFilterPlaceholder', preferredLanguage)}
+                         aria-label={t('logUserFilterPlaceholder', preferredLanguage)}
+                         className="h-9 w-40"
+                     />
+                     <Select value={actionFilter} onValueChange={handleActionFilterChange}>
+                         <SelectTrigger className="w-[170px] h-9" aria-label={t('logsActionColumn', preferredLanguage)}>
+                             <SelectValue />
+                         </SelectTrigger>
+                         <SelectContent>
+                             <SelectItem value="all">{t('logActionFilterAll', preferredLanguage)}</SelectItem>
+                             {LOG_ACTIONS.map(action => <SelectItem key={action} value={action}>{t(actionLabelKeys[action], preferredLanguage)}</SelectItem>)}
+                         </SelectContent>
+                     </Select>
+                     <ToggleGroup type="single" variant="outline" size="sm" value={viewMode} onValueChange={handleViewModeChange}
+                         aria-label={t('logViewModeLabel', preferredLanguage)} className="w-fit">
+                         <ToggleGroupItem value="entries" className="px-3">{t('logViewModeEntries', preferredLanguage)}</ToggleGroupItem>
+                         <ToggleGroupItem value="activity" className="px-3">{t('logViewModeActivity', preferredLanguage)}</ToggleGroupItem>
+                     </ToggleGroup>
+                     <div className="flex items-center gap-2 ml-auto">
+                         {lastRefreshedAt && (
+                             <span className="text-xs text-neutral-500">{t('logLastRefreshed', preferredLanguage, { time: lastRefreshedAt.toLocaleTimeString() })}</span>
+                         )}
+                         <Select value={String(autoRefreshSeconds)} onValueChange={(value) => setAutoRefreshSeconds(Number(value))}>
+                             <SelectTrigger className="w-[170px] h-9" aria-label={t('logAutoRefreshLabel', preferredLanguage)}>
+                                 <SelectValue />
+                             </SelectTrigger>
+                             <SelectContent>
+                                 {AUTO_REFRESH_INTERVALS.map(seconds => (
+                                     <SelectItem key={seconds} value={String(seconds)}>
+                                         {seconds === 0 ? t('logAutoRefreshOff', preferredLanguage) : t('logAutoRefreshEvery', preferredLanguage, { seconds })}
+                                     </SelectItem>
+                                 ))}
+                             </SelectContent>
+                         </Select>
+                         <Button variant="outline" size="icon" className="h-9 w-9" onClick={() => fetchLogs(currentPage, effectiveQuery)} disabled={isBusy} title={t('logRefreshNow', preferredLanguage)}>
+                             <RefreshCw className="h-4 w-4" />
+                         </Button>
+                     </div>
+                 </div>
+
                  {/* --- Purge Controls - Use light gray bg --- */}
                   <div className="flex flex-wrap items-center justify-end gap-2 p-2 border rounded-lg bg-neutral-50"> {/* Changed bg-muted to bg-neutral-50 */}
+                     {/* Export of all logs matching the current filters */}
+                     <div className="flex items-center gap-2">
+                         <DropdownMenu>
+                             <DropdownMenuTrigger asChild>
+                                 <Button variant="outline" size="sm" disabled={!!exportProgress || isLoading || totalLogs === 0}>
+                                     {exportProgress ? <LoadingSpinner size='sm' className='mr-2'/> : <Download className='mr-2 h-4 w-4'/>}
+                                     {t('logExportButton', preferredLanguage)}
+                                 </Button>
+                             </DropdownMenuTrigger>
+                             <DropdownMenuContent align="start">
+                                 <DropdownMenuItem onSelect={() => handleExport('csv')}>{t('logExportCsv', preferredLanguage, { count: totalLogs })}</DropdownMenuItem>
+                                 <DropdownMenuItem onSelect={() => handleExport('json')}>{t('logExportJson', preferredLanguage, { count: totalLogs })}</DropdownMenuItem>
+                             </DropdownMenuContent>
+                         </DropdownMenu>
+                         {exportProgress && (
+                             <>
+                                 <span className="text-sm text-neutral-600" role="status">
+                                     {t('logExportProgress', preferredLanguage, { done: exportProgress.done, total: exportProgress.total })}
+                                 </span>
+                                 <Button variant="ghost" size="sm" onClick={() => { exportCancelledRef.current = true; }}>
+                                     {t('cancelButton', preferredLanguage)}
+                                 </Button>
+                             </>
+                         )}
+                     </div>
                      {purgeError && <ErrorDisplay message={purgeError} className="mr-auto"/>}
                      <div className="flex items-center gap-2 ml-auto">
                          <span className="text-sm text-neutral-600">{t('purgeLogsOlderThanLabel', preferredLanguage)}</span> {/* Adjusted text color */}
@@ -227,10 +473,46 @@ const LogViewer: React.FC = () => {
                  {error && <ErrorDisplay message={error} />}
 
                  {/* Loading State */}
+                 <LoadingStatus isLoading={isLoading} label={t('logViewerTitle', preferredLanguage)} count={logs.length} />
                  {isLoading && <div className='flex justify-center py-10'><LoadingSpinner /></div>}
 
+                 {/* Activity Table: who changed what */}
+                 {!isLoading && !error && logs.length > 0 && viewMode === 'activity' && (
+                    <div className="border rounded-lg overflow-hidden">
+                        <Table>
+                            <TableHeader className='bg-white dark:bg-white'>
+                                <TableRow>
+                                    <TableHead className='w-[180px]'>{t('logsTimestampColumn', preferredLanguage)}</TableHead>
+                                    <TableHead className='w-[140px]'>{t('logsUserColumn', preferredLanguage)}</TableHead>
+                                    <TableHead className='w-[120px]'>{t('logsActionColumn', preferredLanguage)}</TableHead>
+                                    <TableHead className='w-[140px]'>{t('logsCategoryColumn', preferredLanguage)}</TableHead>
+                                    <TableHead>{t('logsTargetColumn', preferredLanguage)}</TableHead>
+                                </TableRow>
+                            </TableHeader>
+                            <TableBody>
+                                {logs.map((log) => {
+                                    const { action, target } = parseLogActivity(log);
+                                    return (
+                                        <TableRow key={log.id}>
+                                            <TableCell className='text-xs'><Timestamp value={log.createdOn} /></TableCell>
+                                            <TableCell className='text-xs font-medium'>{log.userId || <i className='text-neutral-500 not-italic'>{t('logUserSystem', preferredLanguage)}</i>}</TableCell>
+                                            <TableCell>
+                                                {action
+                                                    ? <Badge variant={actionBadgeVariant[action]}>{t(actionLabelKeys[action], preferredLanguage)}</Badge>
+                                                    : <span className='text-xs text-neutral-500'>-</span>}
+                                            </TableCell>
+                                            <TableCell className='text-xs'>{log.category || <i className='text-neutral-500 not-italic'>{t('logCategoryGeneral', preferredLanguage)}</i>}</TableCell>
+                                            <TableCell className='text-sm' title={log.message}>{target}</TableCell>
+                                        </TableRow>
+                                    );
+                                })}
+                            </TableBody>
+                        </Table>
+                    </div>
+                 )}
+
                  {/* Log Table */}
-                 {!isLoading && !error && logs.length > 0 && (
+                 {!isLoading && !error && logs.length > 0 && viewMode === 'entries' && (
                     // Wrap table in div for border and overflow handling
                     <div className="border rounded-lg overflow-hidden">
                         {/* REMOVED max-h and overflow-y-auto */}
@@ -252,7 +534,7 @@ const LogViewer: React.FC = () => {
                                         <React.Fragment key={log.id}>
                                             {/* TableRow uses light gray hover */}
                                             <TableRow>
-                                                <TableCell className='text-xs'>{new Date(log.createdOn).toLocaleString()}</TableCell>
+                                                <TableCell className='text-xs'><Timestamp value={log.createdOn} /></TableCell>
                                                 <TableCell>
                                                     <Badge variant={getBadgeVariant(log.level)} className='capitalize'>{getLogLevelText(log.level)}</Badge>
                                                 </TableCell>
@@ -284,12 +566,14 @@ const LogViewer: React.FC = () => {
                  )}
 
                  {/* Pagination */}
-                 {totalPages > 1 && (
+                 {!isLoading && !error && (
                      <div className="pt-4 flex justify-center"> {/* Add padding top */}
                          <Pagination
                               currentPage={currentPage}
                               totalPages={totalPages}
                               onPageChange={handlePageChange}
+                              totalItems={totalLogs}
+                              pageSize={pageSize}
                          />
                      </div>
                  )}
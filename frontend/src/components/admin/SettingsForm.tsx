@@ -16,20 +16,31 @@ import api from '@/lib/api';
 import { AppConfigKeys } from '../../../../backend/src/functionalities/config/models';
 import { cn } from '@/lib/utils';
 import { settingsSchema, SettingsFormData } from '@/lib/zodSchemas';
-import { toast } from "sonner";
-import { Trash2 } from 'lucide-react';
+import { SETTINGS_FORM_DEFAULTS, settingsFromConfig, settingsChanges } from '@/lib/settingsConfig';
+import { toast } from '@/lib/toast';
+import { Trash2, RefreshCw, Check, X } from 'lucide-react';
 import { AlertDialog, AlertDialogAction, AlertDialogCancel, AlertDialogContent, AlertDialogDescription, AlertDialogFooter, AlertDialogHeader, AlertDialogTitle, AlertDialogTrigger } from "@/components/ui/alert-dialog";
 import { t } from '@/translations/utils';
 // --- NEW: Import supportedLanguages ---
 import { supportedLanguages, type SupportedLanguage } from '@/translations/models';
 // ------------------------------------
 
+// Automatic retries of a failed settings fetch before only the manual "Retry" button is left
+const SETTINGS_FETCH_AUTO_RETRIES = 2;
+const SETTINGS_FETCH_RETRY_DELAY = 3000; // ms
+
 const SettingsForm: React.FC = () => {
     const { token, preferredLanguage } = useAuth();
     const [isLoading, setIsLoading] = useState(true);
     const [loadError, setLoadError] = useState<string | null>(null);
+    // "Attempted" (isLoading/loadError) is tracked separately from "successfully loaded"
+    const [hasLoaded, setHasLoaded] = useState(false);
+    const [autoRetryCount, setAutoRetryCount] = useState(0);
     const [saveStatus, setSaveStatus] = useState<'idle' | 'saving' | 'success' | 'error'>('idle');
     const [saveError, setSaveError] = useState<string | null>(null);
+    // Outcome of the last save per setting, shown next to the field so partial failures are clear
+    // (a failure stays visible, with the value kept for another try, until that value is edited)
+    const [saveResults, setSaveResults] = useState<Partial<Record<keyof SettingsFormData, { ok: true } | { ok: false, message: string, value: unknown }>>>({});
     const [originalHttpPort, setOriginalHttpPort] = useState<number | null>(null);
     const [originalHttpsPort, setOriginalHttpsPort] = useState<number | null>(null);
     const [originalKeyPath, setOriginalKeyPath] = useState<string | null>(null);
@@ -37,17 +48,14 @@ const SettingsForm: React.FC = () => {
     const [originalCaPath, setOriginalCaPath] = useState<string | null>(null);
     const [isClearConfirmOpen, setIsClearConfirmOpen] = useState(false);
     const [isClearingHttps, setIsClearingHttps] = useState(false);
+    // Keys the server returned no value for - the form shows a default for them, which is never saved unless edited
+    const [unsetKeys, setUnsetKeys] = useState<AppConfigKeys[]>([]);
 
-    const { register, handleSubmit, reset, setValue, formState: { errors, isDirty }, watch, control } = useForm<SettingsFormData>({ // Added control
+    const { register, handleSubmit, reset, resetField, formState: { errors, isDirty, dirtyFields }, watch, control } = useForm<SettingsFormData>({ // Added control
         resolver: zodResolver(settingsSchema),
-        defaultValues: {
-            [AppConfigKeys.DEFAULT_LANGUAGE]: 'en',
-            [AppConfigKeys.HTTP_PORT]: 8080,
-            [AppConfigKeys.HTTPS_PORT]: 8443,
-            [AppConfigKeys.HTTPS_KEY_PATH]: '',
-            [AppConfigKeys.HTTPS_CERT_PATH]: '',
-            [AppConfigKeys.HTTPS_CA_PATH]: '',
-        }
+        // Re-check a field when leaving it rather than on every keystroke (half-typed ports aren't errors yet)
+        reValidateMode: 'onBlur',
+        defaultValues: SETTINGS_FORM_DEFAULTS,
     });
 
     const watchedHttpPort = watch(AppConfigKeys.HTTP_PORT);
@@ -55,6 +63,7 @@ const SettingsForm: React.FC = () => {
     const watchedKeyPath = watch(AppConfigKeys.HTTPS_KEY_PATH);
     const watchedCertPath = watch(AppConfigKeys.HTTPS_CERT_PATH);
     const watchedCaPath = watch(AppConfigKeys.HTTPS_CA_PATH);
+    const watchedValues = watch();
 
     const isHttpsCurrentlyEnabled = !!watchedKeyPath && !!watchedCertPath;
 
@@ -89,124 +98,97 @@ const SettingsForm: React.FC = () => {
                  }))
             );
 
-            const newFormValues: Partial<SettingsFormData> = {};
-            results.forEach(result => {
+            // Don't fill the form with fallbacks - keep it unloaded so the fetch can be retried
+            const failedKeys = results.filter(result => result.error).map(result => Object.keys(result)[0]);
+            if (failedKeys.length > 0) {
+                setLoadError(t('settingsLoadFailedError', preferredLanguage, { keys: failedKeys.join(', ') }));
+                return;
+            }
+
+            const config = Object.fromEntries(results.map(result => {
                 const key = Object.keys(result)[0] as AppConfigKeys;
-                 if (result.error) {
-                     setLoadError(prev => prev ? `${prev}, ${key}` : `Failed to load ${key}`);
-                     switch(key) {
-                         case AppConfigKeys.HTTP_PORT: newFormValues[key] = 8080; break;
-                         case AppConfigKeys.HTTPS_PORT: newFormValues[key] = 8443; break;
-                         case AppConfigKeys.DEFAULT_LANGUAGE: newFormValues[key] = 'en'; break;
-                         default: newFormValues[key] = '';
-                     }
-                     return;
-                 }
-
-                const value = result[key];
-                console.log(`SettingsForm: Fetched ${key}:`, value, `(Type: ${typeof value})`);
-
-                switch (key) {
-                    case AppConfigKeys.HTTP_PORT:
-                        const httpPort = parseInt(String(value), 10);
-                        newFormValues[key] = !isNaN(httpPort) ? httpPort : 8080;
-                        setOriginalHttpPort(newFormValues[key] ?? null);
-                        break;
-                    case AppConfigKeys.HTTPS_PORT:
-                        const httpsPort = parseInt(String(value), 10);
-                        newFormValues[key] = !isNaN(httpsPort) ? httpsPort : 8443;
-                        setOriginalHttpsPort(newFormValues[key] ?? null);
-                        break;
-                    case AppConfigKeys.DEFAULT_LANGUAGE:
-                         // --- Ensure the fetched value is a valid language ---
-                         newFormValues[key] = typeof value === 'string' && supportedLanguages.includes(value as SupportedLanguage) ? value as SupportedLanguage : 'en';
-                         // ------------------------------------------------------
-                         break;
-                    case AppConfigKeys.HTTPS_KEY_PATH:
-                        newFormValues[key] = value && typeof value === 'string' && !value.includes('SET (Path Hidden)') ? value : '';
-                        setOriginalKeyPath(value);
-                        break;
-                    case AppConfigKeys.HTTPS_CERT_PATH:
-                        newFormValues[key] = value && typeof value === 'string' && !value.includes('SET (Path Hidden)') ? value : '';
-                        setOriginalCertPath(value);
-                        break;
-                    case AppConfigKeys.HTTPS_CA_PATH:
-                        newFormValues[key] = value && typeof value === 'string' && !value.includes('SET (Path Hidden)') ? value : '';
-                        setOriginalCaPath(value);
-                        break;
-                }
-            });
+                return [key, result[key]];
+            }));
+            console.log("SettingsForm: Fetched config:", config);
+            const { values: newFormValues, unsetKeys: newUnsetKeys } = settingsFromConfig(config);
+            setOriginalHttpPort(newFormValues[AppConfigKeys.HTTP_PORT]);
+            setOriginalHttpsPort(newFormValues[AppConfigKeys.HTTPS_PORT]);
+            setOriginalKeyPath(config[AppConfigKeys.HTTPS_KEY_PATH] ?? null);
+            setOriginalCertPath(config[AppConfigKeys.HTTPS_CERT_PATH] ?? null);
+            setOriginalCaPath(config[AppConfigKeys.HTTPS_CA_PATH] ?? null);
 
             console.log("SettingsForm: Resetting form with values:", newFormValues);
-            reset(newFormValues as SettingsFormData, { keepDirty: false, keepErrors: false });
+            reset(newFormValues, { keepDirty: false, keepErrors: false });
+            setUnsetKeys(newUnsetKeys);
+            setHasLoaded(true);
+            setAutoRetryCount(0);
 
         } catch (err: any) {
              console.error("SettingsForm: Unexpected error during Promise.all:", err);
              const msg = err.message || 'Failed to load one or more settings';
              setLoadError(msg); toast.error(msg);
-             reset(); setOriginalHttpPort(8080); setOriginalHttpsPort(8443);
-             setOriginalKeyPath(null); setOriginalCertPath(null); setOriginalCaPath(null);
         } finally {
             setIsLoading(false);
         }
-    }, [token, reset]);
+    }, [token, reset, preferredLanguage]);
 
     useEffect(() => {
         fetchSettings();
     }, [fetchSettings]);
 
+    // Retry a failed fetch automatically a few times (transient failures shouldn't block the form)
+    useEffect(() => {
+        if (!loadError || isLoading || autoRetryCount >= SETTINGS_FETCH_AUTO_RETRIES) return;
+        const timer = setTimeout(() => {
+            setAutoRetryCount(count => count + 1);
+            fetchSettings();
+        }, SETTINGS_FETCH_RETRY_DELAY);
+        return () => clearTimeout(timer);
+    }, [loadError, isLoading, autoRetryCount, fetchSettings]);
+
+    const handleRetryFetch = () => {
+        setAutoRetryCount(SETTINGS_FETCH_AUTO_RETRIES); // Manual retry - stop the automatic ones
+        fetchSettings();
+    };
+
     const onSubmit = async (data: SettingsFormData) => {
         console.log("SettingsForm: onSubmit called with data:", data);
         if (!token) return;
 
         setSaveStatus('saving');
         setSaveError(null);
+        setSaveResults({});
         let anyError = false;
         let restartRequiredBySave = false;
 
-        const savePromises = (Object.keys(data) as Array<keyof SettingsFormData>).map(key => {
-            let valueToSave: string | null | number = data[key]; // Allow number for port types
-             if ([AppConfigKeys.HTTPS_KEY_PATH, AppConfigKeys.HTTPS_CERT_PATH, AppConfigKeys.HTTPS_CA_PATH].includes(key as AppConfigKeys) && valueToSave === '') {
-                valueToSave = null;
-            }
-             // --- Convert number to string ONLY if it's not null ---
-             if (typeof valueToSave === 'number') {
-                 valueToSave = String(valueToSave);
-             } else if (valueToSave === null) {
-                 // Keep null as null for paths, but ensure ports/lang are not null before API call
-                 if (key === AppConfigKeys.DEFAULT_LANGUAGE || key === AppConfigKeys.HTTP_PORT || key === AppConfigKeys.HTTPS_PORT) {
-                      console.warn(`Attempting to save null for required config ${key}. This shouldn't happen due to validation.`);
-                      // Decide how to handle - skip or send default? Skip for now.
-                      return Promise.resolve({ key, skipped: true });
-                 }
-             }
-              // --- API expects string or null for value ---
-             if (valueToSave !== null && typeof valueToSave !== 'string') {
-                 console.error(`Invalid type for ${key} before API call: ${typeof valueToSave}`);
-                 return Promise.resolve({ key, skipped: true, error: new Error(`Invalid type for ${key}`) });
-             }
-
-             // --- Skip API call if value is undefined (shouldn't happen with zod) ---
-             if (valueToSave === undefined) return Promise.resolve({ key, skipped: true });
-
-
-             return api.setConfig(key as AppConfigKeys, valueToSave, token)
-                 .then(response => ({ key, success: true, message: response.message }))
-                 .catch(err => ({ key, success: false, error: err }));
-        });
+        // Only keys the user actually changed are sent, so values the form doesn't know
+        // (unset on the server, hidden paths shown as empty) are never overwritten
+        const changes = settingsChanges(data, dirtyFields);
+        if (changes.length === 0) {
+            setSaveStatus('idle');
+            toast.info(t('settingsNoChangesToSave', preferredLanguage));
+            return;
+        }
+
+        const savePromises = changes.map(({ key, value }) =>
+            api.setConfig(key, value, token)
+                .then(response => ({ key, success: true as const, message: response.message }))
+                .catch(err => ({ key, success: false as const, error: err })));
 
         try {
             const results = await Promise.all(savePromises);
 
+            const newSaveResults: typeof saveResults = {};
             results.forEach(result => {
-                 if (result.skipped) return;
                 if (!result.success) {
                     anyError = true;
                     const msg = result.error?.message || `Failed to save ${result.key}.`;
-                    setSaveError(prev => (prev ? `${prev}\n${msg}` : msg));
-                    toast.error(t('errorMessageTemplate', preferredLanguage, { message: `Failed to save ${result.key}: ${msg}` }));
+                    newSaveResults[result.key] = { ok: false, message: msg, value: data[result.key] };
                     console.error(`${result.key} Save Error:`, result.error);
                 } else {
+                     newSaveResults[result.key] = { ok: true };
+                     // Saved - no longer an unsaved edit (failed fields stay dirty with what the user typed)
+                     resetField(result.key, { defaultValue: data[result.key] });
                      if (result.message?.includes("Manual server restart required")) {
                          restartRequiredBySave = true;
                      } else if (result.message?.includes("HTTPS configuration reloaded") || result.message?.includes("HTTPS server stopped")) {
@@ -214,6 +196,7 @@ const SettingsForm: React.FC = () => {
                      }
                 }
             });
+            setSaveResults(newSaveResults);
 
             if (!anyError) {
                 setSaveStatus('success');
@@ -227,7 +210,9 @@ const SettingsForm: React.FC = () => {
                 setTimeout(() => setSaveStatus('idle'), 2500);
             } else {
                 setSaveStatus('error');
-                 await fetchSettings();
+                const failedCount = Object.values(newSaveResults).filter(result => !result.ok).length;
+                toast.error(t('settingsPartialSaveError', preferredLanguage, { failed: failedCount, total: changes.length }));
+                // No refetch here - it would reset the form and throw away the values that failed to save
             }
 
         } catch (err: any) {
@@ -236,7 +221,6 @@ const SettingsForm: React.FC = () => {
             toast.error(t('errorMessageTemplate', preferredLanguage, { message: msg }));
             setSaveStatus('error');
             console.error("SettingsForm: Unexpected Save error:", err);
-            await fetchSettings();
         }
     };
 
@@ -258,17 +242,48 @@ const SettingsForm: React.FC = () => {
          }
      };
 
-    if (isLoading) { return <div className='flex justify-center p-10'><LoadingSpinner /></div>; }
-    if (loadError && !isLoading) { return <ErrorDisplay message={`Error loading settings: ${loadError}`} />; }
+    // Result of the last save for one setting - hidden again once the field is edited
+    const renderSaveResult = (key: keyof SettingsFormData) => {
+        const result = saveResults[key];
+        if (!result || (result.ok ? dirtyFields[key] : watchedValues[key] !== result.value)) return null;
+        return result.ok
+            ? <p className="flex items-center gap-1 text-xs text-green-700"><Check className="h-3 w-3" /> {t('settingsFieldSaved', preferredLanguage)}</p>
+            : <p className="flex items-center gap-1 text-xs text-destructive"><X className="h-3 w-3" /> {t('settingsFieldSaveFailed', preferredLanguage, { message: result.message })}</p>;
+    };
+
+    const loadErrorBlock = loadError && (
+        <div className="space-y-2">
+            <ErrorDisplay message={loadError} />
+            <div className="flex items-center gap-3">
+                <Button type="button" variant="outline" size="sm" onClick={handleRetryFetch} disabled={isLoading}>
+                    <RefreshCw className="mr-2 h-4 w-4" /> {t('settingsRetryButton', preferredLanguage)}
+                </Button>
+                {autoRetryCount < SETTINGS_FETCH_AUTO_RETRIES && (
+                    <span className="text-xs text-muted-foreground">{t('settingsAutoRetrying', preferredLanguage, { attempt: autoRetryCount + 1, max: SETTINGS_FETCH_AUTO_RETRIES })}</span>
+                )}
+            </div>
+        </div>
+    );
+
+    // Until settings have been loaded once, show either the spinner or the error with a retry option
+    if (!hasLoaded) {
+        if (isLoading) { return <div className='flex justify-center p-10'><LoadingSpinner /></div>; }
+        if (loadError) { return loadErrorBlock; }
+    }
 
     return (
         <Card className="bg-white dark:bg-white text-neutral-900 dark:text-neutral-900">
             <CardHeader>
-                <CardTitle>{t('appSettingsTitleAdmin', preferredLanguage)}</CardTitle>
+                <CardTitle className="flex items-center gap-2">
+                    {t('appSettingsTitleAdmin', preferredLanguage)}
+                    {isDirty && <span className="h-2 w-2 rounded-full bg-orange-500" title={t('settingsUnsavedChanges', preferredLanguage)} />}
+                </CardTitle>
                 <CardDescription>{t('appSettingsDescriptionAdmin', preferredLanguage)}</CardDescription>
             </CardHeader>
             <CardContent>
                 <form onSubmit={handleSubmit(onSubmit)} className="space-y-6 max-w-2xl">
+                    {/* A refetch (e.g. after saving) failed - keep the form, offer retry */}
+                    {loadErrorBlock}
                     {saveError && <ErrorDisplay message={saveError} />}
 
                     {/* General Settings Section */}
@@ -298,6 +313,8 @@ const SettingsForm: React.FC = () => {
                                     )}
                                 />
                                 {errors[AppConfigKeys.DEFAULT_LANGUAGE] && <p className="text-xs text-destructive">{errors[AppConfigKeys.DEFAULT_LANGUAGE]?.message}</p>}
+                                {unsetKeys.includes(AppConfigKeys.DEFAULT_LANGUAGE) && !dirtyFields[AppConfigKeys.DEFAULT_LANGUAGE] && <p className="text-xs text-muted-foreground">{t('settingsValueNotSetHint', preferredLanguage)}</p>}
+                                {renderSaveResult(AppConfigKeys.DEFAULT_LANGUAGE)}
                              </div>
                          </div>
                     </div>
@@ -311,12 +328,16 @@ const SettingsForm: React.FC = () => {
                                 <Label htmlFor="http-port">{t('httpPortLabel', preferredLanguage)}</Label>
                                 <Input id="http-port" type="number" {...register(AppConfigKeys.HTTP_PORT, { valueAsNumber: true })} aria-invalid={!!errors[AppConfigKeys.HTTP_PORT]} className={cn(errors[AppConfigKeys.HTTP_PORT] && "border-destructive")} />
                                 {errors[AppConfigKeys.HTTP_PORT] && <p className="text-xs text-destructive">{errors[AppConfigKeys.HTTP_PORT]?.message}</p>}
+                                {unsetKeys.includes(AppConfigKeys.HTTP_PORT) && !dirtyFields[AppConfigKeys.HTTP_PORT] && <p className="text-xs text-muted-foreground">{t('settingsValueNotSetHint', preferredLanguage)}</p>}
+                                {renderSaveResult(AppConfigKeys.HTTP_PORT)}
                              </div>
                              {/* HTTPS Port */}
                              <div className="grid gap-1.5">
                                 <Label htmlFor="https-port">{t('httpsPortLabel', preferredLanguage)}</Label>
                                 <Input id="https-port" type="number" {...register(AppConfigKeys.HTTPS_PORT, { valueAsNumber: true })} aria-invalid={!!errors[AppConfigKeys.HTTPS_PORT]} className={cn(errors[AppConfigKeys.HTTPS_PORT] && "border-destructive")} />
                                 {errors[AppConfigKeys.HTTPS_PORT] && <p className="text-xs text-destructive">{errors[AppConfigKeys.HTTPS_PORT]?.message}</p>}
+                                {unsetKeys.includes(AppConfigKeys.HTTPS_PORT) && !dirtyFields[AppConfigKeys.HTTPS_PORT] && <p className="text-xs text-muted-foreground">{t('settingsValueNotSetHint', preferredLanguage)}</p>}
+                                {renderSaveResult(AppConfigKeys.HTTPS_PORT)}
                              </div>
                          </div>
                     </div>
@@ -359,16 +380,19 @@ const SettingsForm: React.FC = () => {
                                 <Label htmlFor="https-key-path">{t('httpsKeyPathLabel', preferredLanguage)}</Label>
                                 <Input id="https-key-path" {...register(AppConfigKeys.HTTPS_KEY_PATH)} placeholder="/path/to/your/private.key" aria-invalid={!!errors[AppConfigKeys.HTTPS_KEY_PATH]} className={cn(errors[AppConfigKeys.HTTPS_KEY_PATH] && "border-destructive")} />
                                 {errors[AppConfigKeys.HTTPS_KEY_PATH] && <p className="text-xs text-destructive">{errors[AppConfigKeys.HTTPS_KEY_PATH]?.message}</p>}
+                                {renderSaveResult(AppConfigKeys.HTTPS_KEY_PATH)}
                             </div>
                             <div className="grid gap-1.5">
                                 <Label htmlFor="https-cert-path">{t('httpsCertPathLabel', preferredLanguage)}</Label>
                                 <Input id="https-cert-path" {...register(AppConfigKeys.HTTPS_CERT_PATH)} placeholder="/path/to/your/certificate.crt" aria-invalid={!!errors[AppConfigKeys.HTTPS_CERT_PATH]} className={cn(errors[AppConfigKeys.HTTPS_CERT_PATH] && "border-destructive")} />
                                 {errors[AppConfigKeys.HTTPS_CERT_PATH] && <p className="text-xs text-destructive">{errors[AppConfigKeys.HTTPS_CERT_PATH]?.message}</p>}
+                                {renderSaveResult(AppConfigKeys.HTTPS_CERT_PATH)}
                             </div>
                             <div className="grid gap-1.5">
                                 <Label htmlFor="https-ca-path">{t('httpsCaPathLabel', preferredLanguage)}</Label>
                                 <Input id="https-ca-path" {...register(AppConfigKeys.HTTPS_CA_PATH)} placeholder="/path/to/your/ca_bundle.crt" aria-invalid={!!errors[AppConfigKeys.HTTPS_CA_PATH]} className={cn(errors[AppConfigKeys.HTTPS_CA_PATH] && "border-destructive")} />
                                 {errors[AppConfigKeys.HTTPS_CA_PATH] && <p className="text-xs text-destructive">{errors[AppConfigKeys.HTTPS_CA_PATH]?.message}</p>}
+                                {renderSaveResult(AppConfigKeys.HTTPS_CA_PATH)}
                             </div>
                          </div>
                     </div>
@@ -379,6 +403,9 @@ const SettingsForm: React.FC = () => {
                              {saveStatus === 'success' && t('saveButton', preferredLanguage) + '!'}
                             {(saveStatus === 'idle' || saveStatus === 'error') && t('saveButton', preferredLanguage)}
                          </Button>
+                         {isDirty && saveStatus !== 'saving' && (
+                            <p className="text-sm text-muted-foreground" role="status">{t('settingsUnsavedChanges', preferredLanguage)}</p>
+                         )}
                          {needsRestart && isDirty && (
                             <p className="text-sm text-orange-600 font-medium">{t('saveSettingsRestartWarning', preferredLanguage)}</p>
                          )}
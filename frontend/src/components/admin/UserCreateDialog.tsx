@@ -10,7 +10,7 @@ import { Label } from "@/components/ui/label";
 import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, DialogFooter, DialogClose, DialogTrigger } from "@/components/ui/dialog";
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
-import { toast } from "sonner";
+import { toast } from '@/lib/toast';
 import { cn } from '@/lib/utils';
 import { t } from '@/translations/utils'; // Import translation utility
 
@@ -1,4 +1,4 @@
-import React, { useState, useEffect, useCallback } from 'react';
+import React, { useState, useEffect, useCallback, useRef, useMemo } from 'react';
 import { useForm, Controller } from 'react-hook-form'; // Added Controller
 import { zodResolver } from '@hookform/resolvers/zod';
 // Updated import: Added userCreateSchema and UserCreateFormData, updatePreferredLanguageFormSchema
@@ -10,21 +10,24 @@ import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, Di
 import { Input } from '@/components/ui/input';
 import { Label } from '@/components/ui/label';
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import LoadingStatus from '@/components/shared/LoadingStatus';
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
+import EmptyState from '@/components/shared/EmptyState';
 import TagSelector from '@/components/shared/TagSelector'; // Import TagSelector
 import UserCreateDialog from './UserCreateDialog'; // Import the new create user dialog
 import { Card, CardContent, CardDescription, CardHeader, CardTitle } from "@/components/ui/card";
 import { useAuth } from '@/hooks/useAuth';
 import api from '@/lib/api';
+import { loadViewState, saveViewState, type UsersRoleFilter } from '@/lib/viewState';
 // Updated UserRole import - User now potentially includes assignedTags and preferredLanguage
 // Import supportedLanguages constant from backend models
 import type { User, UserRole, SupportedLanguage } from '../../../../backend/src/functionalities/user/models';
 import { supportedLanguages } from '../../../../backend/src/functionalities/user/models'; // Import the constant
 import type { Tag } from '../../../../backend/src/functionalities/tag/models';
-import { toast } from "sonner";
+import { toast } from '@/lib/toast';
 import { cn } from '@/lib/utils';
 // Updated imports: Added PlusCircle, Languages icon
-import { KeyRound, Ban, Tags, PlusCircle, Languages } from 'lucide-react';
+import { KeyRound, Ban, Tags, PlusCircle, Languages, AlertCircle, Search, SearchX } from 'lucide-react';
 import { Badge } from '@/components/ui/badge';
 import { Tooltip, TooltipContent, TooltipProvider, TooltipTrigger } from "@/components/ui/tooltip"; // Import Tooltip
 import { t } from '@/translations/utils'; // Import translation utility
@@ -37,6 +40,9 @@ const UserManagement: React.FC = () => {
     const [fetchError, setFetchError] = useState<string | null>(null);
     const [updateError, setUpdateError] = useState<string | null>(null);
     const [updatingLogin, setUpdatingLogin] = useState<string | null>(null);
+    // Last confirmed role per login with role changes in flight - the revert target if the changes fail
+    const pendingRoleChanges = useRef(new Map<string, Pick<Omit<User, 'password'>, 'role' | 'assignedTags'> & { inFlight: number }>());
+    const [roleChangeErrors, setRoleChangeErrors] = useState<Record<string, string>>({});
     const [settingPasswordLogin, setSettingPasswordLogin] = useState<string | null>(null);
     const [isSetPasswordDialogOpen, setIsSetPasswordDialogOpen] = useState(false);
     const [targetUserForPassword, setTargetUserForPassword] = useState<Omit<User, 'password'> | null>(null);
@@ -54,6 +60,19 @@ const UserManagement: React.FC = () => {
 
     // --- State for Create User Dialog ---
     const [isCreateUserDialogOpen, setIsCreateUserDialogOpen] = useState(false);
+    // --- Client-side filter over the loaded users (remembered like the components filter) ---
+    const [loginFilter, setLoginFilter] = useState(() => loadViewState().usersFilter ?? '');
+    const [roleFilter, setRoleFilter] = useState<UsersRoleFilter>(() => loadViewState().usersRoleFilter ?? 'all');
+    useEffect(() => { saveViewState({ usersFilter: loginFilter, usersRoleFilter: roleFilter }); }, [loginFilter, roleFilter]);
+
+    const visibleUsers = useMemo(() => {
+        const needle = loginFilter.trim().toLowerCase();
+        return users.filter(user =>
+            (!needle || user.login.toLowerCase().includes(needle)) &&
+            (roleFilter === 'all' || (roleFilter === 'none' ? user.role === null : user.role === roleFilter))
+        );
+    }, [users, loginFilter, roleFilter]);
+    const isFiltered = loginFilter.trim() !== '' || roleFilter !== 'all';
     // ------------------------------------
 
     const { register: registerPassword, handleSubmit: handlePasswordSubmit, reset: resetPasswordForm, formState: { errors: passwordErrors } } = useForm<SetPasswordFormData>({
@@ -117,11 +136,15 @@ const UserManagement: React.FC = () => {
     const handleRoleChange = useCallback(async (login: string, newRole: UserRole | null) => {
         // Use translated warning
         if (!token || login === adminUser?.login) { toast.warning(t('cannotChangeOwnRoleWarning', preferredLanguage)); return; }
-        setUpdatingLogin(login); setUpdateError(null);
         const originalUser = users.find(u => u.login === login);
         if (!originalUser) return; // Safety check
+        setUpdatingLogin(login); setUpdateError(null);
+        setRoleChangeErrors(({ [login]: _cleared, ...rest }) => rest);
 
-        const originalRole = originalUser.role;
+        // Keep the stored original if a change for this user is still in flight - the list already shows the optimistic role
+        const pending = pendingRoleChanges.current.get(login);
+        if (pending) pending.inFlight++;
+        else pendingRoleChanges.current.set(login, { role: originalUser.role, assignedTags: originalUser.assignedTags, inFlight: 1 });
         let roleText = ''; // Translate role for toast
         switch(newRole) {
             case 'admin': roleText = t('adminRoleOption', preferredLanguage); break;
@@ -134,6 +157,10 @@ const UserManagement: React.FC = () => {
             // Optimistic UI update
             setUsers(prev => prev.map(u => (u.login === login ? { ...u, role: newRole, assignedTags: newRole === 'user' ? u.assignedTags : undefined } : u)));
             await api.updateUserRole(login, newRole, token);
+            // Changes still in flight now revert to this confirmed role instead of the original one
+            const confirmed = pendingRoleChanges.current.get(login);
+            if (confirmed && --confirmed.inFlight > 0) confirmed.role = newRole;
+            else pendingRoleChanges.current.delete(login);
             // Use translated success message
             toast.success(t('roleUpdatedSuccess', preferredLanguage, { login, roleText }));
 
@@ -152,10 +179,15 @@ const UserManagement: React.FC = () => {
              }
         } catch (err: any) {
             const msg = t('userRoleUpdateFailedError', preferredLanguage, { login, message: err.message }); // Use translated error template
-            setUpdateError(msg);
             toast.error(t('errorMessageTemplate', preferredLanguage, { message: msg }));
-            // Revert UI on error
-            setUsers(prev => prev.map(u => (u.login === login ? { ...u, role: originalRole, assignedTags: originalRole === 'user' ? originalUser.assignedTags : undefined } : u)));
+            setRoleChangeErrors(prev => ({ ...prev, [login]: err.message || msg }));
+            // Revert UI on error to the last confirmed role - unless a newer change is still in flight and will settle it
+            const original = pendingRoleChanges.current.get(login);
+            if (original && --original.inFlight > 0) return;
+            pendingRoleChanges.current.delete(login);
+            if (original) {
+                setUsers(prev => prev.map(u => (u.login === login ? { ...u, role: original.role, assignedTags: original.role === 'user' ? original.assignedTags : undefined } : u)));
+            }
         } finally { setUpdatingLogin(null); }
     }, [token, adminUser?.login, users, fetchUsers, preferredLanguage, openAssignTagsDialog]); // Added openAssignTagsDialog
 
@@ -295,9 +327,43 @@ const UserManagement: React.FC = () => {
                 <CardContent>
                     {fetchError && !isLoading && <ErrorDisplay message={fetchError} className='mb-4' />}
                     {updateError && !isLoading && <ErrorDisplay message={updateError} className='mb-4' />}
+                    <LoadingStatus isLoading={isLoading} label={t('userManagementTitleAdmin', preferredLanguage)} count={users.length} />
                     {isLoading && <div className="flex justify-center py-10"><LoadingSpinner /></div>}
 
                     {!isLoading && !fetchError && users.length > 0 && (
+                        <div className="flex flex-wrap items-center gap-2 mb-4">
+                            <div className="relative w-full max-w-xs">
+                                <Search className="absolute left-2.5 top-2.5 h-4 w-4 text-muted-foreground" />
+                                <Input
+                                    type="search"
+                                    value={loginFilter}
+                                    onChange={(e) => setLoginFilter(e.target.value)}
+                                    placeholder={t('userFilterPlaceholder', preferredLanguage)}
+                                    aria-label={t('userFilterPlaceholder', preferredLanguage)}
+                                    className="pl-8"
+                                />
+                            </div>
+                            <Select value={roleFilter} onValueChange={(value) => setRoleFilter(value as UsersRoleFilter)}>
+                                <SelectTrigger className="w-[180px] h-9" aria-label={t('userRoleFilterLabel', preferredLanguage)}><SelectValue /></SelectTrigger>
+                                <SelectContent>
+                                    <SelectItem value="all">{t('userRoleFilterAll', preferredLanguage)}</SelectItem>
+                                    <SelectItem value="admin">{t('adminRoleOption', preferredLanguage)}</SelectItem>
+                                    <SelectItem value="employee">{t('employeeRoleOption', preferredLanguage)}</SelectItem>
+                                    <SelectItem value="user">{t('userRoleOption', preferredLanguage)}</SelectItem>
+                                    <SelectItem value="none">{t('noRoleOption', preferredLanguage)}</SelectItem>
+                                </SelectContent>
+                            </Select>
+                            <span className="text-sm text-muted-foreground">
+                                {isFiltered
+                                    ? t('userFilterCount', preferredLanguage, { shown: visibleUsers.length, total: users.length })
+                                    : t('userTotalCount', preferredLanguage, { total: users.length })}
+                            </span>
+                        </div>
+                    )}
+                    {!isLoading && !fetchError && users.length > 0 && visibleUsers.length === 0 && (
+                        <EmptyState icon={SearchX} title={t('userFilterNoMatches', preferredLanguage)} />
+                    )}
+                    {!isLoading && !fetchError && visibleUsers.length > 0 && (
                         <div className="border rounded-lg overflow-hidden">
                              {/* Table styles adjusted for white bg */}
                             <Table>
@@ -312,7 +378,7 @@ const UserManagement: React.FC = () => {
                                     </TableRow>
                                 </TableHeader>
                                 <TableBody>
-                                    {users.map((user) => {
+                                    {visibleUsers.map((user) => {
                                         const isSelf = user.login === adminUser?.login;
                                         const isProcessing = updatingLogin === user.login || settingPasswordLogin === user.login;
                                         // Calculate title beforehand to avoid complex JSX inside attribute
@@ -344,6 +410,11 @@ const UserManagement: React.FC = () => {
                                                         </Select>
                                                         {updatingLogin === user.login && !isAssignTagsDialogOpen && !isSetLanguageDialogOpen && <LoadingSpinner size="sm" />}
                                                     </div>
+                                                    {roleChangeErrors[user.login] && (
+                                                        <p className="mt-1 flex items-center gap-1 text-xs text-destructive max-w-[220px]">
+                                                            <AlertCircle className="h-3 w-3 shrink-0" /> {t('roleChangeRevertedError', preferredLanguage, { message: roleChangeErrors[user.login] })}
+                                                        </p>
+                                                    )}
                                                 </TableCell>
                                                 {/* Preferred Language Cell */}
                                                 <TableCell>
@@ -0,0 +1,295 @@
+import React, { useState, useEffect } from 'react';
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, DialogFooter } from "@/components/ui/dialog";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { Checkbox } from "@/components/ui/checkbox";
+import { Alert, AlertDescription, AlertTitle } from "@/components/ui/alert";
+import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from "@/components/ui/table";
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import { AlertTriangle, CheckCircle, Download, FileCheck, Upload } from 'lucide-react';
+import { useAuth } from '@/hooks/useAuth';
+import api from '@/lib/api';
+import { parseCsv, toCsv, downloadTextFile } from '@/lib/csv';
+import { createArchiveDocumentFormSchema } from '@/lib/zodSchemas';
+import { toast } from '@/lib/toast';
+import { t } from '@/translations/utils';
+import type { Tag } from '../../../../backend/src/functionalities/tag/models';
+import type { CreateArchiveDocumentInput } from '../../../../backend/src/functionalities/archive/document/models';
+
+// CSV columns map 1:1 to archive document fields; tagIds are ';'-separated tag IDs
+const IMPORT_COLUMNS = [
+    'type', 'title', 'creator', 'creationDate', 'parentUnitArchiveDocumentId', 'topographicSignature',
+    'numberOfPages', 'documentType', 'dimensions', 'binding', 'condition', 'documentLanguage',
+    'contentDescription', 'remarks', 'accessLevel', 'accessConditions', 'additionalInformation',
+    'relatedDocumentsReferences', 'isDigitized', 'digitizedVersionLink', 'tagIds',
+] as const;
+const REQUIRED_COLUMNS = ['type', 'title', 'creator', 'creationDate'];
+const MAX_LISTED_ISSUES = 100;
+
+interface ImportIssue {
+    row: number; // Line in the file, the header being line 1
+    field: string;
+    message: string;
+}
+
+interface ValidatedRow {
+    row: number;
+    data: CreateArchiveDocumentInput;
+}
+
+interface ValidationResult {
+    validRows: ValidatedRow[];
+    issues: ImportIssue[];
+    rowCount: number;
+}
+
+interface ArchiveImportDialogProps {
+    isOpen: boolean;
+    onOpenChange: (open: boolean) => void;
+    availableTags: Tag[];
+    defaultParentId?: number | null; // Rows without a parent land in this unit
+    onImported: () => void;
+}
+
+const parseBoolean = (value: string): boolean | string => {
+    const normalized = value.trim().toLowerCase();
+    if (['true', '1', 'yes', 'y'].includes(normalized)) return true;
+    if (['false', '0', 'no', 'n', ''].includes(normalized)) return false;
+    return value; // Left as-is so the schema reports it
+};
+
+const ArchiveImportDialog: React.FC<ArchiveImportDialogProps> = ({ isOpen, onOpenChange, availableTags, defaultParentId, onImported }) => {
+    const { token, preferredLanguage } = useAuth();
+    const [fileName, setFileName] = useState<string | null>(null);
+    const [rows, setRows] = useState<string[][]>([]);
+    const [result, setResult] = useState<ValidationResult | null>(null);
+    const [isValidating, setIsValidating] = useState(false);
+    const [isImporting, setIsImporting] = useState(false);
+    const [importProgress, setImportProgress] = useState<{ done: number; total: number } | null>(null);
+    const [skipInvalidAcknowledged, setSkipInvalidAcknowledged] = useState(false);
+
+    useEffect(() => {
+        if (isOpen) {
+            setFileName(null); setRows([]); setResult(null);
+            setImportProgress(null); setSkipInvalidAcknowledged(false);
+        }
+    }, [isOpen]);
+
+    const handleFileChange = async (e: React.ChangeEvent<HTMLInputElement>) => {
+        const file = e.target.files?.[0];
+        setResult(null); setSkipInvalidAcknowledged(false);
+        if (!file) { setFileName(null); setRows([]); return; }
+        setFileName(file.name);
+        setRows(parseCsv(await file.text()));
+    };
+
+    // Dry run: checks every row without creating anything
+    const handleValidate = async () => {
+        if (!token || rows.length === 0) return;
+        setIsValidating(true);
+        try {
+            const issues: ImportIssue[] = [];
+            const validRows: ValidatedRow[] = [];
+            const [header, ...dataRows] = rows;
+            const columns = header.map(h => h.trim());
+
+            const unknownColumns = columns.filter(c => c && !(IMPORT_COLUMNS as readonly string[]).includes(c));
+            unknownColumns.forEach(c => issues.push({ row: 1, field: c, message: t('archiveImportUnknownColumn', preferredLanguage) }));
+            const missingColumns = REQUIRED_COLUMNS.filter(c => !columns.includes(c));
+            missingColumns.forEach(c => issues.push({ row: 1, field: c, message: t('archiveImportMissingColumn', preferredLanguage) }));
+            if (missingColumns.length > 0) {
+                setResult({ validRows, issues, rowCount: dataRows.length });
+                return;
+            }
+
+            const knownTagIds = new Set(availableTags.map(tag => tag.tagId));
+            const parentCheckCache = new Map<number, string | null>(); // parent id -> error message, null when valid
+            const checkParent = async (parentId: number): Promise<string | null> => {
+                if (!parentCheckCache.has(parentId)) {
+                    try {
+                        const parent = await api.getArchiveDocumentById(parentId, token);
+                        parentCheckCache.set(parentId, parent.type === 'unit' ? null : t('archiveInvalidParentTypeError', preferredLanguage, { id: parentId }));
+                    } catch {
+                        parentCheckCache.set(parentId, t('archiveImportParentNotFound', preferredLanguage, { id: parentId }));
+                    }
+                }
+                return parentCheckCache.get(parentId)!;
+            };
+
+            for (const [index, values] of dataRows.entries()) {
+                const rowNumber = index + 2;
+                const raw: Record<string, string> = {};
+                columns.forEach((column, i) => { raw[column] = (values[i] ?? '').trim(); });
+                const rowIssues: ImportIssue[] = [];
+
+                const tagIds = (raw.tagIds ?? '').split(';').map(s => s.trim()).filter(Boolean).map(Number);
+                tagIds.filter(id => !knownTagIds.has(id)).forEach(id =>
+                    rowIssues.push({ row: rowNumber, field: 'tagIds', message: t('archiveImportUnknownTag', preferredLanguage, { id }) }));
+
+                const candidate = {
+                    ...Object.fromEntries(IMPORT_COLUMNS.filter(c => c !== 'tagIds' && c !== 'isDigitized').map(c => [c, raw[c] || null])),
+                    type: raw.type || undefined,
+                    title: raw.title ?? '',
+                    creator: raw.creator ?? '',
+                    creationDate: raw.creationDate ?? '',
+                    parentUnitArchiveDocumentId: raw.parentUnitArchiveDocumentId || defaultParentId || null,
+                    isDigitized: parseBoolean(raw.isDigitized ?? ''),
+                    tagIds: tagIds.filter(id => knownTagIds.has(id)),
+                };
+                const parsed = createArchiveDocumentFormSchema.safeParse(candidate);
+                if (!parsed.success) {
+                    parsed.error.issues.forEach(issue => rowIssues.push({ row: rowNumber, field: issue.path.join('.') || '-', message: issue.message }));
+                } else if (parsed.data.parentUnitArchiveDocumentId) {
+                    const parentError = await checkParent(parsed.data.parentUnitArchiveDocumentId);
+                    if (parentError) rowIssues.push({ row: rowNumber, field: 'parentUnitArchiveDocumentId', message: parentError });
+                }
+
+                if (rowIssues.length > 0) issues.push(...rowIssues);
+                else if (parsed.success) validRows.push({ row: rowNumber, data: parsed.data as CreateArchiveDocumentInput });
+            }
+            setResult({ validRows, issues, rowCount: dataRows.length });
+        } finally {
+            setIsValidating(false);
+        }
+    };
+
+    const handleDownloadReport = () => {
+        if (!result) return;
+        const report = toCsv([['row', 'field', 'message'], ...result.issues.map(issue => [issue.row, issue.field, issue.message])]);
+        downloadTextFile(report, `${(fileName ?? 'import').replace(/\.csv$/i, '')}-validation-report.csv`);
+    };
+
+    const handleImport = async () => {
+        if (!token || !result || result.validRows.length === 0) return;
+        setIsImporting(true);
+        setImportProgress({ done: 0, total: result.validRows.length });
+        const failedRows: number[] = [];
+        for (const [index, { row, data }] of result.validRows.entries()) {
+            try {
+                await api.createArchiveDocument(data, token);
+            } catch (err) {
+                console.error(`Import of row ${row} failed:`, err);
+                failedRows.push(row);
+            }
+            setImportProgress({ done: index + 1, total: result.validRows.length });
+        }
+        setIsImporting(false);
+        const createdCount = result.validRows.length - failedRows.length;
+        if (createdCount > 0) toast.success(t('archiveImportSuccess', preferredLanguage, { count: createdCount }));
+        if (failedRows.length > 0) {
+            toast.error(t('archiveImportRowsFailed', preferredLanguage, { count: failedRows.length, rows: failedRows.join(', ') }));
+        } else {
+            onOpenChange(false);
+        }
+        onImported();
+    };
+
+    const hasIssues = (result?.issues.length ?? 0) > 0;
+    const canImport = !!result && result.validRows.length > 0 && (!hasIssues || skipInvalidAcknowledged) && !isImporting && !isValidating;
+
+    return (
+        <Dialog open={isOpen} onOpenChange={(open) => { if (!isImporting) onOpenChange(open); }}>
+            <DialogContent className="sm:max-w-2xl">
+                <DialogHeader>
+                    <DialogTitle className='flex items-center gap-2'><Upload className='h-4 w-4' /> {t('archiveImportTitle', preferredLanguage)}</DialogTitle>
+                    <DialogDescription>{t('archiveImportDescription', preferredLanguage, { columns: REQUIRED_COLUMNS.join(', ') })}</DialogDescription>
+                </DialogHeader>
+
+                <div className="space-y-4 py-2">
+                    <Input
+                        type="file"
+                        accept=".csv,text/csv"
+                        onChange={handleFileChange}
+                        disabled={isValidating || isImporting}
+                    />
+                    {fileName && !result && (
+                        <p className='text-sm text-muted-foreground'>{t('archiveImportRowsRead', preferredLanguage, { count: Math.max(0, rows.length - 1) })}</p>
+                    )}
+
+                    {result && !hasIssues && (
+                        <Alert>
+                            <CheckCircle className="h-4 w-4" />
+                            <AlertTitle>{t('archiveImportValidTitle', preferredLanguage)}</AlertTitle>
+                            <AlertDescription>{t('archiveImportValidText', preferredLanguage, { count: result.validRows.length })}</AlertDescription>
+                        </Alert>
+                    )}
+                    {result && hasIssues && (
+                        <>
+                            <Alert variant="destructive">
+                                <AlertTriangle className="h-4 w-4" />
+                                <AlertTitle>{t('archiveImportIssuesTitle', preferredLanguage, { count: result.issues.length })}</AlertTitle>
+                                <AlertDescription>
+                                    {t('archiveImportIssuesText', preferredLanguage, { valid: result.validRows.length, total: result.rowCount })}
+                                </AlertDescription>
+                            </Alert>
+                            <div className="max-h-60 overflow-y-auto border rounded-md">
+                                <Table>
+                                    <TableHeader>
+                                        <TableRow>
+                                            <TableHead className="w-[70px]">{t('archiveImportRowColumn', preferredLanguage)}</TableHead>
+                                            <TableHead className="w-[180px]">{t('archiveImportFieldColumn', preferredLanguage)}</TableHead>
+                                            <TableHead>{t('archiveImportMessageColumn', preferredLanguage)}</TableHead>
+                                        </TableRow>
+                                    </TableHeader>
+                                    <TableBody>
+                                        {result.issues.slice(0, MAX_LISTED_ISSUES).map((issue, i) => (
+                                            <TableRow key={i}>
+                                                <TableCell className="font-mono text-xs">{issue.row}</TableCell>
+                                                <TableCell className="font-mono text-xs">{issue.field}</TableCell>
+                                                <TableCell className="text-xs">{issue.message}</TableCell>
+                                            </TableRow>
+                                        ))}
+                                    </TableBody>
+                                </Table>
+                            </div>
+                            {result.issues.length > MAX_LISTED_ISSUES && (
+                                <p className='text-xs text-muted-foreground'>{t('archiveImportMoreIssues', preferredLanguage, { count: result.issues.length - MAX_LISTED_ISSUES })}</p>
+                            )}
+                            <div className="flex flex-wrap items-center justify-between gap-2">
+                                <Button type="button" variant="outline" size="sm" onClick={handleDownloadReport}>
+                                    <Download className="mr-2 h-4 w-4" /> {t('archiveImportDownloadReport', preferredLanguage)}
+                                </Button>
+                                {result.validRows.length > 0 && (
+                                    <div className="flex items-center gap-2">
+                                        <Checkbox
+                                            id="archive-import-skip-invalid"
+                                            checked={skipInvalidAcknowledged}
+                                            onCheckedChange={(checked) => setSkipInvalidAcknowledged(checked === true)}
+                                            disabled={isImporting}
+                                        />
+                                        <Label htmlFor="archive-import-skip-invalid" className="text-sm font-normal cursor-pointer">
+                                            {t('archiveImportSkipInvalid', preferredLanguage)}
+                                        </Label>
+                                    </div>
+                                )}
+                            </div>
+                        </>
+                    )}
+
+                    {importProgress && isImporting && (
+                        <p className='text-sm text-muted-foreground inline-flex items-center gap-2'>
+                            <LoadingSpinner size='sm' /> {t('archiveImportProgress', preferredLanguage, { done: importProgress.done, total: importProgress.total })}
+                        </p>
+                    )}
+                </div>
+
+                <DialogFooter>
+                    <Button type="button" variant="outline" onClick={() => onOpenChange(false)} disabled={isImporting}>
+                        {t('cancelButton', preferredLanguage)}
+                    </Button>
+                    <Button type="button" variant="secondary" onClick={handleValidate} disabled={rows.length === 0 || isValidating || isImporting}>
+                        {isValidating ? <LoadingSpinner size="sm" className="mr-2" /> : <FileCheck className="mr-2 h-4 w-4" />}
+                        {t('archiveImportValidateButton', preferredLanguage)}
+                    </Button>
+                    <Button type="button" onClick={handleImport} disabled={!canImport}>
+                        {isImporting ? <LoadingSpinner size="sm" className="mr-2" /> : <Upload className="mr-2 h-4 w-4" />}
+                        {t('archiveImportButton', preferredLanguage, { count: result?.validRows.length ?? 0 })}
+                    </Button>
+                </DialogFooter>
+            </DialogContent>
+        </Dialog>
+    );
+};
+
+export default ArchiveImportDialog;
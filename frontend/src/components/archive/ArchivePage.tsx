@@ -1,41 +1,72 @@
-import React, { useState, useEffect, useCallback, useMemo } from 'react';
+import React, { useState, useEffect, useCallback, useMemo, useRef } from 'react';
 import { useSearchParams, useNavigate, Link } from 'react-router-dom';
 import { Button } from '@/components/ui/button';
+import { Checkbox } from '@/components/ui/checkbox';
+import { Label } from '@/components/ui/label';
+import { Input } from '@/components/ui/input';
+import { DropdownMenu, DropdownMenuContent, DropdownMenuCheckboxItem, DropdownMenuSeparator, DropdownMenuItem, DropdownMenuTrigger } from '@/components/ui/dropdown-menu';
+import { ToggleGroup, ToggleGroupItem } from '@/components/ui/toggle-group';
 import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogTrigger } from '@/components/ui/dialog';
 import DocumentList from './DocumentList';
 import DocumentForm from './DocumentForm';
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import EmptyState from '@/components/shared/EmptyState';
+import LoadingStatus from '@/components/shared/LoadingStatus';
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
 import SearchBar, { type SearchFieldOption } from '@/components/shared/SearchBar';
+import useDebounce from '@/components/shared/useDebounce';
 import BatchTagDialog from './BatchTagDialog';
+import ArchiveImportDialog from './ArchiveImportDialog';
+import BulkEditFieldDialog from './BulkEditFieldDialog';
 import { useAuth } from '@/hooks/useAuth';
+import { useConfirm } from '@/hooks/useConfirm';
+import { useSaveGuardedOpenChange } from '@/hooks/usePendingRequests';
+import { useCreateShortcut } from '@/hooks/useCreateShortcut';
+import { useUiPreferences } from '@/hooks/useUiPreferences';
 import api from '@/lib/api';
+import { cachedSearch, cachedCount, prefetchAdjacentPages } from '@/lib/pageCache';
+import { loadViewState, saveViewState } from '@/lib/viewState';
+import { archiveQuery } from '@/lib/searchQuery';
+import { downloadTextFile } from '@/lib/csv';
+import { archiveDocumentsToCsv } from '@/lib/archiveCsv';
 import type { Tag } from '../../../../backend/src/functionalities/tag/models';
 import type { ArchiveDocument, ArchiveDocumentSearchResult, ArchiveDocumentType } from '../../../../backend/src/functionalities/archive/document/models';
 import type { SearchRequest, SearchResponse, SearchQueryElement } from '../../../../backend/src/utils/search';
-import { PlusCircle, ArrowLeft, Folder, FileText, Tags, MinusCircle, Archive as ArchiveIcon, FileSearch } from 'lucide-react';
-import { Pagination } from '@/components/shared/Pagination';
-import { toast } from "sonner";
+import { PlusCircle, ArrowLeft, Folder, FileText, Tags, MinusCircle, Archive as ArchiveIcon, FileSearch, MonitorCheck, Upload, Download, PencilLine, EyeOff, Search, Tag as TagIcon, FolderOpen, SearchX } from 'lucide-react';
+import { Pagination, isPastLastPage } from '@/components/shared/Pagination';
+import { toast } from '@/lib/toast';
 import { Card, CardContent, CardHeader, CardDescription } from '@/components/ui/card';
 import DocumentPreviewDialog from './DocumentPreviewDialog';
+import UnitBreadcrumbs from './UnitBreadcrumbs';
 import { cn } from '@/lib/utils';
 import { t } from '@/translations/utils'; // Import translation utility
 
 const ARCHIVE_PAGE_SIZE = 10;
+const ARCHIVE_EXPORT_PAGE_SIZE = 200;
+const ARCHIVE_EXPORT_CONFIRM_THRESHOLD = 2000;
+const ARCHIVE_EXPORT_MAX_ITEMS = 20000;
+
+// Whether the app was opened on the archive root - only then is the last visited unit restored, see restoredView below
+let canRestoreStoredUnit = window.location.pathname === '/archive' && !new URLSearchParams(window.location.search).has('unitId');
 
 const ArchivePage: React.FC = () => {
   const { token, user, preferredLanguage } = useAuth();
+  const confirm = useConfirm();
+  const { bulkConfirmThreshold } = useUiPreferences();
   const navigate = useNavigate();
   const [searchParams] = useSearchParams();
   const parentUnitId = searchParams.get('unitId') ? Number(searchParams.get('unitId')) : null;
 
   const [documents, setDocuments] = useState<ArchiveDocumentSearchResult[]>([]);
+  const [unitChildCounts, setUnitChildCounts] = useState<Record<number, number>>({}); // Unit id -> direct children
   const [parentUnit, setParentUnit] = useState<ArchiveDocument | null>(null);
   const [availableTags, setAvailableTags] = useState<Tag[]>([]);
   const [isLoading, setIsLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [editingDoc, setEditingDoc] = useState<ArchiveDocument | null>(null);
+  const [copyingDoc, setCopyingDoc] = useState<ArchiveDocument | null>(null); // Source of a "Duplicate" - the form creates a new item
   const [isFormOpen, setIsFormOpen] = useState(false);
+  const handleFormOpenChange = useSaveGuardedOpenChange(setIsFormOpen);
   const [formInitialType, setFormInitialType] = useState<ArchiveDocumentType | undefined>(undefined);
   const [formInitialParentId, setFormInitialParentId] = useState<number | undefined>(undefined);
   const [formInitialParentTitle, setFormInitialParentTitle] = useState<string | undefined>(undefined);
@@ -44,8 +75,46 @@ const ArchivePage: React.FC = () => {
   const [previewingDoc, setPreviewingDoc] = useState<ArchiveDocument | null>(null);
   const [isPreviewOpen, setIsPreviewOpen] = useState(false);
 
-  const [searchQuery, setSearchQuery] = useState<SearchRequest['query']>([]);
-  const [currentPage, setCurrentPage] = useState(1);
+  const [searchBarQuery, setSearchQuery] = useState<SearchRequest['query']>([]);
+  const [searchGroups, setSearchGroups] = useState<NonNullable<SearchRequest['groups']>>([]); // "Match any" criteria from the search bar
+  const [digitizedOnly, setDigitizedOnly] = useState(false);
+  const [typeFilter, setTypeFilter] = useState<'all' | ArchiveDocumentType>('all');
+  const [showDisabled, setShowDisabled] = useState(false); // Admin/employee only - others never get disabled items
+  const [quickText, setQuickText] = useState(''); // Title/creator fragment
+  const debouncedQuickText = useDebounce(quickText.trim(), 400);
+  const [quickTagIds, setQuickTagIds] = useState<number[]>([]); // Admin/employee only, like the "Tags" search field
+  const [exportProgress, setExportProgress] = useState<{ done: number, total: number } | null>(null);
+  const exportCancelledRef = useRef(false);
+  // The quick filters (text, tags, "Digitized only", item type) are applied on top of the search bar criteria (also for batch tagging)
+  const searchQuery = useMemo<SearchRequest['query']>(() => {
+      const query = archiveQuery(searchBarQuery);
+      if (debouncedQuickText) query.without('text').fragment('text', debouncedQuickText);
+      if (quickTagIds.length > 0) query.without('tags').anyOf('tags', quickTagIds);
+      if (digitizedOnly) query.without('isDigitized').eq('isDigitized', true);
+      if (typeFilter !== 'all' && !parentUnitId) query.without('type').eq('type', typeFilter);
+      return query.build();
+  }, [searchBarQuery, debouncedQuickText, quickTagIds, digitizedOnly, typeFilter, parentUnitId]);
+
+  // A new quick text starts from the first page
+  const isFirstQuickTextRef = useRef(true);
+  useEffect(() => {
+      if (isFirstQuickTextRef.current) { isFirstQuickTextRef.current = false; return; }
+      setCurrentPage(1);
+  }, [debouncedQuickText]);
+
+  const toggleQuickTag = (tagId: number) => {
+      setQuickTagIds(prev => prev.includes(tagId) ? prev.filter(id => id !== tagId) : [...prev, tagId]);
+      setCurrentPage(1);
+  };
+  // Reopening the app on the archive returns to the last visited unit and page. The unit is restored only
+  // for the route the app was opened on, so navigating to the archive root always leads there.
+  const [restoredView] = useState(() => {
+      const stored = loadViewState();
+      const unitId = parentUnitId ?? (canRestoreStoredUnit ? stored.archiveUnitId ?? null : null);
+      canRestoreStoredUnit = false;
+      return { unitId, page: (stored.archiveUnitId ?? null) === unitId ? stored.archivePage ?? 1 : 1 };
+  });
+  const [currentPage, setCurrentPage] = useState(restoredView.page);
   const [pageSize, setPageSize] = useState(ARCHIVE_PAGE_SIZE);
   const [totalDocs, setTotalDocs] = useState(0);
   const [totalPages, setTotalPages] = useState(1);
@@ -53,12 +122,14 @@ const ArchivePage: React.FC = () => {
   const [isBatchTagDialogOpen, setIsBatchTagDialogOpen] = useState(false);
   const [batchTagAction, setBatchTagAction] = useState<'add' | 'remove'>('add');
   const [isBatchTagLoading, setIsBatchTagLoading] = useState(false);
+  const [isImportDialogOpen, setIsImportDialogOpen] = useState(false);
+  const [isBulkEditOpen, setIsBulkEditOpen] = useState(false);
 
   const isAdmin = user?.role === 'admin';
   const isEmployee = user?.role === 'employee';
   const isUserRole = user?.role === 'user';
 
-  const isSearchActive = useMemo(() => searchQuery.length > 0, [searchQuery]);
+  const isSearchActive = useMemo(() => searchQuery.length > 0 || searchGroups.length > 0, [searchQuery, searchGroups]);
 
   const headerIcon = useMemo(() => {
       if (parentUnitId) return Folder;
@@ -67,27 +138,52 @@ const ArchivePage: React.FC = () => {
 
   useEffect(() => {
      if (editingDoc) setFormDialogTitle(t('archiveEditItemDialogTitle', preferredLanguage, { itemType: t(editingDoc.type === 'unit' ? 'archiveUnitLabel' : 'archiveDocumentLabel', preferredLanguage) }));
+     else if (copyingDoc) setFormDialogTitle(t('archiveDuplicateDialogTitle', preferredLanguage, { title: copyingDoc.title }));
      else if (parentUnitId && parentUnit) setFormDialogTitle(t('archiveCreateInUnitDialogTitle', preferredLanguage, { unitTitle: parentUnit.title }));
      else setFormDialogTitle(`${t('createButton', preferredLanguage)} ${t('archiveItemLabel', preferredLanguage)}`);
-  }, [editingDoc, parentUnitId, parentUnit, preferredLanguage]);
+  }, [editingDoc, copyingDoc, parentUnitId, parentUnit, preferredLanguage]);
+
+
+  // Redirect to the restored unit; until the URL points at it, nothing is persisted
+  const isRestoringUnitRef = useRef(!parentUnitId && !!restoredView.unitId);
+  useEffect(() => {
+      if (isRestoringUnitRef.current) navigate(`/archive?unitId=${restoredView.unitId}`, { replace: true });
+  }, [navigate, restoredView]);
+
+  // Each unit listing starts on its first page (except the restored one)
+  const previousUnitIdRef = useRef(parentUnitId);
+  useEffect(() => {
+      if (previousUnitIdRef.current === parentUnitId) return;
+      previousUnitIdRef.current = parentUnitId;
+      const isRestoredUnit = isRestoringUnitRef.current && parentUnitId === restoredView.unitId;
+      isRestoringUnitRef.current = false;
+      setCurrentPage(isRestoredUnit ? restoredView.page : 1);
+  }, [parentUnitId, restoredView]);
 
+  useEffect(() => {
+      if (!isRestoringUnitRef.current) saveViewState({ archiveUnitId: parentUnitId, archivePage: currentPage });
+  }, [parentUnitId, currentPage]);
 
   useEffect(() => {
+    let isCurrent = true; // The unit may change again before this one loads
     const fetchParentUnit = async () => {
         if (!token || !parentUnitId) { setParentUnit(null); return; }
         setIsLoading(true); setError(null);
         try {
             const unit = await api.getArchiveDocumentById(parentUnitId, token);
+            if (!isCurrent) return;
             if (unit.type !== 'unit') throw new Error(t('archiveInvalidParentTypeError', preferredLanguage, { id: parentUnitId }));
             setParentUnit(unit);
         } catch (err: any) {
+            if (!isCurrent) return;
             const msg = t('archiveParentUnitLoadError', preferredLanguage, { message: err.message });
             setError(msg);
             toast.error(t('errorMessageTemplate', preferredLanguage, { message: msg }));
             setParentUnit(null);
-        } finally { setIsLoading(false); }
+        } finally { if (isCurrent) setIsLoading(false); }
     };
     fetchParentUnit();
+    return () => { isCurrent = false; };
   }, [token, parentUnitId, preferredLanguage]);
 
   useEffect(() => {
@@ -105,29 +201,100 @@ const ArchivePage: React.FC = () => {
     fetchTags();
   }, [token, isAdmin, isEmployee]);
 
+   // Every listing request gets a new generation; responses of older (superseded) requests are
+   // dropped, so quick unit/page switches can't be overwritten by a slower earlier response
+   const fetchGenerationRef = useRef(0);
+
+   // The search query narrowed to what the listing shows: the open unit and (by default) active items only
+   const scopeListingQuery = useCallback((query: SearchRequest['query']): SearchQueryElement[] => {
+       const finalQuery = archiveQuery(query);
+       if (parentUnitId) finalQuery.without('parentUnitArchiveDocumentId').eq('parentUnitArchiveDocumentId', parentUnitId);
+       // Disabled items are hidden unless asked for (or the search bar filters on "active" itself)
+       if (!showDisabled && !finalQuery.has('active')) finalQuery.eq('active', true);
+       return finalQuery.build();
+   }, [parentUnitId, showDisabled]);
+
    const fetchDocuments = useCallback(async (page = currentPage, query = searchQuery) => {
        if (!token) return;
+       const generation = ++fetchGenerationRef.current;
+       const isStale = () => generation !== fetchGenerationRef.current;
        setIsLoading(true); setError(null);
        try {
-           let finalQuery: SearchQueryElement[] = [...query];
-           if (parentUnitId) {
-               finalQuery = finalQuery.filter(q => q.field !== 'parentUnitArchiveDocumentId');
-               finalQuery.push({ field: 'parentUnitArchiveDocumentId', condition: 'EQ', value: parentUnitId, not: false });
-           }
-           const searchRequest: SearchRequest = { query: finalQuery, page: page, pageSize: pageSize };
-           const response = await api.searchArchiveDocuments(searchRequest, token);
+           const searchRequest: SearchRequest = { query: scopeListingQuery(query), page: page, pageSize: pageSize, ...(searchGroups.length > 0 && { groups: searchGroups }) };
+           const response = await cachedSearch('archive', api.searchArchiveDocuments, searchRequest, token);
+           if (isStale()) return;
+           if (isPastLastPage(response)) { setCurrentPage(response.totalPages); return; } // Refetches the last page
            setDocuments(response.data);
            setTotalDocs(response.totalSize);
            setTotalPages(response.totalPages);
            setCurrentPage(response.page);
+           prefetchAdjacentPages('archive', api.searchArchiveDocuments, { ...searchRequest, page: response.page }, response.totalPages, token);
        } catch (err: any) {
+           if (isStale()) return;
            const msg = err.message || t('archiveFetchError', preferredLanguage);
            setError(msg);
            toast.error(t('errorMessageTemplate', preferredLanguage, { message: msg }));
            console.error("Fetch Error:", err);
            setDocuments([]); setTotalDocs(0); setTotalPages(1);
-       } finally { setIsLoading(false); }
-   }, [token, pageSize, currentPage, searchQuery, parentUnitId, preferredLanguage]);
+       } finally { if (!isStale()) setIsLoading(false); }
+   }, [token, pageSize, currentPage, searchQuery, searchGroups, scopeListingQuery, preferredLanguage]);
+
+   // Exports every item matching the current listing (all pages, up to a cap) as CSV
+   const handleExportCsv = async () => {
+       if (!token || totalDocs === 0) return;
+       const total = Math.min(totalDocs, ARCHIVE_EXPORT_MAX_ITEMS);
+       if (totalDocs > ARCHIVE_EXPORT_CONFIRM_THRESHOLD) {
+           const message = totalDocs > ARCHIVE_EXPORT_MAX_ITEMS
+               ? t('archiveExportCappedConfirm', preferredLanguage, { count: totalDocs, max: ARCHIVE_EXPORT_MAX_ITEMS })
+               : t('archiveExportLargeConfirm', preferredLanguage, { count: totalDocs });
+           if (!await confirm({ message, confirmLabel: t('archiveExportCsvButton', preferredLanguage), destructive: false })) return;
+       }
+       exportCancelledRef.current = false;
+       setExportProgress({ done: 0, total });
+       try {
+           const query = scopeListingQuery(searchQuery);
+           const exported: ArchiveDocumentSearchResult[] = [];
+           for (let page = 1; exported.length < total; page++) {
+               const response = await api.searchArchiveDocuments({ query, groups: searchGroups, page, pageSize: ARCHIVE_EXPORT_PAGE_SIZE }, token);
+               if (exportCancelledRef.current) { toast.info(t('archiveExportCancelled', preferredLanguage)); return; }
+               exported.push(...response.data.slice(0, total - exported.length));
+               setExportProgress({ done: exported.length, total });
+               if (page >= response.totalPages || response.data.length === 0) break;
+           }
+           const fileName = `archive-${new Date().toISOString().slice(0, 10)}.csv`;
+           downloadTextFile(archiveDocumentsToCsv(exported), fileName);
+           toast.success(t('archiveExportSuccess', preferredLanguage, { count: exported.length, fileName }));
+       } catch (err: any) {
+           const msg = err.message || t('archiveExportFailed', preferredLanguage);
+           toast.error(t('errorMessageTemplate', preferredLanguage, { message: msg }));
+           console.error("Export Archive Error:", err);
+       } finally {
+           setExportProgress(null);
+       }
+   };
+
+   // Child counts of the listed units - one count query per unit, served from the count cache when fresh.
+   // Disabled children are counted only when shown, so the badge matches what opening the unit lists.
+   useEffect(() => {
+       const unitIds = documents.filter(doc => doc.type === 'unit').map(doc => doc.archiveDocumentId!);
+       if (!token || unitIds.length === 0) return;
+       const childCountQuery = (unitId: number) => {
+           const query = archiveQuery().eq('parentUnitArchiveDocumentId', unitId);
+           if (!showDisabled) query.eq('active', true);
+           return query.build();
+       };
+       let isCurrent = true;
+       Promise.all(unitIds.map(unitId =>
+           cachedCount('archive-children', api.searchArchiveDocuments, childCountQuery(unitId), token)
+               .then(count => [unitId, count] as const)
+               .catch(err => { console.warn(`Child count of unit ${unitId} failed:`, err); return null; })
+       )).then(results => {
+           if (!isCurrent) return;
+           const loaded = results.filter((result): result is readonly [number, number] => result !== null);
+           setUnitChildCounts(prev => ({ ...prev, ...Object.fromEntries(loaded) }));
+       });
+       return () => { isCurrent = false; };
+   }, [documents, token, showDisabled]);
 
    useEffect(() => {
        if (parentUnitId && !parentUnit) {
@@ -140,13 +307,22 @@ const ArchivePage: React.FC = () => {
 
     const handleEdit = (doc: ArchiveDocument) => {
         if (!isAdmin && !isEmployee) { toast.error(t('archivePermissionErrorEdit', preferredLanguage)); return; }
-        setEditingDoc(doc);
+        setEditingDoc(doc); setCopyingDoc(null);
+        setFormInitialType(undefined); setFormInitialParentId(undefined); setFormInitialParentTitle(undefined);
+        setIsFormOpen(true);
+    };
+
+    // New item prefilled from an existing one (signatures and tags included), filed in the same unit by default
+    const handleDuplicate = (doc: ArchiveDocument) => {
+        if (!isAdmin && !isEmployee) { toast.error(t('archivePermissionErrorCreate', preferredLanguage)); return; }
+        setEditingDoc(null); setCopyingDoc(doc);
         setFormInitialType(undefined); setFormInitialParentId(undefined); setFormInitialParentTitle(undefined);
         setIsFormOpen(true);
     };
 
     const handleCreateNew = () => {
         if (!isAdmin && !isEmployee) { toast.error(t('archivePermissionErrorCreate', preferredLanguage)); return; }
+        setCopyingDoc(null);
         if (parentUnitId && parentUnit) {
             setEditingDoc(null); setFormInitialType('document'); setFormInitialParentId(parentUnitId);
             setFormInitialParentTitle(parentUnit.title);
@@ -156,6 +332,7 @@ const ArchivePage: React.FC = () => {
         }
         setIsFormOpen(true);
     };
+    useCreateShortcut(handleCreateNew, isAdmin || isEmployee);
 
     const handleDisable = async (docId: number) => {
         if (!isAdmin && !isEmployee) { toast.error(t('archivePermissionErrorDisable', preferredLanguage)); return; }
@@ -163,7 +340,7 @@ const ArchivePage: React.FC = () => {
         const docToDisable = documents.find(d => d.archiveDocumentId === docId) ?? editingDoc ?? previewingDoc;
         if (!docToDisable) return;
         const itemTypeLabel = t(docToDisable.type === 'unit' ? 'archiveUnitLabel' : 'archiveDocumentLabel', preferredLanguage);
-        if (!window.confirm(t('archiveDisableConfirm', preferredLanguage, { itemType: itemTypeLabel }))) return;
+        if (!await confirm({ message: t('archiveDisableConfirm', preferredLanguage, { itemType: itemTypeLabel }), confirmLabel: t('disableButton', preferredLanguage) })) return;
 
         setError(null); setIsLoading(true);
         try {
@@ -182,14 +359,30 @@ const ArchivePage: React.FC = () => {
         } finally { setIsLoading(false); }
     };
 
+    const handleRestore = async (docId: number) => {
+        if (!isAdmin && !isEmployee) { toast.error(t('archivePermissionErrorEdit', preferredLanguage)); return; }
+        if (!token || !docId) return;
+        setError(null); setIsLoading(true);
+        try {
+            const restored = await api.enableArchiveDocument(docId, token);
+            toast.success(t('archiveRestoreSuccess', preferredLanguage, { title: restored.title }));
+            if (previewingDoc?.archiveDocumentId === docId) setPreviewingDoc({ ...previewingDoc, active: true });
+            await fetchDocuments(currentPage, searchQuery);
+        } catch (err: any) {
+            const msg = err.message || 'Failed';
+            toast.error(t('errorMessageTemplate', preferredLanguage, { message: t('archiveRestoreFailed', preferredLanguage, { message: msg }) }));
+            console.error("Restore Error:", err);
+        } finally { setIsLoading(false); }
+    };
+
     const handleSaveSuccess = async () => {
-        setIsFormOpen(false); setEditingDoc(null);
+        setIsFormOpen(false); setEditingDoc(null); setCopyingDoc(null);
         const actionText = editingDoc ? t('updated', preferredLanguage) : t('created', preferredLanguage);
         toast.success(t('archiveSaveSuccess', preferredLanguage, { action: actionText }));
         await fetchDocuments(currentPage, searchQuery);
     };
 
-   const handleSearch = (newQuery: SearchRequest['query']) => { setSearchQuery(newQuery); setCurrentPage(1); };
+   const handleSearch = (newQuery: SearchRequest['query'], newGroups: SearchRequest['groups'] = []) => { setSearchQuery(newQuery); setSearchGroups(newGroups); setCurrentPage(1); };
    const handlePageChange = (newPage: number) => { setCurrentPage(newPage); };
 
     const handlePreview = useCallback(async (doc: ArchiveDocumentSearchResult) => {
@@ -226,10 +419,22 @@ const ArchivePage: React.FC = () => {
            setIsBatchTagDialogOpen(false);
            return;
        }
+       // The dialog itself is the confirmation for small batches; large ones need the count typed in
+       if (totalDocs > bulkConfirmThreshold) {
+           const actionText = t(batchTagAction === 'add' ? 'addButton' : 'removeButton', preferredLanguage);
+           const confirmed = await confirm({
+               message: t('archiveBatchTagsLargeConfirm', preferredLanguage, { count: totalDocs }),
+               confirmLabel: actionText,
+               destructive: batchTagAction === 'remove',
+               itemCount: totalDocs,
+           });
+           if (!confirmed) return;
+       }
        setIsBatchTagLoading(true);
        try {
            const response = await api.batchTagArchiveDocuments({
-               searchQuery: searchQuery,
+               searchQuery: scopeListingQuery(searchQuery), // Same rows as the listing (and the confirmed count)
+               searchGroups: searchGroups.length > 0 ? searchGroups : undefined,
                tagIds: tagIds,
                action: batchTagAction,
            }, token);
@@ -281,11 +486,15 @@ const ArchivePage: React.FC = () => {
        <div className="flex flex-col sm:flex-row justify-between items-start sm:items-center gap-4">
            <div className='flex items-center gap-4'>
                {parentUnitId && (
-                  <Button variant="outline" size="icon" onClick={() => navigate('/archive')} title={t('backToArchiveButton', preferredLanguage)}>
+                  // Up one level: to the unit containing this one, or to the root
+                  <Button variant="outline" size="icon"
+                      onClick={() => navigate(parentUnit?.parentUnitArchiveDocumentId ? `/archive?unitId=${parentUnit.parentUnitArchiveDocumentId}` : '/archive')}
+                      title={parentUnit?.parentUnitArchiveDocumentId ? t('archiveGoToParentUnitButton', preferredLanguage) : t('backToArchiveButton', preferredLanguage)}>
                       <ArrowLeft className="h-4 w-4" />
                   </Button>
                 )}
                 <div>
+                    <UnitBreadcrumbs unit={parentUnit} />
                     <h1 className="text-2xl font-bold flex items-center gap-2">
                        {parentUnit ? <>{t('archiveUnitLabel', preferredLanguage)}: <span className='text-primary'>{parentUnit.title}</span></>
                                    : <>{t('archiveTitle', preferredLanguage)}</>}
@@ -303,6 +512,7 @@ const ArchivePage: React.FC = () => {
                           <Button
                              variant="ghost"
                              size="sm"
+                             data-help="batch-tags"
                              onClick={() => openBatchTagDialog('add')}
                               title={t(isSearchActive ? 'archiveBatchAddTooltipFiltered' : 'archiveBatchAddTooltipAll', preferredLanguage)}
                              disabled={isBatchTagLoading}
@@ -318,12 +528,33 @@ const ArchivePage: React.FC = () => {
                            >
                               <MinusCircle className="mr-2 h-4 w-4 text-red-600" /> {t('removeButton', preferredLanguage)} {t('tagsLabel', preferredLanguage)}
                           </Button>
+                          {isAdmin && (
+                              <Button variant="ghost" size="sm" onClick={() => setIsBulkEditOpen(true)} title={t('archiveBulkEditTooltip', preferredLanguage)} disabled={isBatchTagLoading || totalDocs === 0}>
+                                  <PencilLine className="mr-2 h-4 w-4" /> {t('archiveBulkEditButton', preferredLanguage)}
+                              </Button>
+                          )}
+                          <Button variant="outline" size="sm" onClick={() => setIsImportDialogOpen(true)} disabled={isBatchTagLoading}>
+                              <Upload className="mr-2 h-4 w-4" /> {t('archiveImportCsvButton', preferredLanguage)}
+                          </Button>
                       </>
                  )}
+                 <Button variant="outline" size="sm" onClick={handleExportCsv} disabled={!!exportProgress || totalDocs === 0} title={t('archiveExportCsvTooltip', preferredLanguage)}>
+                     {exportProgress ? <LoadingSpinner size="sm" className="mr-2" /> : <Download className="mr-2 h-4 w-4" />} {t('archiveExportCsvButton', preferredLanguage)}
+                 </Button>
+                 {exportProgress && (
+                     <>
+                         <span className="text-sm text-muted-foreground" role="status">
+                             {t('archiveExportProgress', preferredLanguage, { done: exportProgress.done, total: exportProgress.total })}
+                         </span>
+                         <Button variant="ghost" size="sm" onClick={() => { exportCancelledRef.current = true; }}>
+                             {t('cancelButton', preferredLanguage)}
+                         </Button>
+                     </>
+                 )}
                  {(isAdmin || isEmployee) && (
-                     <Dialog open={isFormOpen} onOpenChange={setIsFormOpen}>
+                     <Dialog open={isFormOpen} onOpenChange={handleFormOpenChange}>
                          <DialogTrigger asChild>
-                         <Button onClick={handleCreateNew} className='shrink-0'>
+                         <Button onClick={handleCreateNew} className='shrink-0' data-help="create">
                              <PlusCircle className="mr-2 h-4 w-4" />
                              {parentUnitId ? t('archiveCreateDocumentButton', preferredLanguage) : t('createRootItemButton', preferredLanguage)}
                          </Button>
@@ -334,6 +565,7 @@ const ArchivePage: React.FC = () => {
                                 {isFormOpen && (
                                     <DocumentForm
                                         docToEdit={editingDoc}
+                                        copyFrom={copyingDoc}
                                         onSave={handleSaveSuccess}
                                         forceType={formInitialType}
                                         forcedParentId={formInitialParentId}
@@ -352,10 +584,94 @@ const ArchivePage: React.FC = () => {
            fields={searchFields}
            onSearch={handleSearch}
            isLoading={isLoading || isBatchTagLoading}
+           allowMatchAny
        />
        {/* --------------------------------------------- */}
+       <div className="flex flex-wrap items-center gap-4">
+       <div className="relative w-full sm:w-64" data-help="quick-search">
+           <Search className="absolute left-2.5 top-2.5 h-4 w-4 text-muted-foreground" />
+           <Input
+               type="search"
+               value={quickText}
+               onChange={(e) => setQuickText(e.target.value)}
+               placeholder={t('archiveQuickSearchPlaceholder', preferredLanguage)}
+               aria-label={t('archiveQuickSearchPlaceholder', preferredLanguage)}
+               className="pl-8 h-9"
+           />
+       </div>
+       {(isAdmin || isEmployee) && availableTags.length > 0 && (
+           <DropdownMenu>
+               <DropdownMenuTrigger asChild>
+                   <Button variant={quickTagIds.length > 0 ? 'secondary' : 'outline'} size="sm" disabled={isBatchTagLoading}>
+                       <TagIcon className="mr-2 h-4 w-4" />
+                       {quickTagIds.length > 0
+                           ? t('archiveQuickTagFilterActive', preferredLanguage, { count: quickTagIds.length })
+                           : t('archiveQuickTagFilter', preferredLanguage)}
+                   </Button>
+               </DropdownMenuTrigger>
+               <DropdownMenuContent align="start" className="max-h-72 overflow-y-auto">
+                   {availableTags.map(tag => (
+                       <DropdownMenuCheckboxItem
+                           key={tag.tagId}
+                           checked={quickTagIds.includes(tag.tagId!)}
+                           onCheckedChange={() => toggleQuickTag(tag.tagId!)}
+                           onSelect={(e) => e.preventDefault()} // Keep the menu open while picking several tags
+                       >
+                           {tag.name}
+                       </DropdownMenuCheckboxItem>
+                   ))}
+                   {quickTagIds.length > 0 && (
+                       <>
+                           <DropdownMenuSeparator />
+                           <DropdownMenuItem onSelect={() => { setQuickTagIds([]); setCurrentPage(1); }}>{t('archiveQuickTagFilterClear', preferredLanguage)}</DropdownMenuItem>
+                       </>
+                   )}
+               </DropdownMenuContent>
+           </DropdownMenu>
+       )}
+       {!parentUnitId && (
+           <ToggleGroup
+               type="single"
+               size="sm"
+               variant="outline"
+               value={typeFilter}
+               onValueChange={(value) => { if (value) { setTypeFilter(value as typeof typeFilter); setCurrentPage(1); } }}
+               aria-label={t('archiveTypeFilterLabel', preferredLanguage)}
+               disabled={isLoading || isBatchTagLoading}
+               data-help="type-filter"
+           >
+               <ToggleGroupItem value="all" className="px-3">{t('archiveTypeFilterAll', preferredLanguage)}</ToggleGroupItem>
+               <ToggleGroupItem value="unit" className="px-3 gap-1"><Folder className="h-4 w-4" />{t('archiveTypeFilterUnits', preferredLanguage)}</ToggleGroupItem>
+               <ToggleGroupItem value="document" className="px-3 gap-1"><FileText className="h-4 w-4" />{t('archiveTypeFilterDocuments', preferredLanguage)}</ToggleGroupItem>
+           </ToggleGroup>
+       )}
+       <div className="flex items-center gap-2" data-help="digitized-filter">
+           <Checkbox
+               id="archive-digitized-only"
+               checked={digitizedOnly}
+               onCheckedChange={(checked) => { setDigitizedOnly(checked === true); setCurrentPage(1); }}
+               disabled={isLoading || isBatchTagLoading}
+           />
+           <Label htmlFor="archive-digitized-only" className="text-sm font-normal cursor-pointer inline-flex items-center gap-1">
+               <MonitorCheck className="h-4 w-4 text-sky-600" /> {t('archiveDigitizedOnlyFilter', preferredLanguage)}
+           </Label>
+       </div>
+       {(isAdmin || isEmployee) && (
+           <div className="flex items-center gap-2" data-help="show-disabled">
+               <Checkbox
+                   id="archive-show-disabled"
+                   checked={showDisabled}
+                   onCheckedChange={(checked) => { setShowDisabled(checked === true); setCurrentPage(1); }}
+                   disabled={isLoading || isBatchTagLoading}
+               />
+               <Label htmlFor="archive-show-disabled" className="text-sm font-normal cursor-pointer inline-flex items-center gap-1">
+                   <EyeOff className="h-4 w-4 text-muted-foreground" /> {t('archiveShowDisabledFilter', preferredLanguage)}
+               </Label>
+           </div>
+       )}
+       </div>
 
-        <Card>
+        <Card data-help="list">
              <CardHeader>
                   <CardDescription>
                        {totalDocs > 0 && !isLoading && (
@@ -376,6 +692,7 @@ const ArchivePage: React.FC = () => {
                  {error && <ErrorDisplay message={error} />}
              </CardHeader>
              <CardContent>
+                 <LoadingStatus isLoading={isLoading} label={t('archiveTitle', preferredLanguage)} count={documents.length} />
                  {(isLoading || isBatchTagLoading) && <div className='flex justify-center py-10'><LoadingSpinner /></div>}
                  {!isLoading && !isBatchTagLoading && !error && (
                    <>
@@ -383,23 +700,25 @@ const ArchivePage: React.FC = () => {
                         documents={documents}
                         onEdit={handleEdit}
                         onDisable={handleDisable}
+                        onRestore={handleRestore}
+                        onDuplicate={handleDuplicate}
                         onPreview={handlePreview}
                         onOpenUnit={handleOpenUnit}
+                        unitChildCounts={unitChildCounts}
                     />
-                      {totalPages > 1 && (
-                           <div className="mt-6 flex justify-center">
-                               <Pagination currentPage={currentPage} totalPages={totalPages} onPageChange={handlePageChange} />
-                           </div>
-                      )}
-                       {documents.length === 0 && (
-                         <p className="text-center text-muted-foreground pt-6">
-                             {searchQuery.length > 0 ? t('noResultsFound', preferredLanguage) :
-                              parentUnitId ? t('archiveNoItemsInUnit', preferredLanguage, { unitTitle: parentUnit?.title || t('thisUnit', preferredLanguage) }) :
-                              isUserRole ? t('archiveNoItemsForUserTags', preferredLanguage) :
-                              t('archiveIsEmpty', preferredLanguage)}
-                              {(isAdmin || isEmployee) && !parentUnitId && documents.length === 0 && searchQuery.length === 0 && ` ${t('archiveClickCreateHint', preferredLanguage)}`}
-                         </p>
-                      )}
+                      <div className="mt-6 flex justify-center">
+                          <Pagination currentPage={currentPage} totalPages={totalPages} onPageChange={handlePageChange} totalItems={totalDocs} pageSize={pageSize} />
+                      </div>
+                       {documents.length === 0 && (isSearchActive
+                           ? <EmptyState icon={SearchX} title={t('noResultsFound', preferredLanguage)} />
+                           : <EmptyState
+                                icon={parentUnitId ? FolderOpen : ArchiveIcon}
+                                title={parentUnitId ? t('archiveNoItemsInUnit', preferredLanguage, { unitTitle: parentUnit?.title || t('thisUnit', preferredLanguage) }) :
+                                    isUserRole ? t('archiveNoItemsForUserTags', preferredLanguage) : t('archiveIsEmpty', preferredLanguage)}
+                                description={isUserRole ? undefined : t('archiveEmptyDescription', preferredLanguage)}
+                                action={(isAdmin || isEmployee) ? { label: t(parentUnitId ? 'archiveCreateDocumentButton' : 'archiveCreateFirstButton', preferredLanguage), onClick: handleCreateNew } : undefined}
+                             />
+                       )}
                    </>
                  )}
             </CardContent>
@@ -411,6 +730,8 @@ const ArchivePage: React.FC = () => {
             document={previewingDoc}
             onEdit={handleEdit}
             onDisable={handleDisable}
+            onRestore={handleRestore}
+            onDuplicate={handleDuplicate}
             parentUnitTitle={parentUnit?.archiveDocumentId === previewingDoc?.parentUnitArchiveDocumentId ? parentUnit?.title : undefined}
          />
 
@@ -423,6 +744,26 @@ const ArchivePage: React.FC = () => {
              isLoading={isBatchTagLoading}
              itemCount={totalDocs}
          />
+
+         {(isAdmin || isEmployee) && (
+             <ArchiveImportDialog
+                 isOpen={isImportDialogOpen}
+                 onOpenChange={setIsImportDialogOpen}
+                 availableTags={availableTags}
+                 defaultParentId={parentUnitId}
+                 onImported={() => fetchDocuments(currentPage, searchQuery)}
+             />
+         )}
+         {isAdmin && (
+             <BulkEditFieldDialog
+                 isOpen={isBulkEditOpen}
+                 onOpenChange={setIsBulkEditOpen}
+                 searchQuery={scopeListingQuery(searchQuery)}
+                 searchGroups={searchGroups}
+                 totalDocs={totalDocs}
+                 onApplied={() => fetchDocuments(currentPage, searchQuery)}
+             />
+         )}
     </div>
   );
 };
@@ -0,0 +1,298 @@
+import React, { useState, useEffect, useMemo } from 'react';
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, DialogFooter } from "@/components/ui/dialog";
+import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from "@/components/ui/table";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
+import { ToggleGroup, ToggleGroupItem } from '@/components/ui/toggle-group';
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { Checkbox } from "@/components/ui/checkbox";
+import { ArrowLeft, PencilLine } from 'lucide-react';
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import ErrorDisplay from '@/components/shared/ErrorDisplay';
+import { useAuth } from '@/hooks/useAuth';
+import { useConfirm } from '@/hooks/useConfirm';
+import api from '@/lib/api';
+import { createArchiveDocumentFormSchema } from '@/lib/zodSchemas';
+import type { ArchiveDocument, UpdateArchiveDocumentInput } from '../../../../backend/src/functionalities/archive/document/models';
+import type { SearchRequest } from '../../../../backend/src/utils/search';
+import { toast } from '@/lib/toast';
+import { cn } from '@/lib/utils';
+import { t } from '@/translations/utils';
+import type { ArchiveTranslationKey } from '@/translations/models/archive';
+
+// Text fields that can be edited in bulk, with the form label used for each
+const BULK_EDIT_FIELDS = [
+    { field: 'title', labelKey: 'archiveFormTitleLabel' },
+    { field: 'creator', labelKey: 'archiveFormCreatorLabel' },
+    { field: 'creationDate', labelKey: 'archiveFormCreationDateLabel' },
+    { field: 'topographicSignature', labelKey: 'archiveFormTopoSigLabel' },
+    { field: 'numberOfPages', labelKey: 'archiveFormPagesLabel' },
+    { field: 'documentType', labelKey: 'archiveFormDocTypeLabel' },
+    { field: 'dimensions', labelKey: 'archiveFormDimensionsLabel' },
+    { field: 'binding', labelKey: 'archiveFormBindingLabel' },
+    { field: 'condition', labelKey: 'archiveFormConditionLabel' },
+    { field: 'documentLanguage', labelKey: 'archiveFormLanguageLabel' },
+    { field: 'contentDescription', labelKey: 'archiveFormContentDescLabel' },
+    { field: 'remarks', labelKey: 'archiveFormRemarksLabel' },
+    { field: 'accessLevel', labelKey: 'archiveFormAccessLevelLabel' },
+    { field: 'accessConditions', labelKey: 'archiveFormAccessConditionsLabel' },
+    { field: 'additionalInformation', labelKey: 'archiveFormAdditionalInfoLabel' },
+    { field: 'relatedDocumentsReferences', labelKey: 'archiveFormRelatedDocsLabel' },
+] as const satisfies readonly { field: keyof ArchiveDocument, labelKey: ArchiveTranslationKey }[];
+
+type BulkEditField = typeof BULK_EDIT_FIELDS[number]['field'];
+type EditMode = 'set' | 'replace';
+
+const PREVIEW_FETCH_PAGE_SIZE = 100;
+const PREVIEW_MAX_DOCUMENTS = 1000; // Keeps the preview table reviewable
+
+interface PreviewRow {
+    doc: ArchiveDocument;
+    oldValue: string | null;
+    newValue: string | null;
+    error: string | null; // Validation error of the new value
+}
+
+interface BulkEditFieldDialogProps {
+    isOpen: boolean;
+    onOpenChange: (open: boolean) => void;
+    searchQuery: SearchRequest['query']; // Same filters as the listing
+    searchGroups?: SearchRequest['groups'];
+    totalDocs: number;
+    onApplied: () => void;
+}
+
+const fieldSchemas = createArchiveDocumentFormSchema.innerType().shape;
+
+const BulkEditFieldDialog: React.FC<BulkEditFieldDialogProps> = ({ isOpen, onOpenChange, searchQuery, searchGroups, totalDocs, onApplied }) => {
+    const { token, preferredLanguage } = useAuth();
+    const confirm = useConfirm();
+    const [field, setField] = useState<BulkEditField>('documentType');
+    const [mode, setMode] = useState<EditMode>('set');
+    const [value, setValue] = useState('');
+    const [findText, setFindText] = useState('');
+    const [previewRows, setPreviewRows] = useState<PreviewRow[] | null>(null); // null = edit step
+    const [unchangedCount, setUnchangedCount] = useState(0);
+    const [deselectedIds, setDeselectedIds] = useState<Set<number>>(new Set());
+    const [isPreviewLoading, setIsPreviewLoading] = useState(false);
+    const [applyProgress, setApplyProgress] = useState<{ done: number, total: number } | null>(null);
+    const [error, setError] = useState<string | null>(null);
+
+    useEffect(() => {
+        if (isOpen) {
+            setPreviewRows(null); setDeselectedIds(new Set()); setError(null);
+            setValue(''); setFindText('');
+        }
+    }, [isOpen]);
+
+    const fieldLabel = (f: BulkEditField) => t(BULK_EDIT_FIELDS.find(x => x.field === f)!.labelKey, preferredLanguage).replace(/ \*$| \(.*\)$/, '');
+
+    // Empty result clears optional fields
+    const computeNewValue = (oldValue: string | null): string | null => {
+        const result = mode === 'set' ? value : (oldValue ?? '').split(findText).join(value);
+        return result.trim() === '' ? null : result;
+    };
+
+    const validateValue = (newValue: string | null): string | null => {
+        const parsed = fieldSchemas[field].safeParse(newValue ?? (field === 'title' || field === 'creator' || field === 'creationDate' ? '' : null));
+        return parsed.success ? null : parsed.error.issues[0]?.message ?? t('archiveBulkEditInvalidValue', preferredLanguage);
+    };
+
+    const handlePreview = async () => {
+        if (!token) return;
+        if (mode === 'replace' && !findText) { setError(t('archiveBulkEditFindRequired', preferredLanguage)); return; }
+        setIsPreviewLoading(true);
+        setError(null);
+        try {
+            const docs: ArchiveDocument[] = [];
+            for (let page = 1; docs.length < PREVIEW_MAX_DOCUMENTS; page++) {
+                const response = await api.searchArchiveDocuments({ query: searchQuery, groups: searchGroups, page, pageSize: PREVIEW_FETCH_PAGE_SIZE }, token);
+                docs.push(...response.data);
+                if (page >= response.totalPages || response.data.length === 0) break;
+            }
+            const rows: PreviewRow[] = [];
+            for (const doc of docs.slice(0, PREVIEW_MAX_DOCUMENTS)) {
+                const oldValue = (doc[field] as string | null | undefined) ?? null;
+                const newValue = computeNewValue(oldValue);
+                if (newValue === oldValue) continue;
+                rows.push({ doc, oldValue, newValue, error: validateValue(newValue) });
+            }
+            setUnchangedCount(Math.min(docs.length, PREVIEW_MAX_DOCUMENTS) - rows.length);
+            // Rows that would fail validation start deselected
+            setDeselectedIds(new Set(rows.filter(r => r.error).map(r => r.doc.archiveDocumentId!)));
+            setPreviewRows(rows);
+        } catch (err: any) {
+            setError(err.message || t('archiveBulkEditPreviewFailed', preferredLanguage));
+            console.error("Bulk Edit Preview Error:", err);
+        } finally {
+            setIsPreviewLoading(false);
+        }
+    };
+
+    const selectedRows = useMemo(
+        () => (previewRows ?? []).filter(r => !deselectedIds.has(r.doc.archiveDocumentId!)),
+        [previewRows, deselectedIds]
+    );
+
+    const toggleRow = (id: number) => setDeselectedIds(prev => {
+        const next = new Set(prev);
+        if (next.has(id)) next.delete(id); else next.add(id);
+        return next;
+    });
+
+    const toggleAll = (selectAll: boolean) => setDeselectedIds(
+        selectAll ? new Set(previewRows!.filter(r => r.error).map(r => r.doc.archiveDocumentId!)) : new Set(previewRows!.map(r => r.doc.archiveDocumentId!))
+    );
+
+    const handleApply = async () => {
+        if (!token || selectedRows.length === 0) return;
+        const count = selectedRows.length;
+        const confirmed = await confirm({
+            message: t('archiveBulkEditConfirm', preferredLanguage, { count, field: fieldLabel(field) }),
+            confirmLabel: t('archiveBulkEditApplyButton', preferredLanguage, { count }),
+            destructive: false,
+            itemCount: count,
+        });
+        if (!confirmed) return;
+
+        const failedTitles: string[] = [];
+        setApplyProgress({ done: 0, total: count });
+        for (const [i, row] of selectedRows.entries()) {
+            try {
+                const payload = { [field]: row.newValue } as UpdateArchiveDocumentInput;
+                await api.updateArchiveDocument(row.doc.archiveDocumentId!, payload, token);
+            } catch (err) {
+                console.error(`Bulk Edit Document ${row.doc.archiveDocumentId} Error:`, err);
+                failedTitles.push(row.doc.title);
+            }
+            setApplyProgress({ done: i + 1, total: count });
+        }
+        setApplyProgress(null);
+
+        const updated = count - failedTitles.length;
+        if (updated > 0) toast.success(t('archiveBulkEditSummary', preferredLanguage, { updated, total: count }));
+        if (failedTitles.length > 0) toast.error(t('archiveBulkEditFailures', preferredLanguage, { failed: failedTitles.length, titles: failedTitles.join(', ') }));
+        onApplied();
+        onOpenChange(false);
+    };
+
+    const isApplying = applyProgress !== null;
+    const allSelected = !!previewRows && selectedRows.length === previewRows.filter(r => !r.error).length && selectedRows.length > 0;
+
+    return (
+        <Dialog open={isOpen} onOpenChange={(open) => { if (!isApplying) onOpenChange(open); }}>
+            <DialogContent className={cn(previewRows ? "sm:max-w-4xl" : "sm:max-w-md")}>
+                <DialogHeader>
+                    <DialogTitle className='flex items-center gap-2'><PencilLine className='h-4 w-4' /> {t('archiveBulkEditTitle', preferredLanguage)}</DialogTitle>
+                    <DialogDescription>
+                        {previewRows
+                            ? t('archiveBulkEditPreviewDescription', preferredLanguage, { count: previewRows.length, unchanged: unchangedCount, field: fieldLabel(field) })
+                            : t('archiveBulkEditDescription', preferredLanguage, { count: totalDocs.toLocaleString() })}
+                    </DialogDescription>
+                </DialogHeader>
+
+                {error && <ErrorDisplay message={error} />}
+
+                {!previewRows ? (
+                    <div className="grid gap-4 py-2">
+                        <div className="grid gap-1.5">
+                            <Label htmlFor="bulk-edit-field">{t('archiveBulkEditFieldLabel', preferredLanguage)}</Label>
+                            <Select value={field} onValueChange={(v) => setField(v as BulkEditField)}>
+                                <SelectTrigger id="bulk-edit-field"><SelectValue /></SelectTrigger>
+                                <SelectContent>
+                                    {BULK_EDIT_FIELDS.map(f => <SelectItem key={f.field} value={f.field}>{fieldLabel(f.field)}</SelectItem>)}
+                                </SelectContent>
+                            </Select>
+                        </div>
+                        <ToggleGroup type="single" variant="outline" size="sm" value={mode} onValueChange={(v) => { if (v) setMode(v as EditMode); }} className="justify-start">
+                            <ToggleGroupItem value="set">{t('archiveBulkEditModeSet', preferredLanguage)}</ToggleGroupItem>
+                            <ToggleGroupItem value="replace">{t('archiveBulkEditModeReplace', preferredLanguage)}</ToggleGroupItem>
+                        </ToggleGroup>
+                        {mode === 'replace' && (
+                            <div className="grid gap-1.5">
+                                <Label htmlFor="bulk-edit-find">{t('archiveBulkEditFindLabel', preferredLanguage)}</Label>
+                                <Input id="bulk-edit-find" value={findText} onChange={(e) => setFindText(e.target.value)} />
+                            </div>
+                        )}
+                        <div className="grid gap-1.5">
+                            <Label htmlFor="bulk-edit-value">{t(mode === 'set' ? 'archiveBulkEditNewValueLabel' : 'archiveBulkEditReplaceWithLabel', preferredLanguage)}</Label>
+                            <Input id="bulk-edit-value" value={value} onChange={(e) => setValue(e.target.value)} />
+                            {mode === 'set' && <p className="text-xs text-muted-foreground">{t('archiveBulkEditEmptyClearsHint', preferredLanguage)}</p>}
+                        </div>
+                        {totalDocs > PREVIEW_MAX_DOCUMENTS && (
+                            <p className="text-xs text-amber-700">{t('archiveBulkEditCapNote', preferredLanguage, { max: PREVIEW_MAX_DOCUMENTS })}</p>
+                        )}
+                    </div>
+                ) : previewRows.length === 0 ? (
+                    <p className="text-sm text-muted-foreground text-center py-6">{t('archiveBulkEditNothingChanges', preferredLanguage)}</p>
+                ) : (
+                    <div className="max-h-[55vh] overflow-auto border rounded-md">
+                        <Table>
+                            <TableHeader>
+                                <TableRow>
+                                    <TableHead className="w-[40px]">
+                                        <Checkbox checked={allSelected} onCheckedChange={(checked) => toggleAll(!!checked)} disabled={isApplying} aria-label={t('archiveBulkEditSelectAll', preferredLanguage)} />
+                                    </TableHead>
+                                    <TableHead>{t('titleLabel', preferredLanguage)}</TableHead>
+                                    <TableHead>{t('archiveBulkEditOldValueColumn', preferredLanguage)}</TableHead>
+                                    <TableHead>{t('archiveBulkEditNewValueColumn', preferredLanguage)}</TableHead>
+                                </TableRow>
+                            </TableHeader>
+                            <TableBody>
+                                {previewRows.map(row => {
+                                    const id = row.doc.archiveDocumentId!;
+                                    const isSelected = !deselectedIds.has(id);
+                                    return (
+                                        <TableRow key={id} className={cn(!isSelected && "opacity-50")}>
+                                            <TableCell>
+                                                <Checkbox checked={isSelected} onCheckedChange={() => toggleRow(id)} disabled={isApplying || !!row.error} aria-label={row.doc.title} />
+                                            </TableCell>
+                                            <TableCell className="text-sm max-w-[220px] truncate" title={row.doc.title}>{row.doc.title}</TableCell>
+                                            <TableCell className="text-xs text-muted-foreground line-through break-words max-w-[260px]">
+                                                {row.oldValue ?? <i>{t('archiveBulkEditEmptyValue', preferredLanguage)}</i>}
+                                            </TableCell>
+                                            <TableCell className="text-xs break-words max-w-[260px]">
+                                                {row.newValue ?? <i className="text-muted-foreground">{t('archiveBulkEditEmptyValue', preferredLanguage)}</i>}
+                                                {row.error && <p className="text-destructive">{row.error}</p>}
+                                            </TableCell>
+                                        </TableRow>
+                                    );
+                                })}
+                            </TableBody>
+                        </Table>
+                    </div>
+                )}
+
+                <DialogFooter className="sm:justify-between gap-2">
+                    {previewRows ? (
+                        <Button type="button" variant="ghost" onClick={() => setPreviewRows(null)} disabled={isApplying}>
+                            <ArrowLeft className="mr-2 h-4 w-4" /> {t('archiveBulkEditBackButton', preferredLanguage)}
+                        </Button>
+                    ) : <span />}
+                    <div className="flex gap-2 items-center">
+                        {applyProgress && (
+                            <span className="text-sm text-muted-foreground" role="status">
+                                {t('archiveBulkEditProgress', preferredLanguage, { done: applyProgress.done, total: applyProgress.total })}
+                            </span>
+                        )}
+                        <Button type="button" variant="outline" onClick={() => onOpenChange(false)} disabled={isApplying}>{t('cancelButton', preferredLanguage)}</Button>
+                        {previewRows ? (
+                            <Button type="button" onClick={handleApply} disabled={isApplying || selectedRows.length === 0}>
+                                {isApplying && <LoadingSpinner size="sm" className="mr-2" />}
+                                {t('archiveBulkEditApplyButton', preferredLanguage, { count: selectedRows.length })}
+                            </Button>
+                        ) : (
+                            <Button type="button" onClick={handlePreview} disabled={isPreviewLoading || totalDocs === 0}>
+                                {isPreviewLoading && <LoadingSpinner size="sm" className="mr-2" />}
+                                {t('archiveBulkEditPreviewButton', preferredLanguage)}
+                            </Button>
+                        )}
+                    </div>
+                </DialogFooter>
+            </DialogContent>
+        </Dialog>
+    );
+};
+
+export default BulkEditFieldDialog;
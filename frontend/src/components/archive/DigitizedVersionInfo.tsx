@@ -0,0 +1,77 @@
+import React, { useState, useEffect } from 'react';
+import { AlertTriangle, ExternalLink } from 'lucide-react';
+import CopyButton from '@/components/shared/CopyButton';
+import { useAuth } from '@/hooks/useAuth';
+import { isHttpUrl } from '@/lib/zodSchemas';
+import { t } from '@/translations/utils';
+
+interface DigitizedVersionInfoProps {
+    isDigitized: boolean;
+    link?: string | null;
+}
+
+// Links to these file types get a thumbnail (judged by the path only - nothing is fetched to find out)
+const IMAGE_EXTENSIONS = /\.(png|jpe?g|gif|webp|avif|bmp|svg)$/i;
+
+const isImageUrl = (link: string) => {
+    try {
+        return IMAGE_EXTENSIONS.test(new URL(link).pathname);
+    } catch {
+        return false;
+    }
+};
+
+// "Digitized" line of the document preview: the link opens in a new tab, image links also show a thumbnail.
+// Only http(s) links are made clickable; anything else (e.g. entered before validation existed) is shown as text.
+const DigitizedVersionInfo: React.FC<DigitizedVersionInfoProps> = ({ isDigitized, link }) => {
+    const { preferredLanguage } = useAuth();
+    const [thumbnailFailed, setThumbnailFailed] = useState(false);
+    const trimmedLink = link?.trim() ?? '';
+    const isValidLink = isHttpUrl(trimmedLink);
+
+    useEffect(() => { setThumbnailFailed(false); }, [trimmedLink]);
+
+    return (
+        <div className='space-y-1'>
+            <p>
+                <strong>{t('archivePreviewDigitizedLabel', preferredLanguage)}:</strong>{' '}
+                {isDigitized ? t('archivePreviewDigitizedYes', preferredLanguage) : t('archivePreviewDigitizedNo', preferredLanguage)}
+                {trimmedLink && (isValidLink ? (
+                    <>
+                        {' - '}
+                        <a href={trimmedLink} target="_blank" rel="noopener noreferrer" className='text-primary hover:underline break-all inline-flex items-center gap-1'>
+                            {trimmedLink} <ExternalLink className='h-3 w-3 shrink-0' />
+                        </a>
+                    </>
+                ) : (
+                    <> - <span className='break-all'>{trimmedLink}</span></>
+                ))}
+                {trimmedLink && <CopyButton value={trimmedLink} label={t('archivePreviewDigitizedLabel', preferredLanguage)} className='align-middle ml-1' />}
+            </p>
+            {isDigitized && !trimmedLink && (
+                <p className='text-xs text-amber-600 dark:text-amber-500 inline-flex items-center gap-1'>
+                    <AlertTriangle className='h-3.5 w-3.5' /> {t('archivePreviewDigitizedMissingLink', preferredLanguage)}
+                </p>
+            )}
+            {trimmedLink && !isValidLink && (
+                <p className='text-xs text-destructive inline-flex items-center gap-1'>
+                    <AlertTriangle className='h-3.5 w-3.5' /> {t('archivePreviewDigitizedInvalidLink', preferredLanguage)}
+                </p>
+            )}
+            {isValidLink && isImageUrl(trimmedLink) && !thumbnailFailed && (
+                <a href={trimmedLink} target="_blank" rel="noopener noreferrer" className='block w-fit' title={t('archivePreviewDigitizedOpenImage', preferredLanguage)}>
+                    <img
+                        src={trimmedLink}
+                        alt={t('archivePreviewDigitizedThumbnailAlt', preferredLanguage)}
+                        loading="lazy"
+                        referrerPolicy="no-referrer"
+                        onError={() => setThumbnailFailed(true)}
+                        className='max-h-40 max-w-xs rounded border object-contain bg-muted'
+                    />
+                </a>
+            )}
+        </div>
+    );
+};
+
+export default DigitizedVersionInfo;
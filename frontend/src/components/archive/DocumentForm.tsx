@@ -15,11 +15,14 @@ import TagSelector from '@/components/shared/TagSelector';
 import SignaturePathSelector from '@/components/shared/SignaturePathSelector';
 import UnitSelector from './UnitSelector';
 import { useAuth } from '@/hooks/useAuth';
+import { useConfirm } from '@/hooks/useConfirm';
 import api from '@/lib/api';
+import { loadViewState, saveViewState } from '@/lib/viewState';
 // Updated type imports (no ownerUserId/ownerLogin)
 import type { ArchiveDocument, ArchiveDocumentType } from '../../../../backend/src/functionalities/archive/document/models';
 import type { CreateArchiveDocumentInput, UpdateArchiveDocumentInput } from '../../../../backend/src/functionalities/archive/document/models';
-import { toast } from "sonner";
+import { toast } from '@/lib/toast';
+import { AlertTriangle, ChevronDown, ChevronRight, ChevronsDownUp, ChevronsUpDown } from 'lucide-react';
 import { cn } from '@/lib/utils';
 import { z } from 'zod';
 import { t } from '@/translations/utils';
@@ -27,8 +30,19 @@ import { t } from '@/translations/utils';
 
 type CreateArchiveDocumentFormData = z.infer<typeof createArchiveDocumentFormSchema>;
 
+// Sections of rarely used fields that can be collapsed (remembered across sessions); basic info and indexing always show
+const COLLAPSIBLE_SECTIONS = {
+  physical: ['numberOfPages', 'documentType', 'dimensions', 'binding', 'condition'],
+  context: ['documentLanguage', 'contentDescription', 'remarks', 'relatedDocumentsReferences', 'additionalInformation'],
+  access: ['accessLevel', 'accessConditions', 'isDigitized', 'digitizedVersionLink'],
+} satisfies Record<string, (keyof CreateArchiveDocumentFormData)[]>;
+type CollapsibleSectionId = keyof typeof COLLAPSIBLE_SECTIONS;
+
+const isFilled = (value: unknown) => typeof value === 'string' ? value.trim() !== '' : value !== null && value !== undefined && value !== false;
+
 interface DocumentFormProps {
   docToEdit: ArchiveDocument | null;
+  copyFrom?: ArchiveDocument | null; // Create mode prefilled from this item ("Duplicate"), title marked as a copy
   onSave: () => void;
   forceType?: ArchiveDocumentType;
   forcedParentId?: number;
@@ -38,6 +52,7 @@ interface DocumentFormProps {
 
 const DocumentForm: React.FC<DocumentFormProps> = ({
     docToEdit,
+    copyFrom,
     onSave,
     forceType,
     forcedParentId,
@@ -45,6 +60,7 @@ const DocumentForm: React.FC<DocumentFormProps> = ({
     onTypeChange
 }) => {
   const { token, preferredLanguage } = useAuth();
+  const confirm = useConfirm();
   const [isLoading, setIsLoading] = useState(false);
   const [isFetchingDetails, setIsFetchingDetails] = useState(false);
   const [error, setError] = useState<string | null>(null);
@@ -52,6 +68,9 @@ const DocumentForm: React.FC<DocumentFormProps> = ({
   const [selectedTagIds, setSelectedTagIds] = useState<number[]>([]);
   const [descriptiveSignatures, setDescriptiveSignatures] = useState<number[][]>([]);
   const [selectedParentUnitId, setSelectedParentUnitId] = useState<number | null>(forcedParentId ?? null);
+  const [selectedParentUnit, setSelectedParentUnit] = useState<ArchiveDocument | null>(null); // Loaded incl. inactive, to detect disabled units
+  const [isReenablingParent, setIsReenablingParent] = useState(false);
+  const [collapsedSections, setCollapsedSections] = useState<string[]>(() => loadViewState().archiveFormCollapsedSections ?? []);
 
   const { register, handleSubmit, reset, control, setValue, watch, formState: { errors } } = useForm({
     resolver: zodResolver(createArchiveDocumentFormSchema),
@@ -75,18 +94,18 @@ const DocumentForm: React.FC<DocumentFormProps> = ({
   }, [watchedType, onTypeChange]);
 
   useEffect(() => {
-    if (forcedParentId === undefined) {
-        setValue('parentUnitArchiveDocumentId', selectedParentUnitId);
-    }
-  }, [selectedParentUnitId, setValue, forcedParentId]);
+    setValue('parentUnitArchiveDocumentId', selectedParentUnitId);
+  }, [selectedParentUnitId, setValue]);
 
   useEffect(() => {
     const populateForm = async () => {
-        if (docToEdit?.archiveDocumentId && token) {
+        const sourceDoc = docToEdit ?? copyFrom;
+        const copyTitle = (title: string) => copyFrom && !docToEdit ? t('archiveDuplicateTitle', preferredLanguage, { title }) : title;
+        if (sourceDoc?.archiveDocumentId && token) {
             setIsFetchingDetails(true); setError(null);
             try {
                 // Fetch full doc details including tags
-                const fullDoc = await api.getArchiveDocumentById(docToEdit.archiveDocumentId, token);
+                const fullDoc = await api.getArchiveDocumentById(sourceDoc.archiveDocumentId, token);
                 const tagIds = fullDoc.tags?.map(t => t.tagId!) ?? [];
                 const parentId = fullDoc.parentUnitArchiveDocumentId ?? null;
                 const topoSignature = fullDoc.topographicSignature ?? null;
@@ -95,7 +114,7 @@ const DocumentForm: React.FC<DocumentFormProps> = ({
                 reset({
                     parentUnitArchiveDocumentId: forcedParentId ?? parentId,
                     type: forceType ?? fullDoc.type ?? 'document',
-                    title: fullDoc.title ?? '', creator: fullDoc.creator ?? '',
+                    title: copyTitle(fullDoc.title ?? ''), creator: fullDoc.creator ?? '',
                     creationDate: fullDoc.creationDate ?? '',
                     numberOfPages: fullDoc.numberOfPages ?? null,
                     documentType: fullDoc.documentType ?? null,
@@ -125,17 +144,17 @@ const DocumentForm: React.FC<DocumentFormProps> = ({
                 setError(msg); toast.error(msg); console.error("Load Error:", err);
                  // Fallback to potentially partial data from list
                  reset({
-                    parentUnitArchiveDocumentId: forcedParentId ?? docToEdit.parentUnitArchiveDocumentId ?? null,
-                    type: forceType ?? docToEdit.type ?? 'document',
-                    title: docToEdit.title ?? '', creator: docToEdit.creator ?? '',
-                    creationDate: docToEdit.creationDate ?? '',
-                    tagIds: docToEdit.tags?.map(t => t.tagId!) ?? [],
-                    topographicSignature: docToEdit.topographicSignature ?? null,
-                    descriptiveSignatureElementIds: docToEdit.descriptiveSignatureElementIds ?? [],
+                    parentUnitArchiveDocumentId: forcedParentId ?? sourceDoc.parentUnitArchiveDocumentId ?? null,
+                    type: forceType ?? sourceDoc.type ?? 'document',
+                    title: copyTitle(sourceDoc.title ?? ''), creator: sourceDoc.creator ?? '',
+                    creationDate: sourceDoc.creationDate ?? '',
+                    tagIds: sourceDoc.tags?.map(t => t.tagId!) ?? [],
+                    topographicSignature: sourceDoc.topographicSignature ?? null,
+                    descriptiveSignatureElementIds: sourceDoc.descriptiveSignatureElementIds ?? [],
                  });
-                 setSelectedTagIds(docToEdit.tags?.map(t => t.tagId!) ?? []);
-                 setDescriptiveSignatures(docToEdit.descriptiveSignatureElementIds ?? []);
-                 setSelectedParentUnitId(forcedParentId ?? docToEdit.parentUnitArchiveDocumentId ?? null);
+                 setSelectedTagIds(sourceDoc.tags?.map(t => t.tagId!) ?? []);
+                 setDescriptiveSignatures(sourceDoc.descriptiveSignatureElementIds ?? []);
+                 setSelectedParentUnitId(forcedParentId ?? sourceDoc.parentUnitArchiveDocumentId ?? null);
             } finally { setIsFetchingDetails(false); }
         } else {
             // Reset form for creation
@@ -159,7 +178,34 @@ const DocumentForm: React.FC<DocumentFormProps> = ({
         }
     };
     populateForm();
-  }, [docToEdit, reset, token, forceType, forcedParentId, preferredLanguage]);
+  }, [docToEdit, copyFrom, reset, token, forceType, forcedParentId, preferredLanguage]);
+
+  // Load the selected parent unit (including inactive ones) to warn about disabled units
+  useEffect(() => {
+    if (!selectedParentUnitId || !token) { setSelectedParentUnit(null); return; }
+    let isCancelled = false;
+    api.getArchiveDocumentById(selectedParentUnitId, token, true)
+        .then(unit => { if (!isCancelled) setSelectedParentUnit(unit); })
+        .catch(err => { if (!isCancelled) setSelectedParentUnit(null); console.error("Failed to load parent unit:", err); });
+    return () => { isCancelled = true; };
+  }, [selectedParentUnitId, token]);
+
+  const isParentUnitDisabled = watchedType === 'document' && !!selectedParentUnit && !selectedParentUnit.active;
+
+  const handleReenableParentUnit = async () => {
+    if (!token || !selectedParentUnit?.archiveDocumentId) return;
+    setIsReenablingParent(true);
+    try {
+        const reenabledUnit = await api.updateArchiveDocument(selectedParentUnit.archiveDocumentId, { active: true }, token);
+        setSelectedParentUnit(reenabledUnit);
+        toast.success(t('archiveParentUnitReenabledSuccess', preferredLanguage, { unitTitle: reenabledUnit.title }));
+    } catch (err: any) {
+        toast.error(t('errorMessageTemplate', preferredLanguage, { message: err.message }));
+        console.error("Re-enable Unit Error:", err);
+    } finally {
+        setIsReenablingParent(false);
+    }
+  };
 
   useEffect(() => { setValue('tagIds', selectedTagIds); }, [selectedTagIds, setValue]);
   useEffect(() => { setValue('descriptiveSignatureElementIds', descriptiveSignatures); }, [descriptiveSignatures, setValue]);
@@ -167,9 +213,16 @@ const DocumentForm: React.FC<DocumentFormProps> = ({
   // Removed ownerUserId from form data extraction
   const onSubmit: SubmitHandler<CreateArchiveDocumentFormData> = async (data) => {
     if (!token) return;
+    // Filing into a disabled unit is most likely a mistake - require explicit confirmation
+    if (isParentUnitDisabled && !await confirm({
+        message: t('archiveParentUnitDisabledConfirm', preferredLanguage, { unitTitle: selectedParentUnit?.title ?? '' }),
+        confirmLabel: t('saveButton', preferredLanguage),
+        destructive: false,
+    })) return;
     setIsLoading(true); setError(null);
 
-    const finalParentId = forcedParentId !== undefined ? forcedParentId : selectedParentUnitId;
+    // forcedParentId only preselects the unit - the picker may file the document elsewhere
+    const finalParentId = selectedParentUnitId;
     // Remove fields not directly sent to backend payload, but used for form state/validation
     const { tagIds: _, parentUnitArchiveDocumentId: __, type: _____, descriptiveSignatureElementIds: _______, ...coreData } = data;
 
@@ -239,6 +292,39 @@ const DocumentForm: React.FC<DocumentFormProps> = ({
   };
 
 
+  const updateCollapsedSections = (next: string[]) => {
+    setCollapsedSections(next);
+    saveViewState({ archiveFormCollapsedSections: next });
+  };
+  const toggleSection = (id: CollapsibleSectionId) => updateCollapsedSections(
+    collapsedSections.includes(id) ? collapsedSections.filter(s => s !== id) : [...collapsedSections, id]
+  );
+  const allSectionsExpanded = collapsedSections.length === 0;
+
+  // Failed validation opens the sections holding the invalid fields, so the messages can be seen
+  const onInvalid = (invalidFields: Partial<Record<keyof CreateArchiveDocumentFormData, unknown>>) => {
+    const invalidSections = (Object.keys(COLLAPSIBLE_SECTIONS) as CollapsibleSectionId[])
+      .filter(id => COLLAPSIBLE_SECTIONS[id].some(field => field in invalidFields));
+    if (invalidSections.some(id => collapsedSections.includes(id))) {
+      updateCollapsedSections(collapsedSections.filter(id => !invalidSections.includes(id as CollapsibleSectionId)));
+    }
+  };
+
+  // Card header that folds the section; a folded section tells how many of its fields hold data
+  const sectionHeader = (id: CollapsibleSectionId, titleKey: Parameters<typeof t>[0]) => {
+    const isCollapsed = collapsedSections.includes(id);
+    const filledCount = COLLAPSIBLE_SECTIONS[id].filter(field => isFilled(watch(field))).length;
+    return (
+      <CardHeader className={cn(isCollapsed && 'pb-6')}>
+        <button type="button" className="flex items-center gap-2 text-left" onClick={() => toggleSection(id)} aria-expanded={!isCollapsed}>
+          {isCollapsed ? <ChevronRight className="h-4 w-4 shrink-0" /> : <ChevronDown className="h-4 w-4 shrink-0" />}
+          <CardTitle className='text-lg'>{t(titleKey, preferredLanguage)}</CardTitle>
+          {isCollapsed && filledCount > 0 && <span className="text-xs font-normal text-muted-foreground">{t('archiveFormSectionFilledCount', preferredLanguage, { count: filledCount })}</span>}
+        </button>
+      </CardHeader>
+    );
+  };
+
   const GridItem: React.FC<{ children: React.ReactNode; className?: string }> = ({ children, className }) => (
     <div className={cn("grid gap-1.5", className)}>{children}</div>
   );
@@ -249,12 +335,18 @@ const DocumentForm: React.FC<DocumentFormProps> = ({
 
   return (
     <form
-        onSubmit={handleSubmit(onSubmit)}
+        onSubmit={handleSubmit(onSubmit, onInvalid)}
         className="flex flex-col h-full overflow-hidden"
     >
         {error && <div className="p-1 pr-3"><ErrorDisplay message={error} /></div>}
         <div className="flex-grow p-1 pr-3 space-y-6 overflow-y-auto relative">
             {isLoading && <div className='absolute inset-0 bg-background/80 flex items-center justify-center z-20 rounded-md'><LoadingSpinner/></div>}
+            <div className="flex justify-end -mb-4">
+                <Button type="button" variant="ghost" size="sm" onClick={() => updateCollapsedSections(allSectionsExpanded ? Object.keys(COLLAPSIBLE_SECTIONS) : [])}>
+                    {allSectionsExpanded ? <ChevronsDownUp className="mr-2 h-4 w-4" /> : <ChevronsUpDown className="mr-2 h-4 w-4" />}
+                    {t(allSectionsExpanded ? 'archiveFormCollapseAllSections' : 'archiveFormExpandAllSections', preferredLanguage)}
+                </Button>
+            </div>
             <div className="grid grid-cols-1 lg:grid-cols-2 gap-6">
                 {/* --- Basic Information --- */}
                 <Card className="lg:col-span-2">
@@ -276,18 +368,32 @@ const DocumentForm: React.FC<DocumentFormProps> = ({
                             {errors.type && <p className="text-xs text-destructive">{errors.type.message}</p>}
                             {(!!docToEdit || !!forceType) && <p className="text-xs text-muted-foreground italic">{t('archiveFormTypeDisabledHint', preferredLanguage)}</p>}
                         </GridItem>
-                         {watchedType === 'document' && forcedParentId === undefined && (
+                         {watchedType === 'document' && (
                              <GridItem className="md:col-span-1">
                                 <Label htmlFor="doc-parent">{t('archiveFormParentUnitLabel', preferredLanguage)}</Label>
                                 <UnitSelector selectedUnitId={selectedParentUnitId} onChange={setSelectedParentUnitId} currentDocumentId={docToEdit?.archiveDocumentId} className="w-full" />
                                 <input type="hidden" {...register('parentUnitArchiveDocumentId')} />
+                                {forcedParentId !== undefined && selectedParentUnitId === forcedParentId && (
+                                    <p className="text-xs text-muted-foreground italic">{forcedParentTitle ? `"${forcedParentTitle}" ` : ''}{t('archiveFormParentUnitContextHint', preferredLanguage)}</p>
+                                )}
                                 {errors.parentUnitArchiveDocumentId && <p className="text-xs text-destructive">{errors.parentUnitArchiveDocumentId.message}</p>}
-                             </GridItem>
-                        )}
-                         {watchedType === 'document' && forcedParentId !== undefined && (
-                             <GridItem className="md:col-span-1">
-                                <Label>{t('archiveFormParentUnitLabel', preferredLanguage)}</Label>
-                                <Input value={forcedParentTitle ? `"${forcedParentTitle}" ${t('archiveFormParentUnitContextHint', preferredLanguage)}` : `ID: ${forcedParentId} ${t('archiveFormParentUnitContextHint', preferredLanguage)}`} disabled className='text-muted-foreground'/>
+                                {isParentUnitDisabled && (
+                                    <div className="flex gap-2 p-2 border rounded-md bg-amber-50 text-amber-900 text-xs">
+                                        <AlertTriangle className="h-4 w-4 shrink-0" />
+                                        <div className="space-y-2">
+                                            <p>{t('archiveParentUnitDisabledWarning', preferredLanguage, { unitTitle: selectedParentUnit?.title ?? '' })}</p>
+                                            <div className="flex gap-2">
+                                                <Button type="button" variant="outline" size="sm" className="h-7" onClick={handleReenableParentUnit} disabled={isReenablingParent}>
+                                                    {isReenablingParent && <LoadingSpinner size="sm" className="mr-2" />}
+                                                    {t('archiveReenableUnitButton', preferredLanguage)}
+                                                </Button>
+                                                <Button type="button" variant="ghost" size="sm" className="h-7" onClick={() => setSelectedParentUnitId(null)} disabled={isReenablingParent}>
+                                                    {t('archiveChooseOtherParentButton', preferredLanguage)}
+                                                </Button>
+                                            </div>
+                                        </div>
+                                    </div>
+                                )}
                              </GridItem>
                         )}
                         {watchedType !== 'document' && <div className="md:col-span-1"></div>}
@@ -310,9 +416,13 @@ const DocumentForm: React.FC<DocumentFormProps> = ({
                 </Card>
                 {/* --- Physical Description --- */}
                 <Card className="lg:col-span-1">
-                    <CardHeader><CardTitle className='text-lg'>{t('archiveFormPhysicalDescTitle', preferredLanguage)}</CardTitle></CardHeader>
-                    <CardContent className="grid grid-cols-1 gap-x-6 gap-y-4">
-                        <GridItem><Label htmlFor="doc-pages">{t('archiveFormPagesLabel', preferredLanguage)}</Label><Input id="doc-pages" {...register('numberOfPages')} /></GridItem>
+                    {sectionHeader('physical', 'archiveFormPhysicalDescTitle')}
+                    <CardContent className={cn("grid grid-cols-1 gap-x-6 gap-y-4", collapsedSections.includes('physical') && "hidden")}>
+                        <GridItem>
+                            <Label htmlFor="doc-pages">{t('archiveFormPagesLabel', preferredLanguage)}</Label>
+                            <Input id="doc-pages" {...register('numberOfPages')} inputMode="numeric" aria-invalid={!!errors.numberOfPages} className={cn(errors.numberOfPages && "border-destructive")} />
+                            {errors.numberOfPages && <p className="text-xs text-destructive">{errors.numberOfPages.message}</p>}
+                        </GridItem>
                         <GridItem><Label htmlFor="doc-docType">{t('archiveFormDocTypeLabel', preferredLanguage)}</Label><Input id="doc-docType" {...register('documentType')} placeholder={t('archiveFormDocTypePlaceholder', preferredLanguage)} /></GridItem>
                         <GridItem><Label htmlFor="doc-dimensions">{t('archiveFormDimensionsLabel', preferredLanguage)}</Label><Input id="doc-dimensions" {...register('dimensions')} placeholder={t('archiveFormDimensionsPlaceholder', preferredLanguage)}/></GridItem>
                         <GridItem><Label htmlFor="doc-binding">{t('archiveFormBindingLabel', preferredLanguage)}</Label><Input id="doc-binding" {...register('binding')} placeholder={t('archiveFormBindingPlaceholder', preferredLanguage)} /></GridItem>
@@ -321,8 +431,8 @@ const DocumentForm: React.FC<DocumentFormProps> = ({
                 </Card>
                 {/* --- Content & Context --- */}
                 <Card className="lg:col-span-1">
-                    <CardHeader><CardTitle className='text-lg'>{t('archiveFormContentContextTitle', preferredLanguage)}</CardTitle></CardHeader>
-                    <CardContent className="grid grid-cols-1 gap-4">
+                    {sectionHeader('context', 'archiveFormContentContextTitle')}
+                    <CardContent className={cn("grid grid-cols-1 gap-4", collapsedSections.includes('context') && "hidden")}>
                         <GridItem><Label htmlFor="doc-language">{t('archiveFormLanguageLabel', preferredLanguage)}</Label><Input id="doc-language" {...register('documentLanguage')} placeholder={t('archiveFormLanguagePlaceholder', preferredLanguage)} /></GridItem>
                         <GridItem><Label htmlFor="doc-contentDesc">{t('archiveFormContentDescLabel', preferredLanguage)}</Label><Textarea id="doc-contentDesc" {...register('contentDescription')} rows={4} placeholder={t('archiveFormContentDescPlaceholder', preferredLanguage)} /></GridItem>
                         <GridItem><Label htmlFor="doc-remarks">{t('archiveFormRemarksLabel', preferredLanguage)}</Label><Textarea id="doc-remarks" {...register('remarks')} rows={2} placeholder={t('archiveFormRemarksPlaceholder', preferredLanguage)} /></GridItem>
@@ -332,8 +442,8 @@ const DocumentForm: React.FC<DocumentFormProps> = ({
                 </Card>
                  {/* --- Access & Digitization --- */}
                  <Card className="lg:col-span-1">
-                    <CardHeader><CardTitle className='text-lg'>{t('archiveFormAccessDigitizationTitle', preferredLanguage)}</CardTitle></CardHeader>
-                    <CardContent className="grid grid-cols-1 gap-x-6 gap-y-4">
+                    {sectionHeader('access', 'archiveFormAccessDigitizationTitle')}
+                    <CardContent className={cn("grid grid-cols-1 gap-x-6 gap-y-4", collapsedSections.includes('access') && "hidden")}>
                         <GridItem><Label htmlFor="doc-accessLevel">{t('archiveFormAccessLevelLabel', preferredLanguage)}</Label><Input id="doc-accessLevel" {...register('accessLevel')} placeholder={t('archiveFormAccessLevelPlaceholder', preferredLanguage)} /></GridItem>
                         <GridItem><Label htmlFor="doc-accessCond">{t('archiveFormAccessConditionsLabel', preferredLanguage)}</Label><Input id="doc-accessCond" {...register('accessConditions')} placeholder={t('archiveFormAccessConditionsPlaceholder', preferredLanguage)} /></GridItem>
                         <GridItem className="flex items-center space-x-2 pt-1">
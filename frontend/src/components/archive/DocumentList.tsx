@@ -2,18 +2,22 @@ import React from 'react';
 import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from "@/components/ui/table";
 import { Button } from '@/components/ui/button';
 import { Badge } from '@/components/ui/badge';
-import { Edit, Trash2, FileText, Folder, Eye } from 'lucide-react'; // Icons
+import { Edit, Trash2, FileText, Folder, Eye, MonitorCheck, RotateCcw, CopyPlus } from 'lucide-react'; // Icons
 import type { ArchiveDocument, ArchiveDocumentSearchResult } from '../../../../backend/src/functionalities/archive/document/models';
 import { useAuth } from '@/hooks/useAuth';
 import { cn } from '@/lib/utils';
+import { isHttpUrl } from '@/lib/zodSchemas';
 import { t } from '@/translations/utils'; // Import translation utility
 
 interface DocumentListProps {
   documents: ArchiveDocumentSearchResult[]; // Use search result which includes resolved signatures
   onEdit: (doc: ArchiveDocument) => void; // Pass base type for editing simplicity
   onDisable: (docId: number) => void;
+  onRestore?: (docId: number) => void; // Re-enables a disabled item (shown instead of "Disable")
+  onDuplicate?: (doc: ArchiveDocument) => void; // Opens the create editor prefilled from the item
   onPreview: (doc: ArchiveDocumentSearchResult) => void;
   onOpenUnit: (doc: ArchiveDocumentSearchResult) => void;
+  unitChildCounts?: Record<number, number>; // Unit id -> number of direct children (missing = not loaded yet)
 }
 
 // Temporary type assertion if backend type is missing resolved signatures
@@ -22,7 +26,7 @@ type ArchiveDocumentSearchResultWithResolved = ArchiveDocumentSearchResult & {
 };
 
 
-const DocumentList: React.FC<DocumentListProps> = ({ documents, onEdit, onDisable, onPreview, onOpenUnit }) => {
+const DocumentList: React.FC<DocumentListProps> = ({ documents, onEdit, onDisable, onRestore, onDuplicate, onPreview, onOpenUnit, unitChildCounts }) => {
   const { user, preferredLanguage } = useAuth(); // Get preferredLanguage
 
   const canModify = () => {
@@ -54,7 +58,7 @@ const DocumentList: React.FC<DocumentListProps> = ({ documents, onEdit, onDisabl
                     {/* REMOVED CreatedBy/UpdatedBy Headers */}
                     <TableHead className='max-w-[200px]'>{t('archiveTopoSigLabel', preferredLanguage)}</TableHead>
                     <TableHead className='max-w-[200px]'>{t('archiveDescSigLabel', preferredLanguage)}</TableHead>
-                    <TableHead className="text-right w-[130px]">{t('actionsLabel', preferredLanguage)}</TableHead>
+                    <TableHead className="text-right w-[170px]">{t('actionsLabel', preferredLanguage)}</TableHead>
                 </TableRow>
             </TableHeader>
             <TableBody>
@@ -62,12 +66,13 @@ const DocumentList: React.FC<DocumentListProps> = ({ documents, onEdit, onDisabl
                     const docWithResolved = doc as ArchiveDocumentSearchResultWithResolved;
                     const canUserModify = canModify(); // Check modification permission
                     const isUnit = doc.type === 'unit';
+                    const isDisabled = !doc.active;
 
                     return (
                         <TableRow
                            key={doc.archiveDocumentId}
                            onClick={() => handleClick(doc)}
-                           className='cursor-pointer hover:bg-muted/50 transition-colors'
+                           className={cn('cursor-pointer hover:bg-muted/50 transition-colors', isDisabled && 'opacity-60')}
                            title={isUnit ? t('archiveUnitOpenTitle', preferredLanguage, { title: doc.title }) : t('archiveDocumentPreviewTitle', preferredLanguage, { title: doc.title })}
                         >
                             <TableCell className='text-center'>
@@ -76,7 +81,29 @@ const DocumentList: React.FC<DocumentListProps> = ({ documents, onEdit, onDisabl
                                     : <FileText className='h-4 w-4 text-green-600 inline-block'/>}
                             </TableCell>
                             {/* Title cell: Allow wrapping and set max width */}
-                            <TableCell className="font-medium max-w-sm md:max-w-md whitespace-normal break-words">{doc.title}</TableCell>
+                            <TableCell className="font-medium max-w-sm md:max-w-md whitespace-normal break-words">
+                                <span className={cn(isDisabled && 'line-through decoration-muted-foreground')}>{doc.title}</span>
+                                {isDisabled && <Badge variant="outline" className='ml-2 align-middle text-xs font-normal text-muted-foreground'>{t('archiveDisabledBadge', preferredLanguage)}</Badge>}
+                                {isUnit && unitChildCounts?.[doc.archiveDocumentId!] !== undefined && (
+                                    <Badge
+                                        variant={unitChildCounts[doc.archiveDocumentId!] === 0 ? 'outline' : 'secondary'}
+                                        className='ml-2 align-middle text-xs font-normal'
+                                        title={t('archiveUnitChildCountTooltip', preferredLanguage, { count: unitChildCounts[doc.archiveDocumentId!] })}
+                                    >
+                                        {unitChildCounts[doc.archiveDocumentId!]}
+                                    </Badge>
+                                )}
+                                {/* Digitized indicator - links straight to the digitized version when there is one */}
+                                {/* Only http(s) links are clickable - anything else (e.g. from an older import) is just the indicator */}
+                                {doc.isDigitized && (doc.digitizedVersionLink && isHttpUrl(doc.digitizedVersionLink)
+                                    ? <a href={doc.digitizedVersionLink} target="_blank" rel="noopener noreferrer" onClick={(e) => e.stopPropagation()} className="inline-flex align-middle ml-2 text-sky-600 hover:text-sky-700" title={t('archiveDigitizedOpenLinkTooltip', preferredLanguage)}>
+                                          <MonitorCheck className="h-4 w-4" /><span className="sr-only">{t('archiveDigitizedOpenLinkTooltip', preferredLanguage)}</span>
+                                      </a>
+                                    : <span className="inline-flex align-middle ml-2 text-sky-600" title={[t('archiveDigitizedIndicatorTooltip', preferredLanguage), doc.digitizedVersionLink].filter(Boolean).join('\n')}>
+                                          <MonitorCheck className="h-4 w-4" /><span className="sr-only">{t('archiveDigitizedIndicatorTooltip', preferredLanguage)}</span>
+                                      </span>
+                                )}
+                            </TableCell>
                             {/* REMOVED CreatedBy/UpdatedBy Cells */}
                              <TableCell className='font-mono text-xs truncate' title={doc.topographicSignature || ''}>
                                  {doc.topographicSignature || <i className='text-muted-foreground not-italic'>{t('noneLabel', preferredLanguage)}</i>}
@@ -86,7 +113,7 @@ const DocumentList: React.FC<DocumentListProps> = ({ documents, onEdit, onDisabl
                                     ? (
                                         <div className="flex flex-col">
                                             {docWithResolved.resolvedDescriptiveSignatures.map((sig, index) => (
-                                                <span key={index} className="truncate block">{sig}</span>
+                                                <span key={index} className={cn("truncate block", sig.includes('[ErrID:') && "text-destructive")}>{sig}</span>
                                             ))}
                                         </div>
                                       )
@@ -106,8 +133,18 @@ const DocumentList: React.FC<DocumentListProps> = ({ documents, onEdit, onDisabl
                                         <Edit className="h-4 w-4" />
                                     </Button>
                                 )}
-                                {/* Disable Button (always available if permissions allow) */}
-                                {canUserModify && (
+                                {canUserModify && onDuplicate && (
+                                    <Button variant="ghost" size="icon" onClick={(e) => { e.stopPropagation(); onDuplicate(doc); }} title={t('archiveDuplicateButton', preferredLanguage)}>
+                                        <CopyPlus className="h-4 w-4" />
+                                    </Button>
+                                )}
+                                {/* Restore Button (disabled items) / Disable Button (active items) */}
+                                {canUserModify && isDisabled && onRestore && (
+                                    <Button variant="ghost" size="icon" onClick={(e) => { e.stopPropagation(); onRestore(doc.archiveDocumentId!); }} title={t('archiveRestoreButton', preferredLanguage)}>
+                                        <RotateCcw className="h-4 w-4" />
+                                    </Button>
+                                )}
+                                {canUserModify && !isDisabled && (
                                     <Button variant="ghost" size="icon" onClick={(e) => { e.stopPropagation(); onDisable(doc.archiveDocumentId!); }} title={t('disableButton', preferredLanguage)}>
                                         <Trash2 className="h-4 w-4 text-destructive" />
                                     </Button>
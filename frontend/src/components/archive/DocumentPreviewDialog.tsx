@@ -1,12 +1,17 @@
-import React from 'react';
+import React, { useState, useEffect } from 'react';
 import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, DialogFooter } from '@/components/ui/dialog';
 import { Button } from '@/components/ui/button';
 import { Badge } from '@/components/ui/badge';
 import { ScrollArea } from '@/components/ui/scroll-area';
-import { Link } from 'react-router-dom';
-import { FileText, Folder, Trash2, Edit } from 'lucide-react';
+import { Link, useNavigate } from 'react-router-dom';
+import { Tooltip, TooltipContent, TooltipProvider, TooltipTrigger } from '@/components/ui/tooltip';
+import { FileText, Folder, FolderOpen, Trash2, Edit, RotateCcw, CopyPlus, Lock, Pencil } from 'lucide-react';
 import type { ArchiveDocument, ArchiveDocumentSearchResult } from '../../../../backend/src/functionalities/archive/document/models';
 import { useAuth } from '@/hooks/useAuth';
+import CopyButton from '@/components/shared/CopyButton';
+import DigitizedVersionInfo from './DigitizedVersionInfo';
+import Timestamp from '@/components/shared/Timestamp';
+import { resolveFormattedSignaturePaths } from '@/lib/signaturePaths';
 import { t } from '@/translations/utils'; // Import translation utility
 import { cn } from '@/lib/utils';
 
@@ -22,39 +27,40 @@ interface DocumentPreviewDialogProps {
     document: ArchiveDocumentSearchResult | null;
     onEdit: (doc: ArchiveDocument) => void;
     onDisable: (docId: number) => void;
+    onRestore?: (docId: number) => void; // Offered instead of "Disable" for disabled items
+    onDuplicate?: (doc: ArchiveDocument) => void;
     parentUnitTitle?: string | null;
 }
 
-// --- Date Formatter ---
-let preferredLanguage: string = 'en'; // Global for formatter, updated by component instance
-
-const formatDate = (dateInput: Date | string | undefined | null): string => {
-    if (!dateInput) return t('archivePreviewNotApplicable', preferredLanguage);
-    try {
-        const date = new Date(dateInput);
-        if (isNaN(date.getTime())) return t('archivePreviewInvalidDate', preferredLanguage);
-        // Format date and time for Created/Updated On fields
-        return date.toLocaleString(undefined, {
-             year: 'numeric', month: 'short', day: 'numeric',
-             hour: '2-digit', minute: '2-digit', second: '2-digit'
-            });
-    } catch (e) { return t('archivePreviewErrorDate', preferredLanguage); }
-};
-
-
 const DocumentPreviewDialog: React.FC<DocumentPreviewDialogProps> = ({
     isOpen,
     onOpenChange,
     document: originalDoc,
     onEdit,
     onDisable,
+    onRestore,
+    onDuplicate,
     parentUnitTitle,
 }) => {
-    const { user, preferredLanguage: contextLang } = useAuth();
-    preferredLanguage = contextLang; // Update global for formatter
+    const { user, token, preferredLanguage } = useAuth();
+    const navigate = useNavigate();
 
     const previewingDoc = originalDoc as PreviewDocumentType | null;
 
+    // Documents normally arrive with server-resolved signatures; otherwise they are resolved (and cached) here
+    const [fallbackSignatures, setFallbackSignatures] = useState<string[] | null>(null);
+    useEffect(() => {
+        setFallbackSignatures(null);
+        const paths = previewingDoc?.descriptiveSignatureElementIds ?? [];
+        if (!token || !previewingDoc || previewingDoc.resolvedDescriptiveSignatures !== undefined || paths.length === 0) return;
+        let isCurrent = true;
+        resolveFormattedSignaturePaths(paths, token)
+            .then(formatted => { if (isCurrent) setFallbackSignatures(formatted); })
+            .catch(err => console.error("Failed to resolve descriptive signatures for preview:", err));
+        return () => { isCurrent = false; };
+    }, [previewingDoc, token]);
+    const descriptiveSignatures = previewingDoc?.resolvedDescriptiveSignatures ?? fallbackSignatures ?? [];
+
     if (!previewingDoc) {
         return null;
     }
@@ -67,6 +73,13 @@ const DocumentPreviewDialog: React.FC<DocumentPreviewDialogProps> = ({
         onEdit(previewingDoc as ArchiveDocument);
     };
 
+    // Jump to the unit holding this item so its siblings are visible (root items go to the archive root)
+    const handleOpenContainingUnitClick = () => {
+        onOpenChange(false);
+        const parentId = previewingDoc.parentUnitArchiveDocumentId;
+        navigate(parentId ? `/archive?unitId=${parentId}` : '/archive');
+    };
+
     const handleDisableClick = () => {
         onDisable(previewingDoc.archiveDocumentId!);
     };
@@ -78,16 +91,22 @@ const DocumentPreviewDialog: React.FC<DocumentPreviewDialogProps> = ({
                     <DialogTitle className='flex items-center gap-2'>
                         {previewingDoc.type === 'unit' ? <Folder className='h-5 w-5 text-blue-600'/> : <FileText className='h-5 w-5 text-green-600'/>}
                         {previewingDoc.title}
+                        {!previewingDoc.active && <Badge variant="outline" className='text-xs font-normal text-muted-foreground'>{t('archiveDisabledBadge', preferredLanguage)}</Badge>}
+                        {/* Explains up front why the modifying actions below are unavailable */}
+                        {canModify
+                            ? <Badge variant="secondary" className='text-xs font-normal gap-1'><Pencil className='h-3 w-3'/>{t('archivePreviewCanEditBadge', preferredLanguage)}</Badge>
+                            : <Badge variant="outline" className='text-xs font-normal gap-1 text-muted-foreground'><Lock className='h-3 w-3'/>{t('archivePreviewReadOnlyBadge', preferredLanguage)}</Badge>}
                     </DialogTitle>
                     <DialogDescription className='space-y-1 pt-1 text-left'>
-                        <p><strong>{t('archivePreviewCreatorLabel', preferredLanguage)}:</strong> {previewingDoc.creator}</p>
-                        <p><strong>{t('archivePreviewDateLabel', preferredLanguage)}:</strong> {previewingDoc.creationDate}</p>
+                        <p className='flex items-center gap-1'><strong>{t('archivePreviewIdLabel', preferredLanguage)}:</strong> <span className='font-mono'>{previewingDoc.archiveDocumentId}</span> <CopyButton value={String(previewingDoc.archiveDocumentId)} label={t('archivePreviewIdLabel', preferredLanguage)} /></p>
+                        <p className='flex items-center gap-1'><strong>{t('archivePreviewCreatorLabel', preferredLanguage)}:</strong> {previewingDoc.creator} <CopyButton value={previewingDoc.creator} label={t('archivePreviewCreatorLabel', preferredLanguage)} /></p>
+                        <p className='flex items-center gap-1'><strong>{t('archivePreviewDateLabel', preferredLanguage)}:</strong> {previewingDoc.creationDate} <CopyButton value={previewingDoc.creationDate} label={t('archivePreviewDateLabel', preferredLanguage)} /></p>
                         {previewingDoc.parentUnitArchiveDocumentId && (
                             <p><strong>{t('archivePreviewParentUnitLabel', preferredLanguage)}:</strong> <Link to={`/archive?unitId=${previewingDoc.parentUnitArchiveDocumentId}`} className='text-primary hover:underline' onClick={()=> onOpenChange(false)}>{parentUnitTitle || `ID ${previewingDoc.parentUnitArchiveDocumentId}`}</Link></p>
                         )}
                         {/* --- Updated: Show Created By / Updated By --- */}
-                        <p><strong>{t('archivePreviewCreatedByLabel', preferredLanguage)}:</strong> {previewingDoc.createdBy} ({formatDate(previewingDoc.createdOn)})</p>
-                        <p><strong>{t('archivePreviewUpdatedByLabel', preferredLanguage)}:</strong> {previewingDoc.updatedBy} ({formatDate(previewingDoc.modifiedOn)})</p>
+                        <p><strong>{t('archivePreviewCreatedByLabel', preferredLanguage)}:</strong> {previewingDoc.createdBy} (<Timestamp value={previewingDoc.createdOn} />)</p>
+                        <p><strong>{t('archivePreviewUpdatedByLabel', preferredLanguage)}:</strong> {previewingDoc.updatedBy} (<Timestamp value={previewingDoc.modifiedOn} />)</p>
                         {/* ------------------------------------------ */}
                         {previewingDoc.tags && previewingDoc.tags.length > 0 && (
                             <div className="flex flex-wrap gap-1 pt-1 items-center">
@@ -101,14 +120,20 @@ const DocumentPreviewDialog: React.FC<DocumentPreviewDialogProps> = ({
                              <div className='flex flex-wrap gap-1 pt-1 items-center'>
                                  <strong className='mr-1'>{t('archivePreviewTopoSigLabel', preferredLanguage)}:</strong>
                                  <Badge variant="outline" className='font-mono text-xs'>{previewingDoc.topographicSignature}</Badge>
+                                 <CopyButton value={previewingDoc.topographicSignature} label={t('archivePreviewTopoSigLabel', preferredLanguage)} />
                              </div>
                          )}
-                        {previewingDoc?.resolvedDescriptiveSignatures && previewingDoc.resolvedDescriptiveSignatures.length > 0 && (
+                        {descriptiveSignatures.length > 0 && (
                              <div className='flex flex-wrap gap-1 pt-1 items-center'>
                                  <strong className='mr-1'>{t('archivePreviewDescSigLabel', preferredLanguage)}:</strong>
-                                 {previewingDoc.resolvedDescriptiveSignatures.map((sig: string, idx: number) => (
-                                     <Badge key={`desc-${idx}`} variant="outline" className='font-mono text-xs'>{sig}</Badge>
+                                 {descriptiveSignatures.map((sig: string, idx: number) => (
+                                     <span key={`desc-${idx}`} className='inline-flex items-center'>
+                                         <Badge variant="outline" className={cn('font-mono text-xs', sig.includes('[ErrID:') && 'border-destructive text-destructive')}>{sig}</Badge>
+                                         {descriptiveSignatures.length > 1 && <CopyButton value={sig} label={t('archivePreviewSignaturePathLabel', preferredLanguage)} />}
+                                     </span>
                                  ))}
+                                 {/* With several paths the last button copies all of them, one per line */}
+                                 <CopyButton value={descriptiveSignatures.join('\n')} label={t('archivePreviewDescSigLabel', preferredLanguage)} />
                              </div>
                         )}
                     </DialogDescription>
@@ -118,7 +143,7 @@ const DocumentPreviewDialog: React.FC<DocumentPreviewDialogProps> = ({
                     {/* Content Description */}
                     {previewingDoc.contentDescription && (
                         <div>
-                           <h4 className='font-semibold mb-1 text-base'>{t('archivePreviewContentDescriptionLabel', preferredLanguage)}</h4>
+                           <h4 className='font-semibold mb-1 text-base flex items-center gap-1'>{t('archivePreviewContentDescriptionLabel', preferredLanguage)} <CopyButton value={previewingDoc.contentDescription} label={t('archivePreviewContentDescriptionLabel', preferredLanguage)} /></h4>
                            <p className="text-sm whitespace-pre-wrap">{previewingDoc.contentDescription}</p>
                         </div>
                     )}
@@ -127,12 +152,12 @@ const DocumentPreviewDialog: React.FC<DocumentPreviewDialogProps> = ({
                         <div>
                            <h4 className='font-semibold mb-1 text-base'>{t('archivePreviewPhysicalDetailsLabel', preferredLanguage)}</h4>
                            <ul className='list-disc list-inside text-sm space-y-0.5'>
-                               {previewingDoc.numberOfPages && <li>{t('archivePreviewPagesLabel', preferredLanguage)}: {previewingDoc.numberOfPages}</li>}
-                               {previewingDoc.documentType && <li>{t('archivePreviewTypeLabel', preferredLanguage)}: {previewingDoc.documentType}</li>}
-                               {previewingDoc.dimensions && <li>{t('archivePreviewDimensionsLabel', preferredLanguage)}: {previewingDoc.dimensions}</li>}
-                               {previewingDoc.binding && <li>{t('archivePreviewBindingLabel', preferredLanguage)}: {previewingDoc.binding}</li>}
-                               {previewingDoc.condition && <li>{t('archivePreviewConditionLabel', preferredLanguage)}: {previewingDoc.condition}</li>}
-                               {previewingDoc.documentLanguage && <li>{t('archivePreviewLanguageLabel', preferredLanguage)}: {previewingDoc.documentLanguage}</li>}
+                               {previewingDoc.numberOfPages && <li>{t('archivePreviewPagesLabel', preferredLanguage)}: {previewingDoc.numberOfPages} <CopyButton value={previewingDoc.numberOfPages} label={t('archivePreviewPagesLabel', preferredLanguage)} className='align-middle' /></li>}
+                               {previewingDoc.documentType && <li>{t('archivePreviewTypeLabel', preferredLanguage)}: {previewingDoc.documentType} <CopyButton value={previewingDoc.documentType} label={t('archivePreviewTypeLabel', preferredLanguage)} className='align-middle' /></li>}
+                               {previewingDoc.dimensions && <li>{t('archivePreviewDimensionsLabel', preferredLanguage)}: {previewingDoc.dimensions} <CopyButton value={previewingDoc.dimensions} label={t('archivePreviewDimensionsLabel', preferredLanguage)} className='align-middle' /></li>}
+                               {previewingDoc.binding && <li>{t('archivePreviewBindingLabel', preferredLanguage)}: {previewingDoc.binding} <CopyButton value={previewingDoc.binding} label={t('archivePreviewBindingLabel', preferredLanguage)} className='align-middle' /></li>}
+                               {previewingDoc.condition && <li>{t('archivePreviewConditionLabel', preferredLanguage)}: {previewingDoc.condition} <CopyButton value={previewingDoc.condition} label={t('archivePreviewConditionLabel', preferredLanguage)} className='align-middle' /></li>}
+                               {previewingDoc.documentLanguage && <li>{t('archivePreviewLanguageLabel', preferredLanguage)}: {previewingDoc.documentLanguage} <CopyButton value={previewingDoc.documentLanguage} label={t('archivePreviewLanguageLabel', preferredLanguage)} className='align-middle' /></li>}
                            </ul>
                         </div>
                     )}
@@ -142,19 +167,19 @@ const DocumentPreviewDialog: React.FC<DocumentPreviewDialogProps> = ({
                             <h4 className='font-semibold mb-1 text-base'>{t('archivePreviewOtherDetailsLabel', preferredLanguage)}</h4>
                             <div className='text-sm space-y-1'>
                                 {previewingDoc.remarks && (
-                                    <p><strong>{t('archivePreviewRemarksLabel', preferredLanguage)}:</strong> {previewingDoc.remarks}</p>
+                                    <p><strong>{t('archivePreviewRemarksLabel', preferredLanguage)}:</strong> {previewingDoc.remarks} <CopyButton value={previewingDoc.remarks} label={t('archivePreviewRemarksLabel', preferredLanguage)} className='align-middle' /></p>
                                 )}
                                 {previewingDoc.accessLevel && (
-                                     <p><strong>{t('archivePreviewAccessLabel', preferredLanguage)}:</strong> {previewingDoc.accessLevel} {previewingDoc.accessConditions ? `(${previewingDoc.accessConditions})` : ''}</p>
+                                     <p><strong>{t('archivePreviewAccessLabel', preferredLanguage)}:</strong> {previewingDoc.accessLevel} {previewingDoc.accessConditions ? `(${previewingDoc.accessConditions})` : ''} <CopyButton value={previewingDoc.accessConditions ? `${previewingDoc.accessLevel} (${previewingDoc.accessConditions})` : previewingDoc.accessLevel} label={t('archivePreviewAccessLabel', preferredLanguage)} className='align-middle' /></p>
                                 )}
                                 {previewingDoc.additionalInformation && (
-                                     <p><strong>{t('archivePreviewAdditionalInfoLabel', preferredLanguage)}:</strong> {previewingDoc.additionalInformation}</p>
+                                     <p><strong>{t('archivePreviewAdditionalInfoLabel', preferredLanguage)}:</strong> {previewingDoc.additionalInformation} <CopyButton value={previewingDoc.additionalInformation} label={t('archivePreviewAdditionalInfoLabel', preferredLanguage)} className='align-middle' /></p>
                                 )}
                                 {previewingDoc.relatedDocumentsReferences && (
-                                     <p><strong>{t('archivePreviewRelatedDocsLabel', preferredLanguage)}:</strong> {previewingDoc.relatedDocumentsReferences}</p>
+                                     <p><strong>{t('archivePreviewRelatedDocsLabel', preferredLanguage)}:</strong> {previewingDoc.relatedDocumentsReferences} <CopyButton value={previewingDoc.relatedDocumentsReferences} label={t('archivePreviewRelatedDocsLabel', preferredLanguage)} className='align-middle' /></p>
                                 )}
                                 {(previewingDoc.isDigitized !== null && previewingDoc.isDigitized !== undefined) && (
-                                    <p><strong>{t('archivePreviewDigitizedLabel', preferredLanguage)}:</strong> {previewingDoc.isDigitized ? `${t('archivePreviewDigitizedYes', preferredLanguage)} ${previewingDoc.digitizedVersionLink ? `- ${t('archivePreviewDigitizedYesLink', preferredLanguage)} ` : ''}` : t('archivePreviewDigitizedNo', preferredLanguage)}{previewingDoc.digitizedVersionLink && <a href={previewingDoc.digitizedVersionLink} target="_blank" rel="noopener noreferrer" className='text-primary hover:underline break-all'>{previewingDoc.digitizedVersionLink}</a>}</p>
+                                    <DigitizedVersionInfo isDigitized={!!previewingDoc.isDigitized} link={previewingDoc.digitizedVersionLink} />
                                 )}
                             </div>
                          </div>
@@ -167,7 +192,12 @@ const DocumentPreviewDialog: React.FC<DocumentPreviewDialogProps> = ({
                 <DialogFooter className='gap-2 sm:justify-between pt-4'>
                     {/* Disable button placed on the left */}
                     <div>
-                        {canModify && (
+                        {canModify && !previewingDoc.active && onRestore && (
+                            <Button variant="outline" size="sm" onClick={() => onRestore(previewingDoc.archiveDocumentId!)}>
+                                <RotateCcw className='h-4 w-4 mr-2'/> {t('archiveRestoreButton', preferredLanguage)}
+                            </Button>
+                        )}
+                        {canModify && previewingDoc.active && (
                              <Button
                                 variant="outline"
                                 className={cn('border-destructive text-destructive hover:bg-destructive/10 hover:text-destructive')}
@@ -180,10 +210,32 @@ const DocumentPreviewDialog: React.FC<DocumentPreviewDialogProps> = ({
                     </div>
                     {/* Edit and Close buttons on the right */}
                     <div className='flex gap-2'>
-                         {canModify && (
+                        <Button variant="outline" size="sm" onClick={handleOpenContainingUnitClick}>
+                            <FolderOpen className='h-4 w-4 mr-2'/> {previewingDoc.parentUnitArchiveDocumentId ? t('archiveOpenContainingUnitButton', preferredLanguage) : t('archiveOpenRootButton', preferredLanguage)}
+                        </Button>
+                         {canModify && onDuplicate && (
+                            <Button variant="outline" size="sm" onClick={() => { onOpenChange(false); onDuplicate(previewingDoc as ArchiveDocument); }}>
+                                <CopyPlus className='h-4 w-4 mr-2'/> {t('archiveDuplicateButton', preferredLanguage)}
+                            </Button>
+                         )}
+                         {canModify ? (
                             <Button variant="secondary" size="sm" onClick={handleEditClick}>
                                 <Edit className='h-4 w-4 mr-2'/> {t('editButton', preferredLanguage)}
                             </Button>
+                         ) : (
+                            // Disabled buttons don't fire pointer events, so the tooltip hangs on a wrapper
+                            <TooltipProvider delayDuration={150}>
+                                <Tooltip>
+                                    <TooltipTrigger asChild>
+                                        <span tabIndex={0}>
+                                            <Button variant="secondary" size="sm" disabled className='pointer-events-none'>
+                                                <Edit className='h-4 w-4 mr-2'/> {t('editButton', preferredLanguage)}
+                                            </Button>
+                                        </span>
+                                    </TooltipTrigger>
+                                    <TooltipContent className="max-w-xs">{t('archivePreviewReadOnlyReason', preferredLanguage)}</TooltipContent>
+                                </Tooltip>
+                            </TooltipProvider>
                          )}
                         <Button variant="outline" size="sm" onClick={() => onOpenChange(false)}>{t('closeButton', preferredLanguage)}</Button>
                     </div>
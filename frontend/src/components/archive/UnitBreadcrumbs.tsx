@@ -0,0 +1,94 @@
+import React, { useState, useEffect } from 'react';
+import { Link } from 'react-router-dom';
+import { ChevronRight, Home } from 'lucide-react';
+import { useAuth } from '@/hooks/useAuth';
+import api from '@/lib/api';
+import { registerCacheReset } from '@/lib/appData';
+import type { ArchiveDocument } from '../../../../backend/src/functionalities/archive/document/models';
+import { t } from '@/translations/utils';
+
+interface UnitBreadcrumbsProps {
+    unit: ArchiveDocument | null; // The unit being browsed; null at the archive root
+}
+
+interface UnitCrumb {
+    id: number;
+    title: string;
+    parentId: number | null;
+}
+
+// Ancestor units are shared by every unit below them, so their titles are cached across navigation.
+// Entries expire like other cached lookups, so a renamed unit shows up soon enough.
+const UNIT_CRUMB_CACHE_TTL = 60 * 1000; // ms
+const MAX_UNIT_DEPTH = 50; // Guards against a parent cycle in corrupted data
+const unitCrumbCache = new Map<number, { crumb: UnitCrumb, cachedAt: number }>();
+registerCacheReset(() => unitCrumbCache.clear());
+
+const toCrumb = (unit: ArchiveDocument): UnitCrumb => ({
+    id: unit.archiveDocumentId!,
+    title: unit.title,
+    parentId: unit.parentUnitArchiveDocumentId ?? null,
+});
+
+const getUnitCrumb = async (unitId: number, token: string): Promise<UnitCrumb> => {
+    const cached = unitCrumbCache.get(unitId);
+    if (cached && Date.now() - cached.cachedAt < UNIT_CRUMB_CACHE_TTL) return cached.crumb;
+    const crumb = toCrumb(await api.getArchiveDocumentById(unitId, token));
+    unitCrumbCache.set(unitId, { crumb, cachedAt: Date.now() });
+    return crumb;
+};
+
+// Ancestors of the unit, outermost first
+const loadAncestors = async (unit: ArchiveDocument, token: string): Promise<UnitCrumb[]> => {
+    const ancestors: UnitCrumb[] = [];
+    const seen = new Set<number>([unit.archiveDocumentId!]);
+    let parentId = unit.parentUnitArchiveDocumentId ?? null;
+    while (parentId && !seen.has(parentId) && ancestors.length < MAX_UNIT_DEPTH) {
+        seen.add(parentId);
+        const crumb = await getUnitCrumb(parentId, token);
+        ancestors.unshift(crumb);
+        parentId = crumb.parentId;
+    }
+    return ancestors;
+};
+
+const crumbLinkClass = 'hover:text-primary hover:underline truncate max-w-[200px]';
+
+const UnitBreadcrumbs: React.FC<UnitBreadcrumbsProps> = ({ unit }) => {
+    const { token, preferredLanguage } = useAuth();
+    const [ancestors, setAncestors] = useState<UnitCrumb[]>([]);
+
+    useEffect(() => {
+        if (!unit || !token) { setAncestors([]); return; }
+        unitCrumbCache.set(unit.archiveDocumentId!, { crumb: toCrumb(unit), cachedAt: Date.now() });
+        let isCurrent = true;
+        loadAncestors(unit, token)
+            .then(result => { if (isCurrent) setAncestors(result); })
+            .catch(err => {
+                // The trail is a convenience - without it the root link and the unit title still work
+                console.warn('Failed to load unit ancestors:', err);
+                if (isCurrent) setAncestors([]);
+            });
+        return () => { isCurrent = false; };
+    }, [unit, token]);
+
+    if (!unit) return null;
+
+    return (
+        <nav aria-label={t('archiveBreadcrumbsLabel', preferredLanguage)} className="flex flex-wrap items-center gap-1 text-sm text-muted-foreground">
+            <Link to="/archive" className={`flex items-center gap-1 ${crumbLinkClass}`} title={t('backToArchiveButton', preferredLanguage)}>
+                <Home className="h-3.5 w-3.5" /> {t('archiveTitle', preferredLanguage)}
+            </Link>
+            {ancestors.map(crumb => (
+                <React.Fragment key={crumb.id}>
+                    <ChevronRight className="h-3.5 w-3.5 shrink-0" />
+                    <Link to={`/archive?unitId=${crumb.id}`} className={crumbLinkClass} title={crumb.title}>{crumb.title}</Link>
+                </React.Fragment>
+            ))}
+            <ChevronRight className="h-3.5 w-3.5 shrink-0" />
+            <span className="font-medium text-foreground truncate max-w-[250px]" aria-current="page" title={unit.title}>{unit.title}</span>
+        </nav>
+    );
+};
+
+export default UnitBreadcrumbs;
@@ -1,16 +1,20 @@
-import React, { useState, useEffect, useMemo } from 'react';
-import { Check, ChevronsUpDown, X } from "lucide-react";
+import React, { useState, useEffect } from 'react';
+import { Check, ChevronsUpDown, ChevronLeft, ChevronRight, X } from "lucide-react";
 import { Button } from "@/components/ui/button";
 import { Command, CommandEmpty, CommandGroup, CommandInput, CommandItem, CommandList } from "@/components/ui/command";
 import { Popover, PopoverContent, PopoverTrigger } from "@/components/ui/popover";
 import { useAuth } from '@/hooks/useAuth';
 import api from '@/lib/api';
 import type { ArchiveDocument } from '../../../../backend/src/functionalities/archive/document/models';
-import type { SearchRequest } from '../../../../backend/src/utils/search';
+import type { SearchRequest, SearchQueryElement } from '../../../../backend/src/utils/search';
 import { cn } from '@/lib/utils';
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import useDebounce from '@/components/shared/useDebounce';
 import { t } from '@/translations/utils'; // Import translation utility
 
+const UNIT_PICKER_PAGE_SIZE = 20;
+const DEBOUNCE_DELAY = 300; // ms
+
 interface UnitSelectorProps {
   selectedUnitId: number | null;
   onChange: (selectedId: number | null) => void;
@@ -26,57 +30,70 @@ const UnitSelector: React.FC<UnitSelectorProps> = ({
   currentDocumentId
 }) => {
   const { token, preferredLanguage } = useAuth(); // Get preferredLanguage
-  const [availableUnits, setAvailableUnits] = useState<ArchiveDocument[]>([]);
+  const [units, setUnits] = useState<ArchiveDocument[]>([]);
+  const [selectedUnit, setSelectedUnit] = useState<ArchiveDocument | null>(null);
   const [isLoading, setIsLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
   const [open, setOpen] = useState(false);
   const [searchTerm, setSearchTerm] = useState("");
+  const debouncedSearchTerm = useDebounce(searchTerm, DEBOUNCE_DELAY);
+  const [page, setPage] = useState(1);
+  const [totalPages, setTotalPages] = useState(1);
+
+  // Reset to first page whenever the search term changes
+  useEffect(() => { setPage(1); }, [debouncedSearchTerm]);
 
+  // Fetch one page of units (server-side title search) while the popover is open
   useEffect(() => {
+    if (!open || !token) return;
+    let isCancelled = false;
     const fetchUnits = async () => {
-      if (!token) return;
       setIsLoading(true);
       setError(null);
       try {
-        // Search for items of type 'unit'
-        const searchRequest: SearchRequest = {
-            query: [ { field: 'type', condition: 'EQ', value: 'unit', not: false } ],
-            page: 1,
-            pageSize: 500, // Fetch a reasonable number of units for selection
-        };
+        const query: SearchQueryElement[] = [ { field: 'type', condition: 'EQ', value: 'unit', not: false } ];
+        if (debouncedSearchTerm.trim()) {
+            query.push({ field: 'title', condition: 'FRAGMENT', value: debouncedSearchTerm.trim(), not: false });
+        }
+        const searchRequest: SearchRequest = { query, page, pageSize: UNIT_PICKER_PAGE_SIZE };
         const response = await api.searchArchiveDocuments(searchRequest, token);
+        if (isCancelled) return;
         // Filter out the potential parent document itself
-        setAvailableUnits(
-            response.data.filter(unit => unit.archiveDocumentId !== currentDocumentId)
-                           .sort((a, b) => a.title.localeCompare(b.title)) // Sort locally
-        );
+        setUnits(response.data.filter(unit => unit.archiveDocumentId !== currentDocumentId));
+        setTotalPages(Math.max(1, response.totalPages));
       } catch (err: any) {
+        if (isCancelled) return;
         const msg = err.message || t('unitLoadFailedError', preferredLanguage);
         setError(msg);
         console.error("Failed to load units:", err);
       } finally {
-        setIsLoading(false);
+        if (!isCancelled) setIsLoading(false);
       }
     };
     fetchUnits();
-  }, [token, currentDocumentId, preferredLanguage]); // Add preferredLanguage
+    return () => { isCancelled = true; };
+  }, [open, token, debouncedSearchTerm, page, currentDocumentId, preferredLanguage]); // Add preferredLanguage
+
+  // Resolve the selected unit's title (it may not be on the currently loaded page)
+  useEffect(() => {
+    if (!selectedUnitId || !token) { setSelectedUnit(null); return; }
+    if (selectedUnit?.archiveDocumentId === selectedUnitId) return;
+    const fromPage = units.find(unit => unit.archiveDocumentId === selectedUnitId);
+    if (fromPage) { setSelectedUnit(fromPage); return; }
+    let isCancelled = false;
+    api.getArchiveDocumentById(selectedUnitId, token)
+        .then(unit => { if (!isCancelled) setSelectedUnit(unit); })
+        .catch(err => console.error("Failed to resolve selected unit:", err));
+    return () => { isCancelled = true; };
+  }, [selectedUnitId, token, units, selectedUnit?.archiveDocumentId]);
 
-  const handleSelect = (unitId: number | null) => {
-    onChange(unitId);
+  const handleSelect = (unit: ArchiveDocument | null) => {
+    setSelectedUnit(unit);
+    onChange(unit?.archiveDocumentId ?? null);
     setOpen(false); // Close popover on select/clear
     setSearchTerm("");
   };
 
-   const selectedUnit = useMemo(() => {
-       return availableUnits.find(unit => unit.archiveDocumentId === selectedUnitId);
-   }, [availableUnits, selectedUnitId]);
-
-   const filteredDropdownUnits = useMemo(() => {
-       return availableUnits
-           .filter(unit => unit.title.toLowerCase().includes(searchTerm.toLowerCase()))
-   }, [availableUnits, searchTerm]);
-
-
   return (
     <div className={cn('space-y-2', className)}> {/* Removed p-3, border, bg-white */}
          <Popover open={open} onOpenChange={setOpen}>
@@ -86,18 +103,16 @@ const UnitSelector: React.FC<UnitSelectorProps> = ({
                     role="combobox"
                     aria-expanded={open}
                     className="w-full justify-between min-h-[36px] font-normal text-sm h-9" // Button uses standard outline
-                    disabled={isLoading || !!error}
                 >
                   <span className="truncate">
-                    {isLoading ? t('loadingText', preferredLanguage) :
-                     error ? t('errorText', preferredLanguage) :
-                     selectedUnit ? selectedUnit.title :
+                    {selectedUnit ? selectedUnit.title :
+                     selectedUnitId ? t('loadingText', preferredLanguage) :
                      t('unitSelectorPlaceholder', preferredLanguage)}
                   </span>
-                   {isLoading ? <LoadingSpinner size="sm" className='ml-2'/> : <ChevronsUpDown className="ml-2 h-4 w-4 shrink-0 opacity-50" />}
+                   <ChevronsUpDown className="ml-2 h-4 w-4 shrink-0 opacity-50" />
                 </Button>
              </PopoverTrigger>
-             <PopoverContent className="w-[--radix-popover-trigger-width] p-0" align="start"> {/* Content uses standard popover bg */}
+             <PopoverContent className="w-[--radix-popover-trigger-width] min-w-[280px] p-0" align="start"> {/* Content uses standard popover bg */}
                  <Command shouldFilter={false}>
                     <CommandInput
                         placeholder={t('unitSelectorSearchPlaceholder', preferredLanguage)}
@@ -105,8 +120,10 @@ const UnitSelector: React.FC<UnitSelectorProps> = ({
                         onValueChange={setSearchTerm}
                     />
                     <CommandList>
-                        <CommandEmpty>{isLoading ? t('loadingText', preferredLanguage) : t('unitSelectorNoUnitsFound', preferredLanguage)}</CommandEmpty>
-                         {!isLoading && (
+                        {isLoading && <div className='flex justify-center py-4'><LoadingSpinner size="sm" /></div>}
+                        {!isLoading && error && <p className="p-3 text-xs text-destructive">{error}</p>}
+                        {!isLoading && !error && <CommandEmpty>{t('unitSelectorNoUnitsFound', preferredLanguage)}</CommandEmpty>}
+                         {!isLoading && !error && (
                             <CommandGroup>
                                 <CommandItem
                                     key="clear-unit"
@@ -117,13 +134,11 @@ const UnitSelector: React.FC<UnitSelectorProps> = ({
                                     <X className="mr-2 h-4 w-4 opacity-50" />
                                     {t('clearButton', preferredLanguage)} {t('selection', preferredLanguage)}
                                 </CommandItem>
-                                {filteredDropdownUnits.map((unit) => (
+                                {units.map((unit) => (
                                 <CommandItem
                                     key={unit.archiveDocumentId}
-                                    value={unit.title}
-                                    onSelect={() => {
-                                        handleSelect(unit.archiveDocumentId!);
-                                    }}
+                                    value={String(unit.archiveDocumentId)}
+                                    onSelect={() => handleSelect(unit)}
                                     className='cursor-pointer'
                                 >
                                     <Check
@@ -132,18 +147,30 @@ const UnitSelector: React.FC<UnitSelectorProps> = ({
                                         selectedUnitId === unit.archiveDocumentId ? "opacity-100" : "opacity-0"
                                         )}
                                     />
-                                    {unit.title}
+                                    <span className="truncate">{unit.title}</span>
+                                    {unit.topographicSignature && <span className="ml-auto pl-2 text-xs text-muted-foreground font-mono">{unit.topographicSignature}</span>}
                                 </CommandItem>
                                 ))}
                             </CommandGroup>
                          )}
                     </CommandList>
+                    {/* Page controls */}
+                    {totalPages > 1 && (
+                        <div className="flex items-center justify-between border-t px-2 py-1">
+                            <Button type="button" variant="ghost" size="icon" className="h-7 w-7" onClick={() => setPage(p => Math.max(1, p - 1))} disabled={isLoading || page <= 1} title={t('previousPage', preferredLanguage)}>
+                                <ChevronLeft className="h-4 w-4" />
+                            </Button>
+                            <span className="text-xs text-muted-foreground">{t('unitSelectorPageIndicator', preferredLanguage, { page, totalPages })}</span>
+                            <Button type="button" variant="ghost" size="icon" className="h-7 w-7" onClick={() => setPage(p => Math.min(totalPages, p + 1))} disabled={isLoading || page >= totalPages} title={t('nextPage', preferredLanguage)}>
+                                <ChevronRight className="h-4 w-4" />
+                            </Button>
+                        </div>
+                    )}
                  </Command>
              </PopoverContent>
          </Popover>
-          {error && <p className="text-xs text-destructive mt-1">{error}</p>}
     </div>
   );
 };
 
-export default UnitSelector;
\ No newline at end of file
+export default UnitSelector;
@@ -6,7 +6,9 @@ import {
   SelectTrigger,
   SelectValue,
 } from "@/components/ui/select";
-import { Languages } from 'lucide-react';
+import { Languages, Server } from 'lucide-react';
+import { Button } from '@/components/ui/button';
+import ServerAddressDialog from '@/components/user/ServerAddressDialog';
 // Import types and constants from the new models file
 import { supportedLanguages as appSupportedLanguages, defaultLanguage as appDefaultLanguage, type SupportedLanguage as AppSupportedLanguage } from '@/translations/models'; // Updated path
 // Import the translation function from the new utils file
@@ -25,6 +27,7 @@ const AuthLayout: React.FC<AuthLayoutProps> = ({ children }) => {
   // Local state to manage the language *displayed* in the select component
   // Initialize with the language from context, which is now determined reliably on load
   const [displayLanguage, setDisplayLanguage] = useState<AppSupportedLanguage>(preferredLanguage);
+  const [isServerAddressOpen, setIsServerAddressOpen] = useState(false);
 
   // Update local display language if context language changes (e.g., after login/logout or fetch)
   useEffect(() => {
@@ -53,8 +56,11 @@ const AuthLayout: React.FC<AuthLayoutProps> = ({ children }) => {
 
   return (
     <div className="flex flex-col justify-center items-center min-h-screen w-full bg-muted/40 p-4">
-      {/* Language Picker - Positioned at the top right */}
-      <div className="absolute top-4 right-4">
+      {/* Server address & Language Picker - Positioned at the top right */}
+      <div className="absolute top-4 right-4 flex items-center gap-2">
+        <Button variant="outline" size="icon" className="h-9 w-9 bg-background" onClick={() => setIsServerAddressOpen(true)} title={t('serverAddressMenuItem', displayLanguage)}>
+          <Server className="h-4 w-4 text-muted-foreground" />
+        </Button>
         <Select
           value={displayLanguage} // Use local display language
           onValueChange={(value) => handleLanguageChange(value as AppSupportedLanguage)}
@@ -86,6 +92,7 @@ const AuthLayout: React.FC<AuthLayoutProps> = ({ children }) => {
         }
         return child;
       })}
+      <ServerAddressDialog isOpen={isServerAddressOpen} onOpenChange={setIsServerAddressOpen} />
     </div>
   );
 };
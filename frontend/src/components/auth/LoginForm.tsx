@@ -1,11 +1,13 @@
-import React from 'react';
+import React, { useState } from 'react';
 import { useForm } from 'react-hook-form';
 import { zodResolver } from '@hookform/resolvers/zod';
 import { loginSchema, LoginFormData } from '@/lib/zodSchemas';
 import { useAuth } from '@/hooks/useAuth';
+import { getRememberMePreference } from '@/context/AuthContext';
 import { Button } from "@/components/ui/button";
 import { Input } from "@/components/ui/input";
 import { Label } from "@/components/ui/label";
+import { Checkbox } from "@/components/ui/checkbox";
 import { Card, CardHeader, CardTitle, CardDescription, CardContent, CardFooter } from "@/components/ui/card";
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
@@ -22,6 +24,7 @@ interface LoginFormProps {
 const LoginForm: React.FC<LoginFormProps> = ({ onSwitchToRegister, currentLanguage }) => {
   // Get isLoading directly from useAuth, which reflects initial lang fetch too
   const { login, isLoading, error, clearError } = useAuth();
+  const [rememberMe, setRememberMe] = useState(getRememberMePreference);
   const { register, handleSubmit, formState: { errors } } = useForm<LoginFormData>({
     resolver: zodResolver(loginSchema),
     defaultValues: { login: '', password: '' }
@@ -30,7 +33,7 @@ const LoginForm: React.FC<LoginFormProps> = ({ onSwitchToRegister, currentLangua
   const onSubmit = async (data: LoginFormData) => {
     clearError();
     // Login function no longer needs language passed here
-    const success = await login(data);
+    const success = await login(data, rememberMe);
     if (!success) {
         console.error("Login attempt failed.");
     }
@@ -75,6 +78,16 @@ const LoginForm: React.FC<LoginFormProps> = ({ onSwitchToRegister, currentLangua
             />
             {errors.password && <p className="text-xs text-destructive font-medium">{errors.password.message}</p>}
           </div>
+
+          <div className="flex items-center gap-2">
+            <Checkbox
+                id="remember-me"
+                checked={rememberMe}
+                onCheckedChange={(checked) => setRememberMe(checked === true)}
+                disabled={isLoading}
+            />
+            <Label htmlFor="remember-me" className="font-normal cursor-pointer">{t('rememberMeLabel', currentLanguage)}</Label>
+          </div>
         </CardContent>
         <CardFooter className="flex flex-col gap-4 px-6 pb-6 pt-4">
            {/* Disable button while auth context is loading */}
@@ -2,6 +2,8 @@ import React from 'react';
 import { Navigate, Outlet, useLocation } from 'react-router-dom';
 import { useAuth } from '@/hooks/useAuth';
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import AccessDenied from '@/components/shared/AccessDenied';
+import { t } from '@/translations/utils';
 import type { UserRole } from '../../../../backend/src/functionalities/user/models'; // Adjust path as needed
 
 interface ProtectedRouteProps {
@@ -9,7 +11,7 @@ interface ProtectedRouteProps {
 }
 
 const ProtectedRoute: React.FC<ProtectedRouteProps> = ({ allowedRoles }) => {
-  const { isAuthenticated, isLoading, user } = useAuth();
+  const { isAuthenticated, isLoading, user, preferredLanguage } = useAuth();
   const location = useLocation();
 
   if (isLoading) {
@@ -31,10 +33,15 @@ const ProtectedRoute: React.FC<ProtectedRouteProps> = ({ allowedRoles }) => {
     const userRole = user?.role;
     if (!userRole || !allowedRoles.includes(userRole)) {
       // User is authenticated but doesn't have the required role
-      // Redirect to a 'forbidden' page or dashboard
+      // Explain instead of silently redirecting (the placeholder offers a way back)
       console.warn(`Access denied to ${location.pathname} for role: ${userRole}`);
-      // You could navigate to a dedicated /forbidden page or just the dashboard
-      return <Navigate to="/" replace />;
+      return (
+        <AccessDenied
+          message={allowedRoles.length === 1 && allowedRoles[0] === 'admin'
+            ? undefined
+            : t('accessDeniedRoleMessage', preferredLanguage, { roles: allowedRoles.join(', ') })}
+        />
+      );
     }
   }
 
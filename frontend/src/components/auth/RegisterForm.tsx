@@ -12,6 +12,7 @@ import LoadingSpinner from '@/components/shared/LoadingSpinner';
 import { Alert, AlertDescription, AlertTitle } from "@/components/ui/alert";
 import { CheckCircle } from 'lucide-react';
 import { cn } from '@/lib/utils';
+import { evaluatePasswordStrength, MAX_PASSWORD_SCORE, type PasswordStrengthLevel } from '@/lib/passwordStrength';
 // Updated imports: Get types and function from new locations
 import { type SupportedLanguage } from '@/translations/models'; // Use frontend model type
 import { t } from '@/translations/utils';
@@ -21,15 +22,35 @@ interface RegisterFormProps {
     currentLanguage: SupportedLanguage; // Prop remains the same
 }
 
+const strengthLabelKeys = {
+    weak: 'passwordStrengthWeak',
+    fair: 'passwordStrengthFair',
+    good: 'passwordStrengthGood',
+    strong: 'passwordStrengthStrong',
+} as const satisfies Record<PasswordStrengthLevel, string>;
+
+const strengthBarClass: Record<PasswordStrengthLevel, string> = {
+    weak: 'bg-red-500',
+    fair: 'bg-amber-500',
+    good: 'bg-lime-500',
+    strong: 'bg-green-600',
+};
+
 const RegisterForm: React.FC<RegisterFormProps> = ({ onSwitchToLogin, currentLanguage }) => {
   // Get isLoading directly from useAuth
   const { register: registerUser, isLoading, error, clearError } = useAuth();
   const [isSuccess, setIsSuccess] = useState(false);
-  const { register, handleSubmit, formState: { errors } } = useForm<RegisterFormData>({
+  const { register, handleSubmit, watch, formState: { errors } } = useForm<RegisterFormData>({
     resolver: zodResolver(registerSchema),
     defaultValues: { login: '', password: '', confirmPassword: '' }
   });
 
+  // Checked while typing, so a weak or mistyped password is caught before the request is sent
+  const password = watch('password');
+  const confirmPassword = watch('confirmPassword');
+  const strength = evaluatePasswordStrength(password);
+  const passwordsMismatch = confirmPassword.length > 0 && confirmPassword !== password;
+
   const onSubmit = async (data: RegisterFormData) => {
     clearError();
     setIsSuccess(false);
@@ -94,6 +115,19 @@ const RegisterForm: React.FC<RegisterFormProps> = ({ onSwitchToLogin, currentLan
                         // Disable input while auth context is loading
                         disabled={isLoading}
                     />
+                    {password.length > 0 && (
+                        <div className="space-y-1" aria-live="polite">
+                            <div className="flex gap-1">
+                                {Array.from({ length: MAX_PASSWORD_SCORE }, (_, i) => (
+                                    <div key={i} className={cn("h-1 flex-1 rounded-full bg-neutral-200", i < strength.score && strengthBarClass[strength.level])} />
+                                ))}
+                            </div>
+                            <p className="text-xs text-muted-foreground">
+                                {t('passwordStrengthLabel', currentLanguage, { level: t(strengthLabelKeys[strength.level], currentLanguage) })}
+                                {!strength.meetsMinimum && <> &middot; {t('passwordStrengthRequirements', currentLanguage)}</>}
+                            </p>
+                        </div>
+                    )}
                     {errors.password && <p className="text-xs text-destructive font-medium">{errors.password.message}</p>}
                 </div>
 
@@ -104,17 +138,19 @@ const RegisterForm: React.FC<RegisterFormProps> = ({ onSwitchToLogin, currentLan
                         type="password"
                         placeholder={t('confirmPasswordPlaceholder', currentLanguage)}
                         {...register("confirmPassword")}
-                        aria-invalid={errors.confirmPassword ? "true" : "false"}
-                        className={cn(errors.confirmPassword && "border-destructive focus-visible:ring-destructive")}
+                        aria-invalid={errors.confirmPassword || passwordsMismatch ? "true" : "false"}
+                        className={cn((errors.confirmPassword || passwordsMismatch) && "border-destructive focus-visible:ring-destructive")}
                         // Disable input while auth context is loading
                         disabled={isLoading}
                     />
-                    {errors.confirmPassword && <p className="text-xs text-destructive font-medium">{errors.confirmPassword.message}</p>}
+                    {errors.confirmPassword
+                        ? <p className="text-xs text-destructive font-medium">{errors.confirmPassword.message}</p>
+                        : passwordsMismatch && <p className="text-xs text-destructive font-medium">{t('passwordsMismatchWarning', currentLanguage)}</p>}
                 </div>
             </CardContent>
             <CardFooter className="flex flex-col gap-4 px-6 pb-6 pt-4">
-                 {/* Disable button while auth context is loading */}
-                <Button type="submit" className="w-full" disabled={isLoading}>
+                 {/* Disable button while auth context is loading or until the password is acceptable */}
+                <Button type="submit" className="w-full" disabled={isLoading || !strength.meetsMinimum || passwordsMismatch}>
                     {isLoading ? <LoadingSpinner size="sm" className="mr-2" /> : t('createAccountButton', currentLanguage)}
                 </Button>
                 <p className="text-center text-sm text-muted-foreground">
@@ -0,0 +1,64 @@
+import React, { useEffect } from 'react';
+import { useAuth } from '@/hooks/useAuth';
+import { useConnectivity } from '@/hooks/useConnectivity';
+import api, { getConnectivity, type ConnectivityState } from '@/lib/api';
+import type { AppTranslationKey } from '@/translations/models';
+import { cn } from '@/lib/utils';
+import { t } from '@/translations/utils';
+
+const PING_INTERVAL_MS = 30000;
+
+const dotClassByState: Record<ConnectivityState, string> = {
+    unknown: 'bg-neutral-300',
+    online: 'bg-green-500',
+    slow: 'bg-yellow-400',
+    offline: 'bg-red-500',
+};
+
+const labelKeyByState: Record<ConnectivityState, AppTranslationKey> = {
+    unknown: 'connectivityUnknown',
+    online: 'connectivityOnline',
+    slow: 'connectivitySlow',
+    offline: 'connectivityOffline',
+};
+
+// Dot showing whether the backend is reachable. Regular requests update it as they complete; while the app
+// is idle (no request within the interval) a lightweight ping keeps it current.
+const ConnectivityIndicator: React.FC = () => {
+    const { preferredLanguage } = useAuth();
+    const connectivity = useConnectivity();
+
+    useEffect(() => {
+        const ping = () => { api.pingApi().catch(() => { /* Recorded by fetchApi */ }); };
+        const pingIfIdle = () => {
+            const { checkedAt } = getConnectivity();
+            if (document.visibilityState === 'visible' && (checkedAt === null || Date.now() - checkedAt >= PING_INTERVAL_MS)) ping();
+        };
+        pingIfIdle();
+        const interval = window.setInterval(pingIfIdle, PING_INTERVAL_MS);
+        // The browser knows about lost/restored network connections before any request fails
+        window.addEventListener('online', ping);
+        window.addEventListener('offline', ping);
+        return () => {
+            window.clearInterval(interval);
+            window.removeEventListener('online', ping);
+            window.removeEventListener('offline', ping);
+        };
+    }, []);
+
+    const label = t(labelKeyByState[connectivity.state], preferredLanguage, { latency: connectivity.latencyMs ?? 0 });
+    const details = [
+        label,
+        connectivity.checkedAt !== null && t('connectivityCheckedAt', preferredLanguage, { time: new Date(connectivity.checkedAt).toLocaleTimeString() }),
+        connectivity.lastError && t('connectivityLastError', preferredLanguage, { message: connectivity.lastError.message, time: new Date(connectivity.lastError.at).toLocaleTimeString() }),
+    ].filter(Boolean).join('\n');
+
+    return (
+        <span className="inline-flex h-9 w-5 items-center justify-center" title={details} role="status">
+            <span className={cn('h-2.5 w-2.5 rounded-full', dotClassByState[connectivity.state], connectivity.state === 'offline' && 'animate-pulse')} />
+            <span className="sr-only">{label}</span>
+        </span>
+    );
+};
+
+export default ConnectivityIndicator;
@@ -2,11 +2,19 @@ import React, { useState, useCallback } from 'react';
 import { useLocation, useNavigate, Link } from 'react-router-dom';
 import { Button } from '@/components/ui/button';
 import {
-    LogOut, Menu, Settings, User as UserIcon, Languages,
-    LayoutDashboard, StickyNote, Tag, PenTool, Archive as ArchiveIcon, ShieldAlert, FileSearch
+    LogOut, Menu, Settings, User as UserIcon, Languages, Bell, RotateCcw, Server, ListChecks,
+    LayoutDashboard, StickyNote, Tag, PenTool, Archive as ArchiveIcon, ShieldAlert, FileSearch, Sun, Moon, Monitor, RefreshCw,
+    ZoomIn, ZoomOut, Undo2
 } from 'lucide-react'; // Added page icons
 import { useAuth } from '@/hooks/useAuth';
+import { useUiPreferences } from '@/hooks/useUiPreferences';
+import { UNDO_DELETE_DELAY_MS } from '@/hooks/useUndoableDelete';
+import { toastPositions, toastDurations, bulkConfirmThresholds, deleteModes, themes, zoomLevels, type ToastPosition, type Theme, type DeleteMode } from '@/context/UiPreferencesContext';
 import ChangePasswordDialog from '@/components/user/ChangePasswordDialog';
+import ResetAppDataDialog from '@/components/user/ResetAppDataDialog';
+import ServerAddressDialog from '@/components/user/ServerAddressDialog';
+import PendingChangesIndicator from './PendingChangesIndicator';
+import ConnectivityIndicator from './ConnectivityIndicator';
 import {
   DropdownMenu,
   DropdownMenuContent,
@@ -23,14 +31,30 @@ import {
 } from "@/components/ui/dropdown-menu";
 import { type SupportedLanguage, supportedLanguages as appSupportedLanguages, defaultLanguage as appDefaultLanguage } from '@/translations/models';
 import api from '@/lib/api';
-import { toast } from "sonner";
+import { toast } from '@/lib/toast';
 import { t } from '@/translations/utils';
+import type { AppTranslationKey } from '@/translations/models';
 import { cn } from '@/lib/utils'; // Import cn
 
 interface HeaderProps {
   toggleSidebar?: () => void;
+  onRefresh?: () => void; // Reloads the current view with fresh data
 }
 
+const themeLabelKeys: Record<Theme, AppTranslationKey> = {
+    light: 'themeLight',
+    dark: 'themeDark',
+    system: 'themeSystem',
+};
+const toastPositionLabelKeys: Record<ToastPosition, AppTranslationKey> = {
+    'top-right': 'notificationsPositionTopRight',
+    'top-center': 'notificationsPositionTopCenter',
+    'top-left': 'notificationsPositionTopLeft',
+    'bottom-right': 'notificationsPositionBottomRight',
+    'bottom-center': 'notificationsPositionBottomCenter',
+    'bottom-left': 'notificationsPositionBottomLeft',
+};
+
 // Helper function to get title and icon from path
 const getPageInfoFromPath = (pathname: string, lang: SupportedLanguage, userRole?: string | null): { title: string, Icon: React.ComponentType<{ className?: string }> } => {
     const segments = pathname.split('/').filter(Boolean);
@@ -84,13 +108,16 @@ const getPageInfoFromPath = (pathname: string, lang: SupportedLanguage, userRole
 };
 
 
-const Header: React.FC<HeaderProps> = ({ toggleSidebar }) => {
+const Header: React.FC<HeaderProps> = ({ toggleSidebar, onRefresh }) => {
     // Use preferredLanguage directly from context state
     const { logout, user, updateContextUser, setContextPreferredLanguage, preferredLanguage, token } = useAuth();
     const location = useLocation();
     const navigate = useNavigate();
     const { title: currentPageTitle, Icon: CurrentPageIcon } = getPageInfoFromPath(location.pathname, preferredLanguage, user?.role); // Get title and icon
     const [isChangePasswordOpen, setIsChangePasswordOpen] = useState(false);
+    const [isResetAppDataOpen, setIsResetAppDataOpen] = useState(false);
+    const [isServerAddressOpen, setIsServerAddressOpen] = useState(false);
+    const { toastPosition, toastDuration, bulkConfirmThreshold, theme, resolvedTheme, setToastPosition, setToastDuration, setBulkConfirmThreshold, deleteMode, setDeleteMode, setTheme, zoom, zoomIn, zoomOut, resetZoom } = useUiPreferences();
 
     const handleLogout = async () => {
         await logout();
@@ -139,10 +166,40 @@ const Header: React.FC<HeaderProps> = ({ toggleSidebar }) => {
          </div>
 
          <div className="flex items-center gap-2">
+             {/* Global indicator for in-flight saves/deletes/batch operations */}
+             <PendingChangesIndicator />
+             <ConnectivityIndicator />
+             {onRefresh && (
+                <Button variant="outline" size="icon" className="rounded-full" onClick={onRefresh} title={t('refreshViewTooltip', preferredLanguage)} data-help="refresh">
+                    <RefreshCw className="h-4 w-4" />
+                    <span className="sr-only">{t('refreshViewTooltip', preferredLanguage)}</span>
+                </Button>
+             )}
+             {/* Theme picker: light, dark or follow the system - applied immediately */}
+             <DropdownMenu>
+                <DropdownMenuTrigger asChild>
+                    <Button variant="outline" size="icon" className="rounded-full" title={t('themeLabel', preferredLanguage)}>
+                        {resolvedTheme === 'dark' ? <Moon className="h-4 w-4" /> : <Sun className="h-4 w-4" />}
+                        <span className="sr-only">{t('themeLabel', preferredLanguage)}</span>
+                    </Button>
+                </DropdownMenuTrigger>
+                <DropdownMenuContent align="end">
+                    <DropdownMenuRadioGroup value={theme} onValueChange={(value) => setTheme(value as Theme)}>
+                        {themes.map(option => {
+                            const ThemeIcon = option === 'light' ? Sun : option === 'dark' ? Moon : Monitor;
+                            return (
+                                <DropdownMenuRadioItem key={option} value={option}>
+                                    <ThemeIcon className="mr-2 h-4 w-4" /> {t(themeLabelKeys[option], preferredLanguage)}
+                                </DropdownMenuRadioItem>
+                            );
+                        })}
+                    </DropdownMenuRadioGroup>
+                </DropdownMenuContent>
+             </DropdownMenu>
              {/* User Dropdown Menu */}
             <DropdownMenu>
                 <DropdownMenuTrigger asChild>
-                <Button variant="outline" size="icon" className="rounded-full">
+                <Button variant="outline" size="icon" className="rounded-full" data-help="user-menu">
                     <UserIcon className="h-4 w-4" />
                     <span className="sr-only">User Menu</span>
                 </Button>
@@ -175,10 +232,99 @@ const Header: React.FC<HeaderProps> = ({ toggleSidebar }) => {
                     </DropdownMenuPortal>
                 </DropdownMenuSub>
                 {/* --- End Language Submenu --- */}
+                {/* --- Notifications Submenu --- */}
+                <DropdownMenuSub>
+                    <DropdownMenuSubTrigger>
+                        <Bell className="mr-2 h-4 w-4" />
+                        <span>{t('notificationsLabel', preferredLanguage)}</span>
+                    </DropdownMenuSubTrigger>
+                    <DropdownMenuPortal>
+                        <DropdownMenuSubContent>
+                            <DropdownMenuLabel className='text-xs text-muted-foreground'>{t('notificationsPositionLabel', preferredLanguage)}</DropdownMenuLabel>
+                            <DropdownMenuRadioGroup value={toastPosition} onValueChange={(value) => setToastPosition(value as ToastPosition)}>
+                                {toastPositions.map(position => (
+                                    <DropdownMenuRadioItem key={position} value={position} onSelect={(e) => e.preventDefault()}>
+                                        {t(toastPositionLabelKeys[position], preferredLanguage)}
+                                    </DropdownMenuRadioItem>
+                                ))}
+                            </DropdownMenuRadioGroup>
+                            <DropdownMenuSeparator />
+                            <DropdownMenuLabel className='text-xs text-muted-foreground'>{t('notificationsDurationLabel', preferredLanguage)}</DropdownMenuLabel>
+                            <DropdownMenuRadioGroup value={String(toastDuration)} onValueChange={(value) => setToastDuration(parseInt(value, 10))}>
+                                {toastDurations.map(duration => (
+                                    <DropdownMenuRadioItem key={duration} value={String(duration)} onSelect={(e) => e.preventDefault()}>
+                                        {t('notificationsDurationSeconds', preferredLanguage, { seconds: duration / 1000 })}
+                                    </DropdownMenuRadioItem>
+                                ))}
+                            </DropdownMenuRadioGroup>
+                            <p className='px-2 py-1 text-xs text-muted-foreground max-w-[200px]'>{t('notificationsErrorDurationHint', preferredLanguage)}</p>
+                        </DropdownMenuSubContent>
+                    </DropdownMenuPortal>
+                </DropdownMenuSub>
+                {/* --- End Notifications Submenu --- */}
+                <DropdownMenuSub>
+                    <DropdownMenuSubTrigger>
+                        <ListChecks className="mr-2 h-4 w-4" />
+                        <span>{t('bulkConfirmThresholdLabel', preferredLanguage)}</span>
+                    </DropdownMenuSubTrigger>
+                    <DropdownMenuPortal>
+                        <DropdownMenuSubContent>
+                            <DropdownMenuRadioGroup value={String(bulkConfirmThreshold)} onValueChange={(value) => setBulkConfirmThreshold(parseInt(value, 10))}>
+                                {bulkConfirmThresholds.map(threshold => (
+                                    <DropdownMenuRadioItem key={threshold} value={String(threshold)} onSelect={(e) => e.preventDefault()}>
+                                        {t('bulkConfirmThresholdOption', preferredLanguage, { count: threshold })}
+                                    </DropdownMenuRadioItem>
+                                ))}
+                            </DropdownMenuRadioGroup>
+                            <p className='px-2 py-1 text-xs text-muted-foreground max-w-[200px]'>{t('bulkConfirmThresholdHint', preferredLanguage)}</p>
+                        </DropdownMenuSubContent>
+                    </DropdownMenuPortal>
+                </DropdownMenuSub>
+                <DropdownMenuSub>
+                    <DropdownMenuSubTrigger>
+                        <Undo2 className="mr-2 h-4 w-4" />
+                        <span>{t('deleteModeLabel', preferredLanguage)}</span>
+                    </DropdownMenuSubTrigger>
+                    <DropdownMenuPortal>
+                        <DropdownMenuSubContent>
+                            <DropdownMenuRadioGroup value={deleteMode} onValueChange={(value) => setDeleteMode(value as DeleteMode)}>
+                                {deleteModes.map(mode => (
+                                    <DropdownMenuRadioItem key={mode} value={mode} onSelect={(e) => e.preventDefault()}>
+                                        {t(mode === 'confirm' ? 'deleteModeConfirm' : 'deleteModeUndo', preferredLanguage)}
+                                    </DropdownMenuRadioItem>
+                                ))}
+                            </DropdownMenuRadioGroup>
+                            <p className='px-2 py-1 text-xs text-muted-foreground max-w-[200px]'>{t('deleteModeHint', preferredLanguage, { seconds: UNDO_DELETE_DELAY_MS / 1000 })}</p>
+                        </DropdownMenuSubContent>
+                    </DropdownMenuPortal>
+                </DropdownMenuSub>
+                {/* Zoom controls stay inside the open menu, so several steps can be taken at once */}
+                <div className='flex items-center justify-between gap-2 px-2 py-1 text-sm'>
+                    <span>{t('zoomLabel', preferredLanguage)}</span>
+                    <div className='flex items-center gap-1'>
+                        <Button variant="ghost" size="icon" className="h-7 w-7" onClick={zoomOut} disabled={zoom === zoomLevels[0]} title={t('zoomOutTooltip', preferredLanguage)}>
+                            <ZoomOut className="h-4 w-4" />
+                        </Button>
+                        <Button variant="ghost" size="sm" className="h-7 min-w-[3.5rem] px-1 tabular-nums" onClick={resetZoom} title={t('zoomResetTooltip', preferredLanguage)}>
+                            {Math.round(zoom * 100)}%
+                        </Button>
+                        <Button variant="ghost" size="icon" className="h-7 w-7" onClick={zoomIn} disabled={zoom === zoomLevels[zoomLevels.length - 1]} title={t('zoomInTooltip', preferredLanguage)}>
+                            <ZoomIn className="h-4 w-4" />
+                        </Button>
+                    </div>
+                </div>
                 <DropdownMenuItem onSelect={() => setIsChangePasswordOpen(true)}>
                     <Settings className="mr-2 h-4 w-4" />
                     {t('headerChangePassword', preferredLanguage)}
                 </DropdownMenuItem>
+                <DropdownMenuItem onSelect={() => setIsServerAddressOpen(true)}>
+                    <Server className="mr-2 h-4 w-4" />
+                    {t('serverAddressMenuItem', preferredLanguage)}
+                </DropdownMenuItem>
+                <DropdownMenuItem onSelect={() => setIsResetAppDataOpen(true)}>
+                    <RotateCcw className="mr-2 h-4 w-4" />
+                    {t('resetAppDataMenuItem', preferredLanguage)}
+                </DropdownMenuItem>
                 <DropdownMenuSeparator />
                 <DropdownMenuItem onSelect={handleLogout} className='text-destructive focus:text-destructive focus:bg-destructive/10'>
                     <LogOut className="mr-2 h-4 w-4" /> {t('headerLogout', preferredLanguage)}
@@ -192,6 +338,15 @@ const Header: React.FC<HeaderProps> = ({ toggleSidebar }) => {
             isOpen={isChangePasswordOpen}
             onOpenChange={setIsChangePasswordOpen}
         />
+        {/* Reset App Data Dialog */}
+        <ResetAppDataDialog
+            isOpen={isResetAppDataOpen}
+            onOpenChange={setIsResetAppDataOpen}
+        />
+        <ServerAddressDialog
+            isOpen={isServerAddressOpen}
+            onOpenChange={setIsServerAddressOpen}
+        />
     </header>
     );
 };
@@ -0,0 +1,196 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { useLocation } from 'react-router-dom';
+import { useAuth } from '@/hooks/useAuth';
+import { t } from '@/translations/utils';
+import type { AppTranslationKey } from '@/translations/models';
+
+// Help overlay toggled with "?": callouts over the key controls of the current view.
+// Controls are found by their `data-help` attribute; a topic without a visible control is skipped.
+
+type HelpSection = 'dashboard' | 'archive' | 'notes' | 'signatures' | 'elements' | 'tags' | 'admin';
+
+interface HelpTopic {
+    target: string; // value of the control's data-help attribute
+    textKey: AppTranslationKey;
+}
+
+const COMMON_TOPICS: HelpTopic[] = [
+    { target: 'navigation', textKey: 'helpNavigation' },
+    { target: 'user-menu', textKey: 'helpUserMenu' },
+    { target: 'refresh', textKey: 'helpRefresh' },
+];
+
+const HELP_TOPICS: Record<HelpSection, HelpTopic[]> = {
+    dashboard: COMMON_TOPICS,
+    archive: [
+        { target: 'search', textKey: 'helpSearch' },
+        { target: 'quick-search', textKey: 'helpArchiveQuickSearch' },
+        { target: 'type-filter', textKey: 'helpArchiveTypeFilter' },
+        { target: 'digitized-filter', textKey: 'helpArchiveDigitizedFilter' },
+        { target: 'show-disabled', textKey: 'helpArchiveShowDisabled' },
+        { target: 'batch-tags', textKey: 'helpArchiveBatchTags' },
+        { target: 'create', textKey: 'helpArchiveCreate' },
+        { target: 'list', textKey: 'helpArchiveList' },
+        { target: 'pagination', textKey: 'helpPagination' },
+        ...COMMON_TOPICS,
+    ],
+    notes: [
+        { target: 'search', textKey: 'helpSearch' },
+        { target: 'create', textKey: 'helpNotesCreate' },
+        { target: 'list', textKey: 'helpNotesList' },
+        { target: 'pagination', textKey: 'helpPagination' },
+        ...COMMON_TOPICS,
+    ],
+    signatures: [
+        { target: 'create', textKey: 'helpComponentsCreate' },
+        { target: 'list', textKey: 'helpComponentsList' },
+        ...COMMON_TOPICS,
+    ],
+    elements: [
+        { target: 'search', textKey: 'helpSearch' },
+        { target: 'jump-to-index', textKey: 'helpElementsJumpToIndex' },
+        { target: 'create', textKey: 'helpElementsCreate' },
+        { target: 'list', textKey: 'helpElementsList' },
+        { target: 'pagination', textKey: 'helpPagination' },
+        ...COMMON_TOPICS,
+    ],
+    tags: [
+        { target: 'create', textKey: 'helpTagsCreate' },
+        { target: 'list', textKey: 'helpTagsList' },
+        ...COMMON_TOPICS,
+    ],
+    admin: [
+        { target: 'admin-tabs', textKey: 'helpAdminTabs' },
+        ...COMMON_TOPICS,
+    ],
+};
+
+const getHelpSection = (pathname: string): HelpSection => {
+    const [base, sub] = pathname.split('/').filter(Boolean);
+    switch (base) {
+        case 'archive': return 'archive';
+        case 'notes': return 'notes';
+        case 'signatures': return sub ? 'elements' : 'signatures';
+        case 'tags': return 'tags';
+        case 'admin': return 'admin';
+        default: return 'dashboard';
+    }
+};
+
+// Sections whose help was already shown - help opens by itself on the first visit of each section
+const SEEN_SECTIONS_STORAGE_KEY = 'helpSeenSections';
+const AUTO_SHOW_DELAY = 800; // ms, lets the view render its controls first
+
+const getSeenSections = (): HelpSection[] => {
+    try { return JSON.parse(localStorage.getItem(SEEN_SECTIONS_STORAGE_KEY) || '[]'); }
+    catch { return []; }
+};
+
+const markSectionSeen = (section: HelpSection) => {
+    const seen = getSeenSections();
+    if (!seen.includes(section)) localStorage.setItem(SEEN_SECTIONS_STORAGE_KEY, JSON.stringify([...seen, section]));
+};
+
+const isTextEntryTarget = (target: EventTarget | null) =>
+    target instanceof HTMLElement && (target.isContentEditable || ['INPUT', 'TEXTAREA', 'SELECT'].includes(target.tagName));
+
+interface Callout extends HelpTopic {
+    rect: { top: number; left: number; width: number; height: number };
+}
+
+const CALLOUT_WIDTH = 260;
+const CALLOUT_GAP = 8;
+
+const HelpOverlay: React.FC = () => {
+    const { preferredLanguage } = useAuth();
+    const location = useLocation();
+    const section = getHelpSection(location.pathname);
+    const [isOpen, setIsOpen] = useState(false);
+    const [callouts, setCallouts] = useState<Callout[]>([]);
+
+    const measureCallouts = useCallback(() => {
+        setCallouts(HELP_TOPICS[section].flatMap(topic => {
+            const control = Array.from(document.querySelectorAll<HTMLElement>(`[data-help="${topic.target}"]`))
+                .find(el => el.offsetParent !== null); // First visible one
+            if (!control) return [];
+            const { top, left, width, height } = control.getBoundingClientRect();
+            return [{ ...topic, rect: { top, left, width, height } }];
+        }));
+    }, [section]);
+
+    // "?" toggles, Escape closes; ignored while typing
+    useEffect(() => {
+        const handleKeyDown = (e: KeyboardEvent) => {
+            if (e.key === 'Escape' && isOpen) { setIsOpen(false); return; }
+            if (e.key !== '?' || e.ctrlKey || e.metaKey || e.altKey || isTextEntryTarget(e.target)) return;
+            e.preventDefault();
+            setIsOpen(open => !open);
+            markSectionSeen(section);
+        };
+        window.addEventListener('keydown', handleKeyDown);
+        return () => window.removeEventListener('keydown', handleKeyDown);
+    }, [isOpen, section]);
+
+    // Close on navigation, then auto-show on the first visit of the new section
+    useEffect(() => {
+        setIsOpen(false);
+        if (getSeenSections().includes(section)) return;
+        const timer = setTimeout(() => {
+            setIsOpen(true);
+            markSectionSeen(section);
+        }, AUTO_SHOW_DELAY);
+        return () => clearTimeout(timer);
+    }, [section]);
+
+    useEffect(() => {
+        if (!isOpen) return;
+        measureCallouts();
+        window.addEventListener('resize', measureCallouts);
+        window.addEventListener('scroll', measureCallouts, true);
+        return () => {
+            window.removeEventListener('resize', measureCallouts);
+            window.removeEventListener('scroll', measureCallouts, true);
+        };
+    }, [isOpen, measureCallouts]);
+
+    if (!isOpen) return null;
+
+    // Callouts go below their control, or above it when there's no room left below
+    const calloutStyle = ({ top, left, height }: Callout['rect']): React.CSSProperties => {
+        const placeBelow = top + height + 120 < window.innerHeight;
+        return {
+            top: placeBelow ? top + height + CALLOUT_GAP : top - CALLOUT_GAP,
+            left: Math.max(CALLOUT_GAP, Math.min(left, window.innerWidth - CALLOUT_WIDTH - CALLOUT_GAP)),
+            width: CALLOUT_WIDTH,
+            transform: placeBelow ? undefined : 'translateY(-100%)',
+        };
+    };
+
+    return (
+        <div
+            className="fixed inset-0 z-[100] bg-black/50"
+            onClick={() => setIsOpen(false)}
+            role="dialog"
+            aria-modal="true"
+            aria-label={t('helpOverlayTitle', preferredLanguage)}
+        >
+            {callouts.map(callout => (
+                <React.Fragment key={callout.target}>
+                    <div
+                        className="absolute rounded-md ring-2 ring-primary ring-offset-2 ring-offset-transparent pointer-events-none"
+                        style={{ top: callout.rect.top, left: callout.rect.left, width: callout.rect.width, height: callout.rect.height }}
+                    />
+                    <div className="absolute rounded-md border bg-popover p-2 text-xs text-popover-foreground shadow-lg" style={calloutStyle(callout.rect)}>
+                        {t(callout.textKey, preferredLanguage)}
+                    </div>
+                </React.Fragment>
+            ))}
+            <div className="absolute bottom-4 left-1/2 -translate-x-1/2 rounded-md border bg-popover px-3 py-2 text-sm text-popover-foreground shadow-lg">
+                <span className="font-medium">{t('helpOverlayTitle', preferredLanguage)}</span>
+                <span className="text-muted-foreground"> · {t('helpOverlayCloseHint', preferredLanguage)}</span>
+            </div>
+        </div>
+    );
+};
+
+export default HelpOverlay;
@@ -1,14 +1,27 @@
-import React from 'react';
+import React, { useState, useCallback } from 'react';
 import { Outlet } from 'react-router-dom'; // Import Outlet
 import Sidebar from './Sidebar';
 import Header from './Header';
+import HelpOverlay from './HelpOverlay';
 import { cn } from '@/lib/utils'; // Import cn
+import { clearInMemoryCaches } from '@/lib/appData';
+import { useWarnBeforeUnloadWhilePending } from '@/hooks/usePendingRequests';
 
 const Layout: React.FC = () => {
   // State for mobile sidebar toggle if needed in the future
   // const [isSidebarOpen, setIsSidebarOpen] = useState(false);
   // const toggleSidebar = () => setIsSidebarOpen(!isSidebarOpen);
 
+  useWarnBeforeUnloadWhilePending();
+
+  // Header "Refresh": drop cached pages/lookups and remount the current page, so it fetches
+  // everything again (showing its own loading state). Persisted view state (page, filters) is kept.
+  const [refreshKey, setRefreshKey] = useState(0);
+  const handleRefresh = useCallback(() => {
+    clearInMemoryCaches();
+    setRefreshKey(key => key + 1);
+  }, []);
+
   return (
     // Use flexbox for sidebar + main content layout
     // bg-muted/40 provides a slightly tinted background for the whole layout area
@@ -18,7 +31,7 @@ const Layout: React.FC = () => {
 
       {/* Main content area */}
       <div className="flex flex-col flex-1">
-         <Header /* toggleSidebar={toggleSidebar} // Pass toggle function if needed */ />
+         <Header onRefresh={handleRefresh} /* toggleSidebar={toggleSidebar} // Pass toggle function if needed */ />
         {/* Main content section with padding and max-width for centering */}
         {/* Ensure main content area has a solid background using the theme variable */}
         <main className={cn(
@@ -26,11 +39,13 @@ const Layout: React.FC = () => {
           "bg-background" // Apply the main background color here
         )}>
           {/* Add max-width and center content within the main area */}
-          <div className="max-w-7xl mx-auto">
+          <div className="max-w-7xl mx-auto" key={refreshKey}>
              <Outlet /> {/* Child routes (pages) render here */}
           </div>
         </main>
       </div>
+      {/* "?" help callouts for the current view */}
+      <HelpOverlay />
     </div>
   );
 };
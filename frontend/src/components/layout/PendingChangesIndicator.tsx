@@ -0,0 +1,54 @@
+import React from 'react';
+import { Button } from '@/components/ui/button';
+import { Popover, PopoverContent, PopoverTrigger } from '@/components/ui/popover';
+import { Badge } from '@/components/ui/badge';
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import { useAuth } from '@/hooks/useAuth';
+import { usePendingRequests } from '@/hooks/usePendingRequests';
+import type { PendingRequest } from '@/lib/api';
+import type { AppTranslationKey } from '@/translations/models';
+import { t } from '@/translations/utils';
+
+// Map HTTP methods to translated operation kinds
+const operationKeyByMethod: Partial<Record<PendingRequest['method'], AppTranslationKey>> = {
+    PUT: 'pendingChangesCreate',
+    PATCH: 'pendingChangesUpdate',
+    DELETE: 'pendingChangesDelete',
+    POST: 'pendingChangesAction',
+};
+
+// Shows a spinner with the number of write operations in flight, app-wide
+const PendingChangesIndicator: React.FC = () => {
+    const { preferredLanguage } = useAuth();
+    const pendingRequests = usePendingRequests();
+
+    if (pendingRequests.length === 0) return null;
+
+    return (
+        <Popover>
+            <PopoverTrigger asChild>
+                <Button variant="ghost" size="sm" className="gap-2" title={t('pendingChangesTooltip', preferredLanguage, { count: pendingRequests.length })}>
+                    <LoadingSpinner size="sm" />
+                    <Badge variant="secondary">{pendingRequests.length}</Badge>
+                    <span className="sr-only">{t('pendingChangesTooltip', preferredLanguage, { count: pendingRequests.length })}</span>
+                </Button>
+            </PopoverTrigger>
+            <PopoverContent align="end" className="w-80">
+                <p className="text-sm font-medium mb-2">{t('pendingChangesTitle', preferredLanguage)}</p>
+                <ul className="space-y-1 text-xs">
+                    {pendingRequests.map(request => (
+                        <li key={request.id} className="flex items-center justify-between gap-2">
+                            <span className="truncate">
+                                <span className="font-medium">{t(operationKeyByMethod[request.method] ?? 'pendingChangesAction', preferredLanguage)}</span>{' '}
+                                <span className="font-mono text-muted-foreground">{request.endpoint}</span>
+                            </span>
+                            <span className="text-muted-foreground shrink-0">{new Date(request.startedAt).toLocaleTimeString()}</span>
+                        </li>
+                    ))}
+                </ul>
+            </PopoverContent>
+        </Popover>
+    );
+};
+
+export default PendingChangesIndicator;
@@ -8,7 +8,7 @@ import { useAuth } from '@/hooks/useAuth';
 import {
   LayoutDashboard, StickyNote, Tag, PenTool, Archive, ShieldAlert, LogOut, FileSearch // Added FileSearch
 } from 'lucide-react';
-import { toast } from "sonner"; // Import toast for logout feedback
+import { toast } from '@/lib/toast'; // Import toast for logout feedback
 import { t } from '@/translations/utils'; // Import translation utility
 import type { AppTranslationKey } from '@/translations/models'; // Import key type
 
@@ -67,7 +67,7 @@ const Sidebar: React.FC<SidebarProps> = ({ className }) => {
        </div>
 
       <ScrollArea className="flex-1 px-4 py-2">
-        <nav className="flex flex-col space-y-1">
+        <nav className="flex flex-col space-y-1" data-help="navigation">
           {navItems.map((item) => (
             <NavLink
               key={item.path}
@@ -0,0 +1,110 @@
+import React, { useState, useEffect } from 'react';
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, DialogFooter, DialogClose } from "@/components/ui/dialog";
+import { Button } from "@/components/ui/button";
+import TagSelector from '@/components/shared/TagSelector';
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import { Tags, MinusCircle } from 'lucide-react';
+import { useAuth } from '@/hooks/useAuth';
+import api from '@/lib/api';
+import type { NoteWithDetails } from '../../../../backend/src/functionalities/note/models';
+import type { Tag } from '../../../../backend/src/functionalities/tag/models';
+import { toast } from '@/lib/toast';
+import { t } from '@/translations/utils';
+
+interface NoteBatchTagDialogProps {
+    isOpen: boolean;
+    onOpenChange: (open: boolean) => void;
+    action: 'add' | 'remove';
+    notes: NoteWithDetails[]; // Selected notes
+    availableTags: Tag[];
+    onDone: () => void; // Called after the run when at least one note was updated
+}
+
+const NoteBatchTagDialog: React.FC<NoteBatchTagDialogProps> = ({ isOpen, onOpenChange, action, notes, availableTags, onDone }) => {
+    const { token, preferredLanguage } = useAuth();
+    const [selectedTagIds, setSelectedTagIds] = useState<number[]>([]);
+    const [progress, setProgress] = useState<{ done: number, total: number } | null>(null);
+
+    useEffect(() => {
+        if (isOpen) setSelectedTagIds([]);
+    }, [isOpen, action]);
+
+    // Only the tag list is sent (merged with / stripped of the chosen tags) - the listed notes may be stale,
+    // so sending their other fields could overwrite newer edits
+    const nextTagIds = (note: NoteWithDetails): number[] => {
+        const current = note.tags?.map(tag => tag.tagId!) ?? [];
+        return action === 'add'
+            ? [...new Set([...current, ...selectedTagIds])]
+            : current.filter(id => !selectedTagIds.includes(id));
+    };
+
+    const handleConfirm = async () => {
+        if (!token || selectedTagIds.length === 0) return;
+        const changed = notes.filter(note => (note.tags?.length ?? 0) !== nextTagIds(note).length);
+        const unchanged = notes.length - changed.length;
+        setProgress({ done: 0, total: changed.length });
+        let updated = 0;
+        const failedTitles: string[] = [];
+        for (const note of changed) {
+            try {
+                await api.updateNote(note.noteId!, { tagIds: nextTagIds(note) }, token);
+                updated++;
+            } catch (err) {
+                console.error(`NotesPage: Batch tag update of note ${note.noteId} failed:`, err);
+                failedTitles.push(note.title);
+            }
+            setProgress({ done: updated + failedTitles.length, total: changed.length });
+        }
+        setProgress(null);
+
+        toast.success(t('notesBatchTagSummary', preferredLanguage, { action, updated, unchanged }));
+        if (failedTitles.length > 0) {
+            toast.error(t('notesBatchTagFailures', preferredLanguage, { count: failedTitles.length, titles: failedTitles.join(', ') }));
+        }
+        onOpenChange(false);
+        if (updated > 0) onDone();
+    };
+
+    const isRunning = progress !== null;
+    const icon = action === 'add' ? <Tags className='h-4 w-4' /> : <MinusCircle className='h-4 w-4' />;
+
+    return (
+        <Dialog open={isOpen} onOpenChange={(open) => { if (!isRunning) onOpenChange(open); }}>
+            <DialogContent className="sm:max-w-md">
+                <DialogHeader>
+                    <DialogTitle className='flex items-center gap-2'>
+                        {icon} {t(action === 'add' ? 'notesBatchTagAddTitle' : 'notesBatchTagRemoveTitle', preferredLanguage)}
+                    </DialogTitle>
+                    <DialogDescription>{t('notesBatchTagDescription', preferredLanguage, { action, count: notes.length })}</DialogDescription>
+                </DialogHeader>
+
+                <div className="py-2">
+                    <TagSelector selectedTagIds={selectedTagIds} onChange={setSelectedTagIds} availableTags={availableTags} />
+                </div>
+
+                {progress && (
+                    <p className="text-sm text-muted-foreground text-center" role="status">
+                        {t('notesBatchTagProgress', preferredLanguage, { done: progress.done, total: progress.total })}
+                    </p>
+                )}
+
+                <DialogFooter className='gap-2'>
+                    <DialogClose asChild>
+                        <Button type="button" variant="outline" disabled={isRunning}>{t('cancelButton', preferredLanguage)}</Button>
+                    </DialogClose>
+                    <Button
+                        type="button"
+                        onClick={handleConfirm}
+                        disabled={isRunning || selectedTagIds.length === 0}
+                        variant={action === 'remove' ? 'destructive' : 'default'}
+                    >
+                        {isRunning ? <LoadingSpinner size="sm" className="mr-2" /> : <span className="mr-2">{icon}</span>}
+                        {t(action === 'add' ? 'notesBatchTagConfirmAdd' : 'notesBatchTagConfirmRemove', preferredLanguage, { count: selectedTagIds.length })}
+                    </Button>
+                </DialogFooter>
+            </DialogContent>
+        </Dialog>
+    );
+};
+
+export default NoteBatchTagDialog;
@@ -6,15 +6,17 @@ import { Button } from '@/components/ui/button';
 import { Input } from '@/components/ui/input';
 import { Label } from '@/components/ui/label';
 import { Textarea } from '@/components/ui/textarea';
-import { Checkbox } from '@/components/ui/checkbox';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from '@/components/ui/select';
 import TagSelector from '@/components/shared/TagSelector';
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
 import { useAuth } from '@/hooks/useAuth';
 import api from '@/lib/api';
+import { applyServerFieldErrors } from '@/lib/serverFieldErrors';
 // Import backend types Note and NoteInput
 import type { Note, NoteInput, NoteWithDetails } from '../../../../backend/src/functionalities/note/models';
-import { toast } from "sonner";
+import { NOTE_CONTENT_MAX_LENGTH } from '../../../../backend/src/functionalities/note/models';
+import { toast } from '@/lib/toast';
 import { cn } from '@/lib/utils'; // Import cn
 import { z } from 'zod'; // Import z for inferring type in onSubmit
 import { t } from '@/translations/utils'; // Import translation utility
@@ -22,6 +24,9 @@ import { t } from '@/translations/utils'; // Import translation utility
 // Infer the form data type directly from the schema
 type NoteFormData = z.infer<typeof noteFormSchema>;
 
+// Soft limit - longer notes still save but render slowly in lists and previews
+const NOTE_CONTENT_WARN_LENGTH = 20_000;
+
 interface NoteEditorProps {
   noteToEdit: NoteWithDetails | null; // Use NoteWithDetails which includes tags/owner
   onSave: () => void; // Callback after successful save
@@ -40,7 +45,7 @@ const NoteEditor: React.FC<NoteEditorProps> = ({ noteToEdit, onSave }) => {
   // Check if the current user is an admin
   const isAdmin = user?.role === 'admin';
 
-  const { register, handleSubmit, reset, control, setValue, watch, formState: { errors } } = useForm({ // Remove explicit type here
+  const { register, handleSubmit, reset, control, setValue, watch, setError: setFieldError, formState: { errors } } = useForm({ // Remove explicit type here
     resolver: zodResolver(noteFormSchema),
     defaultValues: {
       title: '',
@@ -106,6 +111,10 @@ const NoteEditor: React.FC<NoteEditorProps> = ({ noteToEdit, onSave }) => {
     setValue('tagIds', selectedTagIds);
   }, [selectedTagIds, setValue]);
 
+  const contentLength = watch('content')?.length ?? 0;
+  const isContentTooLong = contentLength > NOTE_CONTENT_MAX_LENGTH;
+  const isContentLong = contentLength > NOTE_CONTENT_WARN_LENGTH;
+
   // Use the inferred type for 'data'
   const onSubmit = async (data: NoteFormData) => {
     if (!token) return;
@@ -115,7 +124,7 @@ const NoteEditor: React.FC<NoteEditorProps> = ({ noteToEdit, onSave }) => {
     // Construct the payload according to the NoteInput interface
     const payload: NoteInput = {
         title: data.title,
-        content: data.content ?? '', // Ensure content is string, even if undefined/null in form data
+        content: data.content?.trim() ? data.content : null, // Empty/whitespace-only content is sent as null
         shared: Boolean(data.shared), // Ensure shared is boolean
         tagIds: selectedTagIds, // Ensure the latest state is included
     };
@@ -133,7 +142,8 @@ const NoteEditor: React.FC<NoteEditorProps> = ({ noteToEdit, onSave }) => {
       onSave(); // Call the success callback (which handles toast and closing)
     } catch (err: any) {
       const msg = err.message || t('notesSaveFailed', preferredLanguage, { message: '' }).replace(': {message}', ''); // Use translated error
-      setError(msg);
+      // Field-specific messages go next to their inputs; the general error stays for anything else
+      if (applyServerFieldErrors(err, ['title', 'content'] as const, setFieldError) === 0) setError(msg);
       toast.error(t('errorMessageTemplate', preferredLanguage, { message: t('notesSaveFailed', preferredLanguage, { message: msg }) }));
       console.error("Save Note Error:", err);
     } finally {
@@ -162,7 +172,14 @@ const NoteEditor: React.FC<NoteEditorProps> = ({ noteToEdit, onSave }) => {
       <div className="grid gap-1.5"> {/* Adjusted gap */}
         <Label htmlFor="content">{t('notesContentLabel', preferredLanguage)}</Label>
         <Textarea id="content" {...register('content')} rows={6} aria-invalid={errors.content ? "true" : "false"} className={cn(errors.content && "border-destructive")}/>
-        {errors.content && <p className="text-xs text-destructive">{errors.content?.message}</p>}
+        <p className="text-xs text-muted-foreground">{t('notesContentMarkdownHint', preferredLanguage)}</p>
+        <p className={cn("text-xs text-right", isContentTooLong ? "text-destructive" : isContentLong ? "text-amber-600 dark:text-amber-500" : "text-muted-foreground")}>
+            {t('notesContentLength', preferredLanguage, { length: contentLength.toLocaleString(), max: NOTE_CONTENT_MAX_LENGTH.toLocaleString() })}
+        </p>
+        {isContentTooLong
+            ? <p className="text-xs text-destructive">{t('notesContentTooLongError', preferredLanguage, { max: NOTE_CONTENT_MAX_LENGTH.toLocaleString() })}</p>
+            : isContentLong && <p className="text-xs text-amber-600 dark:text-amber-500">{t('notesContentLongWarning', preferredLanguage, { threshold: NOTE_CONTENT_WARN_LENGTH.toLocaleString() })}</p>}
+        {errors.content && !isContentTooLong && <p className="text-xs text-destructive">{errors.content?.message}</p>}
       </div>
 
        <div className="grid gap-1.5"> {/* Adjusted gap */}
@@ -173,42 +190,34 @@ const NoteEditor: React.FC<NoteEditorProps> = ({ noteToEdit, onSave }) => {
           {errors.tagIds && <p className="text-xs text-destructive">{typeof errors.tagIds.message === 'string' ? errors.tagIds.message : 'Invalid tag selection'}</p>}
        </div>
 
-        {/* Use Controller for Shadcn Checkbox */}
-       <div className="flex items-center space-x-2 pt-2"> {/* Added padding top */}
+        {/* Audience - the server only supports private/public notes, so role or per-user sharing isn't offered */}
+       <div className="grid gap-1.5 pt-2">
+           <Label htmlFor="shared">{t('notesAudienceLabel', preferredLanguage)}</Label>
            <Controller
                 name="shared"
                 control={control}
-                render={({ field: { onChange, onBlur, value, name, ref } }) => ( // Destructure field props
-                     <Checkbox
-                         id="shared"
-                         // Ensure 'checked' prop receives a boolean
-                         checked={Boolean(value)}
-                         // Pass the boolean value directly to onChange handler
-                         onCheckedChange={(checked) => onChange(Boolean(checked))}
-                         onBlur={onBlur} // Pass onBlur
-                         name={name} // Pass name
-                         ref={ref} // Pass ref
-                         aria-invalid={errors.shared ? "true" : "false"}
-                         className={cn(errors.shared && "border-destructive")}
-                         // Disable checkbox if user is not the owner AND not an admin
+                render={({ field: { onChange, value } }) => (
+                     <Select
+                         value={value ? 'everyone' : 'private'}
+                         onValueChange={(audience) => onChange(audience === 'everyone')}
+                         // Disable if user is not the owner AND not an admin
                          disabled={!isOwner && !isAdmin}
-                         title={(!isOwner && !isAdmin) ? t('notesShareTooltip', preferredLanguage) : undefined}
-                     />
+                     >
+                         <SelectTrigger id="shared" className={cn('w-full sm:w-[280px]', errors.shared && "border-destructive")} title={(!isOwner && !isAdmin) ? t('notesShareTooltip', preferredLanguage) : undefined}>
+                             <SelectValue />
+                         </SelectTrigger>
+                         <SelectContent>
+                             <SelectItem value="private">{t('notesAudiencePrivate', preferredLanguage)}</SelectItem>
+                             <SelectItem value="everyone">{t('notesAudienceEveryone', preferredLanguage)}</SelectItem>
+                         </SelectContent>
+                     </Select>
                  )}
            />
-          <Label
-             htmlFor="shared"
-             className={cn(
-                 'cursor-pointer font-normal',
-                 (!isOwner && !isAdmin) && 'cursor-not-allowed opacity-70' // Style label when disabled
-             )}
-           >
-             {t('notesSharePubliclyLabel', preferredLanguage)}
-          </Label>
+           <p className="text-xs text-muted-foreground">{t('notesAudienceLimitedHint', preferredLanguage)}</p>
         </div>
          {errors.shared && <p className="text-xs text-destructive">{errors.shared.message}</p>}
 
-      <Button type="submit" disabled={isLoading || isFetchingDetails} className="mt-4 justify-self-start"> {/* Align button left */}
+      <Button type="submit" disabled={isLoading || isFetchingDetails || isContentTooLong} className="mt-4 justify-self-start"> {/* Align button left */}
         {isLoading ? <LoadingSpinner size="sm" className='mr-2' /> : (noteToEdit ? t('editButton', preferredLanguage) : t('createButton', preferredLanguage))} {t('notesTitleSingular', preferredLanguage)} {/* TODO: Add notesTitleSingular */}
       </Button>
     </form>
@@ -0,0 +1,106 @@
+import React, { useState } from 'react';
+import { Button } from '@/components/ui/button';
+import {
+  DropdownMenu, DropdownMenuContent, DropdownMenuItem, DropdownMenuLabel, DropdownMenuSeparator, DropdownMenuTrigger,
+} from '@/components/ui/dropdown-menu';
+import { Download } from 'lucide-react';
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import { useAuth } from '@/hooks/useAuth';
+import api from '@/lib/api';
+import { downloadTextFile } from '@/lib/csv';
+import type { NoteWithDetails } from '../../../../backend/src/functionalities/note/models';
+import type { SearchRequest, SortElement } from '../../../../backend/src/utils/search';
+import { toast } from '@/lib/toast';
+import { t } from '@/translations/utils';
+import type { SupportedLanguage } from '@/translations/models';
+
+type ExportFormat = 'md' | 'json';
+
+const EXPORT_FETCH_PAGE_SIZE = 100;
+
+interface NoteExportMenuProps {
+  notes: NoteWithDetails[]; // Notes of the current page
+  searchQuery: SearchRequest['query'];
+  searchGroups?: SearchRequest['groups'];
+  sort: SortElement;
+  totalNotes: number;
+  disabled?: boolean;
+}
+
+const formatDate = (value: Date | string | undefined, lang: SupportedLanguage) =>
+  value ? new Date(value).toLocaleString(lang) : '-';
+
+// Each note becomes a section: title heading, details list, then the raw (markdown) content
+const notesToMarkdown = (notes: NoteWithDetails[], lang: SupportedLanguage): string => {
+  const sections = notes.map(note => {
+    const tags = note.tags?.map(tag => tag.name).join(', ');
+    const details = [
+      `- **${t('notesAuthorColumn', lang)}:** ${note.ownerLogin ?? '-'}`,
+      `- **${t('tagsLabel', lang)}:** ${tags || '-'}`,
+      `- **${t('notesSharedColumn', lang)}:** ${note.shared ? t('yesButton', lang) : t('noButton', lang)}`,
+      `- **${t('notesModifiedColumn', lang)}:** ${formatDate(note.modifiedOn, lang)}`,
+    ].join('\n');
+    const content = note.content?.trim() ? note.content.trim() : `_${t('notesExportNoContent', lang)}_`;
+    return `## ${note.title}\n\n${details}\n\n${content}\n`;
+  });
+  return `# ${t('notesTitle', lang)}\n\n_${t('notesExportHeader', lang, { count: notes.length, date: new Date().toLocaleString(lang) })}_\n\n${sections.join('\n')}`;
+};
+
+const NoteExportMenu: React.FC<NoteExportMenuProps> = ({ notes, searchQuery, searchGroups, sort, totalNotes, disabled }) => {
+  const { token, preferredLanguage } = useAuth();
+  const [isExporting, setIsExporting] = useState(false);
+
+  // Fetches every page of the current search (same filters and order as the list)
+  const fetchAllNotes = async (token: string): Promise<NoteWithDetails[]> => {
+    const all: NoteWithDetails[] = [];
+    for (let page = 1; ; page++) {
+      const response = await api.searchNotes({ query: searchQuery, groups: searchGroups, page, pageSize: EXPORT_FETCH_PAGE_SIZE, sort: [sort] }, token);
+      all.push(...response.data);
+      if (page >= response.totalPages || response.data.length === 0) return all;
+    }
+  };
+
+  const handleExport = async (format: ExportFormat, allPages: boolean) => {
+    if (!token) return;
+    setIsExporting(true);
+    try {
+      const exported = allPages ? await fetchAllNotes(token) : notes;
+      if (exported.length === 0) { toast.info(t('notesExportNothing', preferredLanguage)); return; }
+      const fileName = `notes-${new Date().toISOString().slice(0, 10)}.${format}`;
+      if (format === 'json') {
+        downloadTextFile(JSON.stringify(exported, null, 2), fileName, 'application/json;charset=utf-8');
+      } else {
+        downloadTextFile(notesToMarkdown(exported, preferredLanguage), fileName, 'text/markdown;charset=utf-8');
+      }
+      toast.success(t('notesExportSuccess', preferredLanguage, { count: exported.length, fileName }));
+    } catch (err: any) {
+      const msg = err.message || t('notesExportFailed', preferredLanguage);
+      toast.error(t('errorMessageTemplate', preferredLanguage, { message: msg }));
+      console.error("NotesPage: Export Notes Error:", err);
+    } finally {
+      setIsExporting(false);
+    }
+  };
+
+  return (
+    <DropdownMenu>
+      <DropdownMenuTrigger asChild>
+        <Button variant="outline" className='shrink-0' disabled={disabled || isExporting || totalNotes === 0}>
+          {isExporting ? <LoadingSpinner size="sm" className="mr-2" /> : <Download className="mr-2 h-4 w-4" />}
+          {t('notesExportButton', preferredLanguage)}
+        </Button>
+      </DropdownMenuTrigger>
+      <DropdownMenuContent align="end">
+        <DropdownMenuLabel>{t('notesExportCurrentPage', preferredLanguage, { count: notes.length })}</DropdownMenuLabel>
+        <DropdownMenuItem onSelect={() => handleExport('md', false)}>{t('notesExportMarkdown', preferredLanguage)}</DropdownMenuItem>
+        <DropdownMenuItem onSelect={() => handleExport('json', false)}>{t('notesExportJson', preferredLanguage)}</DropdownMenuItem>
+        <DropdownMenuSeparator />
+        <DropdownMenuLabel>{t('notesExportAllPages', preferredLanguage, { count: totalNotes })}</DropdownMenuLabel>
+        <DropdownMenuItem onSelect={() => handleExport('md', true)}>{t('notesExportMarkdown', preferredLanguage)}</DropdownMenuItem>
+        <DropdownMenuItem onSelect={() => handleExport('json', true)}>{t('notesExportJson', preferredLanguage)}</DropdownMenuItem>
+      </DropdownMenuContent>
+    </DropdownMenu>
+  );
+};
+
+export default NoteExportMenu;
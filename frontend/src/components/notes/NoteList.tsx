@@ -1,102 +1,164 @@
 import React from 'react';
-import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from "@/components/ui/table";
 import { Button } from '@/components/ui/button';
 import { Badge } from '@/components/ui/badge';
+import { Checkbox } from '@/components/ui/checkbox';
 import { Edit, Trash2, User, Eye } from 'lucide-react'; // Added Eye icon
 import type { NoteWithDetails } from '../../../../backend/src/functionalities/note/models'; // Use NoteWithDetails
+import type { SortElement } from '../../../../backend/src/utils/search';
 import { useAuth } from '@/hooks/useAuth'; // Import useAuth
 import { cn } from '@/lib/utils'; // Import cn
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import DataTable, { type DataTableColumn } from '@/components/shared/DataTable';
+import Timestamp from '@/components/shared/Timestamp';
 import { t } from '@/translations/utils'; // Import translation utility
 
 interface NoteListProps {
   notes: NoteWithDetails[]; // Expect notes with tags and ownerLogin
   onEdit: (note: NoteWithDetails) => void;
   onDelete: (noteId: number) => void;
+  busyRows?: Record<number, 'delete'>; // Notes with a delete request in flight
   onPreview: (note: NoteWithDetails) => void; // Added preview callback
+  sort: SortElement;
+  onSortChange: (sort: SortElement) => void;
+  selectedNoteIds?: number[]; // Multi-select mode (checkbox column) when given together with onToggleSelect
+  onToggleSelect?: (notes: NoteWithDetails[], selected: boolean) => void;
+  emptyMessage?: React.ReactNode; // Shown when there are no notes
 }
 
-const NoteList: React.FC<NoteListProps> = ({ notes, onEdit, onDelete, onPreview }) => {
+const CONTENT_PREVIEW_LENGTH = 80;
+
+// Single-line start of the content: whitespace/newlines collapsed, ellipsis when cut
+const contentPreview = (content: string): string => {
+  const text = content.replace(/\s+/g, ' ').trim();
+  return text.length > CONTENT_PREVIEW_LENGTH ? `${text.slice(0, CONTENT_PREVIEW_LENGTH).trimEnd()}…` : text;
+};
+
+const NoteList: React.FC<NoteListProps> = ({ notes, onEdit, onDelete, busyRows = {}, onPreview, sort, onSortChange, selectedNoteIds, onToggleSelect, emptyMessage }) => {
   const { user, preferredLanguage } = useAuth(); // Get current user and language
   const isAdmin = user?.role === 'admin'; // Check if user is admin
+  const isSelectable = !!selectedNoteIds && !!onToggleSelect;
+  // Only notes the user may update can be selected for batch actions
+  const selectableNotes = notes.filter(note => note.ownerUserId === user?.userId || isAdmin);
+  const selectedOnPage = selectableNotes.filter(note => selectedNoteIds?.includes(note.noteId!)).length;
+  const canDelete = (note: NoteWithDetails) => note.ownerUserId === user?.userId || isAdmin; // Owner OR admin
 
-  if (notes.length === 0) {
-    return null; // Parent handles the empty state message
-  }
+  const selectColumn: DataTableColumn<NoteWithDetails> = {
+      key: 'select',
+      headClassName: 'w-[40px]',
+      header: (
+          <Checkbox
+              checked={selectedOnPage === 0 ? false : selectedOnPage === selectableNotes.length ? true : 'indeterminate'}
+              onCheckedChange={(checked) => onToggleSelect!(selectableNotes, checked === true)}
+              disabled={selectableNotes.length === 0}
+              aria-label={t('notesSelectPageLabel', preferredLanguage)}
+          />
+      ),
+      cell: (note) => (
+          <Checkbox
+              checked={selectedNoteIds!.includes(note.noteId!)}
+              onCheckedChange={(checked) => onToggleSelect!([note], checked === true)}
+              disabled={!canDelete(note)}
+              aria-label={t('notesSelectNoteLabel', preferredLanguage, { title: note.title })}
+          />
+      ),
+  };
 
-  return (
-    <Table>
-        <TableHeader>
-            <TableRow>
-                 {/* Use translated headers */}
-                <TableHead>{t('titleLabel', preferredLanguage)}</TableHead>
-                <TableHead className='w-[150px]'>{t('notesAuthorColumn', preferredLanguage)}</TableHead>
-                <TableHead className='w-[120px]'>{t('notesModifiedColumn', preferredLanguage)}</TableHead>
-                <TableHead className='w-[100px]'>{t('notesSharedColumn', preferredLanguage)}</TableHead>
-                <TableHead>{t('tagsLabel', preferredLanguage)}</TableHead>
-                <TableHead className="text-right w-[130px]">{t('actionsLabel', preferredLanguage)}</TableHead> {/* Increased width */}
-            </TableRow>
-        </TableHeader>
-        <TableBody>
-            {notes.map((note) => {
-                const isOwner = note.ownerUserId === user?.userId;
-                const canDelete = isOwner || isAdmin; // User can delete if they are owner OR admin
+  const columns: DataTableColumn<NoteWithDetails>[] = [
+      ...(isSelectable ? [selectColumn] : []),
+      {
+          key: 'title',
+          header: t('titleLabel', preferredLanguage),
+          sortField: 'title',
+          cellClassName: 'font-medium cursor-pointer hover:text-primary hover:underline',
+          cellProps: (note) => ({ onClick: () => onPreview(note), title: t('notesPreviewTitleTooltip', preferredLanguage, { title: note.title }) }),
+          cell: (note) => note.title,
+      },
+      {
+          // Content snippet to scan notes without opening them - the full text is in the tooltip
+          key: 'contentPreview',
+          header: t('notesContentPreviewColumn', preferredLanguage),
+          headClassName: 'hidden md:table-cell',
+          cellClassName: 'hidden md:table-cell text-xs text-muted-foreground max-w-[320px] whitespace-normal break-words',
+          cellProps: (note) => ({ title: note.content?.trim() ? note.content : undefined }),
+          cell: (note) => note.content?.trim()
+              ? contentPreview(note.content)
+              : <i className='text-muted-foreground/70'>{t('notesNoContentPlaceholder', preferredLanguage)}</i>,
+      },
+      {
+          key: 'ownerLogin',
+          header: t('notesAuthorColumn', preferredLanguage),
+          sortField: 'ownerLogin',
+          headClassName: 'w-[150px]',
+          cellClassName: 'text-sm text-muted-foreground',
+          cell: (note) => {
+              const isOwner = note.ownerUserId === user?.userId;
+              return (
+                  <div className='flex items-center gap-1' title={note.ownerLogin}>
+                      {isOwner ? <User className='h-3 w-3 text-primary'/> : <User className='h-3 w-3'/>}
+                      <span className={cn(isOwner && 'font-medium text-foreground')}>{note.ownerLogin ?? 'Unknown'}</span>
+                  </div>
+              );
+          },
+      },
+      { key: 'modifiedOn', header: t('notesModifiedColumn', preferredLanguage), sortField: 'modifiedOn', initialSortDirection: 'DESC', headClassName: 'w-[120px]', cellClassName: 'text-sm', cell: (note) => <Timestamp value={note.modifiedOn} /> },
+      {
+          key: 'shared',
+          header: t('notesSharedColumn', preferredLanguage),
+          sortField: 'shared',
+          initialSortDirection: 'DESC',
+          headClassName: 'w-[100px]',
+          cell: (note) => note.shared
+              ? <Badge variant="outline" title={t('notesAudienceEveryone', preferredLanguage)}>{t('notesSharedBadge', preferredLanguage)}</Badge>
+              : <Badge variant="secondary" title={t('notesAudiencePrivate', preferredLanguage)}>{t('notesPrivateBadge', preferredLanguage)}</Badge>,
+      },
+      {
+          key: 'tags',
+          header: t('tagsLabel', preferredLanguage),
+          cell: (note) => (
+              <div className='flex flex-wrap gap-1 max-w-[200px]'>
+                  {note.tags?.slice(0, 3).map(tag => (
+                      <Badge key={tag.tagId} variant='secondary' className='text-xs font-normal'>{tag.name}</Badge>
+                  ))}
+                  {note.tags && note.tags.length > 3 && (
+                      <Badge variant='outline' className='text-xs font-normal'>+{note.tags.length - 3} more</Badge>
+                  )}
+                  {(!note.tags || note.tags.length === 0) && <span className='text-xs italic text-muted-foreground'>{t('notesNoTagsPlaceholder', preferredLanguage)}</span>}
+              </div>
+          ),
+      },
+  ];
 
-                return (
-                    <TableRow key={note.noteId}>
-                        {/* Make Title cell clickable */}
-                        <TableCell
-                           className="font-medium cursor-pointer hover:text-primary hover:underline"
-                           onClick={() => onPreview(note)}
-                           title={t('notesPreviewTitleTooltip', preferredLanguage, { title: note.title })} // Use translated tooltip
-                        >
-                           {note.title}
-                        </TableCell>
-                         {/* Author Column */}
-                         <TableCell className='text-sm text-muted-foreground'>
-                             <div className='flex items-center gap-1' title={note.ownerLogin}>
-                                {isOwner ? <User className='h-3 w-3 text-primary'/> : <User className='h-3 w-3'/>}
-                                <span className={cn(isOwner && 'font-medium text-foreground')}>{note.ownerLogin ?? 'Unknown'}</span>
-                             </div>
-                         </TableCell>
-                        <TableCell className='text-sm'>{new Date(note.modifiedOn).toLocaleDateString()}</TableCell>
-                        <TableCell>
-                            {note.shared ? <Badge variant="outline">{t('notesSharedBadge', preferredLanguage)}</Badge> : <Badge variant="secondary">{t('notesPrivateBadge', preferredLanguage)}</Badge>}
-                        </TableCell>
-                         <TableCell>
-                             <div className='flex flex-wrap gap-1 max-w-[200px]'>
-                                 {note.tags?.slice(0, 3).map(tag => (
-                                     <Badge key={tag.tagId} variant='secondary' className='text-xs font-normal'>{tag.name}</Badge>
-                                 ))}
-                                 {note.tags && note.tags.length > 3 && (
-                                    <Badge variant='outline' className='text-xs font-normal'>+{note.tags.length - 3} more</Badge>
-                                 )}
-                                 {(!note.tags || note.tags.length === 0) && <span className='text-xs italic text-muted-foreground'>{t('notesNoTagsPlaceholder', preferredLanguage)}</span>}
-                             </div>
-                         </TableCell>
-                        <TableCell className="text-right space-x-1">
-                             {/* Use translated titles */}
-                             <Button variant="ghost" size="icon" onClick={() => onPreview(note)} title={t('previewButton', preferredLanguage)}>
-                                 <Eye className="h-4 w-4" />
-                             </Button>
-                             {/* Edit button always visible if user can access the note */}
-                             <Button variant="ghost" size="icon" onClick={() => onEdit(note)} title={t('editButton', preferredLanguage)}>
-                                <Edit className="h-4 w-4" />
-                            </Button>
-                            {/* Delete button visible if owner OR admin */}
-                            {canDelete ? (
-                                <Button variant="ghost" size="icon" onClick={() => onDelete(note.noteId!)} title={t('deleteButton', preferredLanguage)}>
-                                <Trash2 className="h-4 w-4 text-destructive" />
-                                </Button>
-                            ) : (
-                                // Optional: Add placeholder or leave empty if not deletable
-                                <span className="inline-block w-9 h-9"></span> // Placeholder to maintain alignment
-                            )}
-                        </TableCell>
-                    </TableRow>
-                );
-            })}
-        </TableBody>
-    </Table>
+  const renderActions = (note: NoteWithDetails) => (
+      <>
+          <Button variant="ghost" size="icon" onClick={() => onPreview(note)} title={t('previewButton', preferredLanguage)}>
+              <Eye className="h-4 w-4" />
+          </Button>
+          {/* Edit button always visible if user can access the note */}
+          <Button variant="ghost" size="icon" onClick={() => onEdit(note)} title={t('editButton', preferredLanguage)}>
+              <Edit className="h-4 w-4" />
+          </Button>
+          {canDelete(note) ? (
+              <Button variant="ghost" size="icon" disabled={!!busyRows[note.noteId!]} onClick={() => onDelete(note.noteId!)} title={t('deleteButton', preferredLanguage)}>
+                  {busyRows[note.noteId!] ? <LoadingSpinner size="sm" /> : <Trash2 className="h-4 w-4 text-destructive" />}
+              </Button>
+          ) : (
+              <span className="inline-block w-9 h-9"></span> // Placeholder to maintain alignment
+          )}
+      </>
+  );
+
+  return (
+      <DataTable
+          rows={notes}
+          rowKey={(note) => note.noteId!}
+          columns={columns}
+          actions={renderActions}
+          actionsClassName="w-[130px]"
+          rowProps={(note) => ({ 'data-state': selectedNoteIds?.includes(note.noteId!) ? 'selected' : undefined })}
+          emptyMessage={emptyMessage}
+          sort={sort}
+          onSortChange={onSortChange}
+      />
   );
 };
 
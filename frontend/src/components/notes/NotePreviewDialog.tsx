@@ -1,57 +1,82 @@
-import React from 'react';
+import React, { useState, useEffect } from 'react';
 import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, DialogFooter } from '@/components/ui/dialog';
 import { Button } from '@/components/ui/button';
 import { Badge } from '@/components/ui/badge';
 import { ScrollArea } from '@/components/ui/scroll-area';
+import { ToggleGroup, ToggleGroupItem } from '@/components/ui/toggle-group';
+import { Edit, Eye, Code } from 'lucide-react';
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import ErrorDisplay from '@/components/shared/ErrorDisplay';
+import Timestamp from '@/components/shared/Timestamp';
+import MarkdownText from '@/components/shared/MarkdownText';
+import api from '@/lib/api';
 import type { NoteWithDetails } from '../../../../backend/src/functionalities/note/models';
 import { t } from '@/translations/utils'; // Import translation utility
 import { useAuth } from '@/hooks/useAuth'; // Import useAuth to get language
 
+type ContentView = 'rendered' | 'source';
+
 interface NotePreviewDialogProps {
     isOpen: boolean;
     onOpenChange: (isOpen: boolean) => void;
     note: NoteWithDetails | null;
+    onEdit?: (note: NoteWithDetails) => void;
 }
 
-// --- Date Formatter (Copied from NotesPage) ---
-const formatDate = (dateInput: Date | string | undefined | null): string => {
-    if (!dateInput) return "N/A"; // TODO: Translate N/A if needed
-    try {
-        const date = new Date(dateInput);
-        if (isNaN(date.getTime())) {
-            console.error("formatDate received invalid date input:", dateInput);
-            return "Invalid Date"; // TODO: Translate "Invalid Date"
-        }
-        // Use locale string for date formatting
-        return date.toLocaleDateString(undefined, {
-            year: 'numeric', month: 'short', day: 'numeric',
-        });
-    } catch (e) {
-        console.error("Error formatting date:", dateInput, e);
-        return "Error"; // TODO: Translate "Error"
-    }
-};
-// ------------------------------------------------
 
 const NotePreviewDialog: React.FC<NotePreviewDialogProps> = ({
     isOpen,
     onOpenChange,
-    note: previewingNote, // Renamed for clarity
+    note: listNote, // Note as known by the list
+    onEdit,
 }) => {
-    const { preferredLanguage } = useAuth(); // Get preferredLanguage
+    const { token, user, preferredLanguage } = useAuth(); // Get preferredLanguage
+    const [fullNote, setFullNote] = useState<NoteWithDetails | null>(null);
+    const [isFetchingDetails, setIsFetchingDetails] = useState(false);
+    const [fetchError, setFetchError] = useState<string | null>(null);
+    // Kept between previews - someone checking the source of one note likely wants it for the next too
+    const [contentView, setContentView] = useState<ContentView>('rendered');
+
+    // The list entry may lack content or tags - fetch the full note in that case
+    useEffect(() => {
+        setFullNote(null);
+        setFetchError(null);
+        if (!isOpen || !listNote?.noteId || !token) return;
+        if (listNote.content !== undefined && listNote.content !== null && listNote.tags !== undefined) return;
+        let isCancelled = false;
+        setIsFetchingDetails(true);
+        api.getNoteById(listNote.noteId, token)
+            .then(note => { if (!isCancelled) setFullNote(note); })
+            .catch((err: any) => {
+                console.error("Fetch Note Preview Details Error:", err);
+                if (!isCancelled) setFetchError(err.message || t('notesLoadDetailsError', preferredLanguage));
+            })
+            .finally(() => { if (!isCancelled) setIsFetchingDetails(false); });
+        return () => { isCancelled = true; };
+    }, [isOpen, listNote, token, preferredLanguage]);
 
+    const previewingNote = fullNote ?? listNote;
     if (!previewingNote) {
         return null;
     }
 
+    const canEdit = !!onEdit && (previewingNote.ownerUserId === user?.userId || user?.role === 'admin');
+    const handleEditClick = () => {
+        onOpenChange(false);
+        onEdit?.(previewingNote);
+    };
+
     return (
         <Dialog open={isOpen} onOpenChange={onOpenChange}>
             <DialogContent className="sm:max-w-2xl">
                 <DialogHeader>
                     <DialogTitle>{previewingNote.title}</DialogTitle>
                     <DialogDescription>
-                        {t('notesPreviewBy', preferredLanguage)} {previewingNote.ownerLogin ?? 'Unknown'} {t('notesPreviewOn', preferredLanguage)} {formatDate(previewingNote.createdOn)}
-                        {previewingNote.shared ? <Badge variant="outline" className='ml-2'>{t('notesSharedBadge', preferredLanguage)}</Badge> : null}
+                        {t('notesPreviewBy', preferredLanguage)} {previewingNote.ownerLogin ?? 'Unknown'} · <Timestamp value={previewingNote.createdOn} />
+                        {previewingNote.modifiedOn && <span className='ml-1'>· {t('notesPreviewModified', preferredLanguage)} <Timestamp value={previewingNote.modifiedOn} /></span>}
+                        {previewingNote.shared
+                            ? <Badge variant="outline" className='ml-2'>{t('notesSharedBadge', preferredLanguage)}</Badge>
+                            : <Badge variant="outline" className='ml-2 text-muted-foreground'>{t('notesPrivateBadge', preferredLanguage)}</Badge>}
                     </DialogDescription>
                     {/* Display Tags */}
                     {previewingNote.tags && previewingNote.tags.length > 0 && (
@@ -62,18 +87,35 @@ const NotePreviewDialog: React.FC<NotePreviewDialogProps> = ({
                         </div>
                     )}
                 </DialogHeader>
+                <ToggleGroup type="single" variant="outline" size="sm" value={contentView}
+                    onValueChange={(value) => { if (value) setContentView(value as ContentView); }}
+                    aria-label={t('notesPreviewViewLabel', preferredLanguage)} className="w-fit">
+                    <ToggleGroupItem value="rendered" className="px-3"><Eye className='h-3.5 w-3.5 mr-1' /> {t('notesPreviewRendered', preferredLanguage)}</ToggleGroupItem>
+                    <ToggleGroupItem value="source" className="px-3"><Code className='h-3.5 w-3.5 mr-1' /> {t('notesPreviewSource', preferredLanguage)}</ToggleGroupItem>
+                </ToggleGroup>
                 {/* Make content scrollable */}
                 <ScrollArea className="max-h-[60vh] my-4">
-                    {/* Use pre-wrap to preserve whitespace and line breaks */}
-                    <pre className="text-sm whitespace-pre-wrap font-sans p-1">
-                       {previewingNote.content || <i className="text-muted-foreground">{t('notesNoContentPlaceholder', preferredLanguage)}</i>}
-                    </pre>
+                    {fetchError && <ErrorDisplay message={fetchError} className='mb-2' />}
+                    {isFetchingDetails ? (
+                        <div className="flex justify-center py-6"><LoadingSpinner /></div>
+                    ) : !previewingNote.content?.trim() ? (
+                        <i className="text-sm text-muted-foreground p-1">{t('notesNoContentPlaceholder', preferredLanguage)}</i>
+                    ) : contentView === 'rendered' ? (
+                        // Markdown is rendered to React elements only - HTML in shared notes is shown as text, never executed
+                        <MarkdownText text={previewingNote.content} className="text-sm p-1" />
+                    ) : (
+                        // Use pre-wrap to preserve whitespace and line breaks
+                        <pre className="text-sm whitespace-pre-wrap font-mono p-1">{previewingNote.content}</pre>
+                    )}
                 </ScrollArea>
                 <DialogFooter>
                      {/* Use translated button text */}
+                    {canEdit && (
+                        <Button variant="secondary" onClick={handleEditClick} disabled={isFetchingDetails}>
+                            <Edit className='h-4 w-4 mr-2'/> {t('editButton', preferredLanguage)}
+                        </Button>
+                    )}
                     <Button variant="outline" onClick={() => onOpenChange(false)}>{t('closeButton', preferredLanguage)}</Button>
-                    {/* Optional: Add Edit button here if needed */}
-                    {/* <Button onClick={() => { onOpenChange(false); onEdit(previewingNote); }}>Edit</Button> */}
                 </DialogFooter>
             </DialogContent>
         </Dialog>
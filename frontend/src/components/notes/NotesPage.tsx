@@ -1,27 +1,48 @@
-import React, { useState, useEffect, useCallback } from 'react';
+import React, { useState, useEffect, useCallback, useMemo, useRef } from 'react';
 import { Button } from '@/components/ui/button';
 import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogTrigger } from '@/components/ui/dialog'; // Removed unused imports
 import NoteList from './NoteList';
 import NoteEditor from './NoteEditor';
+import NoteExportMenu from './NoteExportMenu';
+import NoteBatchTagDialog from './NoteBatchTagDialog';
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import EmptyState from '@/components/shared/EmptyState';
+import LoadingStatus from '@/components/shared/LoadingStatus';
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
 import SearchBar, { type SearchFieldOption } from '@/components/shared/SearchBar';
-import { Pagination } from '@/components/shared/Pagination';
+import { Pagination, isPastLastPage } from '@/components/shared/Pagination';
 import { useAuth } from '@/hooks/useAuth';
+import { useConfirm } from '@/hooks/useConfirm';
+import { useSaveGuardedOpenChange } from '@/hooks/usePendingRequests';
+import { useCreateShortcut } from '@/hooks/useCreateShortcut';
+import { useBusyRows } from '@/hooks/useBusyRows';
+import { useUiPreferences } from '@/hooks/useUiPreferences';
+import { useUndoableDelete } from '@/hooks/useUndoableDelete';
 import api from '@/lib/api';
+import { cachedSearch, prefetchAdjacentPages } from '@/lib/pageCache';
+import { loadViewState, saveViewState } from '@/lib/viewState';
+import { noteQuery } from '@/lib/searchQuery';
+import { ToggleGroup, ToggleGroupItem } from '@/components/ui/toggle-group';
 import type { NoteInput, NoteWithDetails } from '../../../../backend/src/functionalities/note/models';
 import type { Tag } from '../../../../backend/src/functionalities/tag/models';
-import type { SearchRequest, SearchResponse, SearchQueryElement } from '../../../../backend/src/utils/search';
-import { PlusCircle } from 'lucide-react'; // Removed unused X icon
-import { toast } from "sonner";
+import type { SearchRequest, SearchResponse, SearchQueryElement, SortElement } from '../../../../backend/src/utils/search';
+import { PlusCircle, Tags, MinusCircle, X, SearchX, StickyNote } from 'lucide-react';
+import { toast } from '@/lib/toast';
 import { Card, CardContent, CardHeader, CardTitle, CardDescription } from '@/components/ui/card';
 import NotePreviewDialog from './NotePreviewDialog'; // Import the extracted component
 import { t } from '@/translations/utils'; // Import translation utility
 
 const NOTES_PAGE_SIZE = 10;
+const NOTES_DEFAULT_SORT: SortElement = { field: 'modifiedOn', direction: 'DESC' };
+
+type OwnershipFilter = 'all' | 'mine' | 'shared';
 
 const NotesPage: React.FC = () => {
   const { user, token, isLoading: isAuthLoading, preferredLanguage } = useAuth(); // Get preferredLanguage
+  const confirm = useConfirm();
+  const { busyRows, runBusy } = useBusyRows();
+  const { deleteMode } = useUiPreferences();
+  const { hiddenIds: deletingNoteIds, scheduleDelete } = useUndoableDelete();
   const isAdmin = user?.role === 'admin';
   const [notes, setNotes] = useState<NoteWithDetails[]>([]);
   const [availableTags, setAvailableTags] = useState<Tag[]>([]);
@@ -29,6 +50,7 @@ const NotesPage: React.FC = () => {
   const [error, setError] = useState<string | null>(null);
   const [editingNote, setEditingNote] = useState<NoteWithDetails | null>(null);
   const [isEditorOpen, setIsEditorOpen] = useState(false);
+  const handleEditorOpenChange = useSaveGuardedOpenChange(setIsEditorOpen); // Editor stays open until its save finishes
 
   // --- State for Preview ---
   const [previewingNote, setPreviewingNote] = useState<NoteWithDetails | null>(null);
@@ -37,10 +59,19 @@ const NotesPage: React.FC = () => {
 
   // Search & Pagination State
   const [searchQuery, setSearchQuery] = useState<SearchRequest['query']>([]);
-  const [currentPage, setCurrentPage] = useState(1);
+  const [searchGroups, setSearchGroups] = useState<NonNullable<SearchRequest['groups']>>([]); // "Match any" criteria from the search bar
+  const [ownershipFilter, setOwnershipFilter] = useState<OwnershipFilter>('all');
+  const [sort, setSort] = useState<SortElement>(NOTES_DEFAULT_SORT);
+  const [currentPage, setCurrentPage] = useState(() => loadViewState().notesPage ?? 1); // Restored from the last visit
   const [totalNotes, setTotalNotes] = useState(0);
   const [totalPages, setTotalPages] = useState(1);
 
+  // Multi-select for batch tagging - kept across pages and searches until cleared
+  const [selectedNotes, setSelectedNotes] = useState<Record<number, NoteWithDetails>>({});
+  const [batchTagAction, setBatchTagAction] = useState<'add' | 'remove' | null>(null);
+  const selectedNoteList = Object.values(selectedNotes);
+  const visibleNotes = useMemo(() => notes.filter(note => !deletingNoteIds.has(note.noteId!)), [notes, deletingNoteIds]);
+
   // Fetch available tags for the search bar options
   useEffect(() => {
     const fetchTags = async () => {
@@ -55,20 +86,30 @@ const NotesPage: React.FC = () => {
     if (token) { fetchTags(); }
   }, [token]);
 
+  // Search bar criteria plus the All / Mine / Shared filter; "Shared" means notes shared by other users
+  const notesQuery = useMemo(() => {
+    const query = noteQuery(searchQuery);
+    if (ownershipFilter === 'mine' && user?.userId) query.eq('ownerUserId', user.userId);
+    if (ownershipFilter === 'shared' && user?.userId) query.eq('shared', true).eq('ownerUserId', user.userId, true);
+    return query.build();
+  }, [searchQuery, ownershipFilter, user?.userId]);
+
   // Function to fetch/search notes
-  const fetchNotes = useCallback(async (page = currentPage, query = searchQuery) => {
+  const fetchNotes = useCallback(async (page = currentPage, query = notesQuery) => {
     if (!token || !user?.userId) {
         console.warn("NotesPage: fetchNotes called without user/token.");
         setIsLoading(false); setNotes([]); setTotalNotes(0); setTotalPages(1); return;
     }
-    const searchRequest: SearchRequest = { query: query, page, pageSize: NOTES_PAGE_SIZE };
+    const searchRequest: SearchRequest = { query: query, page, pageSize: NOTES_PAGE_SIZE, sort: [sort], ...(searchGroups.length > 0 && { groups: searchGroups }) };
     setIsLoading(true); setError(null);
     try {
-        const response = await api.searchNotes(searchRequest, token);
+        const response = await cachedSearch('notes', api.searchNotes, searchRequest, token);
+        if (isPastLastPage(response)) { setCurrentPage(response.totalPages); return; } // Refetches the last page
         setNotes(response.data);
         setTotalNotes(response.totalSize);
         setTotalPages(response.totalPages);
         setCurrentPage(response.page);
+        prefetchAdjacentPages('notes', api.searchNotes, { ...searchRequest, page: response.page }, response.totalPages, token);
     } catch (err: any) {
         const msg = err.message || t('notesFetchError', preferredLanguage); // Use translated error
         setError(msg);
@@ -76,18 +117,23 @@ const NotesPage: React.FC = () => {
         console.error("NotesPage: Fetch Notes Error:", err);
         setNotes([]); setTotalNotes(0); setTotalPages(1);
     } finally { setIsLoading(false); }
-  }, [token, user?.userId, currentPage, searchQuery, preferredLanguage]); // Add preferredLanguage
+  }, [token, user?.userId, currentPage, notesQuery, searchGroups, sort, preferredLanguage]); // Add preferredLanguage
+  // Undoable deletes finish after a delay - by then the page, search or sort may have changed
+  const fetchNotesRef = useRef(fetchNotes);
+  fetchNotesRef.current = fetchNotes;
 
   // Trigger fetchNotes whenever dependencies change, BUT ONLY IF AUTH IS READY
   useEffect(() => {
     if (!isAuthLoading && token && user?.userId) {
-        fetchNotes(currentPage, searchQuery);
+        fetchNotes(currentPage, notesQuery);
     } else {
          if (!isAuthLoading && (!token || !user?.userId)) {
             setNotes([]); setTotalNotes(0); setTotalPages(1); setCurrentPage(1);
         }
     }
-  }, [isAuthLoading, token, user?.userId, currentPage, searchQuery, fetchNotes]);
+  }, [isAuthLoading, token, user?.userId, currentPage, notesQuery, fetchNotes]);
+
+  useEffect(() => { saveViewState({ notesPage: currentPage }); }, [currentPage]);
 
   // --- CRUD Handlers ---
   const handleEdit = (note: NoteWithDetails) => {
@@ -99,6 +145,7 @@ const NotesPage: React.FC = () => {
     setEditingNote(null);
     setIsEditorOpen(true);
   };
+  useCreateShortcut(handleCreateNew);
 
    const handleDelete = async (noteId: number) => {
        if (!token || !noteId) { toast.error(t('invalidRequestError', preferredLanguage)); return; }; // Use translated error
@@ -108,23 +155,38 @@ const NotesPage: React.FC = () => {
        if (!isOwner && !isAdmin) {
            toast.error(t('notesPermissionErrorDelete', preferredLanguage)); return;
        }
-       if (!window.confirm(t('notesDeleteConfirm', preferredLanguage))) { // Use translated confirmation
+       const handleDeleteFailed = (err: any) => {
+            const msg = err.message || 'unknown error';
+            setError(t('notesDeleteFailed', preferredLanguage, { message: msg }));
+            toast.error(t('errorMessageTemplate', preferredLanguage, { message: t('notesDeleteFailed', preferredLanguage, { message: msg }) }));
+            console.error("NotesPage: Delete Note Error:", err);
+       };
+
+       if (deleteMode === 'undo') {
+           // Refetches whatever page is shown once the delete goes through; a now-empty last page falls back by itself
+           scheduleDelete(noteId, {
+               label: noteToDelete.title,
+               request: () => api.deleteNote(noteId, token),
+               onDeleted: () => fetchNotesRef.current(),
+               onFailed: handleDeleteFailed,
+           });
+           return;
+       }
+
+       if (!await confirm({ message: t('notesDeleteConfirm', preferredLanguage) })) { // Use translated confirmation
            return;
        }
 
-       setError(null); setIsLoading(true);
+       setError(null);
        try {
-           await api.deleteNote(noteId, token);
+           await runBusy(noteId, 'delete', () => api.deleteNote(noteId, token));
            toast.success(t('notesDeleteSuccess', preferredLanguage));
            const newTotalPages = Math.ceil((totalNotes - 1) / NOTES_PAGE_SIZE);
            const newCurrentPage = Math.max(1, (currentPage > newTotalPages) ? newTotalPages : currentPage);
            if (currentPage !== newCurrentPage) { setCurrentPage(newCurrentPage); }
-           else { await fetchNotes(newCurrentPage, searchQuery); }
+           else { await fetchNotes(newCurrentPage, notesQuery); }
        } catch (err: any) {
-            const msg = err.message || 'unknown error';
-            setError(t('notesDeleteFailed', preferredLanguage, { message: msg }));
-            toast.error(t('errorMessageTemplate', preferredLanguage, { message: t('notesDeleteFailed', preferredLanguage, { message: msg }) }));
-            console.error("NotesPage: Delete Note Error:", err); setIsLoading(false);
+           handleDeleteFailed(err);
        }
    };
 
@@ -133,7 +195,7 @@ const NotesPage: React.FC = () => {
     setEditingNote(null);
     const actionText = editingNote ? t('updated', preferredLanguage) : t('created', preferredLanguage); // TODO: Add updated/created keys
     toast.success(t('notesSaveSuccess', preferredLanguage, { action: actionText }));
-    await fetchNotes(currentPage, searchQuery);
+    await fetchNotes(currentPage, notesQuery);
   };
 
   // --- Preview Handler ---
@@ -141,16 +203,45 @@ const NotesPage: React.FC = () => {
     setPreviewingNote(note);
     setIsPreviewOpen(true);
   };
+
+  // Closing the preview also forgets the previewed note
+  const handlePreviewOpenChange = (isOpen: boolean) => {
+    setIsPreviewOpen(isOpen);
+    if (!isOpen) setPreviewingNote(null);
+  };
   // ---------------------
 
+  // --- Selection Handlers ---
+  const handleToggleSelect = (toggled: NoteWithDetails[], selected: boolean) => {
+      setSelectedNotes(prev => {
+          const next = { ...prev };
+          toggled.forEach(note => { if (selected) next[note.noteId!] = note; else delete next[note.noteId!]; });
+          return next;
+      });
+  };
+  const handleBatchTagDone = async () => {
+      setSelectedNotes({});
+      await fetchNotes(currentPage, notesQuery);
+  };
+
   // --- Search & Pagination Handlers ---
-  const handleSearch = (newQuery: SearchRequest['query']) => {
+  const handleSearch = (newQuery: SearchRequest['query'], newGroups: SearchRequest['groups'] = []) => {
       setSearchQuery(newQuery);
+      setSearchGroups(newGroups);
+      setCurrentPage(1);
+  };
+  const handleOwnershipFilterChange = (value: string) => {
+      if (!value) return; // Single-select toggle groups emit '' when the active item is clicked again
+      setOwnershipFilter(value as OwnershipFilter);
       setCurrentPage(1);
   };
   const handlePageChange = (newPage: number) => {
       setCurrentPage(newPage);
   };
+  const handleSortChange = (newSort: SortElement) => {
+      setSort(newSort);
+      setCurrentPage(1);
+  };
 
   // Define fields for the SearchBar using translations
   const searchFields: SearchFieldOption[] = [
@@ -180,9 +271,11 @@ const NotesPage: React.FC = () => {
                  <h1 className="text-2xl font-bold">{t('notesTitle', preferredLanguage)}</h1>
                  <p className='text-muted-foreground'>{t('notesDescription', preferredLanguage)}</p>
             </div>
-            <Dialog open={isEditorOpen} onOpenChange={setIsEditorOpen}>
+            <div className="flex gap-2 shrink-0">
+            <NoteExportMenu notes={notes} searchQuery={notesQuery} searchGroups={searchGroups} sort={sort} totalNotes={totalNotes} disabled={isLoading} />
+            <Dialog open={isEditorOpen} onOpenChange={handleEditorOpenChange}>
             <DialogTrigger asChild>
-                <Button onClick={handleCreateNew} className='shrink-0'>
+                <Button onClick={handleCreateNew} className='shrink-0' data-help="create">
                     <PlusCircle className="mr-2 h-4 w-4" /> {t('createButton', preferredLanguage)} {t('notesTitleSingular', preferredLanguage)} {/* TODO: Add notesTitleSingular */}
                 </Button>
             </DialogTrigger>
@@ -193,6 +286,7 @@ const NotesPage: React.FC = () => {
                 {isEditorOpen && <NoteEditor noteToEdit={editingNote} onSave={handleSaveSuccess} />}
             </DialogContent>
             </Dialog>
+            </div>
         </div>
 
        {/* Search Bar Section */}
@@ -200,32 +294,57 @@ const NotesPage: React.FC = () => {
            fields={searchFields}
            onSearch={handleSearch}
            isLoading={isLoading || isAuthLoading}
+           allowMatchAny
        />
+       <ToggleGroup type="single" variant="outline" size="sm" value={ownershipFilter} onValueChange={handleOwnershipFilterChange}
+           aria-label={t('notesFilterLabel', preferredLanguage)} className="w-fit">
+           <ToggleGroupItem value="all" className="px-3">{t('notesFilterAll', preferredLanguage)}</ToggleGroupItem>
+           <ToggleGroupItem value="mine" className="px-3">{t('notesFilterMine', preferredLanguage)}</ToggleGroupItem>
+           <ToggleGroupItem value="shared" className="px-3">{t('notesFilterShared', preferredLanguage)}</ToggleGroupItem>
+       </ToggleGroup>
 
         {/* Notes List Section */}
-        <Card>
+        <Card data-help="list">
             <CardHeader>
                  {error && !isLoading && <ErrorDisplay message={error} />}
+                 {selectedNoteList.length > 0 && (
+                     <div className="flex flex-wrap items-center gap-2 text-sm">
+                         <span className="text-muted-foreground">{t('notesSelectedCount', preferredLanguage, { count: selectedNoteList.length })}</span>
+                         <Button variant="outline" size="sm" onClick={() => setBatchTagAction('add')}>
+                             <Tags className="mr-2 h-4 w-4" /> {t('notesBatchTagAddButton', preferredLanguage)}
+                         </Button>
+                         <Button variant="outline" size="sm" onClick={() => setBatchTagAction('remove')}>
+                             <MinusCircle className="mr-2 h-4 w-4" /> {t('notesBatchTagRemoveButton', preferredLanguage)}
+                         </Button>
+                         <Button variant="ghost" size="sm" onClick={() => setSelectedNotes({})}>
+                             <X className="mr-2 h-4 w-4" /> {t('notesClearSelectionButton', preferredLanguage)}
+                         </Button>
+                     </div>
+                 )}
             </CardHeader>
             <CardContent>
+                <LoadingStatus isLoading={isLoading} label={t('notesTitle', preferredLanguage)} count={notes.length} />
                 {isLoading && <div className="flex justify-center py-10"><LoadingSpinner /></div>}
                 {!isLoading && (
                     <>
-                        <NoteList notes={notes} onEdit={handleEdit} onDelete={handleDelete} onPreview={handlePreview} />
-                        {totalPages > 1 && (
+                        <NoteList
+                            notes={visibleNotes} onEdit={handleEdit} onDelete={handleDelete} busyRows={busyRows} onPreview={handlePreview} sort={sort} onSortChange={handleSortChange}
+                            selectedNoteIds={selectedNoteList.map(note => note.noteId!)} onToggleSelect={handleToggleSelect}
+                            emptyMessage={error ? undefined : searchQuery.length > 0 || searchGroups.length > 0
+                                ? <EmptyState icon={SearchX} title={t('noResultsFound', preferredLanguage)} />
+                                : <EmptyState icon={StickyNote} title={t('notesNoNotesFound', preferredLanguage)} description={t('notesEmptyDescription', preferredLanguage)}
+                                    action={{ label: t('notesCreateFirstButton', preferredLanguage), onClick: handleCreateNew }} />}
+                        />
+                        {!error && (
                             <div className="mt-6 flex justify-center">
                                 <Pagination
                                     currentPage={currentPage}
                                     totalPages={totalPages}
                                     onPageChange={handlePageChange}
+                                    totalItems={totalNotes}
+                                    pageSize={NOTES_PAGE_SIZE}
                                 />
                             </div>
-                        )}
-                         {/* Use translated empty states */}
-                        {notes.length === 0 && !error && (
-                            searchQuery.length > 0
-                                ? <p className="text-center text-muted-foreground pt-6">{t('noResultsFound', preferredLanguage)}</p>
-                                : <p className="text-center text-muted-foreground pt-6">{t('notesNoNotesFound', preferredLanguage)} {t('notesClickCreateHint', preferredLanguage)}</p>
                         )}
                         {error && notes.length === 0 && (
                             <p className="text-center text-destructive pt-6">{t('notesLoadErrorPlaceholder', preferredLanguage)}</p> // Use translated error placeholder
@@ -238,10 +357,20 @@ const NotesPage: React.FC = () => {
          {/* --- Note Preview Dialog --- */}
          <NotePreviewDialog
              isOpen={isPreviewOpen}
-             onOpenChange={setIsPreviewOpen}
+             onOpenChange={handlePreviewOpenChange}
              note={previewingNote}
+             onEdit={handleEdit}
           />
          {/* --- End Preview Dialog --- */}
+
+         <NoteBatchTagDialog
+             isOpen={batchTagAction !== null}
+             onOpenChange={(open) => { if (!open) setBatchTagAction(null); }}
+             action={batchTagAction ?? 'add'}
+             notes={selectedNoteList}
+             availableTags={availableTags}
+             onDone={handleBatchTagDone}
+         />
     </div>
   );
 };
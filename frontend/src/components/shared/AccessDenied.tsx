@@ -0,0 +1,37 @@
+import React from 'react';
+import { useNavigate } from 'react-router-dom';
+import { ShieldAlert } from 'lucide-react';
+import { Button } from '@/components/ui/button';
+import { Card, CardContent, CardHeader, CardTitle } from '@/components/ui/card';
+import { useAuth } from '@/hooks/useAuth';
+import { t } from '@/translations/utils';
+
+interface AccessDeniedProps {
+  message?: string; // Defaults to the "administrator privileges required" message
+}
+
+// Shown instead of content the current user's role can't use, with a way back to a permitted view
+const AccessDenied: React.FC<AccessDeniedProps> = ({ message }) => {
+  const { preferredLanguage } = useAuth();
+  const navigate = useNavigate();
+
+  return (
+    <div className='p-4 md:p-6'>
+      <Card className='border-destructive'>
+        <CardHeader>
+          <CardTitle className='text-destructive flex items-center gap-2'>
+            <ShieldAlert className='h-5 w-5' /> {t('accessDeniedTitle', preferredLanguage)}
+          </CardTitle>
+        </CardHeader>
+        <CardContent className='space-y-4'>
+          <p>{message ?? t('accessDeniedMessage', preferredLanguage)}</p>
+          <Button variant="outline" onClick={() => navigate('/', { replace: true })}>
+            {t('accessDeniedBackButton', preferredLanguage)}
+          </Button>
+        </CardContent>
+      </Card>
+    </div>
+  );
+};
+
+export default AccessDenied;
@@ -0,0 +1,45 @@
+import React from 'react';
+import { Copy } from 'lucide-react';
+import { Button } from '@/components/ui/button';
+import { useAuth } from '@/hooks/useAuth';
+import { toast } from '@/lib/toast';
+import { cn } from '@/lib/utils';
+import { t } from '@/translations/utils';
+
+interface CopyButtonProps {
+    value: string;
+    label: string; // Name of the copied field, used in tooltip & confirmation toast
+    className?: string;
+}
+
+// Small icon button copying a single value to the clipboard
+const CopyButton: React.FC<CopyButtonProps> = ({ value, label, className }) => {
+    const { preferredLanguage } = useAuth();
+
+    const handleCopy = async (e: React.MouseEvent) => {
+        e.stopPropagation(); // Don't trigger row/parent click handlers
+        try {
+            await navigator.clipboard.writeText(value);
+            toast.success(t('copiedToClipboard', preferredLanguage, { label }));
+        } catch (err: any) {
+            console.error("Clipboard copy failed:", err);
+            toast.error(t('errorMessageTemplate', preferredLanguage, { message: t('copyToClipboardFailed', preferredLanguage) }));
+        }
+    };
+
+    return (
+        <Button
+            type="button"
+            variant="ghost"
+            size="icon"
+            className={cn("h-6 w-6 shrink-0 text-muted-foreground hover:text-foreground", className)}
+            onClick={handleCopy}
+            title={t('copyFieldTooltip', preferredLanguage, { label })}
+        >
+            <Copy className="h-3.5 w-3.5" />
+            <span className="sr-only">{t('copyFieldTooltip', preferredLanguage, { label })}</span>
+        </Button>
+    );
+};
+
+export default CopyButton;
@@ -0,0 +1,95 @@
+import React from 'react';
+import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from "@/components/ui/table";
+import { ArrowUp, ArrowDown, ArrowUpDown } from 'lucide-react';
+import type { SortElement } from '../../../../backend/src/utils/search';
+import { useAuth } from '@/hooks/useAuth';
+import { cn } from '@/lib/utils';
+import { t } from '@/translations/utils';
+
+export interface DataTableColumn<TRow> {
+    key: string;
+    header: React.ReactNode;
+    cell: (row: TRow) => React.ReactNode;
+    headClassName?: string;
+    cellClassName?: string;
+    cellProps?: (row: TRow) => React.TdHTMLAttributes<HTMLTableCellElement>; // Per-row cell attributes (handlers, title, extra classes)
+    // Sortable column: clicking the header sorts by this field (needs `sort` + `onSortChange` on the table)
+    sortField?: string;
+    initialSortDirection?: SortElement['direction']; // Direction when the column becomes the sort column, ASC by default
+}
+
+interface DataTableProps<TRow> {
+    rows: TRow[];
+    rowKey: (row: TRow) => React.Key;
+    columns: DataTableColumn<TRow>[];
+    actions?: (row: TRow) => React.ReactNode; // Right-aligned actions column when given
+    actionsClassName?: string;
+    rowProps?: (row: TRow) => React.HTMLAttributes<HTMLTableRowElement> & { 'data-state'?: string };
+    emptyMessage?: React.ReactNode; // Shown instead of the table when there are no rows (text or an <EmptyState>); nothing is rendered when omitted
+    sort?: SortElement;
+    onSortChange?: (sort: SortElement) => void;
+    className?: string;
+}
+
+// Shared table layout: column definitions + row renderers, optional sortable headers, actions column and empty state
+const DataTable = <TRow,>({
+    rows, rowKey, columns, actions, actionsClassName, rowProps, emptyMessage, sort, onSortChange, className,
+}: DataTableProps<TRow>) => {
+    const { preferredLanguage } = useAuth();
+
+    if (rows.length === 0) {
+        if (React.isValidElement(emptyMessage)) return emptyMessage;
+        return emptyMessage ? <p className="text-center text-muted-foreground pt-6">{emptyMessage}</p> : null;
+    }
+
+    // Clicking the active column flips the direction, another column starts with its initial direction
+    const renderHead = (column: DataTableColumn<TRow>) => {
+        if (!column.sortField || !sort || !onSortChange) {
+            return <TableHead key={column.key} className={column.headClassName}>{column.header}</TableHead>;
+        }
+        const field = column.sortField;
+        const isActive = sort.field === field;
+        const SortIcon = !isActive ? ArrowUpDown : sort.direction === 'ASC' ? ArrowUp : ArrowDown;
+        return (
+            <TableHead key={column.key} className={column.headClassName} aria-sort={isActive ? (sort.direction === 'ASC' ? 'ascending' : 'descending') : 'none'}>
+                <button
+                    type="button"
+                    className={cn('inline-flex items-center gap-1 hover:text-foreground', isActive && 'text-foreground')}
+                    onClick={() => onSortChange({ field, direction: isActive ? (sort.direction === 'ASC' ? 'DESC' : 'ASC') : column.initialSortDirection ?? 'ASC' })}
+                    title={t('tableSortByTooltip', preferredLanguage, { column: typeof column.header === 'string' ? column.header : field })}
+                >
+                    {column.header}
+                    <SortIcon className={cn('h-3 w-3', !isActive && 'opacity-40')} />
+                </button>
+            </TableHead>
+        );
+    };
+
+    return (
+        <Table className={className}>
+            <TableHeader>
+                <TableRow>
+                    {columns.map(renderHead)}
+                    {actions && <TableHead className={cn('text-right', actionsClassName)}>{t('actionsLabel', preferredLanguage)}</TableHead>}
+                </TableRow>
+            </TableHeader>
+            <TableBody>
+                {rows.map(row => (
+                    <TableRow key={rowKey(row)} {...rowProps?.(row)}>
+                        {columns.map(column => {
+                            const { className: extraClassName, ...cellProps } = column.cellProps?.(row) ?? {};
+                            return (
+                                <TableCell key={column.key} className={cn(column.cellClassName, extraClassName)} {...cellProps}>
+                                    {column.cell(row)}
+                                </TableCell>
+                            );
+                        })}
+                        {actions && <TableCell className="text-right space-x-1">{actions(row)}</TableCell>}
+                    </TableRow>
+                ))}
+            </TableBody>
+        </Table>
+    );
+};
+
+export default DataTable;
@@ -1,31 +1,36 @@
 import React, { useState, useEffect, useCallback, useMemo, useRef } from 'react'; // Added useRef
 import { Button } from '@/components/ui/button';
 import { Label } from '@/components/ui/label';
-import { X, Plus, Search as SearchIcon, ChevronsUpDown, ArrowRight, Network, Ban, PlusCircle } from 'lucide-react';
+import { X, Plus, Search as SearchIcon, ChevronsUpDown, ArrowRight, Network, Ban, PlusCircle, Globe } from 'lucide-react';
 import { Badge } from '@/components/ui/badge';
 import { Command, CommandEmpty, CommandGroup, CommandInput, CommandItem, CommandList } from "@/components/ui/command";
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
 import LoadingSpinner from './LoadingSpinner';
 import { useAuth } from '@/hooks/useAuth';
 import api from '@/lib/api';
+import { cacheSignatureElements, resolveSignaturePaths } from '@/lib/signaturePaths';
+import { elementQuery } from '@/lib/searchQuery';
 import type { SignatureComponent } from '../../../../backend/src/functionalities/signature/component/models';
 import type { SignatureElement, CreateSignatureElementInput } from '../../../../backend/src/functionalities/signature/element/models';
 import { cn } from '@/lib/utils';
 // Removed Popover imports
 import { ToggleGroup, ToggleGroupItem } from "@/components/ui/toggle-group"
-import type { SearchRequest, SearchQueryElement } from '../../../../backend/src/utils/search';
-import { toast } from "sonner";
+import type { SearchRequest } from '../../../../backend/src/utils/search';
+import { toast } from '@/lib/toast';
 import { Dialog, DialogContent, DialogHeader, DialogTitle } from '@/components/ui/dialog'; // Keep Dialog imports for internal create dialog
 import ElementForm from '@/components/signatures/ElementForm';
 import useDebounce from './useDebounce';
+import ElementSearchEverywhere from './ElementSearchEverywhere';
 import { t } from '@/translations/utils'; // Import translation utility
 
-type SelectionMode = "free" | "hierarchical";
+type SelectionMode = "free" | "hierarchical" | "everywhere";
 
 interface ElementBrowserDialogContentProps { // Renamed interface
     onSelectSignature: (signature: number[]) => void;
     onCloseDialog: () => void; // Renamed prop
     initialPath?: number[];
+    // false: a confirmed path stays in place and the browser stays open, so sibling paths can be added from it
+    clearOnConfirm?: boolean;
 }
 
 const MAX_SEARCH_RESULTS = 200;
@@ -45,6 +50,7 @@ const ElementBrowserDialogContent: React.FC<ElementBrowserDialogContentProps> =
     onSelectSignature,
     onCloseDialog, // Use renamed prop
     initialPath = [],
+    clearOnConfirm = true,
 }) => {
     const { token, preferredLanguage } = useAuth(); // Get preferredLanguage
     const [components, setComponents] = useState<SignatureComponent[]>([]);
@@ -59,6 +65,7 @@ const ElementBrowserDialogContent: React.FC<ElementBrowserDialogContentProps> =
     const [isCreateElementDialogOpen, setIsCreateElementDialogOpen] = useState(false);
     const [componentForCreate, setComponentForCreate] = useState<SignatureComponent | null>(null);
     const [refetchElementsTrigger, setRefetchElementsTrigger] = useState(0);
+    const [addedPathCount, setAddedPathCount] = useState(0); // Paths confirmed while the browser stayed open
 
     const debouncedSearchTerm = useDebounce(searchTerm, DEBOUNCE_DELAY);
     const stringifiedInitialPath = useMemo(() => JSON.stringify(initialPath), [initialPath]);
@@ -66,6 +73,7 @@ const ElementBrowserDialogContent: React.FC<ElementBrowserDialogContentProps> =
     // --- Ref for the search input ---
     const searchInputRef = useRef<HTMLInputElement>(null);
     // --------------------------------
+    const containerRef = useRef<HTMLDivElement>(null);
 
     useEffect(() => {
         const resolveInitialPath = async () => {
@@ -76,18 +84,14 @@ const ElementBrowserDialogContent: React.FC<ElementBrowserDialogContentProps> =
             }
             setIsLoadingElements(true);
             try {
-                const resolvedElements: SignatureElement[] = [];
-                for (const elementId of currentInitialPath) {
-                    const element = await api.getSignatureElementById(elementId, [], token);
-                    if (element) resolvedElements.push(element);
-                    else {
-                        toast.warn(t('signaturePathResolveError', preferredLanguage, { elementId }));
-                        setCurrentSignatureElements([]);
-                        setIsLoadingElements(false);
-                        return;
-                    }
+                const [resolvedElements] = await resolveSignaturePaths([currentInitialPath], token);
+                const missingIndex = resolvedElements.findIndex(el => el === null);
+                if (missingIndex !== -1) {
+                    toast.warn(t('signaturePathResolveError', preferredLanguage, { elementId: currentInitialPath[missingIndex] }));
+                    setCurrentSignatureElements([]);
+                    return;
                 }
-                setCurrentSignatureElements(resolvedElements);
+                setCurrentSignatureElements(resolvedElements as SignatureElement[]);
             } catch (err) {
                 toast.error(t('errorMessageTemplate', preferredLanguage, { message: t('signaturePathInitialResolveError', preferredLanguage) })); // Use translated error
                 setCurrentSignatureElements([]);
@@ -126,29 +130,29 @@ const ElementBrowserDialogContent: React.FC<ElementBrowserDialogContentProps> =
 
             const searchRequest: SearchRequest = { query: [], page: 1, pageSize: MAX_SEARCH_RESULTS };
             let shouldFetch = false;
-            const queryFilters: SearchQueryElement[] = [];
+            const queryFilters = elementQuery();
 
             if (hasSearchTerm) {
-                queryFilters.push({ field: 'name', condition: 'FRAGMENT', value: debouncedSearchTerm.trim(), not: false });
+                queryFilters.fragment('name', debouncedSearchTerm.trim());
                 if (isComponentSelected) {
-                    queryFilters.push({ field: 'signatureComponentId', condition: 'EQ', value: componentIdToFetch, not: false });
+                    queryFilters.eq('signatureComponentId', componentIdToFetch);
                 }
                 shouldFetch = true;
                 wasTriggeredBySearch = true; // Mark as search-triggered
             } else {
                 if (mode === 'hierarchical') {
                     if (lastElementId) {
-                        queryFilters.push({ field: 'parentIds', condition: 'ANY_OF', value: [lastElementId], not: false });
+                        queryFilters.anyOf('parentIds', [lastElementId]);
                         shouldFetch = true;
                     } else if (isComponentSelected) {
-                        queryFilters.push({ field: 'signatureComponentId', condition: 'EQ', value: componentIdToFetch, not: false });
+                        queryFilters.eq('signatureComponentId', componentIdToFetch);
                          // For hierarchical top-level, also filter for elements *without* parents
-                         queryFilters.push({ field: 'hasParents', condition: 'EQ', value: false, not: false });
+                         queryFilters.eq('hasParents', false);
                         shouldFetch = true;
                     }
                 } else { // Free mode
                     if (isComponentSelected) {
-                        queryFilters.push({ field: 'signatureComponentId', condition: 'EQ', value: componentIdToFetch, not: false });
+                        queryFilters.eq('signatureComponentId', componentIdToFetch);
                         shouldFetch = true;
                     }
                     // If free mode and NO component selected AND no search term, don't fetch anything
@@ -160,18 +164,22 @@ const ElementBrowserDialogContent: React.FC<ElementBrowserDialogContentProps> =
                 setElements([]); setIsLoadingElements(false); return;
             }
 
-            searchRequest.query = queryFilters;
+            searchRequest.query = queryFilters.build();
             setIsLoadingElements(true);
             try {
                 const response = await api.searchSignatureElements(searchRequest, token);
+                cacheSignatureElements(response.data);
                 setElements(response.data.sort(compareElements));
             } catch (err: any) {
                  const msg = err.message || t('elementLoadFailedError', preferredLanguage);
                 setError(msg); setElements([]);
             } finally {
                 setIsLoadingElements(false);
-                // --- Refocus input after search results load ---
-                 if (wasTriggeredBySearch && searchInputRef.current) {
+                // --- Refocus input after search results load, or after picking an element (the input was
+                // disabled while loading, which drops focus) so keyboard users can go on with the next level ---
+                 const activeElement = document.activeElement;
+                 const hadFocus = activeElement === document.body || !!containerRef.current?.contains(activeElement);
+                 if ((wasTriggeredBySearch || hadFocus) && searchInputRef.current) {
                      // Use requestAnimationFrame to ensure focus happens after render cycle
                      requestAnimationFrame(() => {
                          searchInputRef.current?.focus();
@@ -201,13 +209,43 @@ const ElementBrowserDialogContent: React.FC<ElementBrowserDialogContentProps> =
         });
     }, [mode]);
 
+    // Breadcrumb navigation: clicking an element of the path truncates the path back to it (its children are refetched)
+    const handleTruncatePath = useCallback((index: number) => {
+        setCurrentSignatureElements(prev => prev.slice(0, index + 1));
+        setSearchTerm('');
+    }, []);
+
     const handleConfirmSignature = useCallback(() => {
         if (currentSignatureElements.length > 0) {
             onSelectSignature(currentSignatureElements.map(el => el.signatureElementId!));
+            if (!clearOnConfirm) {
+                setAddedPathCount(prev => prev + 1);
+                toast.success(t('elementBrowserPathAdded', preferredLanguage, { path: currentSignatureElements.map(el => el.name).join(' / ') }));
+                return;
+            }
             setCurrentSignatureElements([]); setSelectedComponentId(''); setSearchTerm(''); setError(null);
             onCloseDialog(); // Use renamed prop
         }
-    }, [currentSignatureElements, onSelectSignature, onCloseDialog]); // Use renamed prop
+    }, [currentSignatureElements, onSelectSignature, onCloseDialog, clearOnConfirm, preferredLanguage]); // Use renamed prop
+
+    // Keyboard support on top of the list's own Up/Down/Enter: typing anywhere in the browser goes to the search box,
+    // Backspace in an empty search box steps back one level, Ctrl/Cmd+Enter adds the current path
+    const handleKeyDown = useCallback((e: React.KeyboardEvent<HTMLDivElement>) => {
+        if (isCreateElementDialogOpen) return;
+        const target = e.target as HTMLElement;
+        const isInSearch = target === searchInputRef.current;
+        if (e.key === 'Enter' && (e.ctrlKey || e.metaKey)) {
+            e.preventDefault();
+            handleConfirmSignature();
+        } else if (e.key === 'Backspace' && isInSearch && searchTerm === '' && currentSignatureElements.length > 0) {
+            e.preventDefault();
+            handleRemoveLastElement();
+        } else if (!isInSearch && e.key.length === 1 && !e.ctrlKey && !e.metaKey && !e.altKey
+            && !target.closest('input, textarea, select, [role="combobox"], [role="listbox"]')) {
+            // Focusing during keydown makes the typed character land in the search box
+            searchInputRef.current?.focus();
+        }
+    }, [isCreateElementDialogOpen, searchTerm, currentSignatureElements.length, handleConfirmSignature, handleRemoveLastElement]);
 
     const handleOpenCreateElementDialog = useCallback(() => {
         const component = components.find(c => String(c.signatureComponentId) === selectedComponentId);
@@ -250,6 +288,7 @@ const ElementBrowserDialogContent: React.FC<ElementBrowserDialogContentProps> =
     const selectedComponentName = useMemo(() => components.find(c => String(c.signatureComponentId) === selectedComponentId)?.name, [components, selectedComponentId]);
 
     const getNextStepPrompt = useCallback((): string => {
+        if (mode === 'everywhere') return t('elementSearchEverywhereHint', preferredLanguage);
         if (mode === 'hierarchical') {
             if (currentSignatureElements.length === 0) return t('elementBrowserSelectComponentFirst', preferredLanguage);
             return t('elementBrowserSelectChildOf', preferredLanguage, { name: currentSignatureElements[currentSignatureElements.length - 1].name });
@@ -266,29 +305,40 @@ const ElementBrowserDialogContent: React.FC<ElementBrowserDialogContentProps> =
 
     return (
         // This component now renders the *content* of the dialog
-        <div className="space-y-3 p-4 w-full flex flex-col h-full overflow-hidden"> {/* Added flex layout */}
+        <div ref={containerRef} onKeyDown={handleKeyDown} className="space-y-3 p-4 w-full flex flex-col h-full overflow-hidden"> {/* Added flex layout */}
             <div className="flex flex-col gap-3 shrink-0"> {/* Non-scrolling part */}
                 <div className='flex flex-col gap-1.5'>
                     <Label className='text-xs font-medium'>{t('elementBrowserSelectionModeLabel', preferredLanguage)}</Label>
                     <ToggleGroup type="single" value={mode} defaultValue="hierarchical" onValueChange={handleModeChange} aria-label={t('elementBrowserSelectionModeLabel', preferredLanguage)} size="sm">
                         <ToggleGroupItem value="hierarchical" aria-label={t('elementBrowserModeHierarchical', preferredLanguage)} className='flex-1 gap-1'><Network className='h-4 w-4'/><span className={cn(mode === 'hierarchical' && 'font-bold')}>{t('elementBrowserModeHierarchical', preferredLanguage)}</span></ToggleGroupItem>
                         <ToggleGroupItem value="free" aria-label={t('elementBrowserModeFree', preferredLanguage)} className='flex-1 gap-1'><ArrowRight className='h-4 w-4'/><span className={cn(mode === 'free' && 'font-bold')}>{t('elementBrowserModeFree', preferredLanguage)}</span></ToggleGroupItem>
+                        <ToggleGroupItem value="everywhere" aria-label={t('elementBrowserModeEverywhere', preferredLanguage)} className='flex-1 gap-1'><Globe className='h-4 w-4'/><span className={cn(mode === 'everywhere' && 'font-bold')}>{t('elementBrowserModeEverywhere', preferredLanguage)}</span></ToggleGroupItem>
                     </ToggleGroup>
-                    <p className='text-xs text-muted-foreground px-1'>{mode === 'hierarchical' ? t('elementBrowserModeHierarchicalHint', preferredLanguage) : t('elementBrowserModeFreeHint', preferredLanguage)}</p>
+                    <p className='text-xs text-muted-foreground px-1'>{mode === 'hierarchical' ? t('elementBrowserModeHierarchicalHint', preferredLanguage) : mode === 'everywhere' ? t('elementBrowserModeEverywhereHint', preferredLanguage) : t('elementBrowserModeFreeHint', preferredLanguage)}</p>
                 </div>
 
                 <div className="flex flex-wrap items-center gap-1 border rounded p-2 bg-muted min-h-[40px]">
                     <Label className='mr-2 shrink-0 text-xs font-semibold'>{t('elementBrowserPopoverCurrentPathLabel', preferredLanguage)}</Label>
-                    {currentSignatureElements.map((el, index) => (
-                        <React.Fragment key={el.signatureElementId}>
-                        {index > 0 && <span className="text-xs text-muted-foreground">/</span>}
-                        <Badge variant="secondary" className='font-mono text-xs'>{el.index ? `[${el.index}] ` : ''}{el.name}</Badge>
-                        </React.Fragment>
-                    ))}
+                    {currentSignatureElements.map((el, index) => {
+                        const isLast = index === currentSignatureElements.length - 1;
+                        const label = `${el.index ? `[${el.index}] ` : ''}${el.name}`;
+                        return (
+                            <React.Fragment key={el.signatureElementId}>
+                            {index > 0 && <span className="text-xs text-muted-foreground">/</span>}
+                            {isLast
+                                ? <Badge variant="secondary" className='font-mono text-xs'>{label}</Badge>
+                                : (
+                                    <button type="button" onClick={() => handleTruncatePath(index)} title={t('elementBrowserTruncatePathTooltip', preferredLanguage, { name: el.name })}>
+                                        <Badge variant="outline" className='font-mono text-xs cursor-pointer hover:bg-secondary'>{label}</Badge>
+                                    </button>
+                                )}
+                            </React.Fragment>
+                        );
+                    })}
                     {currentSignatureElements.length === 0 && <span className="text-xs text-muted-foreground italic">{t('elementBrowserBuildPathHint', preferredLanguage)}</span>}
                 </div>
 
-                {(currentSignatureElements.length === 0 || mode === 'free') && (
+                {mode !== 'everywhere' && (currentSignatureElements.length === 0 || mode === 'free') && (
                     <Select value={selectedComponentId} onValueChange={setSelectedComponentId} disabled={isLoadingComponents || (mode === 'hierarchical' && currentSignatureElements.length > 0)}>
                         <SelectTrigger className='w-full text-sm h-9'><SelectValue placeholder={t('elementBrowserPopoverSelectComponentPlaceholder', preferredLanguage)} /></SelectTrigger>
                         <SelectContent>
@@ -309,6 +359,7 @@ const ElementBrowserDialogContent: React.FC<ElementBrowserDialogContentProps> =
                      <Label className='text-xs mb-1 block shrink-0'>
                          {getNextStepPrompt()}
                      </Label>
+                     <p className='text-xs text-muted-foreground -mt-1 shrink-0'>{t('elementBrowserKeyboardHint', preferredLanguage)}</p>
                     <Command className='rounded-lg border shadow-sm flex-grow overflow-hidden flex flex-col' filter={() => 1}> {/* flex-grow + overflow */}
                          {/* Attach the ref to CommandInput */}
                         <CommandInput
@@ -346,13 +397,24 @@ const ElementBrowserDialogContent: React.FC<ElementBrowserDialogContentProps> =
                      </Command>
                  </div>
             )}
+             {/* --- Search everywhere: results of all components, grouped by component --- */}
+             {mode === 'everywhere' && (
+                <div className="flex-1 overflow-hidden flex flex-col gap-2 mt-3">
+                    <Label className='text-xs mb-1 block shrink-0'>{getNextStepPrompt()}</Label>
+                    <ElementSearchEverywhere
+                        onSelect={handleSelectElement}
+                        excludeElementIds={currentSignatureElements.map(el => el.signatureElementId!)}
+                        className='rounded-lg border shadow-sm flex-grow overflow-hidden flex flex-col'
+                    />
+                </div>
+             )}
              {/* --- End Scrollable Area --- */}
 
              {/* --- Dialog Footer --- */}
             <div className="flex justify-between items-center mt-auto pt-3 border-t shrink-0">
                 <div className='flex gap-2'>
                      <Button type="button" variant="outline" size="sm" onClick={handleRemoveLastElement} disabled={currentSignatureElements.length === 0}><X className="mr-1 h-3 w-3" /> {t('elementBrowserPopoverRemoveLastButton', preferredLanguage)}</Button>
-                     <Button type="button" variant="ghost" size="sm" onClick={onCloseDialog}><Ban className='mr-1 h-3 w-3'/> {t('cancelButton', preferredLanguage)}</Button>
+                     <Button type="button" variant="ghost" size="sm" onClick={onCloseDialog}><Ban className='mr-1 h-3 w-3'/> {t(addedPathCount > 0 ? 'closeButton' : 'cancelButton', preferredLanguage)}</Button>
                 </div>
                  <Button type="button" size="sm" onClick={handleConfirmSignature} disabled={currentSignatureElements.length === 0}>{t('elementBrowserPopoverAddPathButton', preferredLanguage)}</Button>
              </div>
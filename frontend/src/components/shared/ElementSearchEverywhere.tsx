@@ -0,0 +1,140 @@
+import React, { useState, useEffect, useMemo } from 'react';
+import { Check } from 'lucide-react';
+import { Command, CommandEmpty, CommandGroup, CommandInput, CommandItem, CommandList } from "@/components/ui/command";
+import LoadingSpinner from './LoadingSpinner';
+import useDebounce from './useDebounce';
+import { useAuth } from '@/hooks/useAuth';
+import api from '@/lib/api';
+import type { SignatureComponent } from '../../../../backend/src/functionalities/signature/component/models';
+import type { SignatureElement } from '../../../../backend/src/functionalities/signature/element/models';
+import type { SearchRequest } from '../../../../backend/src/utils/search';
+import { cn } from '@/lib/utils';
+import { t } from '@/translations/utils';
+
+const MAX_RESULTS = 100;
+const DEBOUNCE_DELAY = 300; // ms
+
+interface ElementSearchEverywhereProps {
+    onSelect: (element: SignatureElement) => void;
+    selectedElementIds?: number[]; // Marked with a check
+    excludeElementIds?: number[]; // Hidden from results (e.g. already in the path, element being edited)
+    excludeComponentId?: number; // Hide elements of this component
+    className?: string;
+}
+
+// Searches elements of all components at once (by name or index) and lists results grouped by component
+const ElementSearchEverywhere: React.FC<ElementSearchEverywhereProps> = ({
+    onSelect,
+    selectedElementIds = [],
+    excludeElementIds = [],
+    excludeComponentId,
+    className,
+}) => {
+    const { token, preferredLanguage } = useAuth();
+    const [components, setComponents] = useState<SignatureComponent[]>([]);
+    const [results, setResults] = useState<SignatureElement[]>([]);
+    const [searchTerm, setSearchTerm] = useState('');
+    const [isLoading, setIsLoading] = useState(false);
+    const [error, setError] = useState<string | null>(null);
+    const debouncedSearchTerm = useDebounce(searchTerm, DEBOUNCE_DELAY);
+
+    // Component names for group headings
+    useEffect(() => {
+        if (!token) return;
+        api.getAllSignatureComponents(token)
+            .then(setComponents)
+            .catch(err => console.error("Failed to load components for element search:", err));
+    }, [token]);
+
+    // Name and index matches are fetched in parallel and merged
+    useEffect(() => {
+        const term = debouncedSearchTerm.trim();
+        if (!token || !term) { setResults([]); setError(null); return; }
+        let isCancelled = false;
+        const search = async () => {
+            setIsLoading(true);
+            setError(null);
+            try {
+                const byField = (field: 'name' | 'index'): SearchRequest => ({
+                    query: [{ field, condition: 'FRAGMENT', value: term, not: false }],
+                    page: 1,
+                    pageSize: MAX_RESULTS,
+                });
+                const [byName, byIndex] = await Promise.all([
+                    api.searchSignatureElements(byField('name'), token),
+                    api.searchSignatureElements(byField('index'), token),
+                ]);
+                if (isCancelled) return;
+                const merged = new Map<number, SignatureElement>();
+                [...byIndex.data, ...byName.data].forEach(el => merged.set(el.signatureElementId!, el));
+                setResults(Array.from(merged.values()));
+            } catch (err: any) {
+                if (isCancelled) return;
+                setError(err.message || t('elementLoadFailedError', preferredLanguage));
+                setResults([]);
+            } finally {
+                if (!isCancelled) setIsLoading(false);
+            }
+        };
+        search();
+        return () => { isCancelled = true; };
+    }, [token, debouncedSearchTerm, preferredLanguage]);
+
+    const groupedResults = useMemo(() => {
+        const componentNames = new Map(components.map(c => [c.signatureComponentId!, c.name]));
+        const groups = new Map<number, SignatureElement[]>();
+        results
+            .filter(el => el.signatureComponentId !== excludeComponentId && !excludeElementIds.includes(el.signatureElementId!))
+            .forEach(el => {
+                const group = groups.get(el.signatureComponentId) ?? [];
+                group.push(el);
+                groups.set(el.signatureComponentId, group);
+            });
+        return Array.from(groups.entries())
+            .map(([componentId, elements]) => ({
+                componentId,
+                componentName: componentNames.get(componentId) ?? t('elementSearchEverywhereUnknownComponent', preferredLanguage, { id: componentId }),
+                elements: elements.sort((a, b) => (a.index ?? a.name).localeCompare(b.index ?? b.name)),
+            }))
+            .sort((a, b) => a.componentName.localeCompare(b.componentName));
+    }, [results, components, excludeComponentId, excludeElementIds, preferredLanguage]);
+
+    const hasTerm = debouncedSearchTerm.trim().length > 0;
+
+    return (
+        <Command shouldFilter={false} className={className}>
+            <CommandInput
+                placeholder={t('elementSearchEverywherePlaceholder', preferredLanguage)}
+                value={searchTerm}
+                onValueChange={setSearchTerm}
+            />
+            <CommandList className="max-h-[300px] overflow-y-auto">
+                {isLoading && <div className='p-4 text-center'><LoadingSpinner size='sm' /></div>}
+                {!isLoading && error && <p className="p-3 text-xs text-destructive">{error}</p>}
+                {!isLoading && !error && !hasTerm && <p className="p-3 text-xs text-muted-foreground">{t('elementSearchEverywhereHint', preferredLanguage)}</p>}
+                {!isLoading && !error && hasTerm && groupedResults.length === 0 && <CommandEmpty>{t('elementSearchEverywhereNoResults', preferredLanguage)}</CommandEmpty>}
+                {!isLoading && !error && groupedResults.map(group => (
+                    <CommandGroup key={group.componentId} heading={`${group.componentName} (${group.elements.length})`}>
+                        {group.elements.map(el => (
+                            <CommandItem
+                                key={el.signatureElementId}
+                                value={String(el.signatureElementId)}
+                                onSelect={() => onSelect(el)}
+                                className='cursor-pointer text-sm'
+                            >
+                                <Check className={cn("mr-2 h-4 w-4", selectedElementIds.includes(el.signatureElementId!) ? "opacity-100" : "opacity-0")} />
+                                <span className='font-mono text-xs w-10 mr-2 text-right inline-block text-muted-foreground'>{el.index || '-'}</span>
+                                <span>{el.name}</span>
+                            </CommandItem>
+                        ))}
+                    </CommandGroup>
+                ))}
+                {!isLoading && results.length >= MAX_RESULTS && (
+                    <div className='text-xs text-muted-foreground text-center p-1 italic'>{t('elementSearchEverywhereTooManyResults', preferredLanguage, { count: MAX_RESULTS })}</div>
+                )}
+            </CommandList>
+        </Command>
+    );
+};
+
+export default ElementSearchEverywhere;
@@ -6,6 +6,7 @@ import { Popover, PopoverContent, PopoverTrigger } from "@/components/ui/popover
 import { Badge } from "@/components/ui/badge";
 import { useAuth } from '@/hooks/useAuth';
 import api from '@/lib/api';
+import { resolveSignatureElements } from '@/lib/signaturePaths';
 import type { SignatureComponent } from '../../../../backend/src/functionalities/signature/component/models';
 import type { SignatureElement } from '../../../../backend/src/functionalities/signature/element/models';
 import { cn } from '@/lib/utils';
@@ -100,13 +101,9 @@ const ElementSelector: React.FC<ElementSelectorProps> = ({
             }
             setIsLoadingSelectedDetails(true);
             try {
-                 // Fetch details for each selected ID
-                 const detailsPromises = selectedElementIds.map(id =>
-                     api.getSignatureElementById(id, [], token).catch(() => null) // Return null on error for one ID
-                 );
-                 const results = await Promise.all(detailsPromises);
-                 setSelectedElementObjects(results.filter((el): el is SignatureElement => el !== null)
-                                            .sort((a,b) => a.name.localeCompare(b.name))); // Filter out nulls and sort
+                 // Fetch details for all selected IDs in one batch (missing ones are skipped)
+                 const results = await resolveSignatureElements(selectedElementIds, token);
+                 setSelectedElementObjects(results.sort((a,b) => a.name.localeCompare(b.name)));
             } catch (err) {
                  console.error("Failed to fetch selected element details:", err);
                  setError(t('selectedElementLoadFailedError', preferredLanguage));
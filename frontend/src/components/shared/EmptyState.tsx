@@ -0,0 +1,32 @@
+import React from 'react';
+import type { LucideIcon } from 'lucide-react';
+import { PlusCircle } from 'lucide-react';
+import { Button } from '@/components/ui/button';
+import { cn } from '@/lib/utils';
+
+interface EmptyStateProps {
+    icon: LucideIcon;
+    title: string;
+    description?: string;
+    // Primary action, e.g. "Create your first note"; omit when the user can't act (no permission, filtered results)
+    action?: { label: string; onClick: () => void };
+    className?: string;
+}
+
+// Shown in place of a list that has no rows, so every section explains itself the same way
+const EmptyState: React.FC<EmptyStateProps> = ({ icon: Icon, title, description, action, className }) => (
+    <div className={cn('flex flex-col items-center justify-center gap-2 py-10 text-center', className)}>
+        <div className="rounded-full bg-muted p-3 mb-1">
+            <Icon className="h-6 w-6 text-muted-foreground" />
+        </div>
+        <p className="font-medium">{title}</p>
+        {description && <p className="max-w-sm text-sm text-muted-foreground">{description}</p>}
+        {action && (
+            <Button type="button" size="sm" className="mt-2" onClick={action.onClick}>
+                <PlusCircle className="mr-2 h-4 w-4" />{action.label}
+            </Button>
+        )}
+    </div>
+);
+
+export default EmptyState;
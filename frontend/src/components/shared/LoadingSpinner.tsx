@@ -7,6 +7,7 @@ interface LoadingSpinnerProps {
   className?: string;
 }
 
+// Decorative only - pair with LoadingStatus where screen readers should be told about loading
 const LoadingSpinner: React.FC<LoadingSpinnerProps> = ({ size = 'md', className }) => {
   const sizeClasses = {
     sm: 'h-4 w-4',
@@ -15,7 +16,7 @@ const LoadingSpinner: React.FC<LoadingSpinnerProps> = ({ size = 'md', className
   };
 
   return (
-    <Loader2 className={cn('animate-spin text-primary', sizeClasses[size], className)} />
+    <Loader2 aria-hidden="true" className={cn('animate-spin text-primary', sizeClasses[size], className)} />
   );
 };
 
@@ -0,0 +1,34 @@
+import React, { useEffect, useRef, useState } from 'react';
+import { useAuth } from '@/hooks/useAuth';
+import { t } from '@/translations/utils';
+
+interface LoadingStatusProps {
+    isLoading: boolean;
+    /** What is being loaded, e.g. the translated page title ("Notes") */
+    label: string;
+    /** Number of loaded items, announced once loading completes */
+    count?: number;
+}
+
+// Visually hidden live region announcing loading state to screen readers (spinners are purely visual)
+const LoadingStatus: React.FC<LoadingStatusProps> = ({ isLoading, label, count }) => {
+    const { preferredLanguage } = useAuth();
+    const [message, setMessage] = useState('');
+    const wasLoading = useRef(false);
+
+    useEffect(() => {
+        if (isLoading) {
+            setMessage(t('loadingStatusLoading', preferredLanguage, { label }));
+        } else if (wasLoading.current) {
+            // Only announce completion after an actual loading cycle, not on mount
+            setMessage(count === undefined
+                ? t('loadingStatusDone', preferredLanguage, { label })
+                : t('loadingStatusLoaded', preferredLanguage, { label, count }));
+        }
+        wasLoading.current = isLoading;
+    }, [isLoading, label, count, preferredLanguage]);
+
+    return <div role="status" aria-live="polite" aria-atomic="true" className="sr-only">{message}</div>;
+};
+
+export default LoadingStatus;
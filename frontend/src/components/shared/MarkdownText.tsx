@@ -0,0 +1,132 @@
+import React from 'react';
+import { cn } from '@/lib/utils';
+
+// Minimal, safe markdown renderer for descriptive fields (no HTML passthrough, builds React elements).
+// Supported: paragraphs, line breaks, "#" headings, "-"/"*" and "1." lists, "```" code blocks, ">" quotes,
+// **bold**, *emphasis* / _emphasis_, `code`, [links](https://...) and bare http(s) URLs.
+
+const SAFE_URL = /^(https?:\/\/|mailto:)/i;
+// Order matters: code first (no formatting inside), then links, bold, emphasis, bare URLs
+const INLINE_PATTERN = /(`[^`]+`)|(\[([^\]]+)\]\(([^)\s]+)\))|(\*\*([^*]+)\*\*)|(\*([^*]+)\*|(?<!\w)_([^_]+)_(?!\w))|(https?:\/\/[^\s<]+[^\s<.,;:!?)])/g;
+
+// Links may sit inside clickable rows - don't trigger the row action
+const stopPropagation = (e: React.MouseEvent) => e.stopPropagation();
+
+const renderInline = (text: string, keyPrefix: string): React.ReactNode[] => {
+    const nodes: React.ReactNode[] = [];
+    let lastIndex = 0;
+    // Fresh regex per call - renderInline recurses, a shared global regex would lose its position
+    const pattern = new RegExp(INLINE_PATTERN.source, 'g');
+    let match: RegExpExecArray | null;
+    while ((match = pattern.exec(text)) !== null) {
+        if (match.index > lastIndex) nodes.push(text.slice(lastIndex, match.index));
+        const key = `${keyPrefix}-${match.index}`;
+        const [full, code, link, linkText, linkUrl, bold, boldText, em, emStar, emUnderscore, bareUrl] = match;
+        if (code) {
+            nodes.push(<code key={key} className="rounded bg-muted px-1 font-mono text-[0.9em]">{code.slice(1, -1)}</code>);
+        } else if (link) {
+            nodes.push(SAFE_URL.test(linkUrl)
+                ? <a key={key} href={linkUrl} target="_blank" rel="noopener noreferrer" onClick={stopPropagation} className="text-primary hover:underline">{renderInline(linkText, key)}</a>
+                : full);
+        } else if (bold) {
+            nodes.push(<strong key={key}>{renderInline(boldText, key)}</strong>);
+        } else if (em) {
+            nodes.push(<em key={key}>{renderInline(emStar ?? emUnderscore, key)}</em>);
+        } else if (bareUrl) {
+            nodes.push(<a key={key} href={bareUrl} target="_blank" rel="noopener noreferrer" onClick={stopPropagation} className="text-primary hover:underline break-all">{bareUrl}</a>);
+        }
+        lastIndex = match.index + full.length;
+    }
+    if (lastIndex < text.length) nodes.push(text.slice(lastIndex));
+    return nodes;
+};
+
+// Lines of a paragraph are joined with <br/> (descriptions are typed with single line breaks)
+const renderLines = (lines: string[], keyPrefix: string): React.ReactNode[] =>
+    lines.flatMap((line, i) => [
+        ...(i > 0 ? [<br key={`${keyPrefix}-br-${i}`} />] : []),
+        ...renderInline(line, `${keyPrefix}-${i}`),
+    ]);
+
+const UNORDERED_ITEM = /^\s*[-*]\s+(.*)$/;
+const ORDERED_ITEM = /^\s*\d+[.)]\s+(.*)$/;
+const HEADING = /^(#{1,3})\s+(.*)$/;
+const CODE_FENCE = /^\s*```/;
+const QUOTE = /^\s*>\s?(.*)$/;
+const startsBlock = (line: string) =>
+    HEADING.test(line) || UNORDERED_ITEM.test(line) || ORDERED_ITEM.test(line) || CODE_FENCE.test(line) || QUOTE.test(line);
+
+const renderBlocks = (text: string): React.ReactNode[] => {
+    const blocks: React.ReactNode[] = [];
+    const lines = text.replace(/\r\n?/g, '\n').split('\n');
+    let i = 0;
+    while (i < lines.length) {
+        const line = lines[i];
+        const key = `b-${i}`;
+        if (!line.trim()) { i++; continue; }
+
+        const heading = HEADING.exec(line);
+        if (heading) {
+            const HeadingTag = (['h4', 'h5', 'h6'] as const)[heading[1].length - 1];
+            blocks.push(<HeadingTag key={key} className="font-semibold">{renderInline(heading[2], key)}</HeadingTag>);
+            i++;
+            continue;
+        }
+
+        if (CODE_FENCE.test(line)) {
+            // Verbatim up to the closing fence (or the end of the text if it's never closed)
+            const code: string[] = [];
+            i++;
+            while (i < lines.length && !CODE_FENCE.test(lines[i])) code.push(lines[i++]);
+            i++;
+            blocks.push(<pre key={key} className="rounded bg-muted p-2 font-mono text-[0.9em] whitespace-pre-wrap">{code.join('\n')}</pre>);
+            continue;
+        }
+
+        if (QUOTE.test(line)) {
+            const quoted: string[] = [];
+            while (i < lines.length && QUOTE.test(lines[i])) quoted.push(QUOTE.exec(lines[i++])![1]);
+            blocks.push(<blockquote key={key} className="border-l-2 pl-3 text-muted-foreground">{renderLines(quoted, key)}</blockquote>);
+            continue;
+        }
+
+        const listPattern = UNORDERED_ITEM.test(line) ? UNORDERED_ITEM : ORDERED_ITEM.test(line) ? ORDERED_ITEM : null;
+        if (listPattern) {
+            const items: React.ReactNode[] = [];
+            while (i < lines.length && listPattern.test(lines[i])) {
+                items.push(<li key={`${key}-${i}`}>{renderInline(listPattern.exec(lines[i])![1], `${key}-${i}`)}</li>);
+                i++;
+            }
+            blocks.push(listPattern === UNORDERED_ITEM
+                ? <ul key={key} className="list-disc pl-5">{items}</ul>
+                : <ol key={key} className="list-decimal pl-5">{items}</ol>);
+            continue;
+        }
+
+        const paragraph: string[] = [];
+        while (i < lines.length && lines[i].trim() && !startsBlock(lines[i])) {
+            paragraph.push(lines[i]);
+            i++;
+        }
+        blocks.push(<p key={key}>{renderLines(paragraph, key)}</p>);
+    }
+    return blocks;
+};
+
+interface MarkdownTextProps {
+    text: string;
+    /** Single-line rendering (first line, inline formatting only) for table cells */
+    inline?: boolean;
+    className?: string;
+}
+
+const MarkdownText: React.FC<MarkdownTextProps> = ({ text, inline = false, className }) => {
+    if (inline) {
+        const firstLine = text.split(/\r?\n/).find(line => line.trim()) ?? '';
+        const content = firstLine.replace(HEADING, '$2').replace(UNORDERED_ITEM, '$1').replace(ORDERED_ITEM, '$1');
+        return <span className={className}>{renderInline(content, 'i')}</span>;
+    }
+    return <div className={cn('space-y-2 break-words', className)}>{renderBlocks(text)}</div>;
+};
+
+export default MarkdownText;
@@ -0,0 +1,42 @@
+import React, { useState } from 'react';
+import { Eye, Pencil } from 'lucide-react';
+import { Button } from '@/components/ui/button';
+import { Textarea } from '@/components/ui/textarea';
+import MarkdownText from './MarkdownText';
+import { useAuth } from '@/hooks/useAuth';
+import { cn } from '@/lib/utils';
+import { t } from '@/translations/utils';
+
+interface MarkdownTextareaProps extends React.ComponentProps<"textarea"> {
+    previewValue: string | null | undefined; // Current value (e.g. from react-hook-form `watch`)
+}
+
+// Plain-text textarea for markdown content with a read-only preview toggle.
+// The textarea stays mounted while previewing, so form registration keeps working.
+const MarkdownTextarea: React.FC<MarkdownTextareaProps> = ({ previewValue, className, ...textareaProps }) => {
+    const { preferredLanguage } = useAuth();
+    const [isPreview, setIsPreview] = useState(false);
+
+    return (
+        <div className="space-y-1">
+            <div className="flex justify-end">
+                <Button type="button" variant="ghost" size="sm" className="h-6 px-2 text-xs" onClick={() => setIsPreview(prev => !prev)}>
+                    {isPreview
+                        ? <><Pencil className="mr-1 h-3 w-3" />{t('markdownEditButton', preferredLanguage)}</>
+                        : <><Eye className="mr-1 h-3 w-3" />{t('markdownPreviewButton', preferredLanguage)}</>}
+                </Button>
+            </div>
+            <Textarea {...textareaProps} className={cn(className, isPreview && 'hidden')} />
+            {isPreview && (
+                <div className="min-h-16 rounded-md border px-3 py-2 text-sm bg-muted/30">
+                    {previewValue?.trim()
+                        ? <MarkdownText text={previewValue} />
+                        : <i className="text-muted-foreground">{t('markdownPreviewEmpty', preferredLanguage)}</i>}
+                </div>
+            )}
+            {!isPreview && <p className="text-xs text-muted-foreground">{t('markdownSupportedHint', preferredLanguage)}</p>}
+        </div>
+    );
+};
+
+export default MarkdownTextarea;
@@ -8,42 +8,74 @@ import {
   PaginationNext,
   PaginationPrevious,
 } from "@/components/ui/pagination"; // Use Shadcn Pagination
+import { ChevronsLeft, ChevronsRight } from 'lucide-react';
+import { useAuth } from '@/hooks/useAuth';
+import { usePaginationShortcuts } from '@/hooks/usePaginationShortcuts';
+import { cn } from '@/lib/utils';
+import { t } from '@/translations/utils';
 
 interface PaginationProps {
   currentPage: number;
   totalPages: number;
   onPageChange: (page: number) => void;
   maxVisiblePages?: number; // Max page numbers to show directly
+  // With both given, a "Showing 11–20 of 143" summary is shown (also for a single page or no results)
+  totalItems?: number;
+  pageSize?: number;
+  keyboardShortcuts?: boolean; // PageUp/PageDown/Home/End paging; turn off when several lists share a screen
 }
 
+/**
+ * True when a search response points past its last page (e.g. rows were deleted meanwhile).
+ * Views should then move to `totalPages` instead of showing an empty page. Zero results (0 pages) is not "past".
+ */
+export const isPastLastPage = (response: { page: number; totalPages: number }) =>
+  response.totalPages > 0 && response.page > response.totalPages;
+
 export const Pagination: React.FC<PaginationProps> = ({
-  currentPage,
+  currentPage: requestedPage,
   totalPages,
   onPageChange,
-  maxVisiblePages = 5 // Sensible default (e.g., 1 ... 4 5 6 ... 10)
+  maxVisiblePages = 5, // Sensible default (e.g., 1 ... 4 5 6 ... 10)
+  totalItems,
+  pageSize,
+  keyboardShortcuts = true,
 }) => {
+  const { preferredLanguage } = useAuth();
+  usePaginationShortcuts(Math.min(Math.max(1, requestedPage), totalPages), totalPages, onPageChange, keyboardShortcuts);
+  const hasSummary = totalItems !== undefined && pageSize !== undefined && pageSize > 0;
+
   if (totalPages <= 1) {
-    return null; // Don't render pagination if there's only one page or less
+    // Page controls are pointless for a single page (or zero results) - only the summary remains
+    return hasSummary ? <PaginationSummary currentPage={1} totalItems={totalItems!} pageSize={pageSize!} /> : null;
   }
 
-  const handlePrevious = () => {
-    if (currentPage > 1) {
-      onPageChange(currentPage - 1);
+  // Views may briefly hold a page outside the bounds (e.g. after an error reset) - never page past them
+  const currentPage = Math.min(Math.max(1, requestedPage), totalPages);
+  const isFirstPage = currentPage <= 1;
+  const isLastPage = currentPage >= totalPages;
+
+  const goToPage = (page: number) => {
+    const target = Math.min(Math.max(1, page), totalPages);
+    if (target !== requestedPage) {
+      onPageChange(target);
     }
   };
 
+  const handlePrevious = () => {
+    if (!isFirstPage) goToPage(currentPage - 1);
+  };
+
   const handleNext = () => {
-    if (currentPage < totalPages) {
-      onPageChange(currentPage + 1);
-    }
+    if (!isLastPage) goToPage(currentPage + 1);
   };
 
   const handlePageClick = (page: number) => {
-    if (page !== currentPage) {
-      onPageChange(page);
-    }
+    if (page !== currentPage) goToPage(page);
   };
 
+  const disabledClass = "pointer-events-none opacity-50";
+
   // Logic to determine which page numbers to display
   const getPageNumbers = (): (number | 'ellipsis')[] => {
     const pages: (number | 'ellipsis')[] = [];
@@ -96,15 +128,30 @@ export const Pagination: React.FC<PaginationProps> = ({
 
   const pageNumbers = getPageNumbers();
 
-  return (
-    <ShadPagination>
+  const controls = (
+    <ShadPagination data-help="pagination">
       <PaginationContent>
+        <PaginationItem>
+          <PaginationLink
+            href="#" // Prevent navigation, use onClick
+            onClick={(e) => { e.preventDefault(); if (!isFirstPage) goToPage(1); }}
+            aria-disabled={isFirstPage}
+            aria-label={t('firstPage', preferredLanguage)}
+            aria-keyshortcuts={keyboardShortcuts ? 'Home' : undefined}
+            title={t('firstPage', preferredLanguage)}
+            className={cn(isFirstPage && disabledClass)}
+            size="icon"
+          >
+            <ChevronsLeft className="h-4 w-4" />
+          </PaginationLink>
+        </PaginationItem>
         <PaginationItem>
           <PaginationPrevious
             href="#" // Prevent navigation, use onClick
             onClick={(e) => { e.preventDefault(); handlePrevious(); }}
-            aria-disabled={currentPage === 1}
-            className={currentPage === 1 ? "pointer-events-none opacity-50" : ""}
+            aria-disabled={isFirstPage}
+            aria-keyshortcuts={keyboardShortcuts ? 'PageUp' : undefined}
+            className={cn(isFirstPage && disabledClass)}
             size="default" // Add default size
           />
         </PaginationItem>
@@ -130,12 +177,46 @@ export const Pagination: React.FC<PaginationProps> = ({
           <PaginationNext
             href="#" // Prevent navigation, use onClick
             onClick={(e) => { e.preventDefault(); handleNext(); }}
-            aria-disabled={currentPage === totalPages}
-            className={currentPage === totalPages ? "pointer-events-none opacity-50" : ""}
+            aria-disabled={isLastPage}
+            aria-keyshortcuts={keyboardShortcuts ? 'PageDown' : undefined}
+            className={cn(isLastPage && disabledClass)}
             size="default" // Add default size
           />
         </PaginationItem>
+        <PaginationItem>
+          <PaginationLink
+            href="#" // Prevent navigation, use onClick
+            onClick={(e) => { e.preventDefault(); if (!isLastPage) goToPage(totalPages); }}
+            aria-disabled={isLastPage}
+            aria-label={t('lastPage', preferredLanguage)}
+            aria-keyshortcuts={keyboardShortcuts ? 'End' : undefined}
+            title={t('lastPage', preferredLanguage)}
+            className={cn(isLastPage && disabledClass)}
+            size="icon"
+          >
+            <ChevronsRight className="h-4 w-4" />
+          </PaginationLink>
+        </PaginationItem>
       </PaginationContent>
     </ShadPagination>
   );
+
+  if (!hasSummary) return controls;
+  return (
+    <div className="flex flex-col items-center gap-2">
+      {controls}
+      <PaginationSummary currentPage={currentPage} totalItems={totalItems!} pageSize={pageSize!} />
+    </div>
+  );
+};
+
+const PaginationSummary: React.FC<{ currentPage: number; totalItems: number; pageSize: number }> = ({ currentPage, totalItems, pageSize }) => {
+  const { preferredLanguage } = useAuth();
+  const from = totalItems === 0 ? 0 : Math.min((currentPage - 1) * pageSize + 1, totalItems);
+  const to = Math.min(currentPage * pageSize, totalItems);
+  return (
+    <p className="text-sm text-muted-foreground text-center" role="status">
+      {t('paginationSummary', preferredLanguage, { from: from.toLocaleString(), to: to.toLocaleString(), total: totalItems.toLocaleString() })}
+    </p>
+  );
 };
\ No newline at end of file
@@ -6,7 +6,8 @@ import { Label } from '@/components/ui/label';
 import { Checkbox } from '@/components/ui/checkbox';
 import { Trash2, PlusCircle, Search, RefreshCcw } from 'lucide-react';
 import { cn } from '@/lib/utils';
-import type { SearchRequest, SearchQuery, SearchQueryElement } from '../../../../backend/src/utils/search';
+import { withMatchLogic } from '@/lib/searchQuery';
+import type { SearchRequest, SearchQuery, SearchQueryElement, SearchQueryGroup, SearchLogic } from '../../../../backend/src/utils/search';
 import LoadingSpinner from './LoadingSpinner';
 import TagSelector from './TagSelector';
 import SingleSignaturePathPicker from './SingleSignaturePathPicker'; // Still using the picker component
@@ -26,9 +27,11 @@ export interface SearchFieldOption {
 
 interface SearchBarProps {
   fields: SearchFieldOption[];
-  onSearch: (query: SearchQuery) => void;
+  // With "match any" the criteria arrive as a single OR group instead of the (ANDed) query
+  onSearch: (query: SearchQuery, groups?: SearchQueryGroup[]) => void;
   isLoading?: boolean;
   showResetButton?: boolean;
+  allowMatchAny?: boolean; // Shows the advanced "match all / any" choice (the list must pass `groups` on to the search)
 }
 
 // Translate condition labels
@@ -43,6 +46,8 @@ const getConditionLabel = (condition: SearchQueryElement['condition'], lang: str
         'ANY_OF': 'conditionIsAnyOf',
         'STARTS_WITH': 'conditionStartsWithPath',
         'CONTAINS_SEQUENCE': 'conditionContainsSequence',
+        'IS_NULL': 'conditionIsEmpty',
+        'IS_NOT_NULL': 'conditionIsNotEmpty',
     };
     const key = keyMap[condition];
     return key ? t(key, lang) : condition;
@@ -87,11 +92,13 @@ const SearchBar: React.FC<SearchBarProps> = ({
     fields,
     onSearch,
     isLoading = false,
-    showResetButton = true
+    showResetButton = true,
+    allowMatchAny = false
 }) => {
     const { preferredLanguage } = useAuth(); // Get preferredLanguage
     const getInitialCriterion = useInitialCriterion(fields);
     const [criteria, setCriteria] = useState<SearchCriterionState[]>(() => fields.length > 0 ? [getInitialCriterion()] : []);
+    const [matchLogic, setMatchLogic] = useState<SearchLogic>('AND');
 
     useEffect(() => {
         if (fields.length === 0 && criteria.length > 0) {
@@ -212,27 +219,31 @@ const SearchBar: React.FC<SearchBarProps> = ({
         }).filter((c): c is SearchQueryElement => c !== null);
     }, [criteria, fields]);
 
-    const handleSearchClick = () => { const finalQuery = buildQuery(); onSearch(finalQuery); };
-    const handleResetClick = () => { setCriteria(fields.length > 0 ? [getInitialCriterion()] : []); onSearch([]); };
+    const handleSearchClick = () => {
+        const finalQuery = buildQuery();
+        const { query, groups } = withMatchLogic(finalQuery, allowMatchAny ? matchLogic : 'AND');
+        onSearch(query, groups);
+    };
+    const handleResetClick = () => { setCriteria(fields.length > 0 ? [getInitialCriterion()] : []); setMatchLogic('AND'); onSearch([]); };
     const getFieldType = (fieldName: string | undefined): FieldType => fields.find(f => f.value === fieldName)?.type || 'text';
     const getFieldOptions = (fieldName: string | undefined): SearchFieldOption['options'] => fields.find(f => f.value === fieldName)?.options;
     const getTagsFromOptions = (options: SearchFieldOption['options']): Tag[] => { if (!options) return []; return options.map(opt => ({ tagId: typeof opt.value === 'number' ? opt.value : parseInt(String(opt.value), 10), name: opt.label })).filter(tag => !isNaN(tag.tagId)); }
 
     const isCriteriaDirty = useMemo(() => {
-        if (criteria.length > 1) return true;
+        if (criteria.length > 1 || matchLogic !== 'AND') return true;
         if (criteria.length === 0 || fields.length === 0) return false;
         const defaultCrit = getInitialCriterion();
         const currentCrit = criteria[0];
         if (!currentCrit) return false;
         return ( currentCrit.field !== defaultCrit.field || currentCrit.condition !== defaultCrit.condition || JSON.stringify(currentCrit.value) !== JSON.stringify(defaultCrit.value) || currentCrit.not !== defaultCrit.not );
-    }, [criteria, fields, getInitialCriterion]);
+    }, [criteria, fields, getInitialCriterion, matchLogic]);
 
       // Use translated empty state
      if (fields.length === 0 && !isLoading) { return ( <div className="p-4 border rounded-lg bg-white dark:bg-white text-neutral-600 text-center shadow-sm"> {t('searchNoOptionsAvailable', preferredLanguage)} </div> ); } // Forced white bg
 
     return (
         // Force white background for the entire search bar container
-        <div className="p-4 border rounded-lg bg-white dark:bg-white space-y-3 shadow-sm">
+        <div data-help="search" className="p-4 border rounded-lg bg-white dark:bg-white space-y-3 shadow-sm">
             {criteria.map((criterion) => {
                 const fieldType = getFieldType(criterion.field);
                 const fieldOptions = getFieldOptions(criterion.field);
@@ -298,7 +309,22 @@ const SearchBar: React.FC<SearchBarProps> = ({
                 );
             })}
             <div className="flex justify-between items-center pt-2 flex-wrap gap-2">
-                <Button type="button" variant="outline" onClick={handleAddCriterion} size="sm" disabled={fields.length === 0}> <PlusCircle className="mr-2 h-4 w-4" /> {t('addFilterButton', preferredLanguage)} </Button>
+                <div className="flex items-center gap-2 flex-wrap">
+                    <Button type="button" variant="outline" onClick={handleAddCriterion} size="sm" disabled={fields.length === 0}> <PlusCircle className="mr-2 h-4 w-4" /> {t('addFilterButton', preferredLanguage)} </Button>
+                    {/* Advanced: only meaningful once there is more than one criterion */}
+                    {allowMatchAny && criteria.length > 1 && (
+                        <div className="flex items-center gap-2">
+                            <Label htmlFor="search-match-logic" className="text-xs text-neutral-700">{t('searchMatchLabel', preferredLanguage)}</Label>
+                            <Select value={matchLogic} onValueChange={(value) => setMatchLogic(value as SearchLogic)}>
+                                <SelectTrigger id="search-match-logic" className="h-8 w-auto text-sm"><SelectValue /></SelectTrigger>
+                                <SelectContent>
+                                    <SelectItem value="AND">{t('searchMatchAll', preferredLanguage)}</SelectItem>
+                                    <SelectItem value="OR">{t('searchMatchAny', preferredLanguage)}</SelectItem>
+                                </SelectContent>
+                            </Select>
+                        </div>
+                    )}
+                </div>
                 <div className="flex items-center gap-2">
                     {showResetButton && ( <Button type="button" variant="ghost" onClick={handleResetClick} disabled={isLoading || !isCriteriaDirty} size='sm' title={t('resetFiltersButton', preferredLanguage)} > <RefreshCcw className={cn("mr-2 h-4 w-4", isLoading && "animate-spin")} /> {t('resetButton', preferredLanguage)} </Button> )}
                     <Button type="button" onClick={handleSearchClick} disabled={isLoading || fields.length === 0} size='sm'> {isLoading && <LoadingSpinner size='sm' className='mr-2' />} <Search className="mr-2 h-4 w-4" /> {t('searchButton', preferredLanguage)} </Button>
@@ -1,12 +1,11 @@
 import React, { useState, useEffect, useCallback, useMemo } from 'react'; // Ensure useMemo and useCallback are imported
 import { Button } from '@/components/ui/button';
 import { Label } from '@/components/ui/label';
-import { X, Plus } from 'lucide-react';
+import { X, Plus, GripVertical } from 'lucide-react';
 import { Badge } from '@/components/ui/badge';
 import LoadingSpinner from './LoadingSpinner';
 import { useAuth } from '@/hooks/useAuth';
-import api from '@/lib/api';
-import type { SignatureElement } from '../../../../backend/src/functionalities/signature/element/models';
+import { resolveSignaturePaths, formatSignaturePath } from '@/lib/signaturePaths';
 import { cn } from '@/lib/utils';
 // Import Dialog components
 import { Dialog, DialogTrigger, DialogContent, DialogHeader, DialogTitle } from '@/components/ui/dialog';
@@ -14,8 +13,6 @@ import { Dialog, DialogTrigger, DialogContent, DialogHeader, DialogTitle } from
 import ElementBrowserDialogContent from './ElementBrowserDialogContent.tsx';
 import { t } from '@/translations/utils'; // Import translation utility
 
-type ResolvedSignature = { idPath: number[]; display: string };
-
 interface SignaturePathSelectorProps {
   label: string;
   signatures: number[][]; // Array of paths, e.g., [[1, 5], [1, 8, 3]]
@@ -30,41 +27,58 @@ const SignaturePathSelector: React.FC<SignaturePathSelectorProps> = ({
     className,
 }) => {
   const { token, preferredLanguage } = useAuth(); // Get preferredLanguage
-  const [resolvedSignatures, setResolvedSignatures] = useState<ResolvedSignature[]>([]);
+  // Display text per path (keyed by the JSON of its ids) - kept across reordering/removal, only new paths get resolved
+  const [displayByPath, setDisplayByPath] = useState<Record<string, string>>({});
+  const [failedPaths, setFailedPaths] = useState<string[]>([]);
   const [isLoadingSignatures, setIsLoadingSignatures] = useState(false);
   const [isBrowserOpen, setIsBrowserOpen] = useState(false); // State for Dialog open/close
+  const [dragIndex, setDragIndex] = useState<number | null>(null);
+  const [dropIndex, setDropIndex] = useState<number | null>(null);
 
   // Memoize the stringified version of signatures to stabilize useEffect dependency
   const stringifiedSignatures = useMemo(() => JSON.stringify(signatures), [signatures]);
 
   useEffect(() => {
-    const resolveAllSignatures = async () => {
-        const currentSignatures = JSON.parse(stringifiedSignatures); // Use the memoized string
-        if (!token || currentSignatures.length === 0) {
-            setResolvedSignatures([]); return;
-        }
+    const resolveNewSignatures = async () => {
+        const currentSignatures: number[][] = JSON.parse(stringifiedSignatures); // Use the memoized string
+        const unresolvedPaths = currentSignatures.filter(idPath => idPath.length > 0 && displayByPath[JSON.stringify(idPath)] === undefined);
+        if (!token || unresolvedPaths.length === 0) return;
         setIsLoadingSignatures(true);
-        const resolved: ResolvedSignature[] = [];
         try {
-            for (const idPath of currentSignatures) {
-                if (idPath.length === 0) continue;
-                const elementsInPath: (SignatureElement | null)[] = await Promise.all(
-                    idPath.map((id: number) => api.getSignatureElementById(id, [], token).catch(() => null))
-                );
-                 const displayParts = elementsInPath.map((el: SignatureElement | null, index: number) => {
-                     if (el) return `${el.index ? `[${el.index}]` : ''}${el.name}`;
-                     return `[${t('errorText', preferredLanguage)} ID: ${idPath[index] !== undefined ? idPath[index] : t('unknown', preferredLanguage)}]`; // Use translated unknown
-                 });
-                 resolved.push({ idPath, display: displayParts.join(' / ') });
-            }
-             setResolvedSignatures(resolved.sort((a, b) => a.display.localeCompare(b.display)));
+            // All paths are resolved in one batch instead of a request per element
+            const resolvedPaths = await resolveSignaturePaths(unresolvedPaths, token);
+            const resolved: Record<string, string> = {};
+            unresolvedPaths.forEach((idPath, pathIndex) => {
+                resolved[JSON.stringify(idPath)] = formatSignaturePath(resolvedPaths[pathIndex], idPath);
+            });
+            setDisplayByPath(prev => ({ ...prev, ...resolved }));
+            setFailedPaths([]);
         } catch (error) {
              console.error("Error resolving signatures:", error);
-             setResolvedSignatures(currentSignatures.map((p: number[]) => ({idPath: p, display: `[${p.join(' / ')}] (${t('errorText', preferredLanguage)})`})));
+             setFailedPaths(unresolvedPaths.map(idPath => JSON.stringify(idPath)));
         } finally { setIsLoadingSignatures(false); }
     };
-    resolveAllSignatures();
-  }, [stringifiedSignatures, token, preferredLanguage]); // Add preferredLanguage
+    resolveNewSignatures();
+    // The cache is read, not watched - it only grows from this effect
+  }, [stringifiedSignatures, token]);
+
+  const getDisplay = (idPath: number[]): string => {
+      const key = JSON.stringify(idPath);
+      if (idPath.length === 0) return '';
+      if (failedPaths.includes(key)) return `[${idPath.join(' / ')}] (${t('errorText', preferredLanguage)})`;
+      return displayByPath[key] ?? `[${idPath.join(' / ')}]`;
+  };
+
+  // Order matters (e.g. the first path is the primary one) - dropping a dragged path moves it before the target
+  const moveSignature = (fromIndex: number, toIndex: number) => {
+      if (fromIndex === toIndex) return;
+      const reordered = [...signatures];
+      const [moved] = reordered.splice(fromIndex, 1);
+      reordered.splice(toIndex, 0, moved);
+      onChange(reordered);
+  };
+
+  const endDrag = () => { setDragIndex(null); setDropIndex(null); };
 
   // Renamed callback for clarity
   const handleAddSignatureFromBrowser = useCallback((newSignature: number[]) => {
@@ -73,7 +87,6 @@ const SignaturePathSelector: React.FC<SignaturePathSelectorProps> = ({
       if (!currentSignatures.some((p: number[]) => JSON.stringify(p) === newSignatureStr)) {
           onChange([...currentSignatures, newSignature]);
       }
-      setIsBrowserOpen(false); // Close the dialog
   }, [stringifiedSignatures, onChange]);
 
 
@@ -112,6 +125,7 @@ const SignaturePathSelector: React.FC<SignaturePathSelectorProps> = ({
                          <ElementBrowserDialogContent
                             onSelectSignature={handleAddSignatureFromBrowser}
                             onCloseDialog={handleCloseBrowserDialog}
+                            clearOnConfirm={false}
                          />
                      )}
                  </div>
@@ -121,26 +135,44 @@ const SignaturePathSelector: React.FC<SignaturePathSelectorProps> = ({
        {/* Inner container for badges - use lighter gray background */}
       <div className="flex-grow space-y-1 min-h-[40px] max-h-[150px] overflow-y-auto border rounded bg-neutral-50 p-2">
          {/* Use translated loading text */}
-         {isLoadingSignatures && <div className='flex justify-center p-2'><LoadingSpinner size='sm' /></div>}
-         {!isLoadingSignatures && resolvedSignatures.map((resolved) => (
-          <div key={JSON.stringify(resolved.idPath)} className="flex items-center justify-between gap-2 rounded bg-neutral-100 p-1 px-2 text-sm"> {/* Changed bg-muted to bg-neutral-100 */}
+         {signatures.map((idPath, index) => {
+          const display = getDisplay(idPath);
+          return (
+          <div
+            key={JSON.stringify(idPath)}
+            className={cn(
+                "flex items-center justify-between gap-2 rounded bg-neutral-100 p-1 px-2 text-sm", // Changed bg-muted to bg-neutral-100
+                dragIndex === index && "opacity-50",
+                dropIndex === index && dragIndex !== null && dragIndex !== index && "ring-1 ring-primary"
+            )}
+            draggable={signatures.length > 1}
+            onDragStart={(e) => { e.dataTransfer.effectAllowed = 'move'; setDragIndex(index); }}
+            onDragOver={(e) => { if (dragIndex === null) return; e.preventDefault(); setDropIndex(index); }}
+            onDrop={(e) => { e.preventDefault(); if (dragIndex !== null) moveSignature(dragIndex, index); endDrag(); }}
+            onDragEnd={endDrag}
+          >
+            {signatures.length > 1 && (
+                <GripVertical className="h-3 w-3 shrink-0 cursor-grab text-neutral-400" aria-label={t('signatureDragHandleLabel', preferredLanguage)} />
+            )}
             <span className="font-mono text-xs flex-grow break-words min-w-0 text-neutral-800"> {/* Adjusted text color */}
-                {resolved.display || <span className='italic text-neutral-500'>{t('emptySignaturePlaceholder', preferredLanguage)}</span>} {/* Use translated placeholder */}
+                {display || <span className='italic text-neutral-500'>{t('emptySignaturePlaceholder', preferredLanguage)}</span>} {/* Use translated placeholder */}
             </span>
             <Button
               type="button"
               variant="ghost"
               size="icon"
               className="h-5 w-5 shrink-0 text-neutral-500 hover:text-destructive"
-              onClick={() => removeSignature(resolved.idPath)}
-              aria-label={`${t('removeButton', preferredLanguage)} ${resolved.display}`}
+              onClick={() => removeSignature(idPath)}
+              aria-label={`${t('removeButton', preferredLanguage)} ${display}`}
             >
               <X className="h-3 w-3" />
             </Button>
           </div>
-        ))}
+          );
+        })}
+         {isLoadingSignatures && <div className='flex justify-center p-1'><LoadingSpinner size='sm' /></div>}
          {/* Use translated placeholder */}
-         {!isLoadingSignatures && signatures.length === 0 && <p className="text-xs text-neutral-500 italic text-center py-1">{t('noSignaturesAddedHint', preferredLanguage)}</p>}
+         {signatures.length === 0 && <p className="text-xs text-neutral-500 italic text-center py-1">{t('noSignaturesAddedHint', preferredLanguage)}</p>}
       </div>
     </div>
   );
@@ -5,11 +5,11 @@ import { X, Plus } from 'lucide-react';
 import { Badge } from '@/components/ui/badge';
 import LoadingSpinner from './LoadingSpinner';
 import { useAuth } from '@/hooks/useAuth';
-import api from '@/lib/api';
-import type { SignatureElement } from '../../../../backend/src/functionalities/signature/element/models';
+import { resolveSignaturePaths, formatSignaturePath } from '@/lib/signaturePaths';
 import { cn } from '@/lib/utils';
 import { Popover, PopoverTrigger, PopoverContent } from '@/components/ui/popover';
-import ElementBrowserPopoverContent from './ElementBrowserPopoverContent';
+import ElementBrowserDialogContent from './ElementBrowserDialogContent';
+import CopyButton from './CopyButton';
 import { t } from '@/translations/utils'; // Import translation utility
 
 type ResolvedSignature = { idPath: number[]; display: string };
@@ -44,18 +44,12 @@ const SignatureSelector: React.FC<SignatureSelectorProps> = ({
         setIsLoadingSignatures(true);
         const resolved: ResolvedSignature[] = [];
         try {
-            for (const idPath of currentSignatures) {
-                if (idPath.length === 0) continue;
-                const elementsInPath: (SignatureElement | null)[] = await Promise.all(
-                    idPath.map((id: number) => api.getSignatureElementById(id, [], token).catch(() => null))
-                );
-                 const displayParts = elementsInPath.map((el: SignatureElement | null, index: number) => {
-                     if (el) return `${el.index ? `[${el.index}]` : ''}${el.name}`;
-                     // Use translated error
-                     return `[${t('errorText', preferredLanguage)} ID: ${idPath[index] !== undefined ? idPath[index] : t('unknown', preferredLanguage)}]`; // Use translated unknown
-                 });
-                 resolved.push({ idPath, display: displayParts.join(' / ') });
-            }
+            const nonEmptyPaths: number[][] = currentSignatures.filter((idPath: number[]) => idPath.length > 0);
+            // All paths are resolved in one batch instead of a request per element
+            const resolvedPaths = await resolveSignaturePaths(nonEmptyPaths, token);
+            nonEmptyPaths.forEach((idPath, pathIndex) => {
+                 resolved.push({ idPath, display: formatSignaturePath(resolvedPaths[pathIndex], idPath) });
+            });
              setResolvedSignatures(resolved.sort((a, b) => a.display.localeCompare(b.display)));
         } catch (error) {
              console.error("Error resolving signatures:", error);
@@ -72,7 +66,6 @@ const SignatureSelector: React.FC<SignatureSelectorProps> = ({
       if (!currentSignatures.some((p: number[]) => JSON.stringify(p) === newSignatureStr)) {
           onChange([...currentSignatures, newSignature]);
       }
-      setIsBrowserOpen(false);
   }, [stringifiedSignatures, onChange]);
 
 
@@ -102,9 +95,10 @@ const SignatureSelector: React.FC<SignatureSelectorProps> = ({
              </PopoverTrigger>
              {/* Popover content is forced white */}
              <PopoverContent className="w-[500px] max-w-[calc(100vw-2rem)] p-0" align="start">
-                 <ElementBrowserPopoverContent
+                 <ElementBrowserDialogContent
                      onSelectSignature={addSignatureCallback}
-                     onClosePopover={handleClosePopover}
+                     onCloseDialog={handleClosePopover}
+                     clearOnConfirm={false}
                  />
              </PopoverContent>
          </Popover>
@@ -118,6 +112,7 @@ const SignatureSelector: React.FC<SignatureSelectorProps> = ({
             <span className="font-mono text-xs flex-grow break-words min-w-0 text-neutral-800">
                 {resolved.display || <span className='italic text-neutral-500'>{t('emptySignaturePlaceholder', preferredLanguage)}</span>} {/* Use translated placeholder */}
             </span>
+            {resolved.display && <CopyButton value={resolved.display} label={t('signaturePathCopyLabel', preferredLanguage)} className="h-5 w-5 text-neutral-500 hover:text-neutral-800" />}
             <Button
               type="button"
               variant="ghost"
@@ -0,0 +1,50 @@
+import { useEffect } from 'react';
+import { toast } from '@/lib/toast';
+import { useAuth } from '@/hooks/useAuth';
+import { t } from '@/translations/utils';
+
+// Single-line input types whose pasted content gets collapsed to one trimmed line
+const SINGLE_LINE_INPUT_TYPES = new Set(['text', 'search', 'url', 'email', 'tel', 'number']);
+
+/** Collapses line breaks, tabs and repeated whitespace into single spaces and trims. */
+export const collapseToSingleLine = (text: string) => text.replace(/\s+/g, ' ').trim();
+
+// App-wide paste handler: text copied from spreadsheets etc. often carries trailing newlines or tabs,
+// which break single-line fields (titles, names, indexes). Inputs can opt out with `data-keep-whitespace`.
+const SingleLinePasteGuard = () => {
+    const { preferredLanguage } = useAuth();
+
+    useEffect(() => {
+        const handlePaste = (e: ClipboardEvent) => {
+            const input = e.target;
+            if (!(input instanceof HTMLInputElement) || !SINGLE_LINE_INPUT_TYPES.has(input.type)) return;
+            if (input.readOnly || input.disabled || input.dataset.keepWhitespace !== undefined) return;
+
+            const pasted = e.clipboardData?.getData('text/plain');
+            if (!pasted) return;
+            const cleaned = collapseToSingleLine(pasted);
+            if (cleaned === pasted) return;
+
+            e.preventDefault();
+            // Number inputs don't support selection APIs - replace the whole value instead
+            if (input.type === 'number' || input.selectionStart === null) {
+                input.value = cleaned;
+            } else {
+                input.setRangeText(cleaned, input.selectionStart, input.selectionEnd ?? input.selectionStart, 'end');
+            }
+            // Let React (and react-hook-form) pick up the new value
+            input.dispatchEvent(new Event('input', { bubbles: true }));
+
+            // Only mention it when formatting (not just surrounding spaces) was stripped
+            if (/[\r\n\t]/.test(pasted)) {
+                toast.info(t('pasteCollapsedToSingleLine', preferredLanguage));
+            }
+        };
+        document.addEventListener('paste', handlePaste, true);
+        return () => document.removeEventListener('paste', handlePaste, true);
+    }, [preferredLanguage]);
+
+    return null;
+};
+
+export default SingleLinePasteGuard;
@@ -6,8 +6,7 @@ import ElementBrowserDialogContent from './ElementBrowserDialogContent.tsx';
 import { Badge } from '@/components/ui/badge';
 import { X, Wand2 } from 'lucide-react';
 import { useAuth } from '@/hooks/useAuth';
-import api from '@/lib/api';
-import type { SignatureElement } from '../../../../backend/src/functionalities/signature/element/models';
+import { resolveSignaturePaths, formatSignaturePath } from '@/lib/signaturePaths';
 import { cn } from '@/lib/utils';
 import LoadingSpinner from './LoadingSpinner';
 import { t } from '@/translations/utils'; // Import translation utility
@@ -41,15 +40,8 @@ const SingleSignaturePathPicker: React.FC<SingleSignaturePathPickerProps> = ({
       }
       setIsLoadingPath(true);
       try {
-        const elementsInPath: (SignatureElement | null)[] = await Promise.all(
-          selectedPath.map(id => api.getSignatureElementById(id, [], token).catch(() => null))
-        );
-        const displayParts = elementsInPath.map((el, index) => {
-          if (el) return `${el.index ? `[${el.index}]` : ''}${el.name}`;
-           // Use translated error
-           return `[${t('errorText', preferredLanguage)} ID: ${selectedPath[index]}]`;
-        });
-        setResolvedDisplayPath(displayParts.join(' / '));
+        const [elementsInPath] = await resolveSignaturePaths([selectedPath], token);
+        setResolvedDisplayPath(formatSignaturePath(elementsInPath, selectedPath));
       } catch (error) {
         console.error("Error resolving signature path:", error);
          // Use translated error
@@ -31,6 +31,9 @@ const TagSelector: React.FC<TagSelectorProps> = ({
   const [error, setError] = useState<string | null>(null);
   const [open, setOpen] = useState(false);
   const [searchTerm, setSearchTerm] = useState("");
+  // Selection captured when the dropdown opens - those tags are listed first. Not updated while open so
+  // toggling a tag doesn't make the list jump under the cursor.
+  const [pinnedTagIds, setPinnedTagIds] = useState<number[]>([]);
 
   const tagsToUse = preFetchedTags ?? internalTags;
 
@@ -60,7 +63,12 @@ const TagSelector: React.FC<TagSelectorProps> = ({
       ? selectedTagIds.filter(id => id !== tagId)
       : [...selectedTagIds, tagId];
     onChange(newSelectedIds);
-    setSearchTerm("");
+  };
+
+  const handleOpenChange = (isOpen: boolean) => {
+    if (isOpen) setPinnedTagIds(selectedTagIds);
+    else setSearchTerm("");
+    setOpen(isOpen);
   };
 
    const selectedTags = useMemo(() => {
@@ -69,15 +77,17 @@ const TagSelector: React.FC<TagSelectorProps> = ({
    }, [tagsToUse, selectedTagIds]);
 
    const filteredDropdownTags = useMemo(() => {
+       const term = searchTerm.trim().toLowerCase();
+       const pinned = new Set(pinnedTagIds);
        return tagsToUse
-           .filter(tag => tag.name.toLowerCase().includes(searchTerm.toLowerCase()))
-           .sort((a, b) => a.name.localeCompare(b.name));
-   }, [tagsToUse, searchTerm]);
+           .filter(tag => tag.name.toLowerCase().includes(term))
+           .sort((a, b) => Number(pinned.has(b.tagId!)) - Number(pinned.has(a.tagId!)) || a.name.localeCompare(b.name));
+   }, [tagsToUse, searchTerm, pinnedTagIds]);
 
 
   return (
     <div className={cn('space-y-2', className)}>
-         <Popover open={open} onOpenChange={setOpen}>
+         <Popover open={open} onOpenChange={handleOpenChange}>
              <PopoverTrigger asChild>
                 <Button
                     variant="outline"
@@ -0,0 +1,40 @@
+import React from 'react';
+import { useAuth } from '@/hooks/useAuth';
+import { useTimestampFormat } from '@/hooks/useTimestampFormat';
+import { formatAbsoluteTime, formatRelativeTime, setTimestampFormat } from '@/lib/dateFormat';
+import { cn } from '@/lib/utils';
+import { t } from '@/translations/utils';
+
+interface TimestampProps {
+    value: Date | string | null | undefined;
+    className?: string;
+}
+
+// Date in the user's preferred form with the other one on hover; clicking switches the form for all timestamps
+const Timestamp: React.FC<TimestampProps> = ({ value, className }) => {
+    const { preferredLanguage } = useAuth();
+    const format = useTimestampFormat();
+
+    const date = value ? new Date(value) : null;
+    if (!date || isNaN(date.getTime())) return <span className={className}>-</span>;
+
+    const relative = formatRelativeTime(date, preferredLanguage);
+    const absolute = formatAbsoluteTime(date);
+    const [shown, alternate] = format === 'relative' ? [relative, absolute] : [absolute, relative];
+
+    return (
+        <time
+            dateTime={date.toISOString()}
+            title={`${alternate}\n${t('timestampToggleHint', preferredLanguage)}`}
+            className={cn('cursor-pointer whitespace-nowrap decoration-dotted hover:underline', className)}
+            onClick={(e) => {
+                e.stopPropagation(); // Timestamps sit in clickable rows
+                setTimestampFormat(format === 'relative' ? 'absolute' : 'relative');
+            }}
+        >
+            {shown}
+        </time>
+    );
+};
+
+export default Timestamp;
@@ -5,17 +5,18 @@ import { createSignatureComponentFormSchema, CreateSignatureComponentFormData }
 import { Button } from '@/components/ui/button';
 import { Input } from '@/components/ui/input';
 import { Label } from '@/components/ui/label';
-import { Textarea } from '@/components/ui/textarea';
+import MarkdownTextarea from '@/components/shared/MarkdownTextarea';
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
 import { useAuth } from '@/hooks/useAuth';
 import api from '@/lib/api';
+import { applyServerFieldErrors } from '@/lib/serverFieldErrors';
 // Correctly import both Create and Update input types
 import type { SignatureComponent, SignatureComponentIndexType, CreateSignatureComponentInput, UpdateSignatureComponentInput } from '../../../../backend/src/functionalities/signature/component/models';
 import { cn } from '@/lib/utils'; // Import cn
 import { t } from '@/translations/utils'; // Import translation utility
-import { toast } from "sonner"; // Import toast
+import { toast } from '@/lib/toast'; // Import toast
 
 interface ComponentFormProps {
   componentToEdit: SignatureComponent | null;
@@ -27,7 +28,7 @@ const ComponentForm: React.FC<ComponentFormProps> = ({ componentToEdit, onSave }
   const [isLoading, setIsLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
 
-  const { register, handleSubmit, reset, control, formState: { errors } } = useForm<CreateSignatureComponentFormData>({
+  const { register, handleSubmit, reset, control, watch, setError: setFieldError, formState: { errors } } = useForm<CreateSignatureComponentFormData>({
     resolver: zodResolver(createSignatureComponentFormSchema),
     defaultValues: { name: '', description: '', index_type: 'dec', },
   });
@@ -76,7 +77,8 @@ const ComponentForm: React.FC<ComponentFormProps> = ({ componentToEdit, onSave }
       onSave();
     } catch (err: any) {
        const msg = err.message || t('componentSaveFailedError', preferredLanguage);
-       setError(msg);
+       // Field-specific messages go next to their inputs; the general error stays for anything else
+       if (applyServerFieldErrors(err, ['name', 'description', 'index_type'] as const, setFieldError) === 0) setError(msg);
        toast.error(t('errorMessageTemplate', preferredLanguage, { message: msg })); // Also show in toast
       console.error("Save Component Error:", err);
     } finally {
@@ -98,7 +100,7 @@ const ComponentForm: React.FC<ComponentFormProps> = ({ componentToEdit, onSave }
        <div className="grid gap-1.5">
            {/* Use translated label */}
           <Label htmlFor="comp-description">{t('componentDescriptionLabel', preferredLanguage)} {t('optionalLabel', preferredLanguage)}</Label>
-          <Textarea id="comp-description" {...register('description')} rows={3} aria-invalid={!!errors.description} className={cn(errors.description && "border-destructive")}/>
+          <MarkdownTextarea id="comp-description" {...register('description')} previewValue={watch('description')} rows={3} aria-invalid={!!errors.description} className={cn(errors.description && "border-destructive")}/>
           {errors.description && <p className="text-xs text-destructive">{errors.description.message}</p>}
        </div>
        <div className="grid gap-1.5">
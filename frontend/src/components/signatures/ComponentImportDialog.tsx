@@ -0,0 +1,151 @@
+import React, { useState, useEffect } from 'react';
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, DialogFooter } from "@/components/ui/dialog";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { Alert, AlertDescription, AlertTitle } from "@/components/ui/alert";
+import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from "@/components/ui/table";
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import ErrorDisplay from '@/components/shared/ErrorDisplay';
+import { AlertTriangle, CheckCircle, Upload } from 'lucide-react';
+import { useAuth } from '@/hooks/useAuth';
+import { parseSignatureExport, importSignatureExport, type SignatureExport, type SignatureImportResult } from '@/lib/signatureTransfer';
+import { toast } from '@/lib/toast';
+import { t } from '@/translations/utils';
+
+interface ComponentImportDialogProps {
+    isOpen: boolean;
+    onOpenChange: (open: boolean) => void;
+    onImported: () => void;
+}
+
+const ComponentImportDialog: React.FC<ComponentImportDialogProps> = ({ isOpen, onOpenChange, onImported }) => {
+    const { token, preferredLanguage } = useAuth();
+    const [data, setData] = useState<SignatureExport | null>(null);
+    const [parseError, setParseError] = useState<string | null>(null);
+    const [isImporting, setIsImporting] = useState(false);
+    const [importProgress, setImportProgress] = useState<{ done: number; total: number } | null>(null);
+    const [result, setResult] = useState<SignatureImportResult | null>(null);
+
+    useEffect(() => {
+        if (isOpen) {
+            setData(null); setParseError(null);
+            setImportProgress(null); setResult(null);
+        }
+    }, [isOpen]);
+
+    const handleFileChange = async (e: React.ChangeEvent<HTMLInputElement>) => {
+        const file = e.target.files?.[0];
+        setData(null); setParseError(null); setResult(null);
+        if (!file) return;
+        try {
+            setData(parseSignatureExport(await file.text(), preferredLanguage));
+        } catch (err: any) {
+            setParseError(err.message);
+        }
+    };
+
+    const handleImport = async () => {
+        if (!token || !data) return;
+        setIsImporting(true);
+        setResult(null);
+        try {
+            const importResult = await importSignatureExport(data, token, (done, total) => setImportProgress({ done, total }), preferredLanguage);
+            setResult(importResult);
+            if (importResult.createdComponents > 0 || importResult.createdElements > 0) {
+                toast.success(t('signatureImportSuccess', preferredLanguage, { components: importResult.createdComponents, elements: importResult.createdElements }));
+            }
+            if (importResult.failures.length === 0) onOpenChange(false);
+            else toast.error(t('signatureImportFailuresTitle', preferredLanguage, { count: importResult.failures.length }));
+        } catch (err: any) {
+            setParseError(err.message || t('signatureImportFailedError', preferredLanguage));
+            console.error("Signature Import Error:", err);
+        } finally {
+            setIsImporting(false);
+            onImported();
+        }
+    };
+
+    const elementCount = data?.components.reduce((sum, c) => sum + c.elements.length, 0) ?? 0;
+
+    return (
+        <Dialog open={isOpen} onOpenChange={(open) => { if (!isImporting) onOpenChange(open); }}>
+            <DialogContent className="sm:max-w-2xl">
+                <DialogHeader>
+                    <DialogTitle className='flex items-center gap-2'><Upload className='h-4 w-4' /> {t('signatureImportTitle', preferredLanguage)}</DialogTitle>
+                    <DialogDescription>{t('signatureImportDescription', preferredLanguage)}</DialogDescription>
+                </DialogHeader>
+
+                <div className="space-y-4 py-2">
+                    <Input
+                        type="file"
+                        accept=".json,application/json"
+                        onChange={handleFileChange}
+                        disabled={isImporting}
+                    />
+                    {parseError && <ErrorDisplay message={parseError} />}
+
+                    {data && !result && (
+                        <Alert>
+                            <CheckCircle className="h-4 w-4" />
+                            <AlertTitle>{t('signatureImportReadyTitle', preferredLanguage)}</AlertTitle>
+                            <AlertDescription>
+                                {t('signatureImportReadyText', preferredLanguage, { components: data.components.length, elements: elementCount })}
+                                <span className="block text-xs mt-1">{data.components.map(c => c.name).join(', ')}</span>
+                            </AlertDescription>
+                        </Alert>
+                    )}
+
+                    {result && result.failures.length > 0 && (
+                        <>
+                            <Alert variant="destructive">
+                                <AlertTriangle className="h-4 w-4" />
+                                <AlertTitle>{t('signatureImportFailuresTitle', preferredLanguage, { count: result.failures.length })}</AlertTitle>
+                                <AlertDescription>
+                                    {t('signatureImportSuccess', preferredLanguage, { components: result.createdComponents, elements: result.createdElements })}
+                                </AlertDescription>
+                            </Alert>
+                            <div className="max-h-60 overflow-y-auto border rounded-md">
+                                <Table>
+                                    <TableHeader>
+                                        <TableRow>
+                                            <TableHead className="w-[240px]">{t('signatureImportItemColumn', preferredLanguage)}</TableHead>
+                                            <TableHead>{t('signatureImportMessageColumn', preferredLanguage)}</TableHead>
+                                        </TableRow>
+                                    </TableHeader>
+                                    <TableBody>
+                                        {result.failures.map((failure, i) => (
+                                            <TableRow key={i}>
+                                                <TableCell className="text-xs">{failure.item}</TableCell>
+                                                <TableCell className="text-xs">{failure.message}</TableCell>
+                                            </TableRow>
+                                        ))}
+                                    </TableBody>
+                                </Table>
+                            </div>
+                        </>
+                    )}
+
+                    {importProgress && isImporting && (
+                        <p className='text-sm text-muted-foreground inline-flex items-center gap-2'>
+                            <LoadingSpinner size='sm' /> {t('signatureImportProgress', preferredLanguage, { done: importProgress.done, total: importProgress.total })}
+                        </p>
+                    )}
+                </div>
+
+                <DialogFooter>
+                    <Button type="button" variant="outline" onClick={() => onOpenChange(false)} disabled={isImporting}>
+                        {result ? t('closeButton', preferredLanguage) : t('cancelButton', preferredLanguage)}
+                    </Button>
+                    {!result && (
+                        <Button type="button" onClick={handleImport} disabled={!data || isImporting}>
+                            {isImporting ? <LoadingSpinner size="sm" className="mr-2" /> : <Upload className="mr-2 h-4 w-4" />}
+                            {t('signatureImportButton', preferredLanguage)}
+                        </Button>
+                    )}
+                </DialogFooter>
+            </DialogContent>
+        </Dialog>
+    );
+};
+
+export default ComponentImportDialog;
@@ -2,24 +2,36 @@ import React from 'react'; // Import React
 import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from "@/components/ui/table";
 import { Button } from '@/components/ui/button';
 import { Badge } from '@/components/ui/badge';
-import { Edit, Trash2, ListRestart, FolderOpen } from 'lucide-react'; // Added FolderOpen icon
+import { Edit, Trash2, ListRestart, FileJson, FolderOpen, ArrowDown, ArrowUp, ArrowUpDown } from 'lucide-react'; // Added FolderOpen icon
 import type { SignatureComponent } from '../../../../backend/src/functionalities/signature/component/models';
+import type { ViewState } from '@/lib/viewState';
 import { useAuth } from '@/hooks/useAuth';
 import { cn } from '@/lib/utils';
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import MarkdownText from '@/components/shared/MarkdownText';
 import { t } from '@/translations/utils'; // Import translation utility
 
+export type ComponentSort = ViewState['componentsSort'];
+
+// Names read naturally A-Z, element counts are most interesting largest first
+const INITIAL_SORT_DIRECTION: Record<ComponentSort['field'], ComponentSort['direction']> = { name: 'ASC', index_count: 'DESC' };
+
 interface ComponentListProps {
     components: SignatureComponent[];
+    sort: ComponentSort;
+    onSortChange: (sort: ComponentSort) => void;
     onEdit: (component: SignatureComponent) => void;
     onDelete: (componentId: number) => void;
     // Renamed onSelect to onOpen for clarity
     onOpen: (component: SignatureComponent) => void;
     onReindex: (componentId: number) => void;
+    onExport: (component: SignatureComponent) => void;
+    busyRows?: Record<number, 'delete' | 'reindex' | 'export'>; // Components with a delete/reindex/export request in flight
 }
 
 // Wrap the functional component definition with React.memo
 const ComponentList: React.FC<ComponentListProps> = React.memo(({
-    components, onEdit, onDelete, onOpen, onReindex // Updated prop name
+    components, sort, onSortChange, onEdit, onDelete, onOpen, onReindex, onExport, busyRows = {} // Updated prop name
 }) => {
     const { user, preferredLanguage } = useAuth(); // Get preferredLanguage
     const isAdmin = user?.role === 'admin';
@@ -40,6 +52,24 @@ const ComponentList: React.FC<ComponentListProps> = React.memo(({
          }
     };
 
+    const sortableHead = (field: ComponentSort['field'], label: string, className?: string) => {
+        const isActive = sort.field === field;
+        const SortIcon = !isActive ? ArrowUpDown : sort.direction === 'ASC' ? ArrowUp : ArrowDown;
+        return (
+            <TableHead className={className} aria-sort={isActive ? (sort.direction === 'ASC' ? 'ascending' : 'descending') : 'none'}>
+                <button
+                    type="button"
+                    className={cn('inline-flex items-center gap-1 hover:text-foreground', isActive && 'text-foreground')}
+                    onClick={() => onSortChange({ field, direction: isActive ? (sort.direction === 'ASC' ? 'DESC' : 'ASC') : INITIAL_SORT_DIRECTION[field] })}
+                    title={t('componentSortByTooltip', preferredLanguage, { column: label })}
+                >
+                    {label}
+                    <SortIcon className={cn('h-3 w-3', !isActive && 'opacity-40')} />
+                </button>
+            </TableHead>
+        );
+    };
+
     // Return null if list is empty (parent handles empty message)
     if (components.length === 0) {
         return null;
@@ -54,10 +84,10 @@ const ComponentList: React.FC<ComponentListProps> = React.memo(({
                 <TableHeader>
                     <TableRow>
                          {/* Use translated headers */}
-                        <TableHead>{t('componentNameLabel', preferredLanguage)}</TableHead>
+                        {sortableHead('name', t('componentNameLabel', preferredLanguage))}
                         <TableHead>{t('componentDescriptionLabel', preferredLanguage)}</TableHead>
                         <TableHead>{t('componentIndexTypeLabel', preferredLanguage)}</TableHead>
-                        <TableHead className='text-center w-[100px]'>{t('componentElementsCountLabel', preferredLanguage)}</TableHead>
+                        {sortableHead('index_count', t('componentElementsCountLabel', preferredLanguage), 'text-center w-[100px]')}
                         {/* Actions column header always present, content conditional */}
                         <TableHead className="text-right w-[150px]">{t('actionsLabel', preferredLanguage)}</TableHead>
                     </TableRow>
@@ -78,7 +108,7 @@ const ComponentList: React.FC<ComponentListProps> = React.memo(({
                             </TableCell>
                              {/* Use translated placeholder */}
                              <TableCell className='text-sm text-muted-foreground max-w-xs truncate' title={component.description || ''}>
-                                {component.description || <i className='not-italic'>{t('noDescription', preferredLanguage)}</i>}
+                                {component.description ? <MarkdownText text={component.description} inline /> : <i className='not-italic'>{t('noDescription', preferredLanguage)}</i>}
                             </TableCell>
                             <TableCell><Badge variant="outline">{getIndexTypeLabel(component.index_type)}</Badge></TableCell>
                             <TableCell className="text-center">{component.index_count ?? 0}</TableCell>
@@ -86,14 +116,17 @@ const ComponentList: React.FC<ComponentListProps> = React.memo(({
                                 {isAdmin ? (
                                     <>
                                          {/* Use translated titles */}
-                                        <Button variant="ghost" size="icon" onClick={(e) => { e.stopPropagation(); onReindex(component.signatureComponentId!); }} title={t('reindexElementsButtonTooltip', preferredLanguage)}>
-                                            <ListRestart className="h-4 w-4" />
+                                        <Button variant="ghost" size="icon" disabled={!!busyRows[component.signatureComponentId!]} onClick={(e) => { e.stopPropagation(); onReindex(component.signatureComponentId!); }} title={t('reindexElementsButtonTooltip', preferredLanguage)}>
+                                            {busyRows[component.signatureComponentId!] === 'reindex' ? <LoadingSpinner size="sm" /> : <ListRestart className="h-4 w-4" />}
+                                        </Button>
+                                        <Button variant="ghost" size="icon" disabled={!!busyRows[component.signatureComponentId!]} onClick={(e) => { e.stopPropagation(); onExport(component); }} title={t('exportComponentButtonTooltip', preferredLanguage)}>
+                                            {busyRows[component.signatureComponentId!] === 'export' ? <LoadingSpinner size="sm" /> : <FileJson className="h-4 w-4" />}
                                         </Button>
-                                        <Button variant="ghost" size="icon" onClick={(e) => { e.stopPropagation(); onEdit(component); }} title={t('editComponentButtonTooltip', preferredLanguage)}>
+                                        <Button variant="ghost" size="icon" disabled={!!busyRows[component.signatureComponentId!]} onClick={(e) => { e.stopPropagation(); onEdit(component); }} title={t('editComponentButtonTooltip', preferredLanguage)}>
                                             <Edit className="h-4 w-4" />
                                         </Button>
-                                        <Button variant="ghost" size="icon" onClick={(e) => { e.stopPropagation(); onDelete(component.signatureComponentId!); }} title={t('deleteComponentButtonTooltip', preferredLanguage)}>
-                                            <Trash2 className="h-4 w-4 text-destructive" />
+                                        <Button variant="ghost" size="icon" disabled={!!busyRows[component.signatureComponentId!]} onClick={(e) => { e.stopPropagation(); onDelete(component.signatureComponentId!); }} title={t('deleteComponentButtonTooltip', preferredLanguage)}>
+                                            {busyRows[component.signatureComponentId!] === 'delete' ? <LoadingSpinner size="sm" /> : <Trash2 className="h-4 w-4 text-destructive" />}
                                         </Button>
                                     </>
                                 ) : (
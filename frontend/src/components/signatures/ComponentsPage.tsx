@@ -1,22 +1,37 @@
-import React, { useState, useEffect, useCallback } from 'react';
+import React, { useState, useEffect, useCallback, useMemo } from 'react';
 import { useNavigate } from 'react-router-dom'; // Import useNavigate
 import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
 import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogTrigger } from '@/components/ui/dialog';
-import { PlusCircle } from 'lucide-react';
-import ComponentList from './ComponentList';
+import { PlusCircle, Search, SearchX, Boxes, ListRestart, Download, Upload } from 'lucide-react';
+import ComponentList, { type ComponentSort } from './ComponentList';
 import ComponentForm from './ComponentForm';
+import ReindexPreviewDialog from './ReindexPreviewDialog';
+import ComponentImportDialog from './ComponentImportDialog';
+import { invalidateAncestryCache } from './ElementAncestry';
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import EmptyState from '@/components/shared/EmptyState';
+import LoadingStatus from '@/components/shared/LoadingStatus';
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
 import { useAuth } from '@/hooks/useAuth';
+import { useConfirm } from '@/hooks/useConfirm';
+import { useSaveGuardedOpenChange } from '@/hooks/usePendingRequests';
+import { useCreateShortcut } from '@/hooks/useCreateShortcut';
+import { useBusyRows } from '@/hooks/useBusyRows';
 import api from '@/lib/api';
+import { loadViewState, saveViewState } from '@/lib/viewState';
+import { downloadTextFile } from '@/lib/csv';
+import { buildSignatureExport } from '@/lib/signatureTransfer';
 import type { SignatureComponent } from '../../../../backend/src/functionalities/signature/component/models';
-import { toast } from "sonner";
+import { toast } from '@/lib/toast';
 import { Card, CardHeader, CardTitle, CardContent, CardDescription } from '@/components/ui/card';
 import { t } from '@/translations/utils'; // Import translation utility
 
 // Renamed from SignaturesPage to ComponentsPage
 const ComponentsPage: React.FC = () => {
     const { token, user, preferredLanguage } = useAuth(); // Get preferredLanguage
+    const confirm = useConfirm();
+    const { busyRows, runBusy } = useBusyRows<'delete' | 'reindex' | 'export'>();
     const navigate = useNavigate(); // Hook for navigation
     const isAdmin = user?.role === 'admin';
 
@@ -26,6 +41,27 @@ const ComponentsPage: React.FC = () => {
     const [componentsError, setComponentsError] = useState<string | null>(null);
     const [editingComponent, setEditingComponent] = useState<SignatureComponent | null>(null);
     const [isComponentFormOpen, setIsComponentFormOpen] = useState(false);
+    const handleComponentFormOpenChange = useSaveGuardedOpenChange(setIsComponentFormOpen);
+    const [reindexPreviewComponent, setReindexPreviewComponent] = useState<SignatureComponent | null>(null);
+    const [reindexAllProgress, setReindexAllProgress] = useState<{ done: number, total: number } | null>(null);
+    const [isExportingAll, setIsExportingAll] = useState(false);
+    const [isImportOpen, setIsImportOpen] = useState(false);
+    // Client-side filter & sort of the fully loaded list (kept when leaving the page)
+    const [filterText, setFilterText] = useState(() => loadViewState().componentsFilter ?? '');
+    const [sort, setSort] = useState<ComponentSort>(() => loadViewState().componentsSort ?? { field: 'name', direction: 'ASC' });
+
+    useEffect(() => { saveViewState({ componentsFilter: filterText, componentsSort: sort }); }, [filterText, sort]);
+
+    const visibleComponents = useMemo(() => {
+        const needle = filterText.trim().toLowerCase();
+        const filtered = needle
+            ? components.filter(c => c.name.toLowerCase().includes(needle) || (c.description ?? '').toLowerCase().includes(needle))
+            : components;
+        const direction = sort.direction === 'ASC' ? 1 : -1;
+        return [...filtered].sort((a, b) => direction * (sort.field === 'name'
+            ? a.name.localeCompare(b.name)
+            : (a.index_count ?? 0) - (b.index_count ?? 0) || a.name.localeCompare(b.name)));
+    }, [components, filterText, sort]);
 
     // --- Component Logic ---
 
@@ -69,15 +105,16 @@ const ComponentsPage: React.FC = () => {
         setEditingComponent(null);
         setIsComponentFormOpen(true);
     }, []);
+    useCreateShortcut(handleCreateComponent, isAdmin);
 
     const handleDeleteComponent = useCallback(async (componentId: number) => {
         if (!isAdmin) { toast.error(t('componentAdminRequiredError', preferredLanguage)); return; }
         if (!token) { toast.error(t('componentAuthTokenMissingError', preferredLanguage)); return; }
-        if (!window.confirm(t('confirmDeleteComponentMessage', preferredLanguage))) return;
+        if (!await confirm({ message: t('confirmDeleteComponentMessage', preferredLanguage) })) return;
 
-        setIsComponentsLoading(true); setComponentsError(null);
+        setComponentsError(null);
         try {
-            await api.deleteSignatureComponent(componentId, token);
+            await runBusy(componentId, 'delete', () => api.deleteSignatureComponent(componentId, token));
             toast.success(t('componentDeletedSuccess', preferredLanguage));
             // Refetch after successful delete
             await fetchComponents();
@@ -85,30 +122,102 @@ const ComponentsPage: React.FC = () => {
             const msg = e.message || "Failed";
             setComponentsError(t('componentDeleteFailedError', preferredLanguage));
             toast.error(t('errorMessageTemplate', preferredLanguage, { message: t('componentDeleteFailedError', preferredLanguage) + `: ${msg}` }));
-            setIsComponentsLoading(false); // Stop loading on error
         }
-        // Loading state will be reset by fetchComponents on success
-    }, [isAdmin, token, fetchComponents, preferredLanguage]); // Add preferredLanguage
+    }, [isAdmin, token, fetchComponents, preferredLanguage, confirm, runBusy]); // Add preferredLanguage
 
-    const handleReindexComponent = useCallback(async (componentId: number) => {
+    // Reindexing always goes through the preview dialog, which calls this once the user confirms
+    const handleOpenReindexPreview = useCallback((componentId: number) => {
         if (!isAdmin) { toast.error(t('componentAdminRequiredError', preferredLanguage)); return; }
-        if (!token) { toast.error(t('componentAuthTokenMissingError', preferredLanguage)); return; }
-        if (!window.confirm(t('confirmReindexComponentMessage', preferredLanguage, { componentId }))) return;
+        setReindexPreviewComponent(components.find(c => c.signatureComponentId === componentId) ?? null);
+    }, [isAdmin, components, preferredLanguage]);
+
+    const handleReindexComponent = useCallback(async (componentId: number): Promise<boolean> => {
+        if (!isAdmin) { toast.error(t('componentAdminRequiredError', preferredLanguage)); return false; }
+        if (!token) { toast.error(t('componentAuthTokenMissingError', preferredLanguage)); return false; }
 
-        setIsComponentsLoading(true); setComponentsError(null);
+        setComponentsError(null);
         try {
-            await api.reindexComponentElements(componentId, token);
+            await runBusy(componentId, 'reindex', () => api.reindexComponentElements(componentId, token));
+            invalidateAncestryCache(); // Cached elements and signature paths carry the old indexes
             toast.success(t('componentReindexedSuccess', preferredLanguage));
             // Refetch to update counts etc.
             await fetchComponents();
+            return true;
         } catch(e: any) {
             const msg = e.message || "Failed";
             setComponentsError(t('componentReindexFailedError', preferredLanguage));
             toast.error(t('errorMessageTemplate', preferredLanguage, { message: t('componentReindexFailedError', preferredLanguage) + `: ${msg}` }));
-            setIsComponentsLoading(false); // Stop loading on error
+            return false;
+        }
+    }, [isAdmin, token, fetchComponents, preferredLanguage, runBusy]); // Add preferredLanguage
+
+    // One component after another, so a failure only affects that component and progress can be shown
+    const handleReindexAll = useCallback(async () => {
+        if (!isAdmin) { toast.error(t('componentAdminRequiredError', preferredLanguage)); return; }
+        if (!token) { toast.error(t('componentAuthTokenMissingError', preferredLanguage)); return; }
+        if (components.length === 0) return;
+        const confirmed = await confirm({
+            message: t('confirmReindexAllComponentsMessage', preferredLanguage, { count: components.length }),
+            confirmLabel: t('reindexAllComponentsButton', preferredLanguage),
+            destructive: false,
+        });
+        if (!confirmed) return;
+
+        setComponentsError(null);
+        const targets = [...components];
+        const failedNames: string[] = [];
+        setReindexAllProgress({ done: 0, total: targets.length });
+        for (const [i, component] of targets.entries()) {
+            const componentId = component.signatureComponentId!;
+            try {
+                await runBusy(componentId, 'reindex', () => api.reindexComponentElements(componentId, token));
+            } catch (e) {
+                console.error(`Reindex Component ${componentId} Error:`, e);
+                failedNames.push(component.name);
+            }
+            setReindexAllProgress({ done: i + 1, total: targets.length });
+        }
+        setReindexAllProgress(null);
+        invalidateAncestryCache();
+
+        const reindexed = targets.length - failedNames.length;
+        if (reindexed > 0) toast.success(t('reindexAllComponentsSummary', preferredLanguage, { count: reindexed }));
+        if (failedNames.length > 0) toast.error(t('reindexAllComponentsFailures', preferredLanguage, { failed: failedNames.length, names: failedNames.join(', ') }));
+        await fetchComponents();
+    }, [isAdmin, token, components, fetchComponents, preferredLanguage, confirm, runBusy]);
+
+    // Export for moving a signature scheme to another instance (imported again through ComponentImportDialog)
+    const exportComponents = useCallback(async (targets: SignatureComponent[], fileName: string) => {
+        if (!token) { toast.error(t('componentAuthTokenMissingError', preferredLanguage)); return; }
+        try {
+            const data = await buildSignatureExport(targets, token);
+            downloadTextFile(JSON.stringify(data, null, 2), fileName, 'application/json;charset=utf-8');
+            const elementCount = data.components.reduce((sum, c) => sum + c.elements.length, 0);
+            toast.success(t('componentsExportedSuccess', preferredLanguage, { components: data.components.length, elements: elementCount }));
+        } catch (e: any) {
+            console.error("Export Components Error:", e);
+            toast.error(t('errorMessageTemplate', preferredLanguage, { message: t('componentsExportFailedError', preferredLanguage) + `: ${e.message || "Failed"}` }));
         }
-        // Loading state will be reset by fetchComponents on success
-    }, [isAdmin, token, fetchComponents, preferredLanguage]); // Add preferredLanguage
+    }, [token, preferredLanguage]);
+
+    const handleExportComponent = useCallback((component: SignatureComponent) => {
+        const slug = component.name.replace(/[^\p{L}\p{N}]+/gu, '-').replace(/^-|-$/g, '') || 'component';
+        runBusy(component.signatureComponentId!, 'export', () => exportComponents([component], `signature-component-${slug}.json`));
+    }, [exportComponents, runBusy]);
+
+    const handleExportAll = useCallback(async () => {
+        setIsExportingAll(true);
+        try {
+            await exportComponents(components, `signature-components-${new Date().toISOString().slice(0, 10)}.json`);
+        } finally {
+            setIsExportingAll(false);
+        }
+    }, [exportComponents, components]);
+
+    const handleImportFinished = useCallback(() => {
+        invalidateAncestryCache();
+        fetchComponents();
+    }, [fetchComponents]);
 
     const handleComponentSaveSuccess = useCallback(() => {
         setIsComponentFormOpen(false);
@@ -134,7 +243,7 @@ const ComponentsPage: React.FC = () => {
             </div>
 
             {/* Components Section */}
-            <Card>
+            <Card data-help="list">
                 <CardHeader>
                      <div className="flex flex-col sm:flex-row justify-between items-start sm:items-center gap-4">
                          <div>
@@ -142,11 +251,31 @@ const ComponentsPage: React.FC = () => {
                              <CardTitle>{t('componentsTitle', preferredLanguage)}</CardTitle>
                              <CardDescription>{t('clickComponentToViewElements', preferredLanguage)}</CardDescription>
                          </div>
+                         <div className='flex flex-wrap items-center gap-2 shrink-0'>
+                         {isAdmin && (
+                             <Button variant="outline" size="sm" onClick={handleReindexAll} disabled={!!reindexAllProgress || components.length === 0} title={t('reindexAllComponentsTooltip', preferredLanguage)}>
+                                 {reindexAllProgress ? <LoadingSpinner size="sm" className="mr-2" /> : <ListRestart className="mr-2 h-4 w-4" />}
+                                 {reindexAllProgress
+                                     ? t('reindexAllComponentsProgress', preferredLanguage, { done: reindexAllProgress.done, total: reindexAllProgress.total })
+                                     : t('reindexAllComponentsButton', preferredLanguage)}
+                             </Button>
+                         )}
+                         {isAdmin && (
+                             <>
+                                 <Button variant="outline" size="sm" onClick={handleExportAll} disabled={isExportingAll || components.length === 0} title={t('exportAllComponentsTooltip', preferredLanguage)}>
+                                     {isExportingAll ? <LoadingSpinner size="sm" className="mr-2" /> : <Download className="mr-2 h-4 w-4" />}
+                                     {t('exportAllComponentsButton', preferredLanguage)}
+                                 </Button>
+                                 <Button variant="outline" size="sm" onClick={() => setIsImportOpen(true)} title={t('signatureImportTitle', preferredLanguage)}>
+                                     <Upload className="mr-2 h-4 w-4" /> {t('importComponentsButton', preferredLanguage)}
+                                 </Button>
+                             </>
+                         )}
                          {isAdmin ? (
-                             <Dialog open={isComponentFormOpen} onOpenChange={setIsComponentFormOpen}>
+                             <Dialog open={isComponentFormOpen} onOpenChange={handleComponentFormOpenChange}>
                                  <DialogTrigger asChild>
                                      {/* Use translated button text */}
-                                     <Button onClick={handleCreateComponent} size="sm" className='shrink-0'>
+                                     <Button onClick={handleCreateComponent} size="sm" className='shrink-0' data-help="create">
                                          <PlusCircle className="mr-2 h-4 w-4" /> {t('newComponentButton', preferredLanguage)}
                                      </Button>
                                  </DialogTrigger>
@@ -157,32 +286,64 @@ const ComponentsPage: React.FC = () => {
                                  </DialogContent>
                              </Dialog>
                          ) : (
-                             <Button size="sm" className='shrink-0' disabled title={t('componentAdminRequiredError', preferredLanguage)}>
+                             <Button size="sm" className='shrink-0' disabled title={t('componentAdminRequiredError', preferredLanguage)} data-help="create">
                                 <PlusCircle className="mr-2 h-4 w-4" /> {t('newComponentButton', preferredLanguage)}
                              </Button>
                          )}
+                         </div>
                      </div>
                 </CardHeader>
-                <CardContent>
+                <CardContent className='space-y-4'>
                     {componentsError && <ErrorDisplay message={componentsError} />}
+                    {components.length > 0 && (
+                        <div className="relative max-w-sm">
+                            <Search className="absolute left-2.5 top-2.5 h-4 w-4 text-muted-foreground" />
+                            <Input
+                                type="search"
+                                value={filterText}
+                                onChange={(e) => setFilterText(e.target.value)}
+                                placeholder={t('componentFilterPlaceholder', preferredLanguage)}
+                                aria-label={t('componentFilterPlaceholder', preferredLanguage)}
+                                className="pl-8"
+                            />
+                        </div>
+                    )}
+                    <LoadingStatus isLoading={isComponentsLoading} label={t('componentsTitle', preferredLanguage)} count={components.length} />
                     {isComponentsLoading && <div className='flex justify-center py-6'><LoadingSpinner /></div>}
                     {!isComponentsLoading && !componentsError && (
                         // Pass handleOpenComponent instead of onSelect
                         <ComponentList
-                            components={components}
+                            components={visibleComponents}
+                            sort={sort}
+                            onSortChange={setSort}
                             onEdit={handleEditComponent}
                             onDelete={handleDeleteComponent}
                             onOpen={handleOpenComponent} // Changed prop name
-                            onReindex={handleReindexComponent}
+                            onReindex={handleOpenReindexPreview}
+                            onExport={handleExportComponent}
+                            busyRows={busyRows}
                          />
                     )}
                      {/* Use translated empty state */}
                     {!isComponentsLoading && !componentsError && components.length === 0 && (
-                        <p className="text-center text-muted-foreground py-4">{t('noComponentsFound', preferredLanguage)} {isAdmin ? t('clickToCreate', preferredLanguage, { item: t('newComponentButton', preferredLanguage) }) : ''}</p> // TODO: Add clickToCreate key
+                        <EmptyState icon={Boxes} title={t('noComponentsFound', preferredLanguage)} description={t('componentsEmptyDescription', preferredLanguage)}
+                            action={isAdmin ? { label: t('componentsCreateFirstButton', preferredLanguage), onClick: handleCreateComponent } : undefined} />
+                    )}
+                    {!isComponentsLoading && !componentsError && components.length > 0 && visibleComponents.length === 0 && (
+                        <EmptyState icon={SearchX} title={t('componentFilterNoMatches', preferredLanguage, { filter: filterText.trim() })} />
                     )}
                 </CardContent>
             </Card>
 
+            <ReindexPreviewDialog
+                isOpen={!!reindexPreviewComponent}
+                onOpenChange={(open) => { if (!open) setReindexPreviewComponent(null); }}
+                component={reindexPreviewComponent}
+                onConfirm={handleReindexComponent}
+            />
+
+            <ComponentImportDialog isOpen={isImportOpen} onOpenChange={setIsImportOpen} onImported={handleImportFinished} />
+
             {/* Element section is removed from this page */}
 
         </div>
@@ -0,0 +1,130 @@
+import React, { useState, useEffect } from 'react';
+import { ChevronRight } from 'lucide-react';
+import { Label } from '@/components/ui/label';
+import { Badge } from '@/components/ui/badge';
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import { useAuth } from '@/hooks/useAuth';
+import api from '@/lib/api';
+import { invalidateCachedSignatureElement } from '@/lib/signaturePaths';
+import { registerCacheReset } from '@/lib/appData';
+import type { SignatureElement } from '../../../../backend/src/functionalities/signature/element/models';
+import { t } from '@/translations/utils'; // Import translation utility
+
+// Module-level cache of resolved elements (with parents and component populated)
+const resolvedElementCache = new Map<number, Promise<SignatureElement>>();
+registerCacheReset(() => resolvedElementCache.clear());
+
+const MAX_ANCESTRY_DEPTH = 20; // Safety net against cyclic parent links
+
+const resolveElement = (elementId: number, token: string): Promise<SignatureElement> => {
+    let cached = resolvedElementCache.get(elementId);
+    if (!cached) {
+        cached = api.getSignatureElementById(elementId, ['parents', 'component'], token);
+        // Drop failed lookups so they can be retried later
+        cached.catch(() => resolvedElementCache.delete(elementId));
+        resolvedElementCache.set(elementId, cached);
+    }
+    return cached;
+};
+
+/** Clears cached ancestry data, e.g. after elements were edited (the shared element cache along with it). */
+export const invalidateAncestryCache = (elementId?: number) => {
+    if (elementId === undefined) resolvedElementCache.clear();
+    else resolvedElementCache.delete(elementId);
+    invalidateCachedSignatureElement(elementId);
+};
+
+// Builds the path from root down to the given element, following the first parent at each level
+const resolveAncestry = async (elementId: number, token: string): Promise<{ chain: SignatureElement[]; hasMultipleParents: boolean }> => {
+    const chain: SignatureElement[] = [];
+    const visited = new Set<number>();
+    let hasMultipleParents = false;
+    let currentId: number | undefined = elementId;
+
+    while (currentId !== undefined && !visited.has(currentId) && chain.length < MAX_ANCESTRY_DEPTH) {
+        visited.add(currentId);
+        const element = await resolveElement(currentId, token);
+        chain.unshift(element);
+        const parents = element.parentElements ?? [];
+        if (parents.length > 1) hasMultipleParents = true;
+        currentId = parents[0]?.signatureElementId;
+    }
+    return { chain, hasMultipleParents };
+};
+
+interface ElementAncestryProps {
+    parentIds: number[];
+}
+
+interface AncestryEntry {
+    parentId: number;
+    chain: SignatureElement[];
+    hasMultipleParents: boolean;
+    error?: string;
+}
+
+const ElementAncestry: React.FC<ElementAncestryProps> = ({ parentIds }) => {
+    const { token, preferredLanguage } = useAuth();
+    const [entries, setEntries] = useState<AncestryEntry[]>([]);
+    const [isLoading, setIsLoading] = useState(false);
+
+    useEffect(() => {
+        if (!token || parentIds.length === 0) { setEntries([]); return; }
+        let isCancelled = false;
+        const loadAncestry = async () => {
+            setIsLoading(true);
+            const results = await Promise.all(parentIds.map(async (parentId): Promise<AncestryEntry> => {
+                try {
+                    const { chain, hasMultipleParents } = await resolveAncestry(parentId, token);
+                    return { parentId, chain, hasMultipleParents };
+                } catch (err: any) {
+                    console.error(`Failed to resolve ancestry for element ${parentId}:`, err);
+                    return { parentId, chain: [], hasMultipleParents: false, error: err.message || t('elementLoadDetailsError', preferredLanguage) };
+                }
+            }));
+            if (!isCancelled) {
+                setEntries(results);
+                setIsLoading(false);
+            }
+        };
+        loadAncestry();
+        return () => { isCancelled = true; };
+    }, [parentIds, token, preferredLanguage]);
+
+    if (parentIds.length === 0) return null;
+
+    return (
+        <div className="grid gap-1.5">
+            <Label>{t('elementAncestryLabel', preferredLanguage)}</Label>
+            <div className="p-2 border rounded-md bg-muted/50 space-y-2 text-sm">
+                {isLoading && entries.length === 0 ? (
+                    <div className="flex justify-center py-2"><LoadingSpinner size="sm" /></div>
+                ) : entries.map(entry => (
+                    <div key={entry.parentId} className="flex flex-wrap items-center gap-1">
+                        {entry.error ? (
+                            <span className="text-xs text-destructive">{t('elementAncestryResolveError', preferredLanguage, { id: entry.parentId })}</span>
+                        ) : (
+                            <>
+                                {entry.chain.map((el, idx) => (
+                                    <React.Fragment key={el.signatureElementId}>
+                                        {idx > 0 && <ChevronRight className="h-3 w-3 text-muted-foreground shrink-0" />}
+                                        <Badge variant={idx === entry.chain.length - 1 ? 'secondary' : 'outline'} className="font-normal" title={el.component?.name}>
+                                            {el.component?.name && <span className="text-muted-foreground mr-1">{el.component.name}:</span>}
+                                            {el.index ? `${el.index} ` : ''}{el.name}
+                                        </Badge>
+                                    </React.Fragment>
+                                ))}
+                                {entry.hasMultipleParents && (
+                                    <span className="text-xs text-muted-foreground italic ml-1">{t('elementAncestryMultipleParentsHint', preferredLanguage)}</span>
+                                )}
+                            </>
+                        )}
+                    </div>
+                ))}
+            </div>
+            <p className="text-xs text-muted-foreground">{t('elementAncestryHint', preferredLanguage)}</p>
+        </div>
+    );
+};
+
+export default ElementAncestry;
@@ -5,17 +5,20 @@ import { elementFormSchema } from '@/lib/zodSchemas'; // Use correct schema impo
 import { Button } from '@/components/ui/button';
 import { Input } from '@/components/ui/input';
 import { Label } from '@/components/ui/label';
-import { Textarea } from '@/components/ui/textarea';
+import MarkdownTextarea from '@/components/shared/MarkdownTextarea';
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
 import ElementSelector from './ElementSelector'; // Corrected import path
+import ElementAncestry, { invalidateAncestryCache } from './ElementAncestry';
 import { useAuth } from '@/hooks/useAuth';
 import api from '@/lib/api';
+import { applyServerFieldErrors } from '@/lib/serverFieldErrors';
 import type { SignatureElement, CreateSignatureElementInput, UpdateSignatureElementInput } from '../../../../backend/src/functionalities/signature/element/models'; // Import backend input types
 import type { SignatureComponent } from '../../../../backend/src/functionalities/signature/component/models';
-import { toast } from "sonner";
+import { toast } from '@/lib/toast';
 import { cn } from '@/lib/utils'; // Import cn
 import { Badge } from '@/components/ui/badge'; // Import Badge
+import { RefreshCw } from 'lucide-react';
 import { z } from 'zod'; // Import z for inferring type in onSubmit
 import { t } from '@/translations/utils'; // Import translation utility
 
@@ -35,9 +38,12 @@ const ElementForm: React.FC<ElementFormProps> = ({ elementToEdit, currentCompone
     const [isFetchingDetails, setIsFetchingDetails] = useState(false); // For loading parents
     const [error, setError] = useState<string | null>(null);
     const [selectedParentIds, setSelectedParentIds] = useState<number[]>([]);
+    // Saving without the real parents would overwrite them with an empty list - block it until they load
+    const [parentsLoadFailed, setParentsLoadFailed] = useState(false);
+    const [fetchAttempt, setFetchAttempt] = useState(0); // Bumped by the retry button
 
 
-    const { register, handleSubmit, reset, control, setValue, formState: { errors } } = useForm({ // Remove explicit type here
+    const { register, handleSubmit, reset, control, setValue, watch, setError: setFieldError, formState: { errors } } = useForm({ // Remove explicit type here
         resolver: zodResolver(elementFormSchema),
         defaultValues: {
             name: '',
@@ -53,6 +59,7 @@ const ElementForm: React.FC<ElementFormProps> = ({ elementToEdit, currentCompone
             if (elementToEdit?.signatureElementId && token) {
                  setIsFetchingDetails(true);
                  setError(null);
+                 setParentsLoadFailed(false);
                 try {
                     // Fetch element details including parent IDs
                     const fullElement = await api.getSignatureElementById(elementToEdit.signatureElementId, ['parents'], token);
@@ -77,17 +84,18 @@ const ElementForm: React.FC<ElementFormProps> = ({ elementToEdit, currentCompone
                          parentIds: [],
                      });
                      setSelectedParentIds([]);
+                     setParentsLoadFailed(true);
                 } finally {
                     setIsFetchingDetails(false);
                 }
 
             } else {
                 reset({ name: '', description: '', index: '', parentIds: [] });
-                setSelectedParentIds([]); setError(null); setIsFetchingDetails(false);
+                setSelectedParentIds([]); setError(null); setIsFetchingDetails(false); setParentsLoadFailed(false);
             }
         };
         fetchParentsAndPopulate();
-     }, [elementToEdit, reset, token, preferredLanguage]); // Add preferredLanguage
+     }, [elementToEdit, reset, token, preferredLanguage, fetchAttempt]); // Add preferredLanguage
 
 
      // Update RHF's parentIds when the selector state changes (for validation)
@@ -102,6 +110,10 @@ const ElementForm: React.FC<ElementFormProps> = ({ elementToEdit, currentCompone
             setError(t('componentContextMissingError', preferredLanguage)); // Use translated error
             return;
         }
+        if (elementToEdit && (isFetchingDetails || parentsLoadFailed)) {
+            setError(t('elementParentsNotLoadedError', preferredLanguage));
+            return;
+        }
         setIsLoading(true);
         setError(null);
         let savedElementResult: SignatureElement | null = null; // To store the result
@@ -154,12 +166,16 @@ const ElementForm: React.FC<ElementFormProps> = ({ elementToEdit, currentCompone
             } else {
                  savedElementResult = await api.createSignatureElement(createPayload, token);
             }
+            // Saved element may appear in other elements' ancestry - drop its cached resolution
+            if (savedElementResult?.signatureElementId) invalidateAncestryCache(savedElementResult.signatureElementId);
             onSave(savedElementResult); // Trigger success callback with the result
         } catch (err: any) {
             const msg = err.message || t('elementSaveFailedError', preferredLanguage);
-            setError(msg);
             toast.error(t('errorMessageTemplate', preferredLanguage, { message: msg }));
             console.error("Save Element Error:", err);
+            // Field-specific messages go next to their inputs - keep the form open so they can be fixed
+            if (applyServerFieldErrors(err, ['name', 'description', 'index'] as const, setFieldError) > 0) return;
+            setError(msg);
             onSave(null); // Indicate save failed / pass null
         } finally {
             setIsLoading(false);
@@ -193,7 +209,7 @@ const ElementForm: React.FC<ElementFormProps> = ({ elementToEdit, currentCompone
                      </div>
                      <div className="grid gap-1.5">
                          <Label htmlFor="elem-description">{t('elementDescriptionLabel', preferredLanguage)} {t('optionalLabel', preferredLanguage)}</Label>
-                         <Textarea id="elem-description" {...register('description')} rows={3} aria-invalid={!!errors.description} className={cn(errors.description && "border-destructive")} />
+                         <MarkdownTextarea id="elem-description" {...register('description')} previewValue={watch('description')} rows={3} aria-invalid={!!errors.description} className={cn(errors.description && "border-destructive")} />
                          {errors.description && <p className="text-xs text-destructive">{errors.description.message}</p>}
                      </div>
                      <div className="grid gap-1.5">
@@ -202,6 +218,14 @@ const ElementForm: React.FC<ElementFormProps> = ({ elementToEdit, currentCompone
                          <p className='text-xs text-muted-foreground'>{t('elementIndexHint', preferredLanguage)}</p>
                          {errors.index && <p className="text-xs text-destructive">{errors.index.message}</p>}
                      </div>
+                     {parentsLoadFailed && (
+                         <div className="flex items-center justify-between gap-2 text-sm rounded border border-destructive/50 p-2">
+                             <span className="text-destructive">{t('elementParentsNotLoadedError', preferredLanguage)}</span>
+                             <Button type="button" size="sm" variant="outline" onClick={() => setFetchAttempt(n => n + 1)}>
+                                 <RefreshCw className="mr-2 h-4 w-4" /> {t('elementParentsRetryButton', preferredLanguage)}
+                             </Button>
+                         </div>
+                     )}
                      <div className="grid gap-1.5">
                          <ElementSelector
                              selectedElementIds={selectedParentIds}
@@ -213,6 +237,8 @@ const ElementForm: React.FC<ElementFormProps> = ({ elementToEdit, currentCompone
                          <input type="hidden" {...register('parentIds')} />
                          {errors.parentIds && <p className="text-xs text-destructive">{typeof errors.parentIds.message === 'string' ? errors.parentIds.message : 'Invalid parent selection'}</p>}
                      </div>
+                     {/* Read-only ancestry of the selected parents */}
+                     <ElementAncestry parentIds={selectedParentIds} />
                  </div>
             </div>
              {/* Fixed Footer Area */}
@@ -220,7 +246,7 @@ const ElementForm: React.FC<ElementFormProps> = ({ elementToEdit, currentCompone
                  <Button
                      type="button"
                      onClick={handleSubmit(handleFormSubmit)}
-                     disabled={isLoading || isFetchingDetails}
+                     disabled={isLoading || isFetchingDetails || parentsLoadFailed}
                  >
                      {isLoading ? <LoadingSpinner size="sm" className='mr-2' /> : (elementToEdit ? t('editButton', preferredLanguage) : t('createButton', preferredLanguage))} {t('elementSingularLabel', preferredLanguage)}
                  </Button>
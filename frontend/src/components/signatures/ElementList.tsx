@@ -2,20 +2,30 @@ import React from 'react'; // Import React
 import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from "@/components/ui/table";
 import { Button } from '@/components/ui/button';
 import { Badge } from '@/components/ui/badge';
-import { Edit, Trash2 } from 'lucide-react';
+import { Checkbox } from '@/components/ui/checkbox';
+import { Edit, Trash2, ArrowRightLeft } from 'lucide-react';
 import type { SignatureElement, SignatureElementSearchResult } from '../../../../backend/src/functionalities/signature/element/models';
 import { useAuth } from '@/hooks/useAuth'; // Needed if actions depend on role
 import { cn } from '@/lib/utils'; // Import cn
+import MarkdownText from '@/components/shared/MarkdownText';
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
 import { t } from '@/translations/utils'; // Import translation utility
 
 interface ElementListProps {
   elements: SignatureElementSearchResult[]; // Use search result type which includes parents
   onEdit: (element: SignatureElement) => void;
   onDelete: (elementId: number) => void;
+  onMove?: (element: SignatureElement) => void; // Optional "Move to component..." action
+  highlightedElementId?: number | null; // Row to highlight (e.g. after "jump to index")
+  // Optional multi-select (e.g. for bulk delete) - checkbox column is shown when provided
+  selectedElementIds?: number[];
+  onToggleSelected?: (elementId: number) => void;
+  onToggleAllSelected?: (selectAll: boolean) => void;
+  busyRows?: Record<number, 'delete'>; // Elements with a delete request in flight
 }
 
 // Wrap the functional component definition with React.memo
-const ElementList: React.FC<ElementListProps> = React.memo(({ elements, onEdit, onDelete }) => {
+const ElementList: React.FC<ElementListProps> = React.memo(({ elements, onEdit, onDelete, onMove, highlightedElementId, selectedElementIds = [], onToggleSelected, onToggleAllSelected, busyRows = {} }) => {
   const { user, preferredLanguage } = useAuth(); // Get preferredLanguage
   // Determine if the current user can modify elements (e.g., admin or potentially regular user)
   const canModify = user?.role === 'admin' || user?.role === 'employee'; // Allow admin and employees
@@ -27,23 +37,48 @@ const ElementList: React.FC<ElementListProps> = React.memo(({ elements, onEdit,
 
   console.log("Rendering ElementList"); // Add console log for debugging renders
 
+  const isSelectable = !!onToggleSelected && canModify;
+  const allSelected = elements.every(el => selectedElementIds.includes(el.signatureElementId!));
+
   return (
     // Wrap in div for border and overflow
     <div className="border rounded-lg overflow-hidden">
         <Table>
             <TableHeader>
                 <TableRow>
+                    {isSelectable && (
+                        <TableHead className="w-[40px]">
+                            <Checkbox
+                                checked={allSelected}
+                                onCheckedChange={(checked) => onToggleAllSelected?.(!!checked)}
+                                aria-label={t('elementSelectAllOnPage', preferredLanguage)}
+                            />
+                        </TableHead>
+                    )}
                     {/* Use translated headers */}
                     <TableHead className="w-[80px] text-center">{t('elementIndexLabel', preferredLanguage).split(' (')[0]}</TableHead>
                     <TableHead>{t('elementNameLabel', preferredLanguage)}</TableHead>
                     <TableHead>{t('elementDescriptionLabel', preferredLanguage)}</TableHead>
                     {/* Actions column if user can modify */}
-                    {canModify && <TableHead className="text-right w-[100px]">{t('actionsLabel', preferredLanguage)}</TableHead>}
+                    {canModify && <TableHead className={cn("text-right", onMove ? "w-[140px]" : "w-[100px]")}>{t('actionsLabel', preferredLanguage)}</TableHead>}
                 </TableRow>
             </TableHeader>
             <TableBody>
                 {elements.map((element) => (
-                    <TableRow key={element.signatureElementId}>
+                    <TableRow
+                        key={element.signatureElementId}
+                        id={`element-row-${element.signatureElementId}`}
+                        className={cn(highlightedElementId === element.signatureElementId && 'bg-primary/10 hover:bg-primary/15')}
+                    >
+                        {isSelectable && (
+                            <TableCell>
+                                <Checkbox
+                                    checked={selectedElementIds.includes(element.signatureElementId!)}
+                                    onCheckedChange={() => onToggleSelected!(element.signatureElementId!)}
+                                    aria-label={t('elementSelectRow', preferredLanguage, { name: element.name })}
+                                />
+                            </TableCell>
+                        )}
                         {/* Display index or placeholder */}
                         <TableCell className="font-mono text-center text-sm">
                             {element.index || <i className='text-muted-foreground not-italic'>{t('elementIndexAuto', preferredLanguage)}</i>}
@@ -51,7 +86,7 @@ const ElementList: React.FC<ElementListProps> = React.memo(({ elements, onEdit,
                         <TableCell className="font-medium">{element.name}</TableCell>
                         {/* Truncate description, show placeholder */}
                         <TableCell className='text-sm text-muted-foreground max-w-xs truncate' title={element.description || ''}>
-                            {element.description || <i className='not-italic'>{t('noneLabel', preferredLanguage)}</i>}
+                            {element.description ? <MarkdownText text={element.description} inline /> : <i className='not-italic'>{t('noneLabel', preferredLanguage)}</i>}
                         </TableCell>
                         {/* Action Buttons */}
                         {canModify && (
@@ -60,8 +95,13 @@ const ElementList: React.FC<ElementListProps> = React.memo(({ elements, onEdit,
                                 <Button variant="ghost" size="icon" onClick={() => onEdit(element)} title={t('elementEditButtonTooltip', preferredLanguage)}>
                                     <Edit className="h-4 w-4" />
                                 </Button>
-                                <Button variant="ghost" size="icon" onClick={() => onDelete(element.signatureElementId!)} title={t('elementDeleteButtonTooltip', preferredLanguage)}>
-                                    <Trash2 className="h-4 w-4 text-destructive" />
+                                {onMove && (
+                                    <Button variant="ghost" size="icon" onClick={() => onMove(element)} title={t('moveElementButtonTooltip', preferredLanguage)}>
+                                        <ArrowRightLeft className="h-4 w-4" />
+                                    </Button>
+                                )}
+                                <Button variant="ghost" size="icon" disabled={!!busyRows[element.signatureElementId!]} onClick={() => onDelete(element.signatureElementId!)} title={t('elementDeleteButtonTooltip', preferredLanguage)}>
+                                    {busyRows[element.signatureElementId!] ? <LoadingSpinner size="sm" /> : <Trash2 className="h-4 w-4 text-destructive" />}
                                 </Button>
                             </TableCell>
                         )}
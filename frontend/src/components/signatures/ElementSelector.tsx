@@ -1,17 +1,20 @@
 import React, { useState, useEffect, useMemo } from 'react';
-import { Check, ChevronsUpDown, X } from "lucide-react";
+import { Check, ChevronsUpDown, X, Globe, ListTree } from "lucide-react";
 import { Button } from "@/components/ui/button";
 import { Command, CommandEmpty, CommandGroup, CommandInput, CommandItem, CommandList } from "@/components/ui/command";
 import { Popover, PopoverContent, PopoverTrigger } from "@/components/ui/popover";
 import { Badge } from "@/components/ui/badge";
 import { useAuth } from '@/hooks/useAuth';
 import api from '@/lib/api';
+import { resolveSignatureElements } from '@/lib/signaturePaths';
 import type { SignatureComponent } from '../../../../backend/src/functionalities/signature/component/models';
 import type { SignatureElement } from '../../../../backend/src/functionalities/signature/element/models';
 import { cn } from '@/lib/utils';
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select"; // For Component selection
 import { Label } from '@/components/ui/label'; // Import Label
 import LoadingSpinner from '@/components/shared/LoadingSpinner'; // Import Spinner
+import ElementSearchEverywhere from '@/components/shared/ElementSearchEverywhere';
+import { t } from '@/translations/utils';
 
 interface ElementSelectorProps {
     selectedElementIds: number[]; // IDs of the selected parent elements
@@ -30,7 +33,7 @@ const ElementSelector: React.FC<ElementSelectorProps> = ({
     label = "Select Parent Elements",
     className
 }) => {
-    const { token } = useAuth();
+    const { token, preferredLanguage } = useAuth();
     const [availableComponents, setAvailableComponents] = useState<SignatureComponent[]>([]);
     const [searchComponentId, setSearchComponentId] = useState<string>(""); // Component ID to search within
     const [availableElements, setAvailableElements] = useState<SignatureElement[]>([]); // Elements in the selected component
@@ -41,6 +44,7 @@ const ElementSelector: React.FC<ElementSelectorProps> = ({
     const [open, setOpen] = useState(false);
     const [selectedElementObjects, setSelectedElementObjects] = useState<SignatureElement[]>([]); // Holds full objects for selected IDs for badges
     const [searchTerm, setSearchTerm] = useState(""); // Search within the popover
+    const [searchEverywhere, setSearchEverywhere] = useState(false); // Search all components instead of picking one first
 
     // Fetch Components
     useEffect(() => {
@@ -97,13 +101,9 @@ const ElementSelector: React.FC<ElementSelectorProps> = ({
             }
             setIsLoadingSelectedDetails(true);
             try {
-                 // Fetch details for each selected ID
-                 const detailsPromises = selectedElementIds.map(id =>
-                     api.getSignatureElementById(id, [], token).catch(() => null) // Return null on error for one ID
-                 );
-                 const results = await Promise.all(detailsPromises);
-                 setSelectedElementObjects(results.filter((el): el is SignatureElement => el !== null)
-                                            .sort((a,b) => a.name.localeCompare(b.name))); // Filter out nulls and sort
+                 // Fetch details for all selected IDs in one batch (missing ones are skipped)
+                 const results = await resolveSignatureElements(selectedElementIds, token);
+                 setSelectedElementObjects(results.sort((a,b) => a.name.localeCompare(b.name)));
             } catch (err) {
                  console.error("Failed to fetch selected element details:", err);
                  setSelectedElementObjects([]); // Clear on error
@@ -135,8 +135,16 @@ const ElementSelector: React.FC<ElementSelectorProps> = ({
 
     return (
         <div className={cn('space-y-2 p-3 border rounded bg-muted/30', className)}>
-            <Label className='text-sm font-medium'>{label}</Label> {/* Use Label component */}
+            <div className='flex items-center justify-between gap-2'>
+                <Label className='text-sm font-medium'>{label}</Label> {/* Use Label component */}
+                <Button type="button" variant="ghost" size="sm" className='h-7 px-2 text-xs' onClick={() => setSearchEverywhere(prev => !prev)}>
+                    {searchEverywhere
+                        ? <><ListTree className='mr-1 h-3 w-3' />{t('elementSelectorBrowseByComponent', preferredLanguage)}</>
+                        : <><Globe className='mr-1 h-3 w-3' />{t('elementSearchEverywhereLabel', preferredLanguage)}</>}
+                </Button>
+            </div>
             {/* Component Selector */}
+            {!searchEverywhere && (
             <Select value={searchComponentId} onValueChange={setSearchComponentId} disabled={isLoadingComponents}>
                 <SelectTrigger className='h-9 text-sm'>
                     <SelectValue placeholder="Select Component to find parents..." />
@@ -151,6 +159,7 @@ const ElementSelector: React.FC<ElementSelectorProps> = ({
                      {!isLoadingComponents && availableComponents.length === 0 && <SelectItem value="no-comps" disabled>No components found</SelectItem>}
                 </SelectContent>
             </Select>
+            )}
 
             {/* Element Multi-Select Popover */}
             <Popover open={open} onOpenChange={setOpen}>
@@ -160,10 +169,11 @@ const ElementSelector: React.FC<ElementSelectorProps> = ({
                         role="combobox"
                         aria-expanded={open}
                         className="w-full justify-between min-h-[36px] font-normal" // Ensure min height
-                        disabled={isLoadingElements || !searchComponentId || !!error}
+                        disabled={!searchEverywhere && (isLoadingElements || !searchComponentId || !!error)}
                     >
                         <span className='truncate'>
-                            {isLoadingElements ? 'Loading elements...' :
+                            {searchEverywhere ? t('elementSearchEverywherePlaceholder', preferredLanguage) :
+                             isLoadingElements ? 'Loading elements...' :
                              !searchComponentId ? 'Select component first' :
                              error ? 'Error loading elements' :
                              'Select elements...'}
@@ -172,6 +182,14 @@ const ElementSelector: React.FC<ElementSelectorProps> = ({
                     </Button>
                 </PopoverTrigger>
                 <PopoverContent className="w-[--radix-popover-trigger-width] p-0">
+                    {searchEverywhere ? (
+                        <ElementSearchEverywhere
+                            onSelect={(el) => handleSelectElement(el.signatureElementId!)}
+                            selectedElementIds={selectedElementIds}
+                            excludeElementIds={currentElementId ? [currentElementId] : []}
+                            excludeComponentId={currentComponentId}
+                        />
+                    ) : (
                     <Command shouldFilter={false}> {/* Manual filtering */}
                         <CommandInput
                             placeholder="Search elements..."
@@ -206,6 +224,7 @@ const ElementSelector: React.FC<ElementSelectorProps> = ({
                              )}
                         </CommandList>
                     </Command>
+                    )}
                 </PopoverContent>
             </Popover>
 
@@ -0,0 +1,178 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Button } from '@/components/ui/button';
+import { Badge } from '@/components/ui/badge';
+import { ChevronRight, ChevronDown, Dot, Edit, Trash2, ArrowRightLeft, RefreshCw, ListTree } from 'lucide-react';
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import EmptyState from '@/components/shared/EmptyState';
+import MarkdownText from '@/components/shared/MarkdownText';
+import { useAuth } from '@/hooks/useAuth';
+import api from '@/lib/api';
+import type { SignatureElement, SignatureElementSearchResult } from '../../../../backend/src/functionalities/signature/element/models';
+import type { SearchQueryElement } from '../../../../backend/src/utils/search';
+import { t } from '@/translations/utils';
+
+const TREE_FETCH_PAGE_SIZE = 200;
+const ROOT_KEY = 'root';
+
+interface ElementTreeProps {
+    componentId: number;
+    onEdit: (element: SignatureElement) => void;
+    onDelete: (elementId: number) => void;
+    onMove?: (element: SignatureElement) => void;
+    reloadKey?: number; // Bump to refetch loaded levels (e.g. after an element was saved or deleted)
+    busyRows?: Record<number, 'delete'>; // Elements with a delete request in flight
+}
+
+const compareElements = (a: SignatureElement, b: SignatureElement): number => {
+    const valA = a.index ?? a.name ?? '';
+    const valB = b.index ?? b.name ?? '';
+    const numA = Number(valA);
+    const numB = Number(valB);
+    if (!isNaN(numA) && !isNaN(numB)) return numA - numB;
+    return valA.localeCompare(valB);
+};
+
+// Collapsible tree of a component's elements: roots are elements without parents inside the
+// component, children of a node are fetched (and cached) the first time it is expanded
+const ElementTree: React.FC<ElementTreeProps> = ({ componentId, onEdit, onDelete, onMove, reloadKey = 0, busyRows = {} }) => {
+    const { token, user, preferredLanguage } = useAuth();
+    const canModify = user?.role === 'admin' || user?.role === 'employee';
+
+    // Keyed by parent element id (or ROOT_KEY); missing = not loaded yet
+    const [childrenByKey, setChildrenByKey] = useState<Record<string, SignatureElementSearchResult[]>>({});
+    const [loadingKeys, setLoadingKeys] = useState<string[]>([]);
+    const [failedKeys, setFailedKeys] = useState<string[]>([]);
+    const [expandedIds, setExpandedIds] = useState<number[]>([]);
+
+    const loadLevel = useCallback(async (key: string) => {
+        if (!token) return;
+        const componentFilter: SearchQueryElement = { field: 'signatureComponentId', condition: 'EQ', value: componentId, not: false };
+        const levelFilter: SearchQueryElement = key === ROOT_KEY
+            ? { field: 'hasParentsInComponent', condition: 'EQ', value: false, not: false }
+            : { field: 'parentIds', condition: 'ANY_OF', value: [Number(key)], not: false };
+        setLoadingKeys(prev => [...prev.filter(k => k !== key), key]);
+        setFailedKeys(prev => prev.filter(k => k !== key));
+        try {
+            // A level can be larger than one page - fetch until the last one
+            const level: SignatureElementSearchResult[] = [];
+            for (let page = 1; ; page++) {
+                const response = await api.searchSignatureElements({ query: [componentFilter, levelFilter], page, pageSize: TREE_FETCH_PAGE_SIZE }, token);
+                level.push(...response.data);
+                if (page >= response.totalPages) break;
+            }
+            setChildrenByKey(prev => ({ ...prev, [key]: level.sort(compareElements) }));
+        } catch (err) {
+            console.error(`ElementTree: Failed to load level "${key}" of component ${componentId}:`, err);
+            setFailedKeys(prev => [...prev, key]);
+        } finally {
+            setLoadingKeys(prev => prev.filter(k => k !== key));
+        }
+    }, [token, componentId]);
+
+    // Switching components starts from a collapsed tree
+    useEffect(() => {
+        setChildrenByKey({});
+        setExpandedIds([]);
+    }, [componentId]);
+
+    // (Re)load the roots and every expanded level, keeping the expansion state
+    useEffect(() => {
+        loadLevel(ROOT_KEY);
+        expandedIds.forEach(id => loadLevel(String(id)));
+        // Expansion state is only read here - expanding a node loads its level itself
+    }, [loadLevel, reloadKey]);
+
+    const toggleExpanded = (elementId: number) => {
+        if (expandedIds.includes(elementId)) {
+            setExpandedIds(prev => prev.filter(id => id !== elementId));
+            return;
+        }
+        setExpandedIds(prev => [...prev, elementId]);
+        if (!childrenByKey[String(elementId)]) loadLevel(String(elementId));
+    };
+
+    const renderLevel = (key: string, depth: number, ancestorIds: number[]): React.ReactNode => {
+        const level = childrenByKey[key];
+        if (failedKeys.includes(key)) {
+            return (
+                <div className="flex items-center gap-2 py-1 text-sm text-destructive" style={{ paddingLeft: depth * 20 + 28 }}>
+                    {t('elementTreeLoadFailed', preferredLanguage)}
+                    <Button variant="ghost" size="sm" className="h-6 px-2" onClick={() => loadLevel(key)}>
+                        <RefreshCw className="mr-1 h-3 w-3" /> {t('elementParentsRetryButton', preferredLanguage)}
+                    </Button>
+                </div>
+            );
+        }
+        if (!level) {
+            return loadingKeys.includes(key)
+                ? <div className="py-1" style={{ paddingLeft: depth * 20 + 28 }}><LoadingSpinner size="sm" /></div>
+                : null;
+        }
+        return level.map(element => {
+            const elementId = element.signatureElementId!;
+            const childKey = String(elementId);
+            // Parent links may form a cycle - never expand an element inside its own subtree
+            const isCycle = ancestorIds.includes(elementId);
+            const isExpanded = !isCycle && expandedIds.includes(elementId);
+            const isLeaf = isCycle || childrenByKey[childKey]?.length === 0;
+            return (
+                <React.Fragment key={`${key}-${elementId}`}>
+                    <div
+                        className="flex items-center gap-2 rounded px-1 py-1 hover:bg-muted/50"
+                        style={{ paddingLeft: depth * 20 + 4 }}
+                        role="treeitem"
+                        aria-expanded={isLeaf ? undefined : isExpanded}
+                        aria-level={depth + 1}
+                    >
+                        {isLeaf ? (
+                            <Dot className="h-4 w-4 shrink-0 text-muted-foreground" />
+                        ) : (
+                            <button
+                                type="button"
+                                className="shrink-0 text-muted-foreground hover:text-foreground"
+                                onClick={() => toggleExpanded(elementId)}
+                                aria-label={t(isExpanded ? 'elementTreeCollapse' : 'elementTreeExpand', preferredLanguage, { name: element.name })}
+                            >
+                                {isExpanded ? <ChevronDown className="h-4 w-4" /> : <ChevronRight className="h-4 w-4" />}
+                            </button>
+                        )}
+                        <Badge variant="outline" className="font-mono text-xs">{element.index || t('elementIndexAuto', preferredLanguage)}</Badge>
+                        <span className="font-medium">{element.name}</span>
+                        {element.description && (
+                            <span className="hidden md:inline truncate text-sm text-muted-foreground max-w-md" title={element.description}>
+                                <MarkdownText text={element.description} inline />
+                            </span>
+                        )}
+                        {canModify && (
+                            <div className="ml-auto flex shrink-0 gap-1">
+                                <Button variant="ghost" size="icon" className="h-7 w-7" onClick={() => onEdit(element)} title={t('elementEditButtonTooltip', preferredLanguage)}>
+                                    <Edit className="h-4 w-4" />
+                                </Button>
+                                {onMove && (
+                                    <Button variant="ghost" size="icon" className="h-7 w-7" onClick={() => onMove(element)} title={t('moveElementButtonTooltip', preferredLanguage)}>
+                                        <ArrowRightLeft className="h-4 w-4" />
+                                    </Button>
+                                )}
+                                <Button variant="ghost" size="icon" className="h-7 w-7" disabled={!!busyRows[elementId]} onClick={() => onDelete(elementId)} title={t('elementDeleteButtonTooltip', preferredLanguage)}>
+                                    {busyRows[elementId] ? <LoadingSpinner size="sm" /> : <Trash2 className="h-4 w-4 text-destructive" />}
+                                </Button>
+                            </div>
+                        )}
+                    </div>
+                    {isExpanded && renderLevel(childKey, depth + 1, [...ancestorIds, elementId])}
+                </React.Fragment>
+            );
+        });
+    };
+
+    const roots = childrenByKey[ROOT_KEY];
+    return (
+        <div className="border rounded-lg p-2" role="tree" aria-label={t('elementTreeViewLabel', preferredLanguage)}>
+            {roots && roots.length === 0 && !loadingKeys.includes(ROOT_KEY)
+                ? <EmptyState icon={ListTree} title={t('noElementsFoundInComponent', preferredLanguage)} description={t('elementsEmptyDescription', preferredLanguage)} />
+                : renderLevel(ROOT_KEY, 0, [])}
+        </div>
+    );
+};
+
+export default ElementTree;
@@ -1,22 +1,37 @@
-import React, { useState, useEffect, useCallback } from 'react';
+import React, { useState, useEffect, useCallback, useMemo } from 'react';
 import { useParams, useNavigate, Link } from 'react-router-dom';
 import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
 import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogTrigger } from '@/components/ui/dialog';
-import { PlusCircle, ArrowLeft } from 'lucide-react';
+import { PlusCircle, ArrowLeft, Hash, Trash2, Merge, List, Network, ListTree, SearchX } from 'lucide-react';
 import ElementList from './ElementList';
+import ElementTree from './ElementTree';
 import ElementForm from './ElementForm';
+import MoveElementDialog from './MoveElementDialog';
+import MergeDuplicateElementsDialog from './MergeDuplicateElementsDialog';
+import { invalidateAncestryCache } from './ElementAncestry';
 import SearchBar, { type SearchFieldOption } from '@/components/shared/SearchBar';
-import { Pagination } from '@/components/shared/Pagination';
+import { Pagination, isPastLastPage } from '@/components/shared/Pagination';
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import EmptyState from '@/components/shared/EmptyState';
+import LoadingStatus from '@/components/shared/LoadingStatus';
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
 import { useAuth } from '@/hooks/useAuth';
+import { useConfirm } from '@/hooks/useConfirm';
+import { useSaveGuardedOpenChange } from '@/hooks/usePendingRequests';
+import { useCreateShortcut } from '@/hooks/useCreateShortcut';
+import { useBusyRows } from '@/hooks/useBusyRows';
 import api from '@/lib/api';
+import { cachedSearch, prefetchAdjacentPages } from '@/lib/pageCache';
+import { loadViewState, saveViewState } from '@/lib/viewState';
+import { cacheSignatureElements } from '@/lib/signaturePaths';
 import type { SignatureComponent } from '../../../../backend/src/functionalities/signature/component/models';
 import type { SignatureElement, SignatureElementSearchResult } from '../../../../backend/src/functionalities/signature/element/models';
 import type { SearchRequest, SearchResponse, SearchQueryElement } from '../../../../backend/src/utils/search';
-import { toast } from "sonner";
+import { toast } from '@/lib/toast';
 import { Card, CardHeader, CardTitle, CardContent, CardDescription } from '@/components/ui/card';
 import { Badge } from '@/components/ui/badge'; // Import Badge
+import { ToggleGroup, ToggleGroupItem } from '@/components/ui/toggle-group';
 import { t } from '@/translations/utils'; // Import translation utility
 
 const ELEMENTS_PAGE_SIZE = 15;
@@ -27,6 +42,8 @@ const ElementsPage: React.FC = () => {
     const componentId = parseInt(componentIdStr || '', 10);
     const navigate = useNavigate();
     const { token, user, preferredLanguage } = useAuth(); // Get preferredLanguage
+    const confirm = useConfirm();
+    const { busyRows, runBusy } = useBusyRows();
     const isAdmin = user?.role === 'admin';
     const canModify = isAdmin || user?.role === 'employee'; // Define modification permission
 
@@ -41,10 +58,26 @@ const ElementsPage: React.FC = () => {
     const [elementsError, setElementsError] = useState<string | null>(null);
     const [editingElement, setEditingElement] = useState<SignatureElement | null>(null);
     const [isElementFormOpen, setIsElementFormOpen] = useState(false);
+    const handleElementFormOpenChange = useSaveGuardedOpenChange(setIsElementFormOpen);
+    const [movingElement, setMovingElement] = useState<SignatureElement | null>(null); // Element being moved to another component
     const [elementSearchQuery, setElementSearchQuery] = useState<SearchRequest['query']>([]);
     const [currentElementPage, setCurrentElementPage] = useState(1);
     const [totalElements, setTotalElements] = useState(0);
     const [totalElementPages, setTotalElementPages] = useState(1);
+    // --- Jump to index ---
+    const [jumpIndex, setJumpIndex] = useState('');
+    const [isJumping, setIsJumping] = useState(false);
+    const [highlightedElementId, setHighlightedElementId] = useState<number | null>(null);
+    // --- Bulk delete (selection survives paging; names are kept for the failure summary) ---
+    const [selectedElements, setSelectedElements] = useState<Record<number, string>>({});
+    const selectedElementIds = useMemo(() => Object.keys(selectedElements).map(Number), [selectedElements]);
+    const [bulkDeleteProgress, setBulkDeleteProgress] = useState<{ done: number, total: number } | null>(null);
+    const [isMergeDialogOpen, setIsMergeDialogOpen] = useState(false); // Admin-only duplicate merge tool
+    // --- Flat list or parent/child tree (tree levels are loaded by ElementTree itself) ---
+    const [viewMode, setViewMode] = useState<'list' | 'tree'>(() => loadViewState().elementsViewMode ?? 'list');
+    const [treeReloadKey, setTreeReloadKey] = useState(0);
+
+    useEffect(() => { saveViewState({ elementsViewMode: viewMode }); }, [viewMode]);
 
     // Fetch Parent Component Details
     useEffect(() => {
@@ -85,11 +118,14 @@ const ElementsPage: React.FC = () => {
             const componentFilter: SearchQueryElement = { field: 'signatureComponentId', condition: 'EQ', value: componentId, not: false };
             const finalQuery = [...query.filter(q => q.field !== 'signatureComponentId'), componentFilter];
             const searchRequest: SearchRequest = { query: finalQuery, page, pageSize: ELEMENTS_PAGE_SIZE };
-            const response = await api.searchSignatureElements(searchRequest, token);
+            const response = await cachedSearch('elements', api.searchSignatureElements, searchRequest, token);
+            if (isPastLastPage(response)) { setCurrentElementPage(response.totalPages); return; } // Refetches the last page
             setElements(response.data);
+            cacheSignatureElements(response.data);
             setTotalElements(response.totalSize);
             setTotalElementPages(response.totalPages);
             setCurrentElementPage(response.page);
+            prefetchAdjacentPages('elements', api.searchSignatureElements, { ...searchRequest, page: response.page }, response.totalPages, token);
         } catch (err: any) {
              const msg = err.message || t('elementFetchFailedError', preferredLanguage); // Use translated error
              setElementsError(msg);
@@ -110,6 +146,12 @@ const ElementsPage: React.FC = () => {
         }
     }, [parentComponent, currentElementPage, elementSearchQuery, fetchElements]);
 
+    // Scroll the highlighted element into view once its page is rendered
+    useEffect(() => {
+        if (highlightedElementId === null || isElementsLoading) return;
+        document.getElementById(`element-row-${highlightedElementId}`)?.scrollIntoView({ behavior: 'smooth', block: 'center' });
+    }, [highlightedElementId, isElementsLoading, elements]);
+
     // --- Element CRUD & Other Callbacks ---
     const handleEditElement = useCallback((element: SignatureElement) => {
         if (!canModify) { toast.error(t('insufficientPermissionsError', preferredLanguage)); return; } // Use translated error
@@ -123,18 +165,21 @@ const ElementsPage: React.FC = () => {
         setEditingElement(null);
         setIsElementFormOpen(true);
     }, [canModify, parentComponent, preferredLanguage]); // Add preferredLanguage
+    useCreateShortcut(handleCreateElement, canModify);
 
     const handleDeleteElement = useCallback(async (elementId: number) => {
         if (!canModify) { toast.error(t('insufficientPermissionsError', preferredLanguage)); return; }
         if (!parentComponent || !token) {
             toast.error(t('elementDeletePrereqError', preferredLanguage)); return; // Use translated error
         }
-        if (!window.confirm(t('confirmDeleteElementMessage', preferredLanguage))) return;
+        if (!await confirm({ message: t('confirmDeleteElementMessage', preferredLanguage) })) return;
 
-        setIsElementsLoading(true); setElementsError(null);
+        setElementsError(null);
         try {
-            await api.deleteSignatureElement(elementId, token);
+            await runBusy(elementId, 'delete', () => api.deleteSignatureElement(elementId, token));
+            invalidateAncestryCache(elementId);
             toast.success(t('elementDeletedSuccess', preferredLanguage));
+            setTreeReloadKey(key => key + 1);
             const newTotalElements = totalElements - 1;
             const newTotalPages = Math.max(1, Math.ceil(newTotalElements / ELEMENTS_PAGE_SIZE));
             const newPage = (currentElementPage > newTotalPages) ? newTotalPages : currentElementPage;
@@ -153,10 +198,8 @@ const ElementsPage: React.FC = () => {
             const msg = e.message || "Failed";
             setElementsError(t('elementDeleteFailedError', preferredLanguage));
             toast.error(t('errorMessageTemplate', preferredLanguage, { message: t('elementDeleteFailedError', preferredLanguage) + `: ${msg}` }));
-        } finally {
-            setIsElementsLoading(false);
         }
-    }, [token, parentComponent, totalElements, currentElementPage, elementSearchQuery, fetchElements, canModify, preferredLanguage]); // Add canModify, preferredLanguage
+    }, [token, parentComponent, totalElements, currentElementPage, elementSearchQuery, fetchElements, canModify, preferredLanguage, confirm, runBusy]); // Add canModify, preferredLanguage
 
     const handleElementSaveSuccess = useCallback(async (savedElement: SignatureElement | null) => { // Added parameter
         setIsElementFormOpen(false);
@@ -165,6 +208,7 @@ const ElementsPage: React.FC = () => {
         if (savedElement) {
              toast.success(editingElement ? t('elementUpdatedSuccess', preferredLanguage) : t('elementCreatedSuccess', preferredLanguage, { name: savedElement.name }));
         }
+        if (savedElement) setTreeReloadKey(key => key + 1);
         const currentParentId = parentComponent?.signatureComponentId; // Store ID before potential async operations
 
         // Fixed: Ensure currentParentId is a valid number before proceeding
@@ -189,13 +233,170 @@ const ElementsPage: React.FC = () => {
     }, [parentComponent?.signatureComponentId, currentElementPage, elementSearchQuery, fetchElements, token, editingElement, preferredLanguage]); // Add editingElement, preferredLanguage
 
 
+    const handleMoveElement = useCallback((element: SignatureElement) => {
+        if (!canModify) { toast.error(t('insufficientPermissionsError', preferredLanguage)); return; }
+        setMovingElement(element);
+    }, [canModify, preferredLanguage]);
+
+    const handleElementMoved = useCallback(async (_movedElement: SignatureElement, _targetComponentId: number) => {
+        setMovingElement(null);
+        setTreeReloadKey(key => key + 1);
+        // The element left this component - adjust page if it was the last one on it
+        const newTotalPages = Math.max(1, Math.ceil((totalElements - 1) / ELEMENTS_PAGE_SIZE));
+        const newPage = Math.min(currentElementPage, newTotalPages);
+        await fetchElements(newPage, elementSearchQuery);
+        if (currentElementPage !== newPage) {
+            setCurrentElementPage(newPage);
+        }
+        // Refresh the current component (target component is refetched when navigated to)
+        if (parentComponent?.signatureComponentId && token) {
+            try {
+                const updatedParent = await api.getSignatureComponentById(parentComponent.signatureComponentId, token);
+                setParentComponent(updatedParent);
+            } catch (err) {
+                console.error("Failed to refresh parent component after element move", err);
+                toast.warning(t('parentComponentRefreshError', preferredLanguage));
+            }
+        }
+    }, [totalElements, currentElementPage, elementSearchQuery, fetchElements, parentComponent?.signatureComponentId, token, preferredLanguage]);
+
+    // Find the element with the exact index and switch to the page it is listed on
+    const handleJumpToIndex = useCallback(async () => {
+        const index = jumpIndex.trim();
+        if (!index || !token || isNaN(componentId)) return;
+        setIsJumping(true);
+        try {
+            const componentFilter: SearchQueryElement = { field: 'signatureComponentId', condition: 'EQ', value: componentId, not: false };
+            const found = await api.searchSignatureElements({
+                query: [componentFilter, { field: 'index', condition: 'EQ', value: index, not: false }],
+                page: 1, pageSize: 1,
+            }, token);
+            const target = found.data[0];
+            if (!target?.signatureElementId) {
+                toast.warning(t('elementJumpNotFound', preferredLanguage, { index }));
+                return;
+            }
+            // Results are ordered by ID descending, so the number of listed elements with a
+            // greater ID gives the target's position within the current (filtered) listing
+            const listQuery = [...elementSearchQuery.filter(q => q.field !== 'signatureComponentId'), componentFilter];
+            const [visible, preceding] = await Promise.all([
+                api.searchSignatureElements({ query: [...listQuery, { field: 'signatureElementId', condition: 'EQ', value: target.signatureElementId, not: false }], page: 1, pageSize: 1 }, token),
+                api.searchSignatureElements({ query: [...listQuery, { field: 'signatureElementId', condition: 'GT', value: target.signatureElementId, not: false }], page: 1, pageSize: 1 }, token),
+            ]);
+            if (visible.totalSize === 0) {
+                toast.warning(t('elementJumpFilteredOut', preferredLanguage, { index }));
+                return;
+            }
+            setHighlightedElementId(target.signatureElementId);
+            setCurrentElementPage(Math.floor(preceding.totalSize / ELEMENTS_PAGE_SIZE) + 1);
+            setJumpIndex('');
+        } catch (err: any) {
+            const msg = err.message || t('elementJumpFailedError', preferredLanguage);
+            toast.error(t('errorMessageTemplate', preferredLanguage, { message: msg }));
+            console.error("Jump To Index Error:", err);
+        } finally {
+            setIsJumping(false);
+        }
+    }, [jumpIndex, token, componentId, elementSearchQuery, preferredLanguage]);
+
+    // --- Bulk delete ---
+    const handleToggleElementSelected = useCallback((elementId: number) => {
+        setSelectedElements(({ [elementId]: selectedName, ...rest }) => selectedName !== undefined
+            ? rest
+            : { ...rest, [elementId]: elements.find(el => el.signatureElementId === elementId)?.name ?? `#${elementId}` });
+    }, [elements]);
+
+    // Header checkbox: (de)selects the rows of the current page, selections on other pages are kept
+    const handleToggleAllElementsSelected = useCallback((selectAll: boolean) => {
+        setSelectedElements(prev => {
+            const next = { ...prev };
+            elements.forEach(el => {
+                if (selectAll) next[el.signatureElementId!] = el.name;
+                else delete next[el.signatureElementId!];
+            });
+            return next;
+        });
+    }, [elements]);
+
+    const handleBulkDelete = useCallback(async () => {
+        if (!canModify) { toast.error(t('insufficientPermissionsError', preferredLanguage)); return; }
+        if (!token || !parentComponent || selectedElementIds.length === 0) return;
+        const count = selectedElementIds.length;
+
+        // Check document usage first so the confirmation can warn about referenced elements
+        let usageMessage: string;
+        try {
+            const usage = await api.getSignatureElementsUsage(selectedElementIds, token);
+            const used = usage.filter(u => u.documentCount > 0);
+            const documentCount = used.reduce((sum, u) => sum + u.documentCount, 0);
+            usageMessage = used.length > 0
+                ? t('elementBulkDeleteUsageWarning', preferredLanguage, { usedCount: used.length, count, documentCount })
+                : t('elementBulkDeleteUnusedNote', preferredLanguage);
+        } catch (err: any) {
+            toast.error(t('elementUsageCheckFailed', preferredLanguage, { message: err.message || '' }));
+            console.error("Element Usage Check Error:", err);
+            return;
+        }
+        if (!await confirm({ message: `${t('elementBulkDeleteConfirm', preferredLanguage, { count })}\n\n${usageMessage}`, itemCount: count })) return;
+
+        const failedNames: string[] = [];
+        setBulkDeleteProgress({ done: 0, total: count });
+        for (const [i, elementId] of selectedElementIds.entries()) {
+            try {
+                await runBusy(elementId, 'delete', () => api.deleteSignatureElement(elementId, token));
+                invalidateAncestryCache(elementId);
+            } catch (err) {
+                console.error(`Bulk Delete Element ${elementId} Error:`, err);
+                failedNames.push(selectedElements[elementId] ?? `#${elementId}`);
+            }
+            setBulkDeleteProgress({ done: i + 1, total: count });
+        }
+        setBulkDeleteProgress(null);
+        setSelectedElements({});
+        setTreeReloadKey(key => key + 1);
+
+        const deleted = count - failedNames.length;
+        if (deleted > 0) toast.success(t('elementBulkDeleteSummary', preferredLanguage, { deleted, total: count }));
+        if (failedNames.length > 0) toast.error(t('elementBulkDeleteFailures', preferredLanguage, { failed: failedNames.length, names: failedNames.join(', ') }));
+
+        // Stay within bounds after removing rows, and refresh the component's element count
+        const newTotalPages = Math.max(1, Math.ceil((totalElements - deleted) / ELEMENTS_PAGE_SIZE));
+        const newPage = Math.min(currentElementPage, newTotalPages);
+        await fetchElements(newPage, elementSearchQuery);
+        if (currentElementPage !== newPage) setCurrentElementPage(newPage);
+        try {
+            setParentComponent(await api.getSignatureComponentById(parentComponent.signatureComponentId!, token));
+        } catch (err) {
+            console.error("Failed to refresh parent component after bulk delete", err);
+            toast.warning(t('parentComponentRefreshError', preferredLanguage));
+        }
+    }, [canModify, token, parentComponent, selectedElementIds, selectedElements, totalElements, currentElementPage, elementSearchQuery, fetchElements, preferredLanguage, confirm, runBusy]);
+
+    // Merged duplicates were deleted - refresh the listing and the component's element count
+    const handleElementsMerged = useCallback(async () => {
+        setSelectedElements({});
+        setTreeReloadKey(key => key + 1);
+        await fetchElements(currentElementPage, elementSearchQuery);
+        if (parentComponent?.signatureComponentId && token) {
+            try {
+                setParentComponent(await api.getSignatureComponentById(parentComponent.signatureComponentId, token));
+            } catch (err) {
+                console.error("Failed to refresh parent component after merge", err);
+                toast.warning(t('parentComponentRefreshError', preferredLanguage));
+            }
+        }
+    }, [currentElementPage, elementSearchQuery, fetchElements, parentComponent?.signatureComponentId, token, preferredLanguage]);
+
     // Element Search & Pagination Handlers
     const handleElementSearch = useCallback((newQuery: SearchRequest['query']) => {
         setElementSearchQuery(newQuery);
         setCurrentElementPage(1); // Reset page on new search
+        setHighlightedElementId(null);
+        setSelectedElements({});
     }, []);
     const handleElementPageChange = useCallback((newPage: number) => {
         setCurrentElementPage(newPage);
+        setHighlightedElementId(null);
     }, []);
 
     // --- Render ---
@@ -227,16 +428,22 @@ const ElementsPage: React.FC = () => {
             </div>
 
             {/* Elements Section */}
-            <Card>
+            <Card data-help="list">
                  <CardHeader>
                       <div className="flex flex-col sm:flex-row justify-between items-start sm:items-center gap-4">
                           <div>
                               <CardTitle>{t('elementListElementsHeader', preferredLanguage)}</CardTitle>
                               <CardDescription>{t('elementsDescription', preferredLanguage)}</CardDescription>
                            </div>
-                         <Dialog open={isElementFormOpen} onOpenChange={setIsElementFormOpen}>
+                         <div className="flex gap-2 shrink-0">
+                         {isAdmin && (
+                             <Button variant="outline" size="sm" onClick={() => setIsMergeDialogOpen(true)}>
+                                 <Merge className="mr-2 h-4 w-4" /> {t('elementFindDuplicatesButton', preferredLanguage)}
+                             </Button>
+                         )}
+                         <Dialog open={isElementFormOpen} onOpenChange={handleElementFormOpenChange}>
                             <DialogTrigger asChild>
-                                <Button onClick={handleCreateElement} size="sm" className='shrink-0' disabled={!canModify} title={!canModify ? t('insufficientPermissionsError', preferredLanguage) : ''}>
+                                <Button onClick={handleCreateElement} size="sm" className='shrink-0' data-help="create" disabled={!canModify} title={!canModify ? t('insufficientPermissionsError', preferredLanguage) : ''}>
                                     <PlusCircle className="mr-2 h-4 w-4" /> {t('newElementButton', preferredLanguage)}
                                 </Button>
                             </DialogTrigger>
@@ -252,10 +459,26 @@ const ElementsPage: React.FC = () => {
                                 )}
                             </DialogContent>
                          </Dialog>
+                         </div>
                       </div>
                  </CardHeader>
                  <CardContent className='space-y-4'>
                     {elementsError && <ErrorDisplay message={elementsError} />}
+                    <ToggleGroup type="single" value={viewMode} onValueChange={(value) => { if (value) setViewMode(value as 'list' | 'tree'); }} aria-label={t('elementViewModeLabel', preferredLanguage)} size="sm" className="justify-start">
+                        <ToggleGroupItem value="list" className="gap-1"><List className="h-4 w-4" />{t('elementViewModeList', preferredLanguage)}</ToggleGroupItem>
+                        <ToggleGroupItem value="tree" className="gap-1"><Network className="h-4 w-4" />{t('elementViewModeTree', preferredLanguage)}</ToggleGroupItem>
+                    </ToggleGroup>
+                    {viewMode === 'tree' && (
+                        <ElementTree
+                            componentId={componentId}
+                            onEdit={handleEditElement}
+                            onDelete={handleDeleteElement}
+                            onMove={canModify ? handleMoveElement : undefined}
+                            reloadKey={treeReloadKey}
+                            busyRows={busyRows}
+                        />
+                    )}
+                    {viewMode === 'list' && (<>
                     {/* Search Bar for Elements */}
                      <SearchBar
                         fields={[ // Use translated labels
@@ -267,7 +490,35 @@ const ElementsPage: React.FC = () => {
                         onSearch={handleElementSearch}
                         isLoading={isElementsLoading}
                      />
+                    {/* Jump to index */}
+                    <div className="flex items-center gap-2" data-help="jump-to-index">
+                        <Hash className="h-4 w-4 text-muted-foreground" />
+                        <Input
+                            value={jumpIndex}
+                            onChange={(e) => setJumpIndex(e.target.value)}
+                            onKeyDown={(e) => { if (e.key === 'Enter') { e.preventDefault(); handleJumpToIndex(); } }}
+                            placeholder={t('elementJumpToIndexPlaceholder', preferredLanguage)}
+                            aria-label={t('elementJumpToIndexLabel', preferredLanguage)}
+                            className="h-8 w-44 font-mono text-sm"
+                            disabled={isJumping}
+                        />
+                        {isJumping && <LoadingSpinner size="sm" />}
+                        {/* Bulk actions for selected elements */}
+                        {canModify && (selectedElementIds.length > 0 || bulkDeleteProgress) && (
+                            <div className="ml-auto flex items-center gap-2">
+                                <Button type="button" variant="ghost" size="sm" onClick={() => setSelectedElements({})} disabled={!!bulkDeleteProgress}>
+                                    {t('elementClearSelectionButton', preferredLanguage)}
+                                </Button>
+                                <Button type="button" variant="destructive" size="sm" onClick={handleBulkDelete} disabled={!!bulkDeleteProgress}>
+                                    {bulkDeleteProgress
+                                        ? <><LoadingSpinner size="sm" className="mr-2" />{t('elementBulkDeleteProgress', preferredLanguage, bulkDeleteProgress)}</>
+                                        : <><Trash2 className="mr-2 h-4 w-4" />{t('elementDeleteSelectedButton', preferredLanguage, { count: selectedElementIds.length })}</>}
+                                </Button>
+                            </div>
+                        )}
+                    </div>
                     {/* Element List */}
+                    <LoadingStatus isLoading={isElementsLoading} label={t('elementsTitle', preferredLanguage)} count={elements.length} />
                     {isElementsLoading && <div className='flex justify-center py-10'><LoadingSpinner /></div>}
                     {!isElementsLoading && !elementsError && (
                         <>
@@ -275,23 +526,50 @@ const ElementsPage: React.FC = () => {
                                 elements={elements}
                                 onEdit={handleEditElement}
                                 onDelete={handleDeleteElement}
+                                onMove={canModify ? handleMoveElement : undefined}
+                                highlightedElementId={highlightedElementId}
+                                selectedElementIds={selectedElementIds}
+                                onToggleSelected={canModify ? handleToggleElementSelected : undefined}
+                                onToggleAllSelected={handleToggleAllElementsSelected}
+                                busyRows={busyRows}
                              />
-                            {totalElementPages > 1 && (
-                                <div className="mt-4 flex justify-center">
-                                    <Pagination
-                                        currentPage={currentElementPage}
-                                        totalPages={totalElementPages}
-                                        onPageChange={handleElementPageChange}
-                                     />
-                                </div>
-                             )}
+                            <div className="mt-4 flex justify-center">
+                                <Pagination
+                                    currentPage={currentElementPage}
+                                    totalPages={totalElementPages}
+                                    onPageChange={handleElementPageChange}
+                                    totalItems={totalElements}
+                                    pageSize={ELEMENTS_PAGE_SIZE}
+                                 />
+                            </div>
                               {/* Use translated empty states */}
-                            {elements.length === 0 && elementSearchQuery.length === 0 && ( <p className="text-center text-muted-foreground py-6">{t('noElementsFoundInComponent', preferredLanguage)} {canModify ? t('clickToCreate', preferredLanguage, { item: t('newElementButton', preferredLanguage) }) : ''}</p> )}
-                            {elements.length === 0 && elementSearchQuery.length > 0 && ( <p className="text-center text-muted-foreground py-6">{t('noResultsFound', preferredLanguage)}</p> )}
+                            {elements.length === 0 && elementSearchQuery.length === 0 && (
+                                <EmptyState icon={ListTree} title={t('noElementsFoundInComponent', preferredLanguage)} description={t('elementsEmptyDescription', preferredLanguage)}
+                                    action={canModify ? { label: t('elementsCreateFirstButton', preferredLanguage), onClick: handleCreateElement } : undefined} />
+                            )}
+                            {elements.length === 0 && elementSearchQuery.length > 0 && <EmptyState icon={SearchX} title={t('noResultsFound', preferredLanguage)} />}
                         </>
                     )}
+                    </>)}
                  </CardContent>
             </Card>
+
+            {/* Move Element Dialog */}
+            <MoveElementDialog
+                isOpen={!!movingElement}
+                onOpenChange={(open) => { if (!open) setMovingElement(null); }}
+                element={movingElement}
+                currentComponent={parentComponent}
+                onMoved={handleElementMoved}
+            />
+            {isAdmin && (
+                <MergeDuplicateElementsDialog
+                    isOpen={isMergeDialogOpen}
+                    onOpenChange={setIsMergeDialogOpen}
+                    component={parentComponent}
+                    onMerged={handleElementsMerged}
+                />
+            )}
         </div>
     );
 };
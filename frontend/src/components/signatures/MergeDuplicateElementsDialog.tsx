@@ -0,0 +1,211 @@
+import React, { useState, useEffect, useCallback } from 'react';
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, DialogFooter, DialogClose } from '@/components/ui/dialog';
+import { Button } from '@/components/ui/button';
+import { Badge } from '@/components/ui/badge';
+import { Merge } from 'lucide-react';
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import ErrorDisplay from '@/components/shared/ErrorDisplay';
+import { useAuth } from '@/hooks/useAuth';
+import { useConfirm } from '@/hooks/useConfirm';
+import api from '@/lib/api';
+import type { SignatureElement } from '../../../../backend/src/functionalities/signature/element/models';
+import type { SignatureComponent } from '../../../../backend/src/functionalities/signature/component/models';
+import { toast } from '@/lib/toast';
+import { invalidateAncestryCache } from './ElementAncestry';
+import { cn } from '@/lib/utils';
+import { t } from '@/translations/utils';
+
+interface MergeDuplicateElementsDialogProps {
+    isOpen: boolean;
+    onOpenChange: (open: boolean) => void;
+    component: SignatureComponent;
+    onMerged: () => void; // Called after at least one element was merged away
+}
+
+interface DuplicateGroup {
+    key: string;
+    reason: 'name' | 'index';
+    value: string;
+    elements: SignatureElement[];
+}
+
+// Elements sharing a (case-insensitive) name or an index are likely duplicates.
+// An element can show up in two groups (same name and same index as different elements).
+const findDuplicateGroups = (elements: SignatureElement[]): DuplicateGroup[] => {
+    const groups = new Map<string, DuplicateGroup>();
+    const addTo = (reason: DuplicateGroup['reason'], value: string, element: SignatureElement) => {
+        const key = `${reason}:${value}`;
+        if (!groups.has(key)) groups.set(key, { key, reason, value, elements: [] });
+        groups.get(key)!.elements.push(element);
+    };
+    for (const element of elements) {
+        const name = element.name.trim().toLowerCase();
+        if (name) addTo('name', name, element);
+        const index = element.index?.trim();
+        if (index) addTo('index', index, element);
+    }
+    return [...groups.values()].filter(g => g.elements.length > 1);
+};
+
+const MergeDuplicateElementsDialog: React.FC<MergeDuplicateElementsDialogProps> = ({ isOpen, onOpenChange, component, onMerged }) => {
+    const { token, preferredLanguage } = useAuth();
+    const confirm = useConfirm();
+    const [groups, setGroups] = useState<DuplicateGroup[]>([]);
+    const [documentCounts, setDocumentCounts] = useState<Record<number, number>>({});
+    const [keptIds, setKeptIds] = useState<Record<string, number>>({}); // Group key -> element kept on merge
+    const [isLoading, setIsLoading] = useState(false);
+    const [mergingGroupKey, setMergingGroupKey] = useState<string | null>(null);
+    const [error, setError] = useState<string | null>(null);
+
+    const loadGroups = useCallback(async () => {
+        if (!token || !component.signatureComponentId) return;
+        setIsLoading(true);
+        setError(null);
+        try {
+            const elements = await api.getElementsByComponent(component.signatureComponentId, token);
+            const found = findDuplicateGroups(elements);
+            const ids = [...new Set(found.flatMap(g => g.elements.map(el => el.signatureElementId!)))];
+            const usage = ids.length > 0 ? await api.getSignatureElementsUsage(ids, token) : [];
+            const counts = Object.fromEntries(usage.map(u => [u.signatureElementId, u.documentCount]));
+            // Keep the most referenced element by default (the oldest one on a tie) - fewest documents to rewrite
+            const kept: Record<string, number> = {};
+            for (const group of found) {
+                const best = [...group.elements].sort((a, b) =>
+                    (counts[b.signatureElementId!] ?? 0) - (counts[a.signatureElementId!] ?? 0) || a.signatureElementId! - b.signatureElementId!
+                )[0];
+                kept[group.key] = best.signatureElementId!;
+            }
+            setGroups(found);
+            setDocumentCounts(counts);
+            setKeptIds(kept);
+        } catch (err: any) {
+            const msg = err.message || t('elementDuplicatesLoadError', preferredLanguage);
+            setError(msg);
+            console.error("Load Duplicate Elements Error:", err);
+        } finally {
+            setIsLoading(false);
+        }
+    }, [token, component.signatureComponentId, preferredLanguage]);
+
+    useEffect(() => {
+        if (isOpen) loadGroups();
+    }, [isOpen, loadGroups]);
+
+    const handleMergeGroup = async (group: DuplicateGroup) => {
+        const keptId = keptIds[group.key];
+        const kept = group.elements.find(el => el.signatureElementId === keptId);
+        if (!token || !kept) return;
+        const duplicates = group.elements.filter(el => el.signatureElementId !== keptId);
+        const documentCount = duplicates.reduce((sum, el) => sum + (documentCounts[el.signatureElementId!] ?? 0), 0);
+        const confirmed = await confirm({
+            title: t('elementMergeConfirmTitle', preferredLanguage),
+            message: t('elementMergeConfirmMessage', preferredLanguage, {
+                count: duplicates.length,
+                names: duplicates.map(el => el.name).join(', '),
+                keptName: kept.name,
+                documentCount,
+            }),
+            confirmLabel: t('elementMergeButton', preferredLanguage),
+            itemCount: duplicates.length,
+        });
+        if (!confirmed) return;
+
+        setMergingGroupKey(group.key);
+        let merged = 0;
+        let updatedDocuments = 0;
+        const failedNames: string[] = [];
+        for (const duplicate of duplicates) {
+            try {
+                const result = await api.mergeSignatureElement(duplicate.signatureElementId!, keptId, token);
+                invalidateAncestryCache(duplicate.signatureElementId!);
+                updatedDocuments += result.updatedDocumentCount;
+                merged++;
+            } catch (err) {
+                console.error(`Merge Element ${duplicate.signatureElementId} Error:`, err);
+                failedNames.push(duplicate.name);
+            }
+        }
+        invalidateAncestryCache(keptId);
+        setMergingGroupKey(null);
+
+        if (merged > 0) {
+            toast.success(t('elementMergeSummary', preferredLanguage, { merged, keptName: kept.name, documentCount: updatedDocuments }));
+            onMerged();
+        }
+        if (failedNames.length > 0) toast.error(t('elementMergeFailures', preferredLanguage, { failed: failedNames.length, names: failedNames.join(', ') }));
+        await loadGroups();
+    };
+
+    const isMerging = mergingGroupKey !== null;
+
+    return (
+        <Dialog open={isOpen} onOpenChange={(open) => { if (!isMerging) onOpenChange(open); }}>
+            <DialogContent className="sm:max-w-[650px]">
+                <DialogHeader>
+                    <DialogTitle>{t('elementDuplicatesDialogTitle', preferredLanguage)}</DialogTitle>
+                    <DialogDescription>{t('elementDuplicatesDialogDescription', preferredLanguage, { componentName: component.name })}</DialogDescription>
+                </DialogHeader>
+
+                {error && <ErrorDisplay message={error} />}
+
+                {isLoading ? (
+                    <div className='flex justify-center py-6'><LoadingSpinner /></div>
+                ) : groups.length === 0 ? (
+                    !error && <p className="text-sm text-muted-foreground text-center py-6">{t('elementDuplicatesNoneFound', preferredLanguage)}</p>
+                ) : (
+                    <div className="max-h-[60vh] overflow-y-auto space-y-3 pr-1">
+                        {groups.map(group => (
+                            <div key={group.key} className="border rounded-md p-3 space-y-2">
+                                <div className="flex items-center justify-between gap-2">
+                                    <span className="text-sm font-medium">
+                                        {group.reason === 'name'
+                                            ? t('elementDuplicatesSameName', preferredLanguage, { value: group.elements[0].name.trim() })
+                                            : t('elementDuplicatesSameIndex', preferredLanguage, { value: group.value })}
+                                    </span>
+                                    <Button type="button" size="sm" variant="outline" onClick={() => handleMergeGroup(group)} disabled={isMerging}>
+                                        {mergingGroupKey === group.key ? <LoadingSpinner size="sm" className="mr-2" /> : <Merge className="mr-2 h-4 w-4" />}
+                                        {t('elementMergeButton', preferredLanguage)}
+                                    </Button>
+                                </div>
+                                <p className="text-xs text-muted-foreground">{t('elementDuplicatesKeepHint', preferredLanguage)}</p>
+                                <div className="space-y-1">
+                                    {group.elements.map(el => {
+                                        const isKept = keptIds[group.key] === el.signatureElementId;
+                                        return (
+                                            <label
+                                                key={el.signatureElementId}
+                                                className={cn("flex items-center gap-2 text-sm rounded px-2 py-1 cursor-pointer hover:bg-muted", isKept && "bg-muted")}
+                                            >
+                                                <input
+                                                    type="radio"
+                                                    name={`keep-${group.key}`}
+                                                    checked={isKept}
+                                                    onChange={() => setKeptIds(prev => ({ ...prev, [group.key]: el.signatureElementId! }))}
+                                                    disabled={isMerging}
+                                                />
+                                                <span className="font-mono text-xs text-muted-foreground w-16 shrink-0">{el.index || '-'}</span>
+                                                <span className="flex-1 truncate" title={el.description ?? undefined}>{el.name}</span>
+                                                <Badge variant="outline" className="shrink-0">
+                                                    {t('elementDuplicatesDocumentCount', preferredLanguage, { count: documentCounts[el.signatureElementId!] ?? 0 })}
+                                                </Badge>
+                                                {isKept && <Badge variant="secondary" className="shrink-0">{t('elementDuplicatesKeptBadge', preferredLanguage)}</Badge>}
+                                            </label>
+                                        );
+                                    })}
+                                </div>
+                            </div>
+                        ))}
+                    </div>
+                )}
+
+                <DialogFooter>
+                    <DialogClose asChild>
+                        <Button type="button" variant="outline" disabled={isMerging}>{t('closeButton', preferredLanguage)}</Button>
+                    </DialogClose>
+                </DialogFooter>
+            </DialogContent>
+        </Dialog>
+    );
+};
+
+export default MergeDuplicateElementsDialog;
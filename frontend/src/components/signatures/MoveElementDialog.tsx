@@ -0,0 +1,172 @@
+import React, { useState, useEffect, useMemo } from 'react';
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, DialogFooter, DialogClose } from '@/components/ui/dialog';
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
+import { Button } from '@/components/ui/button';
+import { Label } from '@/components/ui/label';
+import { Checkbox } from '@/components/ui/checkbox';
+import { Badge } from '@/components/ui/badge';
+import { AlertTriangle } from 'lucide-react';
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import ErrorDisplay from '@/components/shared/ErrorDisplay';
+import { useAuth } from '@/hooks/useAuth';
+import api from '@/lib/api';
+import type { SignatureElement, UpdateSignatureElementInput } from '../../../../backend/src/functionalities/signature/element/models';
+import type { SignatureComponent } from '../../../../backend/src/functionalities/signature/component/models';
+import { toast } from '@/lib/toast';
+import { invalidateAncestryCache } from './ElementAncestry';
+import { t } from '@/translations/utils'; // Import translation utility
+
+interface MoveElementDialogProps {
+    isOpen: boolean;
+    onOpenChange: (open: boolean) => void;
+    element: SignatureElement | null;
+    currentComponent: SignatureComponent;
+    // Called with the moved element and the component it now belongs to
+    onMoved: (movedElement: SignatureElement, targetComponentId: number) => void;
+}
+
+const MoveElementDialog: React.FC<MoveElementDialogProps> = ({ isOpen, onOpenChange, element, currentComponent, onMoved }) => {
+    const { token, preferredLanguage } = useAuth();
+    const [components, setComponents] = useState<SignatureComponent[]>([]);
+    const [parents, setParents] = useState<SignatureElement[]>([]);
+    const [targetComponentId, setTargetComponentId] = useState<string>('');
+    const [clearIncompatibleParents, setClearIncompatibleParents] = useState(true);
+    const [isLoading, setIsLoading] = useState(false);
+    const [isMoving, setIsMoving] = useState(false);
+    const [error, setError] = useState<string | null>(null);
+
+    // Load target candidates and the element's current parents when the dialog opens
+    useEffect(() => {
+        if (!isOpen || !element?.signatureElementId || !token) return;
+        const loadData = async () => {
+            setIsLoading(true);
+            setError(null);
+            setTargetComponentId('');
+            setClearIncompatibleParents(true);
+            try {
+                const [allComponents, fullElement] = await Promise.all([
+                    api.getAllSignatureComponents(token),
+                    api.getSignatureElementById(element.signatureElementId!, ['parents'], token),
+                ]);
+                setComponents(
+                    allComponents
+                        .filter(c => c.signatureComponentId !== currentComponent.signatureComponentId)
+                        .sort((a, b) => a.name.localeCompare(b.name))
+                );
+                setParents(fullElement.parentElements ?? []);
+            } catch (err: any) {
+                const msg = err.message || t('elementLoadDetailsError', preferredLanguage);
+                setError(msg);
+                console.error("Load Move Element Data Error:", err);
+            } finally {
+                setIsLoading(false);
+            }
+        };
+        loadData();
+    }, [isOpen, element?.signatureElementId, currentComponent.signatureComponentId, token, preferredLanguage]);
+
+    // Parents living in the target component can't stay parents (elements never parent their own component)
+    const incompatibleParents = useMemo(() => {
+        const targetId = parseInt(targetComponentId, 10);
+        if (isNaN(targetId)) return [];
+        return parents.filter(p => p.signatureComponentId === targetId);
+    }, [parents, targetComponentId]);
+
+    const handleMove = async () => {
+        const targetId = parseInt(targetComponentId, 10);
+        if (!token || !element?.signatureElementId || isNaN(targetId)) return;
+        setIsMoving(true);
+        setError(null);
+        try {
+            const payload: UpdateSignatureElementInput = { signatureComponentId: targetId };
+            if (clearIncompatibleParents && incompatibleParents.length > 0) {
+                const incompatibleIds = new Set(incompatibleParents.map(p => p.signatureElementId));
+                payload.parentIds = parents
+                    .map(p => p.signatureElementId!)
+                    .filter(id => !incompatibleIds.has(id));
+            }
+            const movedElement = await api.updateSignatureElement(element.signatureElementId, payload, token);
+            invalidateAncestryCache(element.signatureElementId);
+            const targetName = components.find(c => c.signatureComponentId === targetId)?.name ?? '';
+            toast.success(t('elementMovedSuccess', preferredLanguage, { name: movedElement.name, componentName: targetName }));
+            onMoved(movedElement, targetId);
+            onOpenChange(false);
+        } catch (err: any) {
+            const msg = err.message || t('elementMoveFailedError', preferredLanguage);
+            setError(msg);
+            toast.error(t('errorMessageTemplate', preferredLanguage, { message: msg }));
+            console.error("Move Element Error:", err);
+        } finally {
+            setIsMoving(false);
+        }
+    };
+
+    return (
+        <Dialog open={isOpen} onOpenChange={onOpenChange}>
+            <DialogContent className="sm:max-w-[500px]">
+                <DialogHeader>
+                    <DialogTitle>{t('moveElementDialogTitle', preferredLanguage)}</DialogTitle>
+                    <DialogDescription>
+                        {t('moveElementDialogDescription', preferredLanguage, { name: element?.name ?? '', componentName: currentComponent.name })}
+                    </DialogDescription>
+                </DialogHeader>
+
+                {error && <ErrorDisplay message={error} />}
+
+                {isLoading ? (
+                    <div className='flex justify-center py-6'><LoadingSpinner /></div>
+                ) : (
+                    <div className="grid gap-4 py-2">
+                        <div className="grid gap-1.5">
+                            <Label htmlFor="move-target-component">{t('moveElementTargetComponentLabel', preferredLanguage)}</Label>
+                            <Select value={targetComponentId} onValueChange={setTargetComponentId} disabled={isMoving || components.length === 0}>
+                                <SelectTrigger id="move-target-component">
+                                    <SelectValue placeholder={components.length === 0 ? t('componentNoComponentsFound', preferredLanguage) : t('selectPlaceholder', preferredLanguage)} />
+                                </SelectTrigger>
+                                <SelectContent>
+                                    {components.map(c => (
+                                        <SelectItem key={c.signatureComponentId} value={String(c.signatureComponentId)}>{c.name}</SelectItem>
+                                    ))}
+                                </SelectContent>
+                            </Select>
+                        </div>
+
+                        {/* Warnings about index and parent relations */}
+                        <div className="flex gap-2 p-3 border rounded-md bg-amber-50 text-amber-900 text-sm">
+                            <AlertTriangle className="h-4 w-4 shrink-0 mt-0.5" />
+                            <div className="space-y-1">
+                                <p>{t('moveElementIndexWarning', preferredLanguage, { index: element?.index || t('elementIndexAuto', preferredLanguage) })}</p>
+                                <p>{t('moveElementParentsWarning', preferredLanguage)}</p>
+                            </div>
+                        </div>
+
+                        {incompatibleParents.length > 0 && (
+                            <div className="space-y-2">
+                                <p className="text-sm">{t('moveElementIncompatibleParentsLabel', preferredLanguage, { count: incompatibleParents.length })}</p>
+                                <div className="flex flex-wrap gap-1">
+                                    {incompatibleParents.map(p => <Badge key={p.signatureElementId} variant="outline">{p.index ? `[${p.index}] ` : ''}{p.name}</Badge>)}
+                                </div>
+                                <div className="flex items-center space-x-2">
+                                    <Checkbox id="move-clear-parents" checked={clearIncompatibleParents} onCheckedChange={(checked) => setClearIncompatibleParents(!!checked)} disabled={isMoving} />
+                                    <Label htmlFor="move-clear-parents" className="text-sm font-normal cursor-pointer">{t('moveElementClearParentsLabel', preferredLanguage)}</Label>
+                                </div>
+                            </div>
+                        )}
+                    </div>
+                )}
+
+                <DialogFooter>
+                    <DialogClose asChild>
+                        <Button type="button" variant="outline" disabled={isMoving}>{t('cancelButton', preferredLanguage)}</Button>
+                    </DialogClose>
+                    <Button type="button" onClick={handleMove} disabled={isLoading || isMoving || !targetComponentId}>
+                        {isMoving && <LoadingSpinner size="sm" className="mr-2" />}
+                        {t('moveElementConfirmButton', preferredLanguage)}
+                    </Button>
+                </DialogFooter>
+            </DialogContent>
+        </Dialog>
+    );
+};
+
+export default MoveElementDialog;
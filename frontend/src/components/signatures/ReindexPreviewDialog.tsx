@@ -0,0 +1,152 @@
+import React, { useState, useEffect, useCallback, useMemo } from 'react';
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, DialogFooter } from '@/components/ui/dialog';
+import { Button } from '@/components/ui/button';
+import { Badge } from '@/components/ui/badge';
+import { ListRestart } from 'lucide-react';
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import ErrorDisplay from '@/components/shared/ErrorDisplay';
+import { useAuth } from '@/hooks/useAuth';
+import api from '@/lib/api';
+import { formatIndex } from '../../../../backend/src/utils/formatIndex';
+import type { SignatureElement } from '../../../../backend/src/functionalities/signature/element/models';
+import type { SignatureComponent } from '../../../../backend/src/functionalities/signature/component/models';
+import { t } from '@/translations/utils';
+
+interface ReindexPreviewDialogProps {
+    isOpen: boolean;
+    onOpenChange: (open: boolean) => void;
+    component: SignatureComponent | null;
+    onConfirm: (componentId: number) => Promise<boolean>; // Runs the actual reindex; the dialog closes when it succeeds
+}
+
+const indexTypeLabelKeys = {
+    dec: 'indexTypeDecimal',
+    roman: 'indexTypeRoman',
+    small_char: 'indexTypeLowerLetter',
+    capital_char: 'indexTypeUpperLetter',
+} as const;
+
+interface IndexPreviewRow {
+    element: SignatureElement;
+    newIndex: string;
+}
+
+// Mirrors the backend's `ORDER BY name COLLATE NOCASE`: only ASCII letters are case-folded, the rest compares by code unit
+const foldAsciiCase = (value: string) => value.replace(/[A-Z]/g, c => c.toLowerCase());
+const compareNoCase = (a: string, b: string) => {
+    const left = foldAsciiCase(a), right = foldAsciiCase(b);
+    return left < right ? -1 : left > right ? 1 : 0;
+};
+
+// Same numbering the reindex endpoint applies: elements by name, counted from 1 in the component's index type
+const computeIndexPreview = (elements: SignatureElement[], component: SignatureComponent): IndexPreviewRow[] =>
+    [...elements]
+        .sort((a, b) => compareNoCase(a.name, b.name))
+        .map((element, i) => ({ element, newIndex: formatIndex(i + 1, component.index_type) }));
+
+const ReindexPreviewDialog: React.FC<ReindexPreviewDialogProps> = ({ isOpen, onOpenChange, component, onConfirm }) => {
+    const { token, preferredLanguage } = useAuth();
+    const [rows, setRows] = useState<IndexPreviewRow[]>([]);
+    const [isLoading, setIsLoading] = useState(false);
+    const [isReindexing, setIsReindexing] = useState(false);
+    const [error, setError] = useState<string | null>(null);
+
+    const loadPreview = useCallback(async () => {
+        if (!token || !component?.signatureComponentId) return;
+        setIsLoading(true);
+        setError(null);
+        try {
+            const elements = await api.getElementsByComponent(component.signatureComponentId, token);
+            setRows(computeIndexPreview(elements, component));
+        } catch (err: any) {
+            const msg = err.message || t('reindexPreviewLoadError', preferredLanguage);
+            setError(msg);
+            setRows([]);
+            console.error("Load Reindex Preview Error:", err);
+        } finally {
+            setIsLoading(false);
+        }
+    }, [token, component, preferredLanguage]);
+
+    useEffect(() => {
+        if (isOpen) loadPreview();
+    }, [isOpen, loadPreview]);
+
+    const changedCount = useMemo(() => rows.filter(row => (row.element.index ?? '') !== row.newIndex).length, [rows]);
+
+    const handleConfirm = async () => {
+        if (!component?.signatureComponentId) return;
+        setIsReindexing(true);
+        try {
+            if (await onConfirm(component.signatureComponentId)) onOpenChange(false);
+        } finally {
+            setIsReindexing(false);
+        }
+    };
+
+    return (
+        <Dialog open={isOpen} onOpenChange={(open) => { if (!isReindexing) onOpenChange(open); }}>
+            <DialogContent className="sm:max-w-[600px]">
+                <DialogHeader>
+                    <DialogTitle>{t('reindexPreviewDialogTitle', preferredLanguage)}</DialogTitle>
+                    <DialogDescription>
+                        {t('reindexPreviewDialogDescription', preferredLanguage, { componentName: component?.name ?? '', indexType: component ? t(indexTypeLabelKeys[component.index_type], preferredLanguage) : '' })}
+                    </DialogDescription>
+                </DialogHeader>
+
+                {error && <ErrorDisplay message={error} />}
+
+                {isLoading ? (
+                    <div className='flex justify-center py-6'><LoadingSpinner /></div>
+                ) : rows.length === 0 ? (
+                    !error && <p className="text-sm text-muted-foreground text-center py-6">{t('reindexPreviewNoElements', preferredLanguage)}</p>
+                ) : (
+                    <>
+                        <p className="text-sm text-muted-foreground">
+                            {t('reindexPreviewChangedCount', preferredLanguage, { changed: changedCount, total: rows.length })}
+                        </p>
+                        <div className="max-h-[50vh] overflow-y-auto border rounded-md">
+                            <table className="w-full text-sm">
+                                <thead className="sticky top-0 bg-muted">
+                                    <tr className="text-left">
+                                        <th className="px-3 py-1.5 font-medium">{t('nameLabel', preferredLanguage)}</th>
+                                        <th className="px-3 py-1.5 font-medium w-28">{t('reindexPreviewCurrentIndex', preferredLanguage)}</th>
+                                        <th className="px-3 py-1.5 font-medium w-28">{t('reindexPreviewNewIndex', preferredLanguage)}</th>
+                                    </tr>
+                                </thead>
+                                <tbody>
+                                    {rows.map(({ element, newIndex }) => {
+                                        const changed = (element.index ?? '') !== newIndex;
+                                        return (
+                                            <tr key={element.signatureElementId} className="border-t">
+                                                <td className="px-3 py-1 truncate max-w-[250px]" title={element.name}>{element.name}</td>
+                                                <td className="px-3 py-1 font-mono text-xs text-muted-foreground">{element.index || '-'}</td>
+                                                <td className="px-3 py-1 font-mono text-xs">
+                                                    {changed
+                                                        ? <Badge variant="secondary" className="font-mono">{newIndex}</Badge>
+                                                        : <span className='text-muted-foreground'>{newIndex}</span>}
+                                                </td>
+                                            </tr>
+                                        );
+                                    })}
+                                </tbody>
+                            </table>
+                        </div>
+                    </>
+                )}
+
+                <DialogFooter>
+                    <Button type="button" variant="outline" onClick={() => onOpenChange(false)} disabled={isReindexing}>
+                        {t('cancelButton', preferredLanguage)}
+                    </Button>
+                    <Button type="button" onClick={handleConfirm} disabled={isLoading || isReindexing || !!error}>
+                        {isReindexing ? <LoadingSpinner size="sm" className="mr-2" /> : <ListRestart className="mr-2 h-4 w-4" />}
+                        {t('reindexPreviewConfirmButton', preferredLanguage)}
+                    </Button>
+                </DialogFooter>
+            </DialogContent>
+        </Dialog>
+    );
+};
+
+export default ReindexPreviewDialog;
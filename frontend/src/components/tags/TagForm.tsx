@@ -10,10 +10,11 @@ import LoadingSpinner from '@/components/shared/LoadingSpinner';
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
 import { useAuth } from '@/hooks/useAuth';
 import api from '@/lib/api';
+import { applyServerFieldErrors } from '@/lib/serverFieldErrors';
 import type { Tag } from '../../../../backend/src/functionalities/tag/models';
 import { cn } from '@/lib/utils'; // Import cn
 import { t } from '@/translations/utils'; // Import translation utility
-import { toast } from "sonner"; // Import toast
+import { toast } from '@/lib/toast'; // Import toast
 
 interface TagFormProps {
   tagToEdit: Tag | null;
@@ -25,7 +26,7 @@ const TagForm: React.FC<TagFormProps> = ({ tagToEdit, onSave }) => {
   const [isLoading, setIsLoading] = useState(false);
   const [error, setError] = useState<string | null>(null);
 
-  const { register, handleSubmit, reset, formState: { errors } } = useForm<TagFormData>({
+  const { register, handleSubmit, reset, setError: setFieldError, formState: { errors } } = useForm<TagFormData>({
     resolver: zodResolver(tagFormSchema),
     defaultValues: {
       name: '',
@@ -63,7 +64,8 @@ const TagForm: React.FC<TagFormProps> = ({ tagToEdit, onSave }) => {
       onSave();
     } catch (err: any) {
       const msg = err.message || t('tagsSaveFailed', preferredLanguage, { message: '' }).replace(': {message}', ''); // Use translated error
-      setError(msg);
+      // Field-specific messages go next to their inputs; the general error stays for anything else
+      if (applyServerFieldErrors(err, ['name', 'description'] as const, setFieldError) === 0) setError(msg);
       toast.error(t('errorMessageTemplate', preferredLanguage, { message: msg })); // Show toast as well
       console.error("Save Tag Error:", err);
     } finally {
@@ -1,59 +1,124 @@
-import React from 'react';
-import { Table, TableBody, TableCell, TableHead, TableHeader, TableRow } from "@/components/ui/table";
+import React, { useState, useRef } from 'react';
 import { Button } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
 import { Edit, Trash2 } from 'lucide-react';
 import type { Tag } from '../../../../backend/src/functionalities/tag/models';
 import { useAuth } from '@/hooks/useAuth'; // To check role for actions
+import { tagFormSchema } from '@/lib/zodSchemas';
+import { cn } from '@/lib/utils';
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import DataTable, { type DataTableColumn } from '@/components/shared/DataTable';
 import { t } from '@/translations/utils'; // Import translation utility
+import { parseSqliteDateTime } from '@/lib/dateFormat';
 
 interface TagListProps {
   tags: Tag[];
   onEdit: (tag: Tag) => void;
   onDelete: (tagId: number) => void;
+  busyRows?: Record<number, 'delete'>; // Tags with a delete request in flight
+  renamingTagId?: number | null; // Tag whose name cell is an inline editor (one at a time)
+  onStartRename?: (tagId: number | null) => void;
+  onRename?: (tag: Tag, name: string) => Promise<boolean>; // Resolves false when the rename failed
+  emptyMessage?: React.ReactNode; // Shown when there are no tags
 }
 
-const TagList: React.FC<TagListProps> = ({ tags, onEdit, onDelete }) => {
-   const { user, preferredLanguage } = useAuth(); // Get preferredLanguage
-   const isAdmin = user?.role === 'admin'; // Assuming only admin can edit/delete tags
+// Inline name editor: Enter/blur commits, Escape reverts. Uses the same name rule as the tag form.
+const InlineNameEditor: React.FC<{ tag: Tag, onCommit: (name: string) => Promise<boolean>, onCancel: () => void }> = ({ tag, onCommit, onCancel }) => {
+  const { preferredLanguage } = useAuth();
+  const [value, setValue] = useState(tag.name);
+  const [error, setError] = useState<string | null>(null);
+  const [isSaving, setIsSaving] = useState(false);
+  const isDoneRef = useRef(false); // Escape/Enter already handled - ignore the blur that follows
 
-  if (tags.length === 0) {
-    return null; // Parent component handles empty state message
-  }
+  const commit = async () => {
+    if (isDoneRef.current || isSaving) return;
+    const name = value.trim();
+    if (name === tag.name) { isDoneRef.current = true; onCancel(); return; }
+    const result = tagFormSchema.shape.name.safeParse(name);
+    if (!result.success) { setError(result.error.issues[0]?.message ?? null); return; }
+    setIsSaving(true);
+    isDoneRef.current = await onCommit(name);
+    setIsSaving(false);
+  };
 
   return (
-    // Remove border/rounded if inside CardContent
-    // <div className="border rounded-lg">
-    <Table>
-        <TableHeader>
-            <TableRow>
-             {/* Use translated headers */}
-            <TableHead>{t('nameLabel', preferredLanguage)}</TableHead>
-            <TableHead>{t('descriptionLabel', preferredLanguage)}</TableHead>
-            {isAdmin && <TableHead className="text-right w-[100px]">{t('actionsLabel', preferredLanguage)}</TableHead>}
-            </TableRow>
-        </TableHeader>
-        <TableBody>
-            {tags.map((tag) => (
-            <TableRow key={tag.tagId}>
-                <TableCell className="font-medium">{tag.name}</TableCell>
-                 {/* Use translated placeholder */}
-                 <TableCell className="text-sm text-muted-foreground">{tag.description || <span className="italic">{t('noDescription', preferredLanguage)}</span>}</TableCell> {/* TODO: Add 'noDescription' to translations */}
-                {isAdmin && (
-                    <TableCell className="text-right space-x-1">
-                    <Button variant="ghost" size="icon" onClick={() => onEdit(tag)} title={t('editButton', preferredLanguage)}>
-                        <Edit className="h-4 w-4" />
-                    </Button>
-                    <Button variant="ghost" size="icon" onClick={() => onDelete(tag.tagId!)} title={t('deleteButton', preferredLanguage)}>
-                        <Trash2 className="h-4 w-4 text-destructive" />
-                    </Button>
-                    </TableCell>
-                )}
-            </TableRow>
-            ))}
-        </TableBody>
-    </Table>
-    // </div>
+    <div className="grid gap-1">
+      <Input
+        autoFocus
+        value={value}
+        onChange={(e) => { setValue(e.target.value); setError(null); }}
+        onFocus={(e) => e.target.select()}
+        onKeyDown={(e) => {
+          if (e.key === 'Enter') { e.preventDefault(); commit(); }
+          else if (e.key === 'Escape') { e.preventDefault(); e.stopPropagation(); isDoneRef.current = true; onCancel(); }
+        }}
+        onBlur={commit}
+        disabled={isSaving}
+        aria-label={t('tagInlineRenameLabel', preferredLanguage, { tagName: tag.name })}
+        aria-invalid={!!error}
+        className={cn("h-8", error && "border-destructive")}
+      />
+      {error && <p className="text-xs text-destructive">{error}</p>}
+    </div>
   );
 };
 
-export default TagList;
\ No newline at end of file
+const TagList: React.FC<TagListProps> = ({ tags, onEdit, onDelete, busyRows = {}, renamingTagId, onStartRename, onRename, emptyMessage }) => {
+   const { user, preferredLanguage } = useAuth(); // Get preferredLanguage
+   const isAdmin = user?.role === 'admin'; // Assuming only admin can edit/delete tags
+   const canRename = isAdmin && !!onStartRename;
+
+   const columns: DataTableColumn<Tag>[] = [
+       {
+           key: 'name',
+           header: t('nameLabel', preferredLanguage),
+           cellClassName: 'font-medium',
+           cell: (tag) => renamingTagId === tag.tagId && onRename && onStartRename
+               ? <InlineNameEditor tag={tag} onCommit={(name) => onRename(tag, name)} onCancel={() => onStartRename(null)} />
+               : tag.name,
+           cellProps: (tag) => canRename && renamingTagId !== tag.tagId
+               ? { className: 'cursor-text', onDoubleClick: () => onStartRename!(tag.tagId!), title: t('tagInlineRenameHint', preferredLanguage) }
+               : {},
+       },
+       {
+           key: 'description',
+           header: t('descriptionLabel', preferredLanguage),
+           cellClassName: 'text-sm text-muted-foreground',
+           cell: (tag) => tag.description || <span className="italic">{t('noDescription', preferredLanguage)}</span>,
+       },
+       // Authorship is unknown for tags created before it was recorded
+       { key: 'createdBy', header: t('tagCreatedByLabel', preferredLanguage), headClassName: 'w-[140px]', cellClassName: 'text-sm text-muted-foreground', cell: (tag) => tag.createdBy || '-' },
+       {
+           key: 'createdOn',
+           header: t('tagCreatedOnLabel', preferredLanguage),
+           headClassName: 'w-[180px]',
+           cellClassName: 'text-sm text-muted-foreground whitespace-nowrap',
+           // Tags from before this was recorded have no date
+           cell: (tag) => tag.createdOn ? parseSqliteDateTime(tag.createdOn).toLocaleString() : '-',
+       },
+   ];
+
+   const renderActions = (tag: Tag) => (
+       <>
+           <Button variant="ghost" size="icon" disabled={!!busyRows[tag.tagId!]} onClick={() => onEdit(tag)} title={t('editButton', preferredLanguage)}>
+               <Edit className="h-4 w-4" />
+           </Button>
+           <Button variant="ghost" size="icon" disabled={!!busyRows[tag.tagId!]} onClick={() => onDelete(tag.tagId!)} title={t('deleteButton', preferredLanguage)}>
+               {busyRows[tag.tagId!] ? <LoadingSpinner size="sm" /> : <Trash2 className="h-4 w-4 text-destructive" />}
+           </Button>
+       </>
+   );
+
+   return (
+       <DataTable
+           rows={tags}
+           rowKey={(tag) => tag.tagId!}
+           columns={columns}
+           actions={isAdmin ? renderActions : undefined}
+           actionsClassName="w-[100px]"
+           emptyMessage={emptyMessage}
+       />
+   );
+};
+
+export default TagList;
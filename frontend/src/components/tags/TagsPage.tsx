@@ -4,24 +4,38 @@ import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogTrigger, Dialog
 import TagList from './TagList';
 import TagForm from './TagForm';
 import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import EmptyState from '@/components/shared/EmptyState';
+import LoadingStatus from '@/components/shared/LoadingStatus';
 import ErrorDisplay from '@/components/shared/ErrorDisplay';
 import { useAuth } from '@/hooks/useAuth';
+import { useConfirm } from '@/hooks/useConfirm';
+import { useSaveGuardedOpenChange } from '@/hooks/usePendingRequests';
+import { useCreateShortcut } from '@/hooks/useCreateShortcut';
+import { useBusyRows } from '@/hooks/useBusyRows';
+import { useUiPreferences } from '@/hooks/useUiPreferences';
+import { useUndoableDelete } from '@/hooks/useUndoableDelete';
 import api from '@/lib/api';
 import type { Tag } from '../../../../backend/src/functionalities/tag/models';
-import { PlusCircle } from 'lucide-react';
-import { toast } from "sonner";
+import { PlusCircle, Tag as TagIcon } from 'lucide-react';
+import { toast } from '@/lib/toast';
 // Import Card components for layout
 import { Card, CardContent, CardHeader, CardTitle, CardDescription } from '@/components/ui/card';
 import { t } from '@/translations/utils'; // Import translation utility
 
 const TagsPage: React.FC = () => {
   const { token, user, preferredLanguage } = useAuth(); // Get preferredLanguage
+  const confirm = useConfirm();
+  const { busyRows, runBusy } = useBusyRows();
+  const { deleteMode } = useUiPreferences();
+  const { hiddenIds: deletingTagIds, scheduleDelete } = useUndoableDelete();
   const isAdmin = user?.role === 'admin'; // Check if current user is an admin
   const [tags, setTags] = useState<Tag[]>([]);
   const [isLoading, setIsLoading] = useState(true); // Start loading initially
   const [error, setError] = useState<string | null>(null);
   const [editingTag, setEditingTag] = useState<Tag | null>(null);
   const [isFormOpen, setIsFormOpen] = useState(false);
+  const handleFormOpenChange = useSaveGuardedOpenChange(setIsFormOpen);
+  const [renamingTagId, setRenamingTagId] = useState<number | null>(null); // Inline name editing
 
   // Function to fetch all tags
   const fetchTags = useCallback(async () => {
@@ -64,6 +78,7 @@ const TagsPage: React.FC = () => {
     setEditingTag(null);
     setIsFormOpen(true);
   };
+  useCreateShortcut(handleCreateNew);
 
   const handleDelete = async (tagId: number) => {
       if (!token || !tagId || !isAdmin) {
@@ -71,24 +86,56 @@ const TagsPage: React.FC = () => {
           return;
       }
       const tagToDelete = tags.find(t => t.tagId === tagId);
-      if (!window.confirm(t('tagsConfirmDeleteMessage', preferredLanguage, { tagName: tagToDelete?.name ?? tagId }))) {
+      const handleDeleteFailed = (err: any) => {
+           const msg = err.message || 'Failed';
+           setError(t('tagsDeleteFailed', preferredLanguage, { message: msg }));
+           toast.error(t('errorMessageTemplate', preferredLanguage, { message: t('tagsDeleteFailed', preferredLanguage, { message: msg }) }));
+           console.error("Delete Tag Error:", err);
+      };
+
+      if (deleteMode === 'undo') {
+          scheduleDelete(tagId, {
+              label: tagToDelete?.name ?? String(tagId),
+              request: () => api.deleteTag(tagId, token),
+              onDeleted: fetchTags,
+              onFailed: handleDeleteFailed,
+          });
+          return;
+      }
+
+      if (!await confirm({ message: t('tagsConfirmDeleteMessage', preferredLanguage, { tagName: tagToDelete?.name ?? tagId }) })) {
           return;
       }
 
       setError(null);
-      setIsLoading(true); // Show loading indicator during delete
 
       try {
-          await api.deleteTag(tagId, token);
+          await runBusy(tagId, 'delete', () => api.deleteTag(tagId, token));
           toast.success(t('tagsDeleteSuccess', preferredLanguage, { tagName: tagToDelete?.name ?? tagId }));
           await fetchTags(); // Refresh list after delete
       } catch (err: any) {
-           const msg = err.message || 'Failed';
-           setError(t('tagsDeleteFailed', preferredLanguage, { message: msg }));
-           toast.error(t('errorMessageTemplate', preferredLanguage, { message: t('tagsDeleteFailed', preferredLanguage, { message: msg }) }));
-           console.error("Delete Tag Error:", err);
-      } finally {
-          setIsLoading(false); // Hide loading indicator
+           handleDeleteFailed(err);
+      }
+  };
+
+  // Inline rename - updates the row in place instead of reloading the whole list
+  const handleRename = async (tag: Tag, name: string): Promise<boolean> => {
+      if (!token || !isAdmin) {
+          toast.error(t('tagsPermissionErrorEdit', preferredLanguage));
+          return false;
+      }
+      try {
+          const updated = await api.updateTag(tag.tagId!, { name }, token);
+          setTags(prev => prev.map(existing => existing.tagId === tag.tagId ? { ...existing, ...updated, name } : existing)
+                              .sort((a, b) => a.name.localeCompare(b.name)));
+          setRenamingTagId(null);
+          toast.success(t('tagInlineRenameSuccess', preferredLanguage, { oldName: tag.name, newName: name }));
+          return true;
+      } catch (err: any) {
+          const msg = err.message || 'Failed';
+          toast.error(t('errorMessageTemplate', preferredLanguage, { message: msg }));
+          console.error("Rename Tag Error:", err);
+          return false;
       }
   };
 
@@ -110,10 +157,10 @@ const TagsPage: React.FC = () => {
                 <p className='text-muted-foreground'>{t('tagsDescription', preferredLanguage)}</p>
             </div>
             {/* Create Tag Button & Dialog */}
-            <Dialog open={isFormOpen} onOpenChange={setIsFormOpen}>
+            <Dialog open={isFormOpen} onOpenChange={handleFormOpenChange}>
                 <DialogTrigger asChild>
                 {/* Allow any authenticated user to trigger create */}
-                <Button onClick={handleCreateNew} className='shrink-0'>
+                <Button onClick={handleCreateNew} className='shrink-0' data-help="create">
                     <PlusCircle className="mr-2 h-4 w-4" />
                     {t('createButton', preferredLanguage)} {t('tagLabelSingular', preferredLanguage)}
                 </Button>
@@ -131,21 +178,23 @@ const TagsPage: React.FC = () => {
        </div>
 
         {/* Tags List Section */}
-        <Card>
+        <Card data-help="list">
             <CardHeader>
                  {error && <ErrorDisplay message={error} />}
             </CardHeader>
             <CardContent>
                 {/* Loading state */}
+                <LoadingStatus isLoading={isLoading} label={t('tagsTitle', preferredLanguage)} count={tags.length} />
                 {isLoading && <div className="flex justify-center py-10"><LoadingSpinner /></div>}
 
                 {/* Tag List Table */}
                 {!isLoading && !error && (
-                    <TagList tags={tags} onEdit={handleEdit} onDelete={handleDelete} />
-                )}
-                {/* Empty State Message */}
-                {!isLoading && !error && tags.length === 0 && (
-                     <p className="text-center text-muted-foreground pt-6">{t('tagsNoTagsFound', preferredLanguage)} {t('tagsClickCreateHint', preferredLanguage)}</p>
+                    <TagList
+                        tags={tags.filter(tag => !deletingTagIds.has(tag.tagId!))} onEdit={handleEdit} onDelete={handleDelete} busyRows={busyRows}
+                        renamingTagId={renamingTagId} onStartRename={setRenamingTagId} onRename={handleRename}
+                        emptyMessage={<EmptyState icon={TagIcon} title={t('tagsNoTagsFound', preferredLanguage)} description={t('tagsEmptyDescription', preferredLanguage)}
+                            action={{ label: t('tagsCreateFirstButton', preferredLanguage), onClick: handleCreateNew }} />}
+                    />
                 )}
                  {/* Error State Message */}
                 {!isLoading && error && tags.length === 0 && (
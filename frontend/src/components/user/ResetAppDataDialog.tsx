@@ -0,0 +1,62 @@
+import React, { useState } from 'react';
+import { useAuth } from '@/hooks/useAuth';
+import { Button } from "@/components/ui/button";
+import { Label } from "@/components/ui/label";
+import { Checkbox } from "@/components/ui/checkbox";
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, DialogFooter, DialogClose } from "@/components/ui/dialog";
+import LoadingSpinner from '@/components/shared/LoadingSpinner';
+import { AlertTriangle } from 'lucide-react';
+import { resetAppData } from '@/lib/appData';
+import { t } from '@/translations/utils';
+
+interface ResetAppDataDialogProps {
+    isOpen: boolean;
+    onOpenChange: (open: boolean) => void;
+}
+
+// Troubleshooting action: clears caches and persisted client state, then reloads the app
+const ResetAppDataDialog: React.FC<ResetAppDataDialogProps> = ({ isOpen, onOpenChange }) => {
+    const { logout, preferredLanguage } = useAuth();
+    const [alsoLogout, setAlsoLogout] = useState(false);
+    const [isResetting, setIsResetting] = useState(false);
+
+    const handleReset = async () => {
+        setIsResetting(true);
+        if (alsoLogout) {
+            await logout(); // Invalidates the token on the server as well
+        }
+        resetAppData({ includeSession: alsoLogout });
+        // Reload so every context starts from the clean persisted state
+        window.location.reload();
+    };
+
+    return (
+        <Dialog open={isOpen} onOpenChange={(open) => { if (!isResetting) onOpenChange(open); }}>
+            <DialogContent className="sm:max-w-[450px]">
+                <DialogHeader>
+                    <DialogTitle>{t('resetAppDataDialogTitle', preferredLanguage)}</DialogTitle>
+                    <DialogDescription>{t('resetAppDataDialogDescription', preferredLanguage)}</DialogDescription>
+                </DialogHeader>
+                <div className="flex gap-2 p-3 border rounded-md bg-amber-50 text-amber-900 text-sm">
+                    <AlertTriangle className="h-4 w-4 shrink-0 mt-0.5" />
+                    <p>{t('resetAppDataWarning', preferredLanguage)}</p>
+                </div>
+                <div className="flex items-center space-x-2">
+                    <Checkbox id="reset-app-data-logout" checked={alsoLogout} onCheckedChange={(checked) => setAlsoLogout(!!checked)} disabled={isResetting} />
+                    <Label htmlFor="reset-app-data-logout" className="text-sm font-normal cursor-pointer">{t('resetAppDataAlsoLogout', preferredLanguage)}</Label>
+                </div>
+                <DialogFooter>
+                    <DialogClose asChild>
+                        <Button type="button" variant="outline" disabled={isResetting}>{t('cancelButton', preferredLanguage)}</Button>
+                    </DialogClose>
+                    <Button type="button" variant="destructive" onClick={handleReset} disabled={isResetting}>
+                        {isResetting && <LoadingSpinner size="sm" className="mr-2" />}
+                        {t('resetAppDataConfirmButton', preferredLanguage)}
+                    </Button>
+                </DialogFooter>
+            </DialogContent>
+        </Dialog>
+    );
+};
+
+export default ResetAppDataDialog;
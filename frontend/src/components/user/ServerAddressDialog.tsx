@@ -0,0 +1,85 @@
+import React, { useState, useEffect } from 'react';
+import { useAuth } from '@/hooks/useAuth';
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { Dialog, DialogContent, DialogHeader, DialogTitle, DialogDescription, DialogFooter, DialogClose } from "@/components/ui/dialog";
+import { DEFAULT_API_BASE_URL, getApiBaseUrl, normalizeApiBaseUrl, setApiBaseUrl } from '@/lib/api';
+import { cn } from '@/lib/utils';
+import { t } from '@/translations/utils';
+
+interface ServerAddressDialogProps {
+    isOpen: boolean;
+    onOpenChange: (open: boolean) => void;
+}
+
+// Points the client at the API of another server. The app reloads afterwards,
+// as the current session and cached data belong to the previous server.
+const ServerAddressDialog: React.FC<ServerAddressDialogProps> = ({ isOpen, onOpenChange }) => {
+    const { preferredLanguage } = useAuth();
+    const [address, setAddress] = useState(getApiBaseUrl());
+    const [validationError, setValidationError] = useState<string | null>(null);
+
+    useEffect(() => {
+        if (isOpen) { setAddress(getApiBaseUrl()); setValidationError(null); }
+    }, [isOpen]);
+
+    const handleAddressChange = (value: string) => {
+        setAddress(value);
+        if (!value.trim()) { setValidationError(null); return; } // Empty means default
+        try { normalizeApiBaseUrl(value); setValidationError(null); }
+        catch (err: any) { setValidationError(err.message); }
+    };
+
+    // Empty input / null falls back to the default address
+    const applyAddress = (value: string | null) => {
+        const previous = getApiBaseUrl();
+        if (setApiBaseUrl(value) === previous) { onOpenChange(false); return; }
+        window.location.reload();
+    };
+
+    const handleSave = () => {
+        if (!validationError) applyAddress(address.trim() ? address : null);
+    };
+
+    return (
+        <Dialog open={isOpen} onOpenChange={onOpenChange}>
+            <DialogContent className="sm:max-w-[450px]">
+                <DialogHeader>
+                    <DialogTitle>{t('serverAddressDialogTitle', preferredLanguage)}</DialogTitle>
+                    <DialogDescription>{t('serverAddressDialogDescription', preferredLanguage, { defaultUrl: DEFAULT_API_BASE_URL })}</DialogDescription>
+                </DialogHeader>
+                <div className="grid gap-1.5">
+                    <Label htmlFor="server-address">{t('serverAddressLabel', preferredLanguage)}</Label>
+                    <Input
+                        id="server-address"
+                        value={address}
+                        onChange={(e) => handleAddressChange(e.target.value)}
+                        onKeyDown={(e) => { if (e.key === 'Enter') { e.preventDefault(); handleSave(); } }}
+                        placeholder="https://archive.example.org/api"
+                        aria-invalid={!!validationError}
+                        className={cn(validationError && "border-destructive")}
+                    />
+                    {validationError
+                        ? <p className="text-xs text-destructive">{validationError}</p>
+                        : <p className="text-xs text-muted-foreground">{t('serverAddressHint', preferredLanguage)}</p>}
+                </div>
+                <DialogFooter className="sm:justify-between">
+                    <Button type="button" variant="ghost" onClick={() => applyAddress(null)} disabled={getApiBaseUrl() === DEFAULT_API_BASE_URL}>
+                        {t('serverAddressResetButton', preferredLanguage)}
+                    </Button>
+                    <div className="flex gap-2">
+                        <DialogClose asChild>
+                            <Button type="button" variant="outline">{t('cancelButton', preferredLanguage)}</Button>
+                        </DialogClose>
+                        <Button type="button" onClick={handleSave} disabled={!!validationError}>
+                            {t('saveButton', preferredLanguage)}
+                        </Button>
+                    </div>
+                </DialogFooter>
+            </DialogContent>
+        </Dialog>
+    );
+};
+
+export default ServerAddressDialog;
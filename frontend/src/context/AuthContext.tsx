@@ -1,12 +1,13 @@
 import React, { createContext, useState, useEffect, ReactNode, useCallback } from 'react';
-import api from '@/lib/api';
+import api, { setSessionRefreshHandler, setSessionExpiredHandler } from '@/lib/api';
+import { clearInMemoryCaches } from '@/lib/appData';
 // Correct the import path assuming backend/src is sibling to frontend/src
 import type { UserCredentials, UserRole, SupportedLanguage } from '../../../backend/src/functionalities/user/models';
 import { supportedLanguages as backendSupportedLanguages } from '../../../backend/src/functionalities/user/models'; // Keep for validation
 // --- UPDATED IMPORT: Import defaultLanguage from frontend translations ---
 import { defaultLanguage as frontendDefaultLanguage } from '@/translations/models';
 // ----------------------------------------------------------------------
-import { toast } from "sonner"; // Import toast
+import { toast } from '@/lib/toast'; // Import toast
 import { t } from '@/translations/utils'; // Import translation util
 
 interface UserState {
@@ -27,7 +28,7 @@ interface AuthState {
 
 interface AuthContextProps extends Omit<AuthState, 'preferredLanguage'> { // Exclude preferredLanguage here as it's within user or top-level
   preferredLanguage: SupportedLanguage; // Explicitly define preferredLanguage here for easy access
-  login: (credentials: UserCredentials) => Promise<boolean>; // Removed preferredLanguage param from login call
+  login: (credentials: UserCredentials, rememberMe?: boolean) => Promise<boolean>; // Removed preferredLanguage param from login call
   logout: () => Promise<void>;
   register: (credentials: UserCredentials, preferredLanguage?: SupportedLanguage) => Promise<boolean>; // Keep lang here
   clearError: () => void;
@@ -46,6 +47,22 @@ const getStoredLanguagePreference = (): SupportedLanguage => {
     return frontendDefaultLanguage; // Return frontend default if nothing stored/valid
 };
 
+// Session keys live in localStorage when "Remember me" was checked at login,
+// otherwise in sessionStorage so the session ends with the browser.
+const SESSION_KEYS = ['authToken', 'authRefreshToken', 'authUserLogin', 'authUserRole', 'authUserId'];
+const REMEMBER_ME_STORAGE_KEY = 'authRememberMe';
+
+const getStoredSessionItem = (key: string): string | null => localStorage.getItem(key) ?? sessionStorage.getItem(key);
+// Storage holding the current session (updates go where the login put it)
+const currentSessionStorage = (): Storage => localStorage.getItem('authToken') ? localStorage : sessionStorage;
+// Also drops in-memory caches - their pages and counts were fetched with the session being ended
+const clearStoredSession = () => {
+  SESSION_KEYS.forEach(key => { localStorage.removeItem(key); sessionStorage.removeItem(key); });
+  clearInMemoryCaches();
+};
+
+// Last "Remember me" choice - the login form starts with it (checked by default)
+export const getRememberMePreference = (): boolean => localStorage.getItem(REMEMBER_ME_STORAGE_KEY) !== 'false';
 
 const initialState: AuthState = {
   token: null,
@@ -62,10 +79,10 @@ export const AuthProvider: React.FC<{ children: ReactNode }> = ({ children }) =>
   // --- Updated Initialization Effect ---
   useEffect(() => {
       const initializeAuth = async () => {
-          const storedToken = localStorage.getItem('authToken');
-          const storedUserLogin = localStorage.getItem('authUserLogin');
-          const storedUserRole = localStorage.getItem('authUserRole') as UserRole | null;
-          const storedUserIdStr = localStorage.getItem('authUserId');
+          const storedToken = getStoredSessionItem('authToken');
+          const storedUserLogin = getStoredSessionItem('authUserLogin');
+          const storedUserRole = getStoredSessionItem('authUserRole') as UserRole | null;
+          const storedUserIdStr = getStoredSessionItem('authUserId');
           let initialLang = getStoredLanguagePreference(); // Start with stored/default
 
           console.log("AuthContext: Initializing Auth. Stored Token:", !!storedToken, "Stored Lang:", initialLang);
@@ -93,10 +110,7 @@ export const AuthProvider: React.FC<{ children: ReactNode }> = ({ children }) =>
               } else {
                   // Invalid stored session data
                   console.error("AuthContext: Invalid UserID or Role found in storage. Clearing auth data.");
-                  localStorage.removeItem('authToken');
-                  localStorage.removeItem('authUserLogin');
-                  localStorage.removeItem('authUserRole');
-                  localStorage.removeItem('authUserId');
+                  clearStoredSession();
                   // Keep language preference
                   setState(prevState => ({ ...initialState, isLoading: false, preferredLanguage: initialLang }));
               }
@@ -137,13 +151,49 @@ export const AuthProvider: React.FC<{ children: ReactNode }> = ({ children }) =>
       initializeAuth();
   }, []); // Run only once on mount
 
+  // Lets the API layer swap an expired session token for a new one (retried once per 401)
+  useEffect(() => {
+      setSessionRefreshHandler(async () => {
+          const refreshToken = getStoredSessionItem('authRefreshToken');
+          if (!refreshToken) return null;
+          try {
+              const { token, refreshToken: nextRefreshToken } = await api.refreshSession(refreshToken);
+              const storage = currentSessionStorage();
+              storage.setItem('authToken', token);
+              storage.setItem('authRefreshToken', nextRefreshToken);
+              setState(prevState => prevState.isAuthenticated ? { ...prevState, token } : prevState);
+              console.log("AuthContext: Session token refreshed.");
+              return token;
+          } catch (err) {
+              console.warn("AuthContext: Session refresh failed, refresh token discarded.", err);
+              localStorage.removeItem('authRefreshToken');
+              sessionStorage.removeItem('authRefreshToken');
+              return null;
+          }
+      });
+      return () => setSessionRefreshHandler(null);
+  }, []);
+
+  // A request still rejected with 401 means the session is gone - drop it and let ProtectedRoute send the user to login
+  useEffect(() => {
+      setSessionExpiredHandler(() => {
+          if (!getStoredSessionItem('authToken')) return; // Already handled (several requests fail at once)
+          console.warn("AuthContext: Session expired, clearing auth data.");
+          clearStoredSession();
+          const lang = getStoredLanguagePreference();
+          setState(prevState => ({ ...initialState, isLoading: false, error: t('sessionExpiredMessage', lang), preferredLanguage: prevState.preferredLanguage }));
+          toast.warning(t('sessionExpiredMessage', lang));
+      });
+      return () => setSessionExpiredHandler(null);
+  }, []);
+
 
    // Login function no longer needs preferredLanguage param, gets it from API response
-   const login = useCallback(async (credentials: UserCredentials): Promise<boolean> => {
+   const login = useCallback(async (credentials: UserCredentials, rememberMe: boolean = true): Promise<boolean> => {
        setState(prevState => ({ ...prevState, isLoading: true, error: null }));
        try {
            const response = await api.login(credentials);
-           const { token, role, login, userId, preferredLanguage: dbPreferredLanguage } = response;
+           const { token, refreshToken, role, login, userId, preferredLanguage: dbPreferredLanguage } = response;
            console.log("AuthContext: Login API response - Token:", !!token, "Role:", role, "Login:", login, "UserID:", userId, "DB Lang:", dbPreferredLanguage);
 
            if (!token || !login || userId === undefined || typeof userId !== 'number' || isNaN(userId)) {
@@ -158,10 +208,15 @@ export const AuthProvider: React.FC<{ children: ReactNode }> = ({ children }) =>
            const langToStore: SupportedLanguage = dbPreferredLanguage && backendSupportedLanguages.includes(dbPreferredLanguage) ? dbPreferredLanguage : frontendDefaultLanguage;
             // ----------------------------------------------------------
 
-           localStorage.setItem('authToken', token);
-           localStorage.setItem('authUserLogin', login);
-           localStorage.setItem('authUserRole', role || ''); // Store empty string for null role
-           localStorage.setItem('authUserId', String(userId));
+           // Without "Remember me" the session never reaches localStorage
+           clearStoredSession();
+           localStorage.setItem(REMEMBER_ME_STORAGE_KEY, String(rememberMe));
+           const storage = rememberMe ? localStorage : sessionStorage;
+           storage.setItem('authToken', token);
+           if (refreshToken) storage.setItem('authRefreshToken', refreshToken);
+           storage.setItem('authUserLogin', login);
+           storage.setItem('authUserRole', role || ''); // Store empty string for null role
+           storage.setItem('authUserId', String(userId));
            localStorage.setItem('authPreferredLanguage', langToStore); // Store the authoritative language
 
            console.log("AuthContext: Setting state after successful login with UserID:", userId, "Lang:", langToStore);
@@ -181,10 +236,7 @@ export const AuthProvider: React.FC<{ children: ReactNode }> = ({ children }) =>
            console.error("AuthContext: Login failed -", errorMessage);
            // Clear auth-related items, but keep language preference from *before* login attempt
            const langBeforeLogin = state.preferredLanguage;
-           localStorage.removeItem('authToken');
-           localStorage.removeItem('authUserLogin');
-           localStorage.removeItem('authUserRole');
-           localStorage.removeItem('authUserId');
+           clearStoredSession();
            // localStorage.removeItem('authPreferredLanguage'); // KEEP language
            setState(prevState => ({
                ...initialState, // Reset auth state
@@ -203,10 +255,7 @@ export const AuthProvider: React.FC<{ children: ReactNode }> = ({ children }) =>
     console.log("AuthContext: logout called.");
 
      // Clear auth-related items, but *keep* language preference
-     localStorage.removeItem('authToken');
-     localStorage.removeItem('authUserLogin');
-     localStorage.removeItem('authUserRole');
-     localStorage.removeItem('authUserId');
+     clearStoredSession();
      // localStorage.removeItem('authPreferredLanguage'); // <-- KEEP THIS
 
      const languageToKeep = state.preferredLanguage; // Get current language before resetting
@@ -263,7 +312,7 @@ export const AuthProvider: React.FC<{ children: ReactNode }> = ({ children }) =>
                 langChanged = true; // Flag that language was updated
             }
             if (updatedUserPartial.role !== undefined && updatedUserPartial.role !== prevState.user.role) {
-                localStorage.setItem('authUserRole', updatedUserPartial.role || '');
+                currentSessionStorage().setItem('authUserRole', updatedUserPartial.role || '');
             }
             // Update top-level language only if it changed via this update
             const topLevelLang = langChanged ? newUserState.preferredLanguage : prevState.preferredLanguage;
@@ -0,0 +1,99 @@
+import React, { createContext, useState, useCallback, useRef, ReactNode } from 'react';
+import {
+    AlertDialog, AlertDialogAction, AlertDialogCancel, AlertDialogContent,
+    AlertDialogDescription, AlertDialogFooter, AlertDialogHeader, AlertDialogTitle,
+} from '@/components/ui/alert-dialog';
+import { buttonVariants } from '@/components/ui/button';
+import { Input } from '@/components/ui/input';
+import { Label } from '@/components/ui/label';
+import { useAuth } from '@/hooks/useAuth';
+import { useUiPreferences } from '@/hooks/useUiPreferences';
+import { t } from '@/translations/utils';
+
+// App-wide confirmation modal replacing window.confirm: `await confirm({...})` resolves to true
+// only when the user presses the confirm button (Cancel, Escape or clicking outside give false).
+// Bulk operations pass `itemCount`: above the user's bulk threshold the count has to be typed in to confirm.
+
+export interface ConfirmOptions {
+    message: string;
+    title?: string; // Defaults to "Are you sure?"
+    confirmLabel?: string; // Defaults to "Delete"
+    destructive?: boolean; // Red confirm button, defaults to true
+    itemCount?: number; // Number of items a bulk operation affects
+}
+
+interface PendingConfirmation extends ConfirmOptions {
+    resolve: (confirmed: boolean) => void;
+}
+
+interface ConfirmDialogContextProps {
+    confirm: (options: ConfirmOptions) => Promise<boolean>;
+}
+
+const ConfirmDialogContext = createContext<ConfirmDialogContextProps | undefined>(undefined);
+
+export const ConfirmDialogProvider: React.FC<{ children: ReactNode }> = ({ children }) => {
+    const { preferredLanguage } = useAuth();
+    const { bulkConfirmThreshold } = useUiPreferences();
+    const [pending, setPending] = useState<PendingConfirmation | null>(null);
+    const [typedCount, setTypedCount] = useState('');
+    const pendingRef = useRef<PendingConfirmation | null>(null);
+
+    const settle = useCallback((confirmed: boolean) => {
+        pendingRef.current?.resolve(confirmed);
+        pendingRef.current = null;
+        setPending(null);
+    }, []);
+
+    const confirm = useCallback((options: ConfirmOptions) => new Promise<boolean>(resolve => {
+        pendingRef.current?.resolve(false); // A newer request replaces an unanswered one
+        pendingRef.current = { ...options, resolve };
+        setPending(pendingRef.current);
+        setTypedCount('');
+    }), []);
+
+    const isDestructive = pending?.destructive ?? true;
+    const requiresTypedCount = (pending?.itemCount ?? 0) > bulkConfirmThreshold;
+    const isConfirmBlocked = requiresTypedCount && typedCount.trim() !== String(pending?.itemCount);
+
+    return (
+        <ConfirmDialogContext.Provider value={{ confirm }}>
+            {children}
+            <AlertDialog open={!!pending} onOpenChange={(open) => { if (!open) settle(false); }}>
+                <AlertDialogContent>
+                    <AlertDialogHeader>
+                        <AlertDialogTitle>{pending?.title ?? t('confirmDialogDefaultTitle', preferredLanguage)}</AlertDialogTitle>
+                        <AlertDialogDescription className="whitespace-pre-line">{pending?.message}</AlertDialogDescription>
+                    </AlertDialogHeader>
+                    {requiresTypedCount && (
+                        <div className="grid gap-1.5">
+                            <Label htmlFor="confirm-typed-count" className="text-sm font-normal">
+                                {t('confirmDialogTypeCountLabel', preferredLanguage, { count: pending?.itemCount })}
+                            </Label>
+                            <Input
+                                id="confirm-typed-count"
+                                value={typedCount}
+                                onChange={(e) => setTypedCount(e.target.value)}
+                                inputMode="numeric"
+                                autoComplete="off"
+                                autoFocus
+                            />
+                        </div>
+                    )}
+                    <AlertDialogFooter>
+                        <AlertDialogCancel>{t('cancelButton', preferredLanguage)}</AlertDialogCancel>
+                        <AlertDialogAction
+                            className={isDestructive ? buttonVariants({ variant: 'destructive' }) : undefined}
+                            onClick={() => settle(true)}
+                            disabled={isConfirmBlocked}
+                        >
+                            {pending?.confirmLabel ?? t('deleteButton', preferredLanguage)}
+                        </AlertDialogAction>
+                    </AlertDialogFooter>
+                </AlertDialogContent>
+            </AlertDialog>
+        </ConfirmDialogContext.Provider>
+    );
+};
+
+export default ConfirmDialogContext;
@@ -0,0 +1,194 @@
+import React, { createContext, useState, useEffect, ReactNode, useCallback } from 'react';
+import { setErrorToastDuration } from '@/lib/toast';
+
+// Client-side UI preferences (not stored on the server, persisted in localStorage)
+
+export const toastPositions = ['top-right', 'top-center', 'top-left', 'bottom-right', 'bottom-center', 'bottom-left'] as const;
+export type ToastPosition = typeof toastPositions[number];
+
+// Selectable auto-dismiss durations (ms) for info/success toasts
+export const toastDurations = [3000, 5000, 8000, 12000] as const;
+
+// Bulk operations affecting more items than this require typing the item count to confirm
+export const bulkConfirmThresholds = [5, 10, 20, 50, 100, 500] as const;
+
+// 'system' follows the OS setting (prefers-color-scheme)
+export const themes = ['light', 'dark', 'system'] as const;
+export type Theme = typeof themes[number];
+
+// Single-row deletes (notes, tags): ask first, or delete right away with a short window to undo
+export const deleteModes = ['confirm', 'undo'] as const;
+export type DeleteMode = typeof deleteModes[number];
+
+// UI zoom factors, stepped through with Ctrl+Plus/Minus (Ctrl+0 resets) or the buttons in the user menu
+export const zoomLevels = [0.8, 0.9, 1, 1.1, 1.25, 1.4, 1.6] as const;
+const DEFAULT_ZOOM = 1;
+
+const DEFAULT_TOAST_POSITION: ToastPosition = 'top-right';
+const DEFAULT_TOAST_DURATION = 5000;
+// Error toasts stay on screen longer than info/success ones
+const ERROR_TOAST_DURATION_MULTIPLIER = 2;
+const DEFAULT_BULK_CONFIRM_THRESHOLD = 20;
+const DEFAULT_THEME: Theme = 'light';
+const DEFAULT_DELETE_MODE: DeleteMode = 'confirm';
+
+interface UiPreferencesState {
+    toastPosition: ToastPosition;
+    toastDuration: number;
+    bulkConfirmThreshold: number;
+    theme: Theme;
+    zoom: number;
+    deleteMode: DeleteMode;
+}
+
+interface UiPreferencesContextProps extends UiPreferencesState {
+    errorToastDuration: number;
+    resolvedTheme: 'light' | 'dark'; // `theme` with 'system' resolved
+    setTheme: (theme: Theme) => void;
+    setToastPosition: (position: ToastPosition) => void;
+    setToastDuration: (duration: number) => void;
+    setBulkConfirmThreshold: (threshold: number) => void;
+    setDeleteMode: (mode: DeleteMode) => void;
+    zoomIn: () => void;
+    zoomOut: () => void;
+    resetZoom: () => void;
+}
+
+const UiPreferencesContext = createContext<UiPreferencesContextProps | undefined>(undefined);
+
+const getStoredToastPosition = (): ToastPosition => {
+    const stored = localStorage.getItem('uiToastPosition') as ToastPosition | null;
+    return stored && toastPositions.includes(stored) ? stored : DEFAULT_TOAST_POSITION;
+};
+
+const getStoredToastDuration = (): number => {
+    const stored = parseInt(localStorage.getItem('uiToastDuration') || '', 10);
+    return !isNaN(stored) && stored > 0 ? stored : DEFAULT_TOAST_DURATION;
+};
+
+const getStoredBulkConfirmThreshold = (): number => {
+    const stored = parseInt(localStorage.getItem('uiBulkConfirmThreshold') || '', 10);
+    return !isNaN(stored) && stored > 0 ? stored : DEFAULT_BULK_CONFIRM_THRESHOLD;
+};
+
+const getStoredTheme = (): Theme => {
+    const stored = localStorage.getItem('uiTheme') as Theme | null;
+    return stored && themes.includes(stored) ? stored : DEFAULT_THEME;
+};
+
+const getStoredDeleteMode = (): DeleteMode => {
+    const stored = localStorage.getItem('uiDeleteMode') as DeleteMode | null;
+    return stored && deleteModes.includes(stored) ? stored : DEFAULT_DELETE_MODE;
+};
+
+const getStoredZoom = (): number => {
+    const stored = parseFloat(localStorage.getItem('uiZoom') || '');
+    return (zoomLevels as readonly number[]).includes(stored) ? stored : DEFAULT_ZOOM;
+};
+
+// Scales the root font size; the UI is sized in rem, so everything grows with the text
+const applyZoom = (zoom: number) => { document.documentElement.style.fontSize = zoom === DEFAULT_ZOOM ? '' : `${zoom * 100}%`; };
+applyZoom(getStoredZoom());
+
+// Next zoom level in the given direction, or the same one at either end of the range
+const stepZoom = (zoom: number, direction: 1 | -1): number => {
+    const index = zoomLevels.indexOf(zoom as typeof zoomLevels[number]);
+    const next = zoomLevels[index + direction];
+    return next ?? zoom;
+};
+
+const systemThemeQuery = window.matchMedia('(prefers-color-scheme: dark)');
+const resolveTheme = (theme: Theme): 'light' | 'dark' => theme === 'system' ? (systemThemeQuery.matches ? 'dark' : 'light') : theme;
+const applyTheme = (theme: 'light' | 'dark') => document.documentElement.classList.toggle('dark', theme === 'dark');
+// Applied before the first render so a dark theme doesn't flash light on startup
+applyTheme(resolveTheme(getStoredTheme()));
+
+export const UiPreferencesProvider: React.FC<{ children: ReactNode }> = ({ children }) => {
+    const [state, setState] = useState<UiPreferencesState>(() => ({
+        toastPosition: getStoredToastPosition(),
+        toastDuration: getStoredToastDuration(),
+        bulkConfirmThreshold: getStoredBulkConfirmThreshold(),
+        theme: getStoredTheme(),
+        zoom: getStoredZoom(),
+        deleteMode: getStoredDeleteMode(),
+    }));
+    const [resolvedTheme, setResolvedTheme] = useState(() => resolveTheme(state.theme));
+
+    const errorToastDuration = state.toastDuration * ERROR_TOAST_DURATION_MULTIPLIER;
+
+    useEffect(() => {
+        setErrorToastDuration(errorToastDuration);
+    }, [errorToastDuration]);
+
+    useEffect(() => {
+        const update = () => {
+            const next = resolveTheme(state.theme);
+            applyTheme(next);
+            setResolvedTheme(next);
+        };
+        update();
+        if (state.theme !== 'system') return;
+        systemThemeQuery.addEventListener('change', update);
+        return () => systemThemeQuery.removeEventListener('change', update);
+    }, [state.theme]);
+
+    useEffect(() => {
+        applyZoom(state.zoom);
+    }, [state.zoom]);
+
+    const setZoom = useCallback((update: (zoom: number) => number) => {
+        setState(prev => {
+            const zoom = update(prev.zoom);
+            localStorage.setItem('uiZoom', String(zoom));
+            return { ...prev, zoom };
+        });
+    }, []);
+    const zoomIn = useCallback(() => setZoom(zoom => stepZoom(zoom, 1)), [setZoom]);
+    const zoomOut = useCallback(() => setZoom(zoom => stepZoom(zoom, -1)), [setZoom]);
+    const resetZoom = useCallback(() => setZoom(() => DEFAULT_ZOOM), [setZoom]);
+
+    // Ctrl+Plus/Minus/0 replace the browser's page zoom, so the level is remembered across sessions
+    useEffect(() => {
+        const handleKeyDown = (e: KeyboardEvent) => {
+            if (!(e.ctrlKey || e.metaKey) || e.altKey) return;
+            if (e.key === '+' || e.key === '=') { e.preventDefault(); zoomIn(); }
+            else if (e.key === '-') { e.preventDefault(); zoomOut(); }
+            else if (e.key === '0') { e.preventDefault(); resetZoom(); }
+        };
+        window.addEventListener('keydown', handleKeyDown);
+        return () => window.removeEventListener('keydown', handleKeyDown);
+    }, [zoomIn, zoomOut, resetZoom]);
+
+    const setToastPosition = useCallback((position: ToastPosition) => {
+        localStorage.setItem('uiToastPosition', position);
+        setState(prev => ({ ...prev, toastPosition: position }));
+    }, []);
+
+    const setToastDuration = useCallback((duration: number) => {
+        localStorage.setItem('uiToastDuration', String(duration));
+        setState(prev => ({ ...prev, toastDuration: duration }));
+    }, []);
+
+    const setBulkConfirmThreshold = useCallback((threshold: number) => {
+        localStorage.setItem('uiBulkConfirmThreshold', String(threshold));
+        setState(prev => ({ ...prev, bulkConfirmThreshold: threshold }));
+    }, []);
+
+    const setDeleteMode = useCallback((mode: DeleteMode) => {
+        localStorage.setItem('uiDeleteMode', mode);
+        setState(prev => ({ ...prev, deleteMode: mode }));
+    }, []);
+
+    const setTheme = useCallback((theme: Theme) => {
+        localStorage.setItem('uiTheme', theme);
+        setState(prev => ({ ...prev, theme }));
+    }, []);
+
+    return (
+        <UiPreferencesContext.Provider value={{ ...state, errorToastDuration, resolvedTheme, setToastPosition, setToastDuration, setBulkConfirmThreshold, setDeleteMode, setTheme, zoomIn, zoomOut, resetZoom }}>
+            {children}
+        </UiPreferencesContext.Provider>
+    );
+};
+
+export default UiPreferencesContext;
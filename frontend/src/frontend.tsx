@@ -6,18 +6,28 @@ import "./index.css"; // Keep main CSS import - Tailwind setup done here
 // import "tailwindcss/preflight.css";
 // import "tailwindcss/index.css";
 import { Toaster } from "@/components/ui/sonner"; // Import Toaster
+import { UiPreferencesProvider } from "@/context/UiPreferencesContext";
+import { useUiPreferences } from "@/hooks/useUiPreferences";
 
 const container = document.getElementById("root");
 if (!container) throw new Error("Failed to find the root element");
 
+// Toaster driven by the user's notification preferences (position & duration) and the theme
+const AppToaster = () => {
+  const { toastPosition, toastDuration, resolvedTheme } = useUiPreferences();
+  return <Toaster richColors theme={resolvedTheme} position={toastPosition} duration={toastDuration} closeButton={true} />;
+};
+
 const root = createRoot(container);
 root.render(
   <React.StrictMode>
     <BrowserRouter>
-      <App />
-      {/* Render Toaster globally - richColors applies preset styles */}
-      {/* Added closeButton={true} to allow manual closing */}
-      <Toaster richColors position="top-right" duration={6000} closeButton={true} />
+      <UiPreferencesProvider>
+        <App />
+        {/* Render Toaster globally - richColors applies preset styles */}
+        {/* Added closeButton={true} to allow manual closing */}
+        <AppToaster />
+      </UiPreferencesProvider>
     </BrowserRouter>
   </React.StrictMode>
 );
\ No newline at end of file
@@ -0,0 +1,18 @@
+import { useState, useCallback } from 'react';
+
+// Row id -> action with a request in flight, so a table can disable/spin just the affected row
+// instead of the whole list; several rows can be busy at once
+export const useBusyRows = <TAction extends string = 'delete'>() => {
+  const [busyRows, setBusyRows] = useState<Record<number, TAction>>({});
+
+  const runBusy = useCallback(async <T>(id: number, action: TAction, request: () => Promise<T>): Promise<T> => {
+    setBusyRows(prev => ({ ...prev, [id]: action }));
+    try {
+      return await request();
+    } finally {
+      setBusyRows(({ [id]: _done, ...rest }) => rest as Record<number, TAction>);
+    }
+  }, []);
+
+  return { busyRows, runBusy };
+};
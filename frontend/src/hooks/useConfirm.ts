@@ -0,0 +1,10 @@
+import { useContext } from 'react';
+import ConfirmDialogContext from '@/context/ConfirmDialogContext';
+
+export const useConfirm = () => {
+  const context = useContext(ConfirmDialogContext);
+  if (context === undefined) {
+    throw new Error('useConfirm must be used within a ConfirmDialogProvider');
+  }
+  return context.confirm;
+};
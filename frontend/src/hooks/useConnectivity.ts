@@ -0,0 +1,7 @@
+import { useSyncExternalStore } from 'react';
+import { getConnectivity, subscribeToConnectivity } from '@/lib/api';
+
+// Reachability of the backend as seen by the most recent request (see lib/api.ts)
+export const useConnectivity = () => {
+  return useSyncExternalStore(subscribeToConnectivity, getConnectivity);
+};
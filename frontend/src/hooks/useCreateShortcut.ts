@@ -0,0 +1,20 @@
+import { useEffect, useRef } from 'react';
+
+// Alt+N opens the "Create" editor of the current section (Ctrl+N is reserved by browsers for a new window).
+// Ignored while a dialog is open, so it never stacks editors; Escape closing the topmost dialog is handled by the dialogs themselves.
+export const useCreateShortcut = (onCreate: () => void, enabled = true) => {
+  const onCreateRef = useRef(onCreate);
+  onCreateRef.current = onCreate;
+
+  useEffect(() => {
+    if (!enabled) return;
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (!e.altKey || e.ctrlKey || e.metaKey || e.shiftKey || e.code !== 'KeyN' || e.repeat) return;
+      if (document.querySelector('[role="dialog"], [role="alertdialog"]')) return;
+      e.preventDefault();
+      onCreateRef.current();
+    };
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [enabled]);
+};
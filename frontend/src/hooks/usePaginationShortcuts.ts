@@ -0,0 +1,37 @@
+import { useEffect, useRef } from 'react';
+
+const isTextEntryFocused = () => {
+  const active = document.activeElement as HTMLElement | null;
+  if (!active) return false;
+  return active.isContentEditable
+    || ['INPUT', 'TEXTAREA', 'SELECT'].includes(active.tagName)
+    || ['textbox', 'combobox', 'searchbox'].includes(active.getAttribute('role') ?? '');
+};
+
+// PageDown/PageUp move to the next/previous page, Home/End jump to the first/last one.
+// Ignored while typing in a field (those keys move the caret there) and while a dialog is open.
+export const usePaginationShortcuts = (currentPage: number, totalPages: number, goToPage: (page: number) => void, enabled = true) => {
+  const stateRef = useRef({ currentPage, totalPages, goToPage });
+  stateRef.current = { currentPage, totalPages, goToPage };
+
+  useEffect(() => {
+    if (!enabled) return;
+    const handleKeyDown = (e: KeyboardEvent) => {
+      if (e.altKey || e.ctrlKey || e.metaKey || e.shiftKey || e.defaultPrevented) return;
+      if (!['PageDown', 'PageUp', 'Home', 'End'].includes(e.key)) return;
+      if (isTextEntryFocused() || document.querySelector('[role="dialog"], [role="alertdialog"]')) return;
+
+      const { currentPage, totalPages, goToPage } = stateRef.current;
+      if (totalPages <= 1) return;
+      const target = e.key === 'PageDown' ? currentPage + 1
+        : e.key === 'PageUp' ? currentPage - 1
+        : e.key === 'Home' ? 1
+        : totalPages;
+      if (target < 1 || target > totalPages || target === currentPage) return;
+      e.preventDefault();
+      goToPage(target);
+    };
+    window.addEventListener('keydown', handleKeyDown);
+    return () => window.removeEventListener('keydown', handleKeyDown);
+  }, [enabled]);
+};
@@ -0,0 +1,39 @@
+import { useSyncExternalStore, useEffect, useCallback } from 'react';
+import { toast } from '@/lib/toast';
+import { getPendingRequests, subscribeToPendingRequests } from '@/lib/api';
+import { useAuth } from '@/hooks/useAuth';
+import { t } from '@/translations/utils';
+
+// Write requests currently in flight anywhere in the app (see lib/api.ts)
+export const usePendingRequests = () => {
+  return useSyncExternalStore(subscribeToPendingRequests, getPendingRequests);
+};
+
+// Asks the browser to confirm closing or reloading the page while writes are in flight,
+// since their results (and the refresh that follows) would be lost
+export const useWarnBeforeUnloadWhilePending = () => {
+  const hasPending = usePendingRequests().length > 0;
+
+  useEffect(() => {
+    if (!hasPending) return;
+    const handleBeforeUnload = (e: BeforeUnloadEvent) => {
+      e.preventDefault();
+      e.returnValue = ''; // Older browsers only show the prompt when this is set
+    };
+    window.addEventListener('beforeunload', handleBeforeUnload);
+    return () => window.removeEventListener('beforeunload', handleBeforeUnload);
+  }, [hasPending]);
+};
+
+// Wraps an editor dialog's onOpenChange so it stays open until the save it started has finished
+export const useSaveGuardedOpenChange = (onOpenChange: (open: boolean) => void) => {
+  const { preferredLanguage } = useAuth();
+
+  return useCallback((open: boolean) => {
+    if (!open && getPendingRequests().length > 0) {
+      toast.info(t('saveInProgressCloseBlocked', preferredLanguage));
+      return;
+    }
+    onOpenChange(open);
+  }, [onOpenChange, preferredLanguage]);
+};
@@ -0,0 +1,7 @@
+import { useSyncExternalStore } from 'react';
+import { getTimestampFormat, subscribeToTimestampFormat } from '@/lib/dateFormat';
+
+// Whether timestamps show relative ("3 days ago") or absolute dates first (see lib/dateFormat.ts)
+export const useTimestampFormat = () => {
+  return useSyncExternalStore(subscribeToTimestampFormat, getTimestampFormat);
+};
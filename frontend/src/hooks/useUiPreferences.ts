@@ -0,0 +1,10 @@
+import { useContext } from 'react';
+import UiPreferencesContext from '@/context/UiPreferencesContext';
+
+export const useUiPreferences = () => {
+  const context = useContext(UiPreferencesContext);
+  if (context === undefined) {
+    throw new Error('useUiPreferences must be used within a UiPreferencesProvider');
+  }
+  return context;
+};
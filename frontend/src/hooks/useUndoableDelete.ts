@@ -0,0 +1,82 @@
+import { useState, useCallback, useEffect, useRef } from 'react';
+import { toast } from '@/lib/toast';
+import { useAuth } from '@/hooks/useAuth';
+import { t } from '@/translations/utils';
+
+// How long a deleted row can be restored before the delete request is actually sent
+export const UNDO_DELETE_DELAY_MS = 5000;
+
+interface ScheduledDelete {
+  timer: ReturnType<typeof setTimeout>;
+  toastId: string | number;
+  run: () => void;
+}
+
+interface ScheduleDeleteOptions {
+  label: string; // Shown in the toast, e.g. the note title
+  request: () => Promise<unknown>;
+  onDeleted: () => unknown; // After the request succeeded (e.g. refetch the list); the row stays hidden until it settles
+  onFailed: (error: any) => void; // The row is shown again before this is called
+}
+
+// Rows deleted optimistically: hidden at once, deleted on the server only when the undo window has passed.
+// Deletes still waiting when the view unmounts are sent right away rather than dropped; closing or
+// reloading the page asks for confirmation first and sends them if the user leaves anyway.
+export const useUndoableDelete = () => {
+  const { preferredLanguage } = useAuth();
+  const [hiddenIds, setHiddenIds] = useState<ReadonlySet<number>>(new Set());
+  const scheduledRef = useRef(new Map<number, ScheduledDelete>());
+
+  const unhide = useCallback((id: number) => {
+    setHiddenIds(prev => { const next = new Set(prev); next.delete(id); return next; });
+  }, []);
+
+  const undoDelete = useCallback((id: number) => {
+    const scheduled = scheduledRef.current.get(id);
+    if (!scheduled) return;
+    clearTimeout(scheduled.timer);
+    scheduledRef.current.delete(id);
+    toast.dismiss(scheduled.toastId);
+    unhide(id);
+  }, [unhide]);
+
+  const scheduleDelete = useCallback((id: number, { label, request, onDeleted, onFailed }: ScheduleDeleteOptions) => {
+    if (scheduledRef.current.has(id)) return;
+    setHiddenIds(prev => new Set(prev).add(id));
+
+    const run = () => {
+      scheduledRef.current.delete(id);
+      request().then(
+        async () => { try { await onDeleted(); } finally { unhide(id); } },
+        error => { unhide(id); onFailed(error); },
+      );
+    };
+    const toastId = toast(t('undoDeleteToast', preferredLanguage, { name: label }), {
+      duration: UNDO_DELETE_DELAY_MS,
+      action: { label: t('undoButton', preferredLanguage), onClick: () => undoDelete(id) },
+    });
+    scheduledRef.current.set(id, { timer: setTimeout(run, UNDO_DELETE_DELAY_MS), toastId, run });
+  }, [preferredLanguage, unhide, undoDelete]);
+
+  useEffect(() => {
+    const scheduled = scheduledRef.current;
+    const flush = () => {
+      scheduled.forEach(({ timer, run }) => { clearTimeout(timer); run(); });
+      scheduled.clear();
+    };
+    const handleBeforeUnload = (e: BeforeUnloadEvent) => {
+      if (scheduled.size === 0) return;
+      e.preventDefault();
+      e.returnValue = ''; // Older browsers only show the prompt when this is set
+    };
+    window.addEventListener('beforeunload', handleBeforeUnload);
+    window.addEventListener('pagehide', flush);
+    return () => {
+      window.removeEventListener('beforeunload', handleBeforeUnload);
+      window.removeEventListener('pagehide', flush);
+      flush();
+    };
+  }, []);
+
+  return { hiddenIds, scheduleDelete };
+};
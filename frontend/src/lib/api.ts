@@ -18,6 +18,7 @@ import type {
     CreateSignatureElementInput,
     UpdateSignatureElementInput,
     SignatureElementSearchResult,
+    SignatureElementUsage,
 } from "../../../backend/src/functionalities/signature/element/models";
 // Updated import for ArchiveDocument types
 import type {
@@ -29,19 +30,200 @@ import type {
 } from "../../../backend/src/functionalities/archive/document/models";
 
 import type { SearchRequest, SearchResponse } from "../../../backend/src/utils/search";
+import { clearPageCache } from "./pageCache";
+import { cachedList, invalidateListsForEndpoint } from "./listCache";
+import { clearInMemoryCaches } from "./appData";
 
 
-const API_BASE_URL = "/api";
+// --- API Base URL ---
+// Defaults to the same origin as the frontend; can be pointed at another server (persisted in localStorage).
+export const DEFAULT_API_BASE_URL = "/api";
+const API_BASE_URL_STORAGE_KEY = 'apiBaseUrl';
+
+// Accepts an absolute http(s) URL or a path on this origin; trailing slashes are dropped
+// so endpoints ("/user/login") can be appended as-is. Throws on malformed input.
+export const normalizeApiBaseUrl = (input: string): string => {
+    const value = input.trim();
+    if (!value) throw new Error("API base URL cannot be empty.");
+    if (value.startsWith('/')) {
+        if (value.startsWith('//')) throw new Error("Use a full URL (http:// or https://) or a path starting with a single '/'.");
+        return value.replace(/\/+$/, '') || '/';
+    }
+    let parsed: URL;
+    try { parsed = new URL(value); }
+    catch { throw new Error(`"${value}" is not a valid URL.`); }
+    if (parsed.protocol !== 'http:' && parsed.protocol !== 'https:') throw new Error("The API base URL must use http:// or https://.");
+    if (parsed.search || parsed.hash) throw new Error("The API base URL cannot contain a query string or fragment.");
+    return `${parsed.origin}${parsed.pathname}`.replace(/\/+$/, '');
+};
+
+const getStoredApiBaseUrl = (): string => {
+    const stored = localStorage.getItem(API_BASE_URL_STORAGE_KEY);
+    if (!stored) return DEFAULT_API_BASE_URL;
+    try { return normalizeApiBaseUrl(stored); }
+    catch { return DEFAULT_API_BASE_URL; }
+};
+
+let apiBaseUrl = getStoredApiBaseUrl();
+
+export const getApiBaseUrl = () => apiBaseUrl;
+
+// Pass null to go back to the default. Cached data came from the previous server, so it's dropped.
+export const setApiBaseUrl = (url: string | null) => {
+    const normalized = url === null ? DEFAULT_API_BASE_URL : normalizeApiBaseUrl(url);
+    if (normalized === DEFAULT_API_BASE_URL) localStorage.removeItem(API_BASE_URL_STORAGE_KEY);
+    else localStorage.setItem(API_BASE_URL_STORAGE_KEY, normalized);
+    if (normalized !== apiBaseUrl) {
+        apiBaseUrl = normalized;
+        clearInMemoryCaches();
+    }
+    return normalized;
+};
 
 type ApiMethod = "GET" | "POST" | "PUT" | "PATCH" | "DELETE" | "HEAD";
 
-// fetchApi function remains the same (handles JSON and Blob)
+interface FetchApiOptions {
+    expectBlob?: boolean;
+    // The endpoint always answers with a record (create/update/get-by-id): an empty success body is an
+    // error instead of the generic `{ success: true }`, so callers never work with a bogus object
+    expectBody?: boolean;
+}
+const EXPECT_BODY: FetchApiOptions = { expectBody: true };
+
+// --- Pending Changes Tracking ---
+// Write requests (create/update/delete, batch operations) in flight anywhere in the app.
+// Searches are POSTs too, but they don't change data, so they are not tracked.
+export interface PendingRequest {
+    id: number;
+    method: ApiMethod;
+    endpoint: string;
+    startedAt: number;
+}
+
+type PendingRequestsListener = (pending: PendingRequest[]) => void;
+
+let pendingRequests: PendingRequest[] = [];
+let nextPendingRequestId = 1;
+const pendingRequestsListeners = new Set<PendingRequestsListener>();
+
+const isTrackedRequest = (method: ApiMethod, endpoint: string) =>
+    method !== 'GET' && method !== 'HEAD' && !endpoint.endsWith('/search') && !endpoint.startsWith('/user/log') && endpoint !== '/user/refresh';
+
+const notifyPendingRequestsListeners = () => {
+    pendingRequestsListeners.forEach(listener => listener(pendingRequests));
+};
+
+export const getPendingRequests = () => pendingRequests;
+
+export const subscribeToPendingRequests = (listener: PendingRequestsListener) => {
+    pendingRequestsListeners.add(listener);
+    return () => { pendingRequestsListeners.delete(listener); };
+};
+
+// --- Connectivity ---
+// Derived from the outcome of every request (and the header's periodic ping): any HTTP answer means the
+// server is reachable, a network error means it is not. The last error is kept after recovery for the tooltip.
+export type ConnectivityState = 'unknown' | 'online' | 'slow' | 'offline';
+
+export interface ConnectivityStatus {
+    state: ConnectivityState;
+    checkedAt: number | null;
+    latencyMs: number | null;
+    lastError: { message: string, at: number } | null;
+}
+
+export const SLOW_RESPONSE_MS = 3000;
+
+type ConnectivityListener = (status: ConnectivityStatus) => void;
+
+let connectivity: ConnectivityStatus = { state: 'unknown', checkedAt: null, latencyMs: null, lastError: null };
+const connectivityListeners = new Set<ConnectivityListener>();
+
+const recordConnectivity = (latencyMs: number | null, errorMessage?: string) => {
+    const now = Date.now();
+    connectivity = errorMessage !== undefined
+        ? { state: 'offline', checkedAt: now, latencyMs: null, lastError: { message: errorMessage, at: now } }
+        : { ...connectivity, state: latencyMs !== null && latencyMs > SLOW_RESPONSE_MS ? 'slow' : 'online', checkedAt: now, latencyMs };
+    connectivityListeners.forEach(listener => listener(connectivity));
+};
+
+export const getConnectivity = () => connectivity;
+
+export const subscribeToConnectivity = (listener: ConnectivityListener) => {
+    connectivityListeners.add(listener);
+    return () => { connectivityListeners.delete(listener); };
+};
+
 async function fetchApi<T>(
     endpoint: string,
     method: ApiMethod = "GET",
     body?: any,
     token?: string | null,
-    options: { expectBlob?: boolean } = {}
+    options: FetchApiOptions = {}
+): Promise<T> {
+    if (!isTrackedRequest(method, endpoint)) {
+        return fetchApiRequest<T>(endpoint, method, body, token, options);
+    }
+    const pending: PendingRequest = { id: nextPendingRequestId++, method, endpoint, startedAt: Date.now() };
+    pendingRequests = [...pendingRequests, pending];
+    notifyPendingRequestsListeners();
+    try {
+        return await fetchApiRequest<T>(endpoint, method, body, token, options);
+    } finally {
+        pendingRequests = pendingRequests.filter(p => p.id !== pending.id);
+        notifyPendingRequestsListeners();
+        // Data changed (or may have) - cached result pages are stale now
+        clearPageCache();
+        invalidateListsForEndpoint(endpoint);
+    }
+}
+
+// --- Session Refresh ---
+// AuthContext registers a handler exchanging the stored refresh token for a new session token.
+// On a 401 the request is retried once with the new token; concurrent 401s share a single refresh.
+type SessionRefreshHandler = () => Promise<string | null>;
+
+let sessionRefreshHandler: SessionRefreshHandler | null = null;
+let sessionRefreshInFlight: Promise<string | null> | null = null;
+// Last successful refresh: requests still sent with the replaced token just retry with the new one
+let lastRefresh: { staleToken: string, freshToken: string } | null = null;
+
+export const setSessionRefreshHandler = (handler: SessionRefreshHandler | null) => {
+    sessionRefreshHandler = handler;
+};
+
+const refreshSessionToken = (staleToken: string): Promise<string | null> => {
+    if (!sessionRefreshHandler) return Promise.resolve(null);
+    if (!sessionRefreshInFlight) {
+        sessionRefreshInFlight = sessionRefreshHandler()
+            .then(freshToken => { lastRefresh = freshToken ? { staleToken, freshToken } : null; return freshToken; })
+            .catch(err => { console.error("fetchApi: Session refresh failed:", err); return null; })
+            .finally(() => { sessionRefreshInFlight = null; });
+    }
+    return sessionRefreshInFlight;
+};
+
+// --- Session Expiry ---
+// Called once a token-authenticated request still gets a 401 (no refresh possible or the retry failed too),
+// so AuthContext can drop the dead session and send the user back to the login page.
+let sessionExpiredHandler: (() => void) | null = null;
+
+export const setSessionExpiredHandler = (handler: (() => void) | null) => {
+    sessionExpiredHandler = handler;
+};
+
+// Auth endpoints answer 401 for bad credentials/refresh tokens (change-password: a wrong current password) -
+// never refresh-and-retry those, nor treat the 401 as an expired session
+const NON_REFRESHABLE_ENDPOINTS = ['/user/login', '/user/refresh', '/user/change-password'];
+const isRefreshableRequest = (endpoint: string) => !NON_REFRESHABLE_ENDPOINTS.includes(endpoint);
+
+// fetchApiRequest function remains the same (handles JSON and Blob)
+async function fetchApiRequest<T>(
+    endpoint: string,
+    method: ApiMethod = "GET",
+    body?: any,
+    token?: string | null,
+    options: FetchApiOptions & { isRetry?: boolean } = {}
 ): Promise<T> {
     const headers: HeadersInit = {};
     if (!(body instanceof FormData) && !options.expectBlob) {
@@ -58,6 +240,8 @@ async function fetchApi<T>(
     const config: RequestInit = {
         method,
         headers,
+        // Deletes still in their undo window are sent as the page unloads - keepalive lets them outlive it
+        keepalive: method === 'DELETE',
     };
 
     if (body && !['GET', 'HEAD'].includes(method)) {
@@ -68,16 +252,34 @@ async function fetchApi<T>(
         }
     }
 
-    const url = `${API_BASE_URL}${endpoint}`;
+    const url = `${apiBaseUrl === '/' ? '' : apiBaseUrl}${endpoint}`;
     let response: Response;
 
+    const requestStartedAt = Date.now();
     try {
         // console.log(`fetchApi: Requesting ${method} ${url}`); // Reduce console noise
         response = await fetch(url, config);
         // console.log(`fetchApi: Received response for ${url} - Status: ${response.status}, OK: ${response.ok}`); // Reduce console noise
     } catch (networkError: any) {
         console.error(`fetchApi: Network Error for ${url}:`, networkError);
-        throw new Error(`Network error: ${networkError.message || 'Failed to connect to API'}`);
+        const message = `Network error: ${networkError.message || 'Failed to connect to API'}`;
+        if (networkError?.name !== 'AbortError') recordConnectivity(null, message); // Cancelled by us, not a lost connection
+        throw new Error(message);
+    }
+    // A proxy answering 502-504 means the API server behind it is down. Blob downloads (backups) take long
+    // by nature - they don't say anything about the server being slow.
+    if (response.status >= 502 && response.status <= 504) recordConnectivity(null, `Server unavailable: ${response.status} ${response.statusText}`);
+    else recordConnectivity(options.expectBlob ? null : Date.now() - requestStartedAt);
+
+    if (response.status === 401 && token && !options.isRetry && isRefreshableRequest(endpoint)) {
+        // A 401 for a request started before another one refreshed the session needs no new refresh
+        const refreshedToken = lastRefresh?.staleToken === token ? lastRefresh.freshToken : await refreshSessionToken(token);
+        if (refreshedToken) {
+            return fetchApiRequest<T>(endpoint, method, body, refreshedToken, { ...options, isRetry: true });
+        }
+    }
+    if (response.status === 401 && token && isRefreshableRequest(endpoint) && endpoint !== '/user/logout') {
+        sessionExpiredHandler?.();
     }
 
     if (!response.ok) {
@@ -113,8 +315,16 @@ async function fetchApi<T>(
         return response.blob() as Promise<T>;
     }
 
+    const emptyBodyError = () => {
+        console.error(`fetchApi: Empty response body for ${url} - Status: ${response.status}`);
+        const error = new Error(`The server returned an empty response (${response.status}) where data was expected.`);
+        (error as any).status = response.status;
+        return error;
+    };
+
     if (response.status === 204 || response.headers.get('content-length') === '0') {
         // console.log(`fetchApi: Handling 204 No Content for ${url}`); // Reduce noise
+        if (options.expectBody) throw emptyBodyError();
         return { success: true } as T;
     }
 
@@ -132,10 +342,14 @@ async function fetchApi<T>(
         return responseText as T;
     }
 
+    if (options.expectBody && responseText.trim() === '') throw emptyBodyError();
+
     try {
         const jsonData = JSON.parse(responseText);
+        if (options.expectBody && (jsonData === null || typeof jsonData !== 'object')) throw emptyBodyError();
         return jsonData as T;
     } catch (jsonError: any) {
+        if (jsonError.status !== undefined) throw jsonError; // Empty/non-object body - already reported
         console.error("fetchApi: JSON Parsing Error:", jsonError, "URL:", url, "Status:", response.status);
         console.error("fetchApi: Raw text that failed to parse:", responseText);
         throw new Error(`Failed to parse API response: ${jsonError.message}.`);
@@ -166,14 +380,15 @@ interface DefaultLanguageResponse {
 const getApiStatus = () => fetchApi<{ message: string }>("/api/status");
 const pingApi = () => fetchApi<string>("/api/ping");
 // Login response and User type now include preferredLanguage
-const login = (credentials: UserCredentials) => fetchApi<{ token: string } & Omit<User, 'password'>>("/user/login", "POST", credentials);
+const login = (credentials: UserCredentials) => fetchApi<{ token: string, refreshToken?: string } & Omit<User, 'password'>>("/user/login", "POST", credentials, null, EXPECT_BODY);
+const refreshSession = (refreshToken: string) => fetchApi<{ token: string, refreshToken: string }>("/user/refresh", "POST", { refreshToken }, null, EXPECT_BODY);
 const logout = (token: string) => fetchApi<{ success: boolean }>("/user/logout", "POST", null, token);
-const register = (userData: RegisterPayload) => fetchApi<Omit<User, 'password'>>("/user/create", "POST", userData);
+const register = (userData: RegisterPayload) => fetchApi<Omit<User, 'password'>>("/user/create", "POST", userData, null, EXPECT_BODY);
 // User type now includes preferredLanguage and potentially assignedTags
 const getAllUsers = (token: string) => fetchApi<Omit<User, "password">[]>("/users/all", "GET", null, token);
-const getUserByLogin = (login: string, token: string) => fetchApi<Omit<User, "password">>(`/user/by-login/${login}`, "GET", null, token);
+const getUserByLogin = (login: string, token: string) => fetchApi<Omit<User, "password">>(`/user/by-login/${login}`, "GET", null, token, EXPECT_BODY);
 const updateUserRole = (login: string, role: UserRole | null, token: string) => fetchApi<{ message: string }>(`/user/by-login/${login}`, "PATCH", { role }, token);
-const updateUserPreferredLanguage = (login: string, language: SupportedLanguage, token: string) => fetchApi<Omit<User, "password">>(`/user/by-login/${login}/language`, "PATCH", { preferredLanguage: language }, token);
+const updateUserPreferredLanguage = (login: string, language: SupportedLanguage, token: string) => fetchApi<Omit<User, "password">>(`/user/by-login/${login}/language`, "PATCH", { preferredLanguage: language }, token, EXPECT_BODY);
 const changePassword = (passwords: { oldPassword: string; password: string; }, token: string) => fetchApi<{ success: boolean }>("/user/change-password", "POST", passwords, token);
 const adminSetUserPassword = (login: string, password: string, token: string) => fetchApi<{ success: boolean }>(`/user/by-login/${login}/set-password`, "PATCH", { password }, token);
 const getAssignedTagsForUser = (login: string, token: string) => fetchApi<Tag[]>(`/user/by-login/${login}/tags`, "GET", null, token);
@@ -184,40 +399,44 @@ const getDefaultLanguage = () => fetchApi<DefaultLanguageResponse>("/config/defa
 const clearHttpsConfig = (token: string) => fetchApi<{ message: string }>("/config/https", "DELETE", null, token);
 const searchLogs = (searchRequest: SearchRequest, token: string) => fetchApi<SearchResponse<LogEntry>>("/logs/search", "POST", searchRequest, token);
 const purgeLogs = (days: number, token: string) => fetchApi<PurgeLogsResponse>(`/logs/purge?days=${days}`, "DELETE", null, token);
-const createTag = (tagData: Pick<Tag, 'name' | 'description'>, token: string) => fetchApi<Tag>('/tag', 'PUT', tagData, token);
-const getAllTags = (token: string) => fetchApi<Tag[]>('/tags', 'GET', null, token);
-const getTagById = (tagId: number, token: string) => fetchApi<Tag>(`/tag/id/${tagId}`, 'GET', null, token);
-const updateTag = (tagId: number, tagData: Partial<Pick<Tag, 'name' | 'description'>>, token: string) => fetchApi<Tag>(`/tag/id/${tagId}`, 'PATCH', tagData, token);
+const createTag = (tagData: Pick<Tag, 'name' | 'description'>, token: string) => fetchApi<Tag>('/tag', 'PUT', tagData, token, EXPECT_BODY);
+const getAllTags = (token: string) => cachedList('tags', token, () => fetchApi<Tag[]>('/tags', 'GET', null, token));
+const getTagById = (tagId: number, token: string) => fetchApi<Tag>(`/tag/id/${tagId}`, 'GET', null, token, EXPECT_BODY);
+const updateTag = (tagId: number, tagData: Partial<Pick<Tag, 'name' | 'description'>>, token: string) => fetchApi<Tag>(`/tag/id/${tagId}`, 'PATCH', tagData, token, EXPECT_BODY);
 const deleteTag = (tagId: number, token: string) => fetchApi<{ message: string }>(`/tag/id/${tagId}`, 'DELETE', null, token);
-const createNote = (noteData: NoteInput, token: string) => fetchApi<NoteWithDetails>('/note', 'PUT', noteData, token);
-const getNoteById = (noteId: number, token: string) => fetchApi<NoteWithDetails>(`/note/id/${noteId}`, 'GET', null, token);
-const updateNote = (noteId: number, noteData: NoteInput, token: string) => fetchApi<NoteWithDetails>(`/note/id/${noteId}`, 'PATCH', noteData, token);
+const createNote = (noteData: NoteInput, token: string) => fetchApi<NoteWithDetails>('/note', 'PUT', noteData, token, EXPECT_BODY);
+const getNoteById = (noteId: number, token: string) => fetchApi<NoteWithDetails>(`/note/id/${noteId}`, 'GET', null, token, EXPECT_BODY);
+const updateNote = (noteId: number, noteData: Partial<NoteInput>, token: string) => fetchApi<NoteWithDetails>(`/note/id/${noteId}`, 'PATCH', noteData, token, EXPECT_BODY);
 const deleteNote = (noteId: number, token: string) => fetchApi<{ message: string }>(`/note/id/${noteId}`, 'DELETE', null, token);
 const getNotesByLogin = (login: string, token: string) => fetchApi<NoteWithDetails[]>(`/notes/by-login/${login}`, 'GET', null, token);
 const searchNotes = (searchRequest: SearchRequest, token: string) => fetchApi<SearchResponse<NoteWithDetails>>("/notes/search", "POST", searchRequest, token);
-const createSignatureComponent = (data: CreateSignatureComponentInput, token: string) => fetchApi<SignatureComponent>('/signature/component', 'PUT', data, token);
-const getAllSignatureComponents = (token: string) => fetchApi<SignatureComponent[]>('/signature/components', 'GET', null, token);
-const getSignatureComponentById = (id: number, token: string) => fetchApi<SignatureComponent>(`/signature/component/${id}`, 'GET', null, token);
-const updateSignatureComponent = (id: number, data: UpdateSignatureComponentInput, token: string) => fetchApi<SignatureComponent>(`/signature/component/${id}`, 'PATCH', data, token);
+const createSignatureComponent = (data: CreateSignatureComponentInput, token: string) => fetchApi<SignatureComponent>('/signature/component', 'PUT', data, token, EXPECT_BODY);
+const getAllSignatureComponents = (token: string) => cachedList('signatureComponents', token, () => fetchApi<SignatureComponent[]>('/signature/components', 'GET', null, token));
+const getSignatureComponentById = (id: number, token: string) => fetchApi<SignatureComponent>(`/signature/component/${id}`, 'GET', null, token, EXPECT_BODY);
+const updateSignatureComponent = (id: number, data: UpdateSignatureComponentInput, token: string) => fetchApi<SignatureComponent>(`/signature/component/${id}`, 'PATCH', data, token, EXPECT_BODY);
 const deleteSignatureComponent = (id: number, token: string) => fetchApi<{ success: boolean }>(`/signature/component/${id}`, 'DELETE', null, token);
 const reindexComponentElements = (id: number, token: string) => fetchApi<{ message: string, finalCount: number }>(`/signature/components/id/${id}/reindex`, 'POST', null, token);
-const createSignatureElement = (data: CreateSignatureElementInput, token: string) => fetchApi<SignatureElement>('/signature/element', 'PUT', data, token);
-const getSignatureElementById = (id: number, populate: ('component' | 'parents')[] = [], token: string) => fetchApi<SignatureElement>(`/signature/element/${id}${populate.length ? `?populate=${populate.join(',')}` : ''}`, 'GET', null, token);
-const updateSignatureElement = (id: number, data: UpdateSignatureElementInput, token: string) => fetchApi<SignatureElement>(`/signature/element/${id}`, 'PATCH', data, token);
+const createSignatureElement = (data: CreateSignatureElementInput, token: string) => fetchApi<SignatureElement>('/signature/element', 'PUT', data, token, EXPECT_BODY);
+const getSignatureElementById = (id: number, populate: ('component' | 'parents')[] = [], token: string) => fetchApi<SignatureElement>(`/signature/element/${id}${populate.length ? `?populate=${populate.join(',')}` : ''}`, 'GET', null, token, EXPECT_BODY);
+const updateSignatureElement = (id: number, data: UpdateSignatureElementInput, token: string) => fetchApi<SignatureElement>(`/signature/element/${id}`, 'PATCH', data, token, EXPECT_BODY);
 const deleteSignatureElement = (id: number, token: string) => fetchApi<{ success: boolean }>(`/signature/element/${id}`, 'DELETE', null, token);
-const getElementsByComponent = (componentId: number, token: string) => fetchApi<SignatureElement[]>(`/signature/components/id/${componentId}/elements/all`, 'GET', null, token);
+const getSignatureElementsByIds = (ids: number[], token: string) => fetchApi<SignatureElement[]>(`/signature/elements/by-ids?ids=${ids.join(',')}`, 'GET', null, token);
+const mergeSignatureElement = (id: number, targetElementId: number, token: string) => fetchApi<{ updatedDocumentCount: number }>(`/signature/element/${id}/merge`, 'POST', { targetElementId }, token);
+const getSignatureElementsUsage = (ids: number[], token: string) => fetchApi<SignatureElementUsage[]>(`/signature/elements/usage?ids=${ids.join(',')}`, 'GET', null, token);
+const getElementsByComponent = (componentId: number, token: string, populate: 'parents'[] = []) => fetchApi<SignatureElement[]>(`/signature/components/id/${componentId}/elements/all${populate.length ? `?populate=${populate.join(',')}` : ''}`, 'GET', null, token);
 const searchSignatureElements = (searchRequest: SearchRequest, token: string) => fetchApi<SearchResponse<SignatureElementSearchResult>>("/signature/elements/search", "POST", searchRequest, token);
 // --- Archive API calls use updated types ---
-const createArchiveDocument = (data: CreateArchiveDocumentInput, token: string) => fetchApi<ArchiveDocument>('/archive/document', 'PUT', data, token);
-const getArchiveDocumentById = (id: number, token: string) => fetchApi<ArchiveDocument>(`/archive/document/id/${id}`, 'GET', null, token);
-const updateArchiveDocument = (id: number, data: UpdateArchiveDocumentInput, token: string) => fetchApi<ArchiveDocument>(`/archive/document/id/${id}`, 'PATCH', data, token);
+const createArchiveDocument = (data: CreateArchiveDocumentInput, token: string) => fetchApi<ArchiveDocument>('/archive/document', 'PUT', data, token, EXPECT_BODY);
+const getArchiveDocumentById = (id: number, token: string, includeInactive = false) => fetchApi<ArchiveDocument>(`/archive/document/id/${id}${includeInactive ? '?includeInactive=true' : ''}`, 'GET', null, token, EXPECT_BODY);
+const updateArchiveDocument = (id: number, data: UpdateArchiveDocumentInput, token: string) => fetchApi<ArchiveDocument>(`/archive/document/id/${id}`, 'PATCH', data, token, EXPECT_BODY);
 const disableArchiveDocument = (id: number, token: string) => fetchApi<{ success: boolean }>(`/archive/document/id/${id}`, 'DELETE', null, token);
+const enableArchiveDocument = (id: number, token: string) => fetchApi<ArchiveDocument>(`/archive/document/id/${id}`, 'PATCH', { active: true }, token, EXPECT_BODY);
 const searchArchiveDocuments = (searchRequest: SearchRequest, token: string) => fetchApi<SearchResponse<ArchiveDocumentSearchResult>>("/archive/documents/search", "POST", searchRequest, token);
 const batchTagArchiveDocuments = (data: BatchTagDocumentsInput, token: string) => fetchApi<{ message: string; count: number }>("/archive/documents/batch-tag", "POST", data, token);
 const backupDatabase = (token: string) => fetchApi<Blob>("/admin/db/backup", "GET", null, token, { expectBlob: true });
 
 export default {
-    getApiStatus, pingApi, login, logout, register, getAllUsers, getUserByLogin,
+    getApiStatus, pingApi, login, refreshSession, logout, register, getAllUsers, getUserByLogin,
     updateUserRole, changePassword, adminSetUserPassword,
     getAssignedTagsForUser, assignTagsToUser, updateUserPreferredLanguage,
     getConfig, setConfig, getDefaultLanguage,
@@ -228,8 +447,8 @@ export default {
     createSignatureComponent, getAllSignatureComponents, getSignatureComponentById,
     updateSignatureComponent, deleteSignatureComponent, reindexComponentElements,
     createSignatureElement, getSignatureElementById, updateSignatureElement,
-    deleteSignatureElement, getElementsByComponent, searchSignatureElements,
+    deleteSignatureElement, getElementsByComponent, searchSignatureElements, getSignatureElementsByIds, getSignatureElementsUsage, mergeSignatureElement,
     createArchiveDocument, getArchiveDocumentById, updateArchiveDocument,
-    disableArchiveDocument, searchArchiveDocuments,
+    disableArchiveDocument, enableArchiveDocument, searchArchiveDocuments,
     batchTagArchiveDocuments, backupDatabase,
 };
\ No newline at end of file
@@ -0,0 +1,32 @@
+// Client-side app data: persisted state in localStorage plus in-memory caches.
+// Used by the "Reset app data" troubleshooting action to get back to a clean state.
+
+// Storage keys holding the current session - in localStorage or sessionStorage depending on "Remember me" (kept unless the user also logs out)
+const SESSION_STORAGE_KEYS = ['authToken', 'authRefreshToken', 'authUserLogin', 'authUserRole', 'authUserId'];
+// Kept in any case - the language is the user's choice (also kept on logout), the server address is connection setup
+const PRESERVED_STORAGE_KEYS = ['authPreferredLanguage', 'authRememberMe', 'apiBaseUrl'];
+
+// In-memory caches register a clear function here
+const cacheResetters = new Set<() => void>();
+
+export const registerCacheReset = (reset: () => void): (() => void) => {
+    cacheResetters.add(reset);
+    return () => { cacheResetters.delete(reset); };
+};
+
+export const clearInMemoryCaches = () => {
+    cacheResetters.forEach(reset => {
+        try { reset(); } catch (err) { console.error("Failed to clear cache:", err); }
+    });
+};
+
+// Clears caches and all persisted client state (UI preferences etc.); keeps the session unless `includeSession`
+export const resetAppData = ({ includeSession }: { includeSession: boolean }) => {
+    clearInMemoryCaches();
+    const keysToKeep = new Set([...PRESERVED_STORAGE_KEYS, ...(includeSession ? [] : SESSION_STORAGE_KEYS)]);
+    for (const storage of [localStorage, sessionStorage]) {
+        Object.keys(storage)
+            .filter(key => !keysToKeep.has(key))
+            .forEach(key => storage.removeItem(key));
+    }
+};
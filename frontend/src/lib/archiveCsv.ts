@@ -0,0 +1,22 @@
+import { toCsv } from '@/lib/csv';
+import type { ArchiveDocumentSearchResult } from '../../../backend/src/functionalities/archive/document/models';
+
+export const ARCHIVE_EXPORT_COLUMNS = [
+    'archiveDocumentId', 'type', 'title', 'creator', 'creationDate', 'numberOfPages',
+    'topographicSignature', 'descriptiveSignatures',
+] as const;
+
+// Archive listing -> CSV text (header row first); resolved descriptive signatures are '; '-separated
+export const archiveDocumentsToCsv = (documents: ArchiveDocumentSearchResult[]): string => {
+    const rows = documents.map(doc => [
+        doc.archiveDocumentId,
+        doc.type,
+        doc.title,
+        doc.creator,
+        doc.creationDate,
+        doc.numberOfPages,
+        doc.topographicSignature,
+        (doc.resolvedDescriptiveSignatures ?? []).join('; '),
+    ]);
+    return toCsv([[...ARCHIVE_EXPORT_COLUMNS], ...rows]);
+};
@@ -0,0 +1,99 @@
+import { describe, expect, test } from 'bun:test';
+import type { ArchiveDocumentSearchResult } from '../../../backend/src/functionalities/archive/document/models';
+import { escapeCsvValue, parseCsv, toCsv } from './csv';
+import { archiveDocumentsToCsv } from './archiveCsv';
+
+describe('escapeCsvValue', () => {
+    test('plain values are written as they are', () => {
+        expect(escapeCsvValue('Letter to the mayor')).toBe('Letter to the mayor');
+        expect(escapeCsvValue(12)).toBe('12');
+        expect(escapeCsvValue(true)).toBe('true');
+    });
+
+    test('null and undefined become empty fields', () => {
+        expect(escapeCsvValue(null)).toBe('');
+        expect(escapeCsvValue(undefined)).toBe('');
+    });
+
+    test('commas, quotes and line breaks are quoted, quotes doubled', () => {
+        expect(escapeCsvValue('Kraków, Poland')).toBe('"Kraków, Poland"');
+        expect(escapeCsvValue('the "Old" town')).toBe('"the ""Old"" town"');
+        expect(escapeCsvValue('line 1\nline 2')).toBe('"line 1\nline 2"');
+        expect(escapeCsvValue('line 1\r\nline 2')).toBe('"line 1\r\nline 2"');
+    });
+
+    test('text that a spreadsheet would run as a formula gets a leading apostrophe', () => {
+        expect(escapeCsvValue('=SUM(A1:A2)')).toBe("'=SUM(A1:A2)");
+        expect(escapeCsvValue('+48 123')).toBe("'+48 123");
+        expect(escapeCsvValue('-2+3')).toBe("'-2+3");
+        expect(escapeCsvValue('@cmd')).toBe("'@cmd");
+        expect(escapeCsvValue('\t=1')).toBe("'\t=1");
+    });
+
+    test('formula guard and quoting combine', () => {
+        expect(escapeCsvValue('=HYPERLINK("http://x", "y")')).toBe(`"'=HYPERLINK(""http://x"", ""y"")"`);
+    });
+
+    test('numbers are not guarded', () => {
+        expect(escapeCsvValue(-5)).toBe('-5');
+    });
+
+    test('the characters are only special at the start', () => {
+        expect(escapeCsvValue('a=b')).toBe('a=b');
+        expect(escapeCsvValue('user@example.com')).toBe('user@example.com');
+    });
+});
+
+describe('toCsv', () => {
+    test('rows are joined with CRLF and read back by parseCsv', () => {
+        const rows = [['title', 'creator'], ['A, B', 'say "hi"'], ['multi\nline', '']];
+        const csv = toCsv(rows);
+        expect(csv).toBe('title,creator\r\n"A, B","say ""hi"""\r\n"multi\nline",');
+        expect(parseCsv(csv)).toEqual(rows);
+    });
+});
+
+const document = (overrides: Partial<ArchiveDocumentSearchResult>): ArchiveDocumentSearchResult => ({
+    archiveDocumentId: 1,
+    parentUnitArchiveDocumentId: null,
+    createdBy: 'admin',
+    updatedBy: 'admin',
+    type: 'document',
+    active: true,
+    topographicSignature: null,
+    descriptiveSignatureElementIds: [],
+    title: 'Title',
+    creator: 'Creator',
+    creationDate: '1920',
+    numberOfPages: null,
+    documentType: null,
+    dimensions: null,
+    binding: null,
+    condition: null,
+    documentLanguage: null,
+    contentDescription: null,
+    accessLevel: null,
+    accessConditions: null,
+    isDigitized: false,
+    createdOn: new Date(0),
+    modifiedOn: new Date(0),
+    ...overrides,
+});
+
+describe('archiveDocumentsToCsv', () => {
+    test('header row, then one row per document with resolved signatures joined', () => {
+        const csv = archiveDocumentsToCsv([
+            document({ archiveDocumentId: 7, type: 'unit', title: 'Fonds 1', topographicSignature: 'R1/S2', resolvedDescriptiveSignatures: ['A/1', 'B/2'] }),
+            document({ archiveDocumentId: 8, title: 'Minutes, 1921', creator: '=cmd', numberOfPages: '12' }),
+        ]);
+        expect(csv.split('\r\n')).toEqual([
+            'archiveDocumentId,type,title,creator,creationDate,numberOfPages,topographicSignature,descriptiveSignatures',
+            '7,unit,Fonds 1,Creator,1920,,R1/S2,A/1; B/2',
+            `8,document,"Minutes, 1921",'=cmd,1920,12,,`,
+        ]);
+    });
+
+    test('no documents gives only the header', () => {
+        expect(archiveDocumentsToCsv([])).toBe('archiveDocumentId,type,title,creator,creationDate,numberOfPages,topographicSignature,descriptiveSignatures');
+    });
+});
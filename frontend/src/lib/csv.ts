@@ -0,0 +1,55 @@
+// Minimal RFC 4180 CSV helpers: quoted fields, escaped quotes ("") and line breaks inside quotes.
+
+export const parseCsv = (text: string): string[][] => {
+    const rows: string[][] = [];
+    let row: string[] = [];
+    let field = '';
+    let inQuotes = false;
+    const input = text.charCodeAt(0) === 0xfeff ? text.slice(1) : text; // Strip BOM
+
+    for (let i = 0; i < input.length; i++) {
+        const char = input[i];
+        if (inQuotes) {
+            if (char === '"' && input[i + 1] === '"') { field += '"'; i++; }
+            else if (char === '"') inQuotes = false;
+            else field += char;
+        } else if (char === '"') {
+            inQuotes = true;
+        } else if (char === ',') {
+            row.push(field); field = '';
+        } else if (char === '\n' || char === '\r') {
+            if (char === '\r' && input[i + 1] === '\n') i++;
+            row.push(field); field = '';
+            rows.push(row); row = [];
+        } else {
+            field += char;
+        }
+    }
+    if (field !== '' || row.length > 0) { row.push(field); rows.push(row); }
+    // Blank lines carry no data
+    return rows.filter(r => r.some(value => value.trim() !== ''));
+};
+
+// Text starting with one of these is run as a formula by spreadsheet apps; a leading apostrophe keeps it text.
+// Only strings are guarded - numbers (e.g. -5) stay as they are.
+const FORMULA_PREFIX = /^[=+\-@\t\r]/;
+
+export const escapeCsvValue = (value: unknown): string => {
+    if (value === null || value === undefined) return '';
+    const text = typeof value === 'string' && FORMULA_PREFIX.test(value) ? `'${value}` : String(value);
+    return /[",\r\n]/.test(text) ? `"${text.replace(/"/g, '""')}"` : text;
+};
+
+export const toCsv = (rows: unknown[][]): string =>
+    rows.map(row => row.map(escapeCsvValue).join(',')).join('\r\n');
+
+export const downloadTextFile = (content: string, fileName: string, mimeType = 'text/csv;charset=utf-8') => {
+    const url = window.URL.createObjectURL(new Blob([content], { type: mimeType }));
+    const a = document.createElement('a');
+    a.href = url;
+    a.download = fileName;
+    document.body.appendChild(a);
+    a.click();
+    a.remove();
+    window.URL.revokeObjectURL(url);
+};
@@ -0,0 +1,82 @@
+import { describe, expect, test } from 'bun:test';
+import { formatAbsoluteTime, formatRelativeTime, parseSqliteDateTime } from './dateFormat';
+
+// Dates are built from local-time parts, so the results don't depend on the machine's time zone
+const now = new Date(2024, 4, 2, 10, 0); // 2024-05-02 10:00
+const ago = (ms: number) => new Date(now.getTime() - ms);
+const MINUTE = 60 * 1000;
+const HOUR = 60 * MINUTE;
+
+describe('formatRelativeTime', () => {
+    test('just now within a minute, either way', () => {
+        expect(formatRelativeTime(ago(0), 'en', now)).toBe('just now');
+        expect(formatRelativeTime(ago(59 * 1000), 'en', now)).toBe('just now');
+        expect(formatRelativeTime(ago(-30 * 1000), 'en', now)).toBe('just now');
+        expect(formatRelativeTime(ago(30 * 1000), 'pl', now)).toBe('przed chwilą');
+    });
+
+    test('minutes from one minute up to an hour', () => {
+        expect(formatRelativeTime(ago(MINUTE), 'en', now)).toBe('1 minute ago');
+        expect(formatRelativeTime(ago(59 * MINUTE), 'en', now)).toBe('59 minutes ago');
+    });
+
+    test('hours on the same day', () => {
+        expect(formatRelativeTime(ago(HOUR), 'en', now)).toBe('1 hour ago');
+        expect(formatRelativeTime(ago(9 * HOUR), 'en', now)).toBe('9 hours ago');
+    });
+
+    test('a few hours across midnight are still hours', () => {
+        expect(formatRelativeTime(new Date(2024, 4, 1, 23, 0), 'en', new Date(2024, 4, 2, 1, 0))).toBe('2 hours ago');
+    });
+
+    test('yesterday by calendar date', () => {
+        expect(formatRelativeTime(new Date(2024, 4, 1, 20, 0), 'en', new Date(2024, 4, 2, 3, 0))).toBe('yesterday');
+        expect(formatRelativeTime(new Date(2024, 4, 1, 0, 5), 'en', now)).toBe('yesterday');
+    });
+
+    test('days up to a month', () => {
+        expect(formatRelativeTime(new Date(2024, 3, 29, 12, 0), 'en', now)).toBe('3 days ago');
+        expect(formatRelativeTime(new Date(2024, 3, 3, 12, 0), 'en', now)).toBe('29 days ago');
+    });
+
+    test('months below a year', () => {
+        expect(formatRelativeTime(new Date(2024, 1, 2, 10, 0), 'en', now)).toBe('3 months ago');
+        expect(formatRelativeTime(new Date(2023, 5, 2, 10, 0), 'en', now)).toBe('11 months ago');
+    });
+
+    test('years from twelve months on', () => {
+        expect(formatRelativeTime(new Date(2023, 4, 2, 10, 0), 'en', now)).toBe('last year');
+        expect(formatRelativeTime(new Date(2023, 3, 1, 10, 0), 'en', now)).toBe('last year');
+        expect(formatRelativeTime(new Date(2022, 2, 1, 10, 0), 'en', now)).toBe('2 years ago');
+    });
+
+    test('future dates read forward', () => {
+        expect(formatRelativeTime(ago(-5 * MINUTE), 'en', now)).toBe('in 5 minutes');
+        expect(formatRelativeTime(new Date(2024, 4, 3, 18, 0), 'en', now)).toBe('tomorrow');
+    });
+});
+
+describe('formatAbsoluteTime', () => {
+    test('date and minutes in local time', () => {
+        expect(formatAbsoluteTime(new Date(2024, 4, 1, 14, 22, 59))).toBe('2024-05-01 14:22');
+    });
+
+    test('pads single-digit parts', () => {
+        expect(formatAbsoluteTime(new Date(2024, 0, 5, 3, 7))).toBe('2024-01-05 03:07');
+    });
+
+    test('midnight and the last minute of the year', () => {
+        expect(formatAbsoluteTime(new Date(2024, 0, 1, 0, 0))).toBe('2024-01-01 00:00');
+        expect(formatAbsoluteTime(new Date(2023, 11, 31, 23, 59))).toBe('2023-12-31 23:59');
+    });
+});
+
+describe('parseSqliteDateTime', () => {
+    test('SQLite DATETIME text is read as UTC', () => {
+        expect(parseSqliteDateTime('2024-05-01 14:22:05').toISOString()).toBe('2024-05-01T14:22:05.000Z');
+    });
+
+    test('ISO strings with a zone are read as they are', () => {
+        expect(parseSqliteDateTime('2024-05-01T14:22:05.123Z').toISOString()).toBe('2024-05-01T14:22:05.123Z');
+    });
+});
@@ -0,0 +1,59 @@
+// Timestamp formatting shared by the notes, archive and log views. Each timestamp shows one form and
+// the other on hover; which form is primary is a user choice kept in view state.
+import { t } from '@/translations/utils';
+import type { SupportedLanguage } from '@/translations/models';
+import { loadViewState, saveViewState, type TimestampFormat } from './viewState';
+import { registerCacheReset } from './appData';
+
+const pad = (value: number) => String(value).padStart(2, '0');
+
+// "2024-05-01 14:22" in local time - sorts naturally and reads the same in every language
+export const formatAbsoluteTime = (date: Date): string =>
+    `${date.getFullYear()}-${pad(date.getMonth() + 1)}-${pad(date.getDate())} ${pad(date.getHours())}:${pad(date.getMinutes())}`;
+
+// SQLite DATETIME text ("2024-05-01 14:22:05") is UTC but has no zone, so Date would read it as local time
+export const parseSqliteDateTime = (value: string): Date =>
+    new Date(/^\d{4}-\d{2}-\d{2} \d{2}:\d{2}:\d{2}$/.test(value) ? `${value.replace(' ', 'T')}Z` : value);
+
+const MINUTE = 60 * 1000;
+const HOUR = 60 * MINUTE;
+const DAY = 24 * HOUR;
+
+// "3 days ago", "yesterday", "in 2 hours". Days are counted between calendar dates, so 23:50 seen at 00:10 is "yesterday".
+export const formatRelativeTime = (date: Date, lang: SupportedLanguage, now: Date = new Date()): string => {
+    const elapsed = now.getTime() - date.getTime();
+    if (Math.abs(elapsed) < MINUTE) return t('timeJustNow', lang);
+
+    const rtf = new Intl.RelativeTimeFormat(lang, { numeric: 'auto' });
+    if (Math.abs(elapsed) < HOUR) return rtf.format(-Math.trunc(elapsed / MINUTE), 'minute');
+
+    const startOfDay = (d: Date) => new Date(d.getFullYear(), d.getMonth(), d.getDate()).getTime();
+    const days = Math.round((startOfDay(now) - startOfDay(date)) / DAY);
+    if (days === 0 || Math.abs(elapsed) < 6 * HOUR) return rtf.format(-Math.trunc(elapsed / HOUR), 'hour');
+    if (Math.abs(days) < 30) return rtf.format(-days, 'day');
+
+    const months = (now.getFullYear() - date.getFullYear()) * 12 + now.getMonth() - date.getMonth();
+    if (Math.abs(months) < 12) return rtf.format(-months, 'month');
+    return rtf.format(-Math.trunc(months / 12), 'year');
+};
+
+// --- Preferred format (relative/absolute), shared by every timestamp on screen ---
+type TimestampFormatListener = (format: TimestampFormat) => void;
+
+// Read on first use - the stored format is the logged-in user's, so it's read again after the session changes
+let timestampFormat: TimestampFormat | null = null;
+const timestampFormatListeners = new Set<TimestampFormatListener>();
+registerCacheReset(() => { timestampFormat = null; });
+
+export const getTimestampFormat = (): TimestampFormat => timestampFormat ??= loadViewState().timestampFormat ?? 'relative';
+
+export const setTimestampFormat = (format: TimestampFormat) => {
+    timestampFormat = format;
+    saveViewState({ timestampFormat: format });
+    timestampFormatListeners.forEach(listener => listener(format));
+};
+
+export const subscribeToTimestampFormat = (listener: TimestampFormatListener) => {
+    timestampFormatListeners.add(listener);
+    return () => { timestampFormatListeners.delete(listener); };
+};
@@ -0,0 +1,43 @@
+import { registerCacheReset } from "./appData";
+
+// Full lists of reference data (all tags, all signature components) shared by every view that needs them,
+// so moving between pages doesn't refetch them each time. An entry is reused until it is older than the TTL,
+// a write request touches its endpoints, or the user refreshes the view (which clears all in-memory caches).
+
+const LIST_CACHE_TTL = 60 * 1000; // ms - bounds staleness from changes made by other users
+
+export type CachedListKey = 'tags' | 'signatureComponents';
+
+// Writes under these endpoints may change the list (element writes update a component's index count)
+const INVALIDATING_ENDPOINT_PREFIXES: Record<CachedListKey, string[]> = {
+    tags: ['/tag'], // Also matches '/tags'
+    signatureComponents: ['/signature/'],
+};
+
+const listCache = new Map<CachedListKey, { list: Promise<unknown[]>, fetchedAt: number, token: string }>();
+
+export const clearListCache = () => listCache.clear();
+registerCacheReset(clearListCache);
+
+/**
+ * Returns the cached list while fresh (and fetched with the same session), otherwise fetches it.
+ * Failed fetches aren't kept. Callers get their own copy, so sorting it in place doesn't affect others.
+ */
+export const cachedList = <T>(key: CachedListKey, token: string, fetcher: () => Promise<T[]>): Promise<T[]> => {
+    const cached = listCache.get(key);
+    if (cached && cached.token === token && Date.now() - cached.fetchedAt < LIST_CACHE_TTL) {
+        return (cached.list as Promise<T[]>).then(list => [...list]);
+    }
+
+    const list = fetcher();
+    list.catch(() => { if (listCache.get(key)?.list === list) listCache.delete(key); });
+    listCache.set(key, { list, fetchedAt: Date.now(), token });
+    return list.then(items => [...items]);
+};
+
+/** Drops the lists a write request to `endpoint` may have changed. */
+export const invalidateListsForEndpoint = (endpoint: string) => {
+    for (const [key, prefixes] of Object.entries(INVALIDATING_ENDPOINT_PREFIXES) as [CachedListKey, string[]][]) {
+        if (prefixes.some(prefix => endpoint.startsWith(prefix))) listCache.delete(key);
+    }
+};
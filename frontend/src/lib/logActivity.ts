@@ -0,0 +1,42 @@
+import type { LogEntry } from '../../../backend/src/functionalities/log/models';
+import type { SearchQueryElement, SearchQueryGroup } from '../../../backend/src/utils/search';
+
+// The log has no dedicated audit columns - changes are info entries whose message follows
+// "<Thing> created: <target>", "<Thing> updated: <target>", "<Thing> deleted: ID <n>" and similar.
+// Actions are recognized by these message fragments (LIKE matching is case-insensitive for ASCII).
+
+export type LogAction = 'create' | 'update' | 'delete' | 'move' | 'reindex';
+
+export const LOG_ACTION_FRAGMENTS: Record<LogAction, string[]> = {
+    create: ['created:'],
+    update: [' updated'],
+    delete: [' deleted'],
+    move: ['moved:', 'merged:'],
+    reindex: ['re-indexed'],
+};
+
+export const LOG_ACTIONS = Object.keys(LOG_ACTION_FRAGMENTS) as LogAction[];
+
+/** Search group matching entries of any of the given actions (one fragment is enough). */
+export const logActionGroup = (actions: LogAction[]): SearchQueryGroup => ({
+    logic: 'OR',
+    query: actions.flatMap(action => LOG_ACTION_FRAGMENTS[action]).map((fragment): SearchQueryElement =>
+        ({ field: 'message', condition: 'FRAGMENT', value: fragment, not: false })),
+});
+
+export interface LogActivity {
+    action: LogAction | null;
+    target: string;
+}
+
+/** Action and target of an entry; the target is what follows "<Thing> <action>:" without the trailing " by <login>". */
+export const parseLogActivity = (entry: LogEntry): LogActivity => {
+    const message = entry.message ?? '';
+    const lower = message.toLowerCase();
+    const action = LOG_ACTIONS.find(a => LOG_ACTION_FRAGMENTS[a].some(fragment => lower.includes(fragment))) ?? null;
+    const colonIndex = message.indexOf(':');
+    const target = action && colonIndex > 0
+        ? message.slice(colonIndex + 1).replace(/\s+by\s+\S+$/, '').trim()
+        : message;
+    return { action, target };
+};
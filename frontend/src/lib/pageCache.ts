@@ -0,0 +1,74 @@
+import type { SearchRequest, SearchResponse } from "../../../backend/src/utils/search";
+import { registerCacheReset } from "./appData";
+
+// Small cache of search result pages, so moving to an adjacent (prefetched) page is instant.
+// Keyed by session + list area + full search request (query, page, page size). Cleared on any write request.
+
+const PAGE_CACHE_MAX_ENTRIES = 30;
+const PAGE_CACHE_TTL = 60 * 1000; // ms - bounds staleness from changes made by other users
+
+type PageFetcher<T> = (request: SearchRequest, token: string) => Promise<SearchResponse<T>>;
+
+interface PageCacheEntry {
+    response: Promise<SearchResponse<any>>;
+    cachedAt: number;
+}
+
+const pageCache = new Map<string, PageCacheEntry>();
+
+// The token is part of the key, so results fetched by one user are never served to another
+const cacheKey = (area: string, request: SearchRequest, token: string) => `${token}:${area}:${JSON.stringify(request)}`;
+
+// Result counts (e.g. children of an archive unit) - tiny, so kept apart from the pages with a larger cap
+const COUNT_CACHE_MAX_ENTRIES = 500;
+const countCache = new Map<string, { total: Promise<number>, cachedAt: number }>();
+
+export const clearPageCache = () => {
+    pageCache.clear();
+    countCache.clear();
+};
+registerCacheReset(clearPageCache);
+
+/** Returns the page from the cache if present (and fresh), otherwise fetches and caches it. */
+export const cachedSearch = <T>(area: string, fetcher: PageFetcher<T>, request: SearchRequest, token: string): Promise<SearchResponse<T>> => {
+    const key = cacheKey(area, request, token);
+    const cached = pageCache.get(key);
+    if (cached && Date.now() - cached.cachedAt < PAGE_CACHE_TTL) return cached.response;
+
+    const response = fetcher(request, token);
+    // Drop failed fetches so they are retried
+    response.catch(() => { if (pageCache.get(key)?.response === response) pageCache.delete(key); });
+    pageCache.delete(key); // Re-insert to keep Map order = age order
+    pageCache.set(key, { response, cachedAt: Date.now() });
+    while (pageCache.size > PAGE_CACHE_MAX_ENTRIES) {
+        pageCache.delete(pageCache.keys().next().value!);
+    }
+    return response;
+};
+
+/** Quietly fetches the pages before and after `request.page` into the cache. */
+export const prefetchAdjacentPages = <T>(area: string, fetcher: PageFetcher<T>, request: SearchRequest, totalPages: number, token: string) => {
+    const currentPage = request.page;
+    [currentPage + 1, currentPage - 1]
+        .filter(page => page >= 1 && page <= totalPages)
+        .forEach(page => {
+            cachedSearch(area, fetcher, { ...request, page }, token)
+                .catch(err => console.warn(`Prefetch of ${area} page ${page} failed:`, err));
+        });
+};
+
+/** Number of results of a search (only its `totalSize` is fetched), cached like pages. */
+export const cachedCount = <T>(area: string, fetcher: PageFetcher<T>, query: SearchRequest['query'], token: string): Promise<number> => {
+    const key = cacheKey(area, { query, page: 1, pageSize: 1 }, token);
+    const cached = countCache.get(key);
+    if (cached && Date.now() - cached.cachedAt < PAGE_CACHE_TTL) return cached.total;
+
+    const total = fetcher({ query, page: 1, pageSize: 1 }, token).then(response => response.totalSize);
+    total.catch(() => { if (countCache.get(key)?.total === total) countCache.delete(key); });
+    countCache.delete(key);
+    countCache.set(key, { total, cachedAt: Date.now() });
+    while (countCache.size > COUNT_CACHE_MAX_ENTRIES) {
+        countCache.delete(countCache.keys().next().value!);
+    }
+    return total;
+};
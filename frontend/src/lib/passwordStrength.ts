@@ -0,0 +1,29 @@
+// Live password strength estimate for forms. The scoring is a rough guide; what the form actually
+// requires is `meetsMinimum`, which matches the rules of `passwordSchema` in zodSchemas.
+
+export const PASSWORD_MIN_LENGTH = 8;
+
+export type PasswordStrengthLevel = 'weak' | 'fair' | 'good' | 'strong';
+
+export interface PasswordStrength {
+    score: number; // 0..MAX_PASSWORD_SCORE
+    level: PasswordStrengthLevel;
+    meetsMinimum: boolean;
+}
+
+export const MAX_PASSWORD_SCORE = 6;
+
+export const evaluatePasswordStrength = (password: string): PasswordStrength => {
+    const hasUpper = /[A-Z]/.test(password);
+    const hasLower = /[a-z]/.test(password);
+    const hasDigit = /[0-9]/.test(password);
+    const hasSymbol = /[^A-Za-z0-9]/.test(password);
+    const longEnough = password.length >= PASSWORD_MIN_LENGTH;
+
+    const score = [longEnough, password.length >= 12, hasUpper, hasLower, hasDigit, hasSymbol].filter(Boolean).length;
+    const meetsMinimum = longEnough && hasUpper && hasLower && hasDigit;
+    const level: PasswordStrengthLevel =
+        !meetsMinimum ? 'weak' : score >= 6 ? 'strong' : score >= 5 ? 'good' : 'fair';
+
+    return { score, level, meetsMinimum };
+};
@@ -0,0 +1,92 @@
+import { describe, expect, test } from 'bun:test';
+import { searchRequestSchema } from '../../../backend/src/utils/search_validation';
+import type { SearchQueryElement } from '../../../backend/src/utils/search';
+import { archiveQuery, withMatchLogic } from './searchQuery';
+
+const titleHasX: SearchQueryElement = { field: 'title', condition: 'FRAGMENT', value: 'X', not: false };
+const creatorHasY: SearchQueryElement = { field: 'creator', condition: 'FRAGMENT', value: 'Y', not: false };
+
+describe('SearchQueryBuilder', () => {
+    test('each method appends its condition in order', () => {
+        expect(archiveQuery()
+            .eq('type', 'unit')
+            .fragment('title', 'letter', true)
+            .anyOf('tags', [1, 2])
+            .isNull('parentUnitArchiveDocumentId')
+            .isNotNull('creator')
+            .build()
+        ).toEqual([
+            { field: 'type', condition: 'EQ', value: 'unit', not: false },
+            { field: 'title', condition: 'FRAGMENT', value: 'letter', not: true },
+            { field: 'tags', condition: 'ANY_OF', value: [1, 2], not: false },
+            { field: 'parentUnitArchiveDocumentId', condition: 'IS_NULL', not: false },
+            { field: 'creator', condition: 'IS_NOT_NULL', not: false },
+        ]);
+    });
+
+    test('starts from the initial criteria without changing them', () => {
+        const initial: SearchQueryElement[] = [titleHasX];
+        const query = archiveQuery(initial).eq('active', true).build();
+        expect(query).toEqual([titleHasX, { field: 'active', condition: 'EQ', value: true, not: false }]);
+        expect(initial).toEqual([titleHasX]);
+    });
+
+    test('has reports whether the field has any condition', () => {
+        const builder = archiveQuery([titleHasX]);
+        expect(builder.has('title')).toBe(true);
+        expect(builder.has('active')).toBe(false);
+    });
+
+    test('without drops every condition on the field and leaves the others', () => {
+        const query = archiveQuery([titleHasX, creatorHasY, { ...titleHasX, value: 'Z' }]).without('title').build();
+        expect(query).toEqual([creatorHasY]);
+    });
+
+    test('without followed by eq replaces an existing condition', () => {
+        // What scopeListingQuery does with the open unit
+        const query = archiveQuery([{ field: 'parentUnitArchiveDocumentId', condition: 'EQ', value: 3, not: false }, titleHasX])
+            .without('parentUnitArchiveDocumentId')
+            .eq('parentUnitArchiveDocumentId', 7)
+            .build();
+        expect(query).toEqual([titleHasX, { field: 'parentUnitArchiveDocumentId', condition: 'EQ', value: 7, not: false }]);
+    });
+
+    test('build returns a copy', () => {
+        const builder = archiveQuery([titleHasX]);
+        const first = builder.build();
+        first.push(creatorHasY);
+        expect(builder.build()).toEqual([titleHasX]);
+    });
+});
+
+describe('withMatchLogic', () => {
+    test('AND keeps the flat query without groups', () => {
+        expect(withMatchLogic([titleHasX, creatorHasY], 'AND')).toEqual({ query: [titleHasX, creatorHasY] });
+    });
+
+    test('OR moves several conditions into one OR group', () => {
+        expect(withMatchLogic([titleHasX, creatorHasY], 'OR')).toEqual({
+            query: [],
+            groups: [{ logic: 'OR', query: [titleHasX, creatorHasY] }],
+        });
+    });
+
+    test('OR with a single condition stays flat', () => {
+        expect(withMatchLogic([titleHasX], 'OR')).toEqual({ query: [titleHasX] });
+    });
+
+    test('serialized requests match the backend request schema', () => {
+        for (const logic of ['AND', 'OR'] as const) {
+            const request = JSON.parse(JSON.stringify({ ...withMatchLogic([titleHasX, creatorHasY], logic), page: 1, pageSize: 10 }));
+            const parsed = searchRequestSchema.safeParse(request);
+            expect(parsed.success).toBe(true);
+            expect(parsed.data).toEqual(request);
+        }
+    });
+
+    test('a flat request without groups is still valid (older clients)', () => {
+        const parsed = searchRequestSchema.safeParse({ query: [titleHasX], page: 1, pageSize: 10 });
+        expect(parsed.success).toBe(true);
+        expect(parsed.data?.groups).toBeUndefined();
+    });
+});
@@ -0,0 +1,94 @@
+import type { SearchQueryElement, SearchQueryGroup, SearchLogic } from '../../../backend/src/utils/search';
+import type { ArchiveDocument } from '../../../backend/src/functionalities/archive/document/models';
+import type { SignatureElement } from '../../../backend/src/functionalities/signature/element/models';
+import type { NoteWithDetails } from '../../../backend/src/functionalities/note/models';
+
+// Typed construction of search queries: field names are checked against what each backend search accepts
+// (its direct columns plus custom handlers), so a typo or a renamed field fails to compile instead of
+// silently matching nothing.
+
+/** Fields accepted by the archive document search (see archive/document/controllers.ts). */
+export type ArchiveSearchField =
+    | Extract<keyof ArchiveDocument, string>
+    | 'tags'                  // ANY_OF tag IDs
+    | 'descriptiveSignature'  // Signature path conditions
+    | 'text';                 // FRAGMENT of title or creator
+
+/** Fields accepted by the signature element search (see signature/element/controllers.ts). */
+export type ElementSearchField =
+    | Extract<keyof SignatureElement, string>
+    | 'parentIds'             // ANY_OF parent element IDs
+    | 'hasParents'            // EQ true/false
+    | 'hasParentsInComponent' // EQ component ID
+    | 'componentName';        // EQ/FRAGMENT of the component's name
+
+/** Fields accepted by the note search (see note/controllers.ts). */
+export type NoteSearchField =
+    | Extract<keyof NoteWithDetails, string> // Includes ownerLogin from the users JOIN
+    | 'tags';                 // ANY_OF tag IDs
+
+type PrimitiveValue = string | number | boolean | null;
+
+export class SearchQueryBuilder<TField extends string> {
+    private elements: SearchQueryElement[];
+
+    /** Starts from existing criteria, e.g. the search bar's query. */
+    constructor(initial: SearchQueryElement[] = []) {
+        this.elements = [...initial];
+    }
+
+    eq(field: TField, value: PrimitiveValue, not = false): this {
+        this.elements.push({ field, condition: 'EQ', value, not });
+        return this;
+    }
+
+    gt(field: TField, value: string | number, not = false): this {
+        this.elements.push({ field, condition: 'GT', value, not });
+        return this;
+    }
+
+    fragment(field: TField, value: string, not = false): this {
+        this.elements.push({ field, condition: 'FRAGMENT', value, not });
+        return this;
+    }
+
+    anyOf(field: TField, values: PrimitiveValue[], not = false): this {
+        this.elements.push({ field, condition: 'ANY_OF', value: values, not });
+        return this;
+    }
+
+    isNull(field: TField): this {
+        this.elements.push({ field, condition: 'IS_NULL', not: false });
+        return this;
+    }
+
+    isNotNull(field: TField): this {
+        this.elements.push({ field, condition: 'IS_NOT_NULL', not: false });
+        return this;
+    }
+
+    /** Drops every condition on the field, so the next one replaces rather than adds to it. */
+    without(field: TField): this {
+        this.elements = this.elements.filter(element => element.field !== field);
+        return this;
+    }
+
+    has(field: TField): boolean {
+        return this.elements.some(element => element.field === field);
+    }
+
+    build(): SearchQueryElement[] {
+        return [...this.elements];
+    }
+}
+
+export const archiveQuery = (initial?: SearchQueryElement[]) => new SearchQueryBuilder<ArchiveSearchField>(initial);
+export const elementQuery = (initial?: SearchQueryElement[]) => new SearchQueryBuilder<ElementSearchField>(initial);
+export const noteQuery = (initial?: SearchQueryElement[]) => new SearchQueryBuilder<NoteSearchField>(initial);
+
+/**
+ * Request criteria for a search bar query: AND keeps the flat `query` (the backend's default meaning),
+ * OR moves the conditions into a single OR group. A lone condition means the same either way, so it stays flat.
+ */
+export const withMatchLogic = (query: SearchQueryElement[], logic: SearchLogic): { query: SearchQueryElement[]; groups?: SearchQueryGroup[] } =>
+    logic === 'OR' && query.length > 1 ? { query: [], groups: [{ logic: 'OR', query }] } : { query };
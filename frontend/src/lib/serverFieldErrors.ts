@@ -0,0 +1,41 @@
+// Per-field validation errors sent by the backend next to `message` (attached to the thrown error by fetchApi).
+// Controllers use zod's `error.format()` shape - `{ field: { _errors: ["..."] } }` - but plain
+// `{ field: "..." }` / `{ field: ["..."] }` maps are accepted as well.
+
+const firstMessage = (value: unknown): string | null => {
+    if (typeof value === 'string') return value || null;
+    if (Array.isArray(value)) return value.find((item): item is string => typeof item === 'string' && item.length > 0) ?? null;
+    if (value && typeof value === 'object') return firstMessage((value as { _errors?: unknown })._errors);
+    return null;
+};
+
+// Field name -> first message; empty when the error carries no per-field details
+export const getServerFieldErrors = (err: unknown): Record<string, string> => {
+    const errors = (err as { errors?: unknown } | null)?.errors;
+    if (!errors || typeof errors !== 'object' || Array.isArray(errors)) return {};
+    const result: Record<string, string> = {};
+    for (const [field, value] of Object.entries(errors)) {
+        if (field === '_errors') continue; // Form-level messages are already in `message`
+        const message = firstMessage(value);
+        if (message) result[field] = message;
+    }
+    return result;
+};
+
+// Shows the messages of the given form fields next to those fields (react-hook-form `setError`);
+// returns how many were applied, so callers can tell a field-level failure from a general one
+export const applyServerFieldErrors = <TField extends string>(
+    err: unknown,
+    fields: readonly TField[],
+    setFieldError: (field: TField, error: { type: string; message: string }) => void
+): number => {
+    const fieldErrors = getServerFieldErrors(err);
+    let applied = 0;
+    for (const field of fields) {
+        if (fieldErrors[field]) {
+            setFieldError(field, { type: 'server', message: fieldErrors[field] });
+            applied++;
+        }
+    }
+    return applied;
+};
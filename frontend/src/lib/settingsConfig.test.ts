@@ -0,0 +1,93 @@
+import { describe, expect, test } from 'bun:test';
+import { AppConfigKeys } from '../../../backend/src/functionalities/config/models';
+import { SETTINGS_FORM_DEFAULTS, settingsChanges, settingsFromConfig } from './settingsConfig';
+
+describe('settingsFromConfig', () => {
+    test('a full response fills every field', () => {
+        const { values, unsetKeys } = settingsFromConfig({
+            [AppConfigKeys.DEFAULT_LANGUAGE]: 'pl',
+            [AppConfigKeys.HTTP_PORT]: '80',
+            [AppConfigKeys.HTTPS_PORT]: '443',
+            [AppConfigKeys.HTTPS_KEY_PATH]: '/etc/tls/key.pem',
+            [AppConfigKeys.HTTPS_CERT_PATH]: '/etc/tls/cert.pem',
+            [AppConfigKeys.HTTPS_CA_PATH]: '',
+        });
+        expect(values).toEqual({
+            [AppConfigKeys.DEFAULT_LANGUAGE]: 'pl',
+            [AppConfigKeys.HTTP_PORT]: 80,
+            [AppConfigKeys.HTTPS_PORT]: 443,
+            [AppConfigKeys.HTTPS_KEY_PATH]: '/etc/tls/key.pem',
+            [AppConfigKeys.HTTPS_CERT_PATH]: '/etc/tls/cert.pem',
+            [AppConfigKeys.HTTPS_CA_PATH]: '',
+        });
+        expect(unsetKeys).toEqual([]);
+    });
+
+    test('keys missing from a partial response get defaults and are reported as unset', () => {
+        const { values, unsetKeys } = settingsFromConfig({
+            [AppConfigKeys.HTTP_PORT]: '9000',
+            [AppConfigKeys.DEFAULT_LANGUAGE]: null,
+        });
+        expect(values).toEqual({ ...SETTINGS_FORM_DEFAULTS, [AppConfigKeys.HTTP_PORT]: 9000 });
+        expect(unsetKeys.sort()).toEqual([
+            AppConfigKeys.DEFAULT_LANGUAGE,
+            AppConfigKeys.HTTPS_PORT,
+            AppConfigKeys.HTTPS_KEY_PATH,
+            AppConfigKeys.HTTPS_CERT_PATH,
+            AppConfigKeys.HTTPS_CA_PATH,
+        ].sort());
+    });
+
+    test('an empty value is returned, not unset', () => {
+        const { unsetKeys } = settingsFromConfig({ [AppConfigKeys.HTTPS_KEY_PATH]: '' });
+        expect(unsetKeys).not.toContain(AppConfigKeys.HTTPS_KEY_PATH);
+    });
+
+    test('invalid values fall back to defaults', () => {
+        const { values } = settingsFromConfig({
+            [AppConfigKeys.DEFAULT_LANGUAGE]: 'xx',
+            [AppConfigKeys.HTTPS_PORT]: 'not a port',
+        });
+        expect(values[AppConfigKeys.DEFAULT_LANGUAGE]).toBe(SETTINGS_FORM_DEFAULTS[AppConfigKeys.DEFAULT_LANGUAGE]);
+        expect(values[AppConfigKeys.HTTPS_PORT]).toBe(SETTINGS_FORM_DEFAULTS[AppConfigKeys.HTTPS_PORT]);
+    });
+
+    test('hidden paths are shown empty', () => {
+        const { values } = settingsFromConfig({ [AppConfigKeys.HTTPS_CERT_PATH]: 'SET (Path Hidden)' });
+        expect(values[AppConfigKeys.HTTPS_CERT_PATH]).toBe('');
+    });
+});
+
+describe('settingsChanges', () => {
+    test('a partial save sends only the edited keys, leaving unset ones untouched', () => {
+        // Loaded from a response without the HTTPS port and paths - their defaults are in the form
+        const { values } = settingsFromConfig({ [AppConfigKeys.DEFAULT_LANGUAGE]: 'en', [AppConfigKeys.HTTP_PORT]: '8080' });
+        const edited = { ...values, [AppConfigKeys.HTTP_PORT]: 8081 };
+        expect(settingsChanges(edited, { [AppConfigKeys.HTTP_PORT]: true })).toEqual([
+            { key: AppConfigKeys.HTTP_PORT, value: '8081' },
+        ]);
+    });
+
+    test('nothing edited sends nothing', () => {
+        expect(settingsChanges(SETTINGS_FORM_DEFAULTS, {})).toEqual([]);
+    });
+
+    test('an emptied path clears the setting, other values are sent as text', () => {
+        const edited = {
+            ...SETTINGS_FORM_DEFAULTS,
+            [AppConfigKeys.DEFAULT_LANGUAGE]: 'pl' as const,
+            [AppConfigKeys.HTTPS_KEY_PATH]: '',
+            [AppConfigKeys.HTTPS_CERT_PATH]: '/etc/tls/cert.pem',
+        };
+        const changes = settingsChanges(edited, {
+            [AppConfigKeys.DEFAULT_LANGUAGE]: true,
+            [AppConfigKeys.HTTPS_KEY_PATH]: true,
+            [AppConfigKeys.HTTPS_CERT_PATH]: true,
+        });
+        expect(changes).toEqual([
+            { key: AppConfigKeys.DEFAULT_LANGUAGE, value: 'pl' },
+            { key: AppConfigKeys.HTTPS_KEY_PATH, value: null },
+            { key: AppConfigKeys.HTTPS_CERT_PATH, value: '/etc/tls/cert.pem' },
+        ]);
+    });
+});
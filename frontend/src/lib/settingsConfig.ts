@@ -0,0 +1,57 @@
+import { AppConfigKeys } from '../../../backend/src/functionalities/config/models';
+import { supportedLanguages, type SupportedLanguage } from '@/translations/models';
+import type { SettingsFormData } from './zodSchemas';
+
+// Mapping between the config endpoints and the admin settings form. "Not returned" (null/undefined from
+// the server) is kept apart from "returned empty": the form shows a default for the former, and only keys
+// the user edited are ever sent back, so a missing value is never overwritten by that default.
+
+export const SETTINGS_FORM_DEFAULTS: SettingsFormData = {
+    [AppConfigKeys.DEFAULT_LANGUAGE]: 'en',
+    [AppConfigKeys.HTTP_PORT]: 8080,
+    [AppConfigKeys.HTTPS_PORT]: 8443,
+    [AppConfigKeys.HTTPS_KEY_PATH]: '',
+    [AppConfigKeys.HTTPS_CERT_PATH]: '',
+    [AppConfigKeys.HTTPS_CA_PATH]: '',
+};
+
+export const SETTINGS_PATH_KEYS = [AppConfigKeys.HTTPS_KEY_PATH, AppConfigKeys.HTTPS_CERT_PATH, AppConfigKeys.HTTPS_CA_PATH] as const;
+
+// Shown by the server instead of a path it doesn't reveal
+const HIDDEN_PATH_MARKER = 'SET (Path Hidden)';
+
+type ConfigValues = Partial<Record<AppConfigKeys, unknown>>;
+
+/** Form values for fetched config values; `unsetKeys` lists the keys the server had no value for. */
+export const settingsFromConfig = (config: ConfigValues): { values: SettingsFormData; unsetKeys: AppConfigKeys[] } => {
+    const values = { ...SETTINGS_FORM_DEFAULTS };
+    const unsetKeys = (Object.keys(SETTINGS_FORM_DEFAULTS) as AppConfigKeys[])
+        .filter(key => config[key] === null || config[key] === undefined);
+
+    const language = config[AppConfigKeys.DEFAULT_LANGUAGE];
+    if (typeof language === 'string' && supportedLanguages.includes(language as SupportedLanguage)) {
+        values[AppConfigKeys.DEFAULT_LANGUAGE] = language as SupportedLanguage;
+    }
+    for (const key of [AppConfigKeys.HTTP_PORT, AppConfigKeys.HTTPS_PORT] as const) {
+        const port = parseInt(String(config[key]), 10);
+        if (!isNaN(port)) values[key] = port;
+    }
+    for (const key of SETTINGS_PATH_KEYS) {
+        const path = config[key];
+        if (typeof path === 'string' && !path.includes(HIDDEN_PATH_MARKER)) values[key] = path;
+    }
+    return { values, unsetKeys };
+};
+
+/** Values to send for the fields the user changed (emptied paths clear the setting); untouched keys are left out. */
+export const settingsChanges = (
+    data: SettingsFormData,
+    dirtyFields: Partial<Record<keyof SettingsFormData, unknown>>,
+): { key: AppConfigKeys; value: string | null }[] =>
+    (Object.keys(data) as AppConfigKeys[])
+        .filter(key => dirtyFields[key] && data[key] !== undefined && data[key] !== null)
+        .map(key => {
+            const value = data[key];
+            if ((SETTINGS_PATH_KEYS as readonly AppConfigKeys[]).includes(key) && value === '') return { key, value: null };
+            return { key, value: String(value) };
+        });
@@ -0,0 +1,103 @@
+import api from './api';
+import { registerCacheReset } from './appData';
+import type { SignatureElement } from '../../../backend/src/functionalities/signature/element/models';
+
+// Resolves descriptive signature paths (arrays of element IDs) to elements with as few requests as possible.
+
+// Elements fetched anywhere in the app (resolved paths, listings, browser searches), shared so the selectors,
+// the element browser and document views don't each request the same elements again.
+// Entries expire like cached result pages, so renamed elements show up soon enough.
+const ELEMENT_CACHE_TTL = 60 * 1000; // ms
+const elementCache = new Map<number, { element: SignatureElement, cachedAt: number }>();
+registerCacheReset(() => elementCache.clear());
+
+/** Stores elements fetched elsewhere so later path resolution can reuse them. */
+export const cacheSignatureElements = (elements: SignatureElement[]) => {
+    const cachedAt = Date.now();
+    elements.forEach(element => {
+        if (element.signatureElementId) elementCache.set(element.signatureElementId, { element, cachedAt });
+    });
+};
+
+/** Drops an element after it was edited or deleted; without an ID the whole cache is cleared. */
+export const invalidateCachedSignatureElement = (elementId?: number) => {
+    if (elementId === undefined) elementCache.clear();
+    else elementCache.delete(elementId);
+};
+
+const getCachedElement = (id: number): SignatureElement | undefined => {
+    const cached = elementCache.get(id);
+    return cached && Date.now() - cached.cachedAt < ELEMENT_CACHE_TTL ? cached.element : undefined;
+};
+
+const MAX_IDS_PER_REQUEST = 200; // Matches the backend limit of the by-ids endpoint
+
+// Set once the batch endpoint turned out to be missing (older backend) - skip straight to per-element requests
+let isBatchEndpointUnavailable = false;
+
+const fetchElementsIndividually = async (ids: number[], token: string): Promise<SignatureElement[]> => {
+    const results = await Promise.all(ids.map(id => api.getSignatureElementById(id, [], token).catch(() => null)));
+    return results.filter((el): el is SignatureElement => el !== null);
+};
+
+const fetchUncachedElements = async (ids: number[], token: string): Promise<SignatureElement[]> => {
+    if (isBatchEndpointUnavailable) return fetchElementsIndividually(ids, token);
+    const chunks: number[][] = [];
+    for (let i = 0; i < ids.length; i += MAX_IDS_PER_REQUEST) chunks.push(ids.slice(i, i + MAX_IDS_PER_REQUEST));
+    try {
+        return (await Promise.all(chunks.map(chunk => api.getSignatureElementsByIds(chunk, token)))).flat();
+    } catch (err: any) {
+        if (err.status !== 404 && err.status !== 405) throw err;
+        console.warn("Batch element lookup unavailable, falling back to per-element requests.");
+        isBatchEndpointUnavailable = true;
+        return fetchElementsIndividually(ids, token);
+    }
+};
+
+const fetchElements = async (ids: number[], token: string): Promise<SignatureElement[]> => {
+    const cached = ids.map(getCachedElement).filter((el): el is SignatureElement => el !== undefined);
+    const missingIds = ids.filter(id => getCachedElement(id) === undefined);
+    if (missingIds.length === 0) return cached;
+    const fetched = await fetchUncachedElements(missingIds, token);
+    cacheSignatureElements(fetched);
+    return [...cached, ...fetched];
+};
+
+/** Resolves every path at once; elements that don't exist (anymore) are `null` at their position. */
+export const resolveSignaturePaths = async (paths: number[][], token: string): Promise<(SignatureElement | null)[][]> => {
+    const uniqueIds = [...new Set(paths.flat())];
+    if (uniqueIds.length === 0) return paths.map(() => []);
+    const elementsById = new Map((await fetchElements(uniqueIds, token)).map(el => [el.signatureElementId!, el]));
+    return paths.map(path => path.map(id => elementsById.get(id) ?? null));
+};
+
+/** Elements for a flat list of IDs (same batching), in the order of `ids`, missing ones skipped. */
+export const resolveSignatureElements = async (ids: number[], token: string): Promise<SignatureElement[]> => {
+    const [resolved] = await resolveSignaturePaths([ids], token);
+    return resolved.filter((el): el is SignatureElement => el !== null);
+};
+
+/** Display form of a resolved path: `[index] name / [index] name`; elements that couldn't be resolved show as `[ErrID:n]`. */
+export const formatSignaturePath = (elements: (SignatureElement | null)[], idPath: number[]): string =>
+    elements.map((el, i) => el ? `${el.index ? `[${el.index}] ` : ''}${el.name}` : `[ErrID:${idPath[i]}]`).join(' / ');
+
+// Formatted paths, for views showing documents without server-resolved signatures (same expiry as elements)
+const FORMATTED_PATH_TTL = ELEMENT_CACHE_TTL;
+const formattedPathCache = new Map<string, { text: string, cachedAt: number }>();
+registerCacheReset(() => formattedPathCache.clear());
+
+const getFormattedPath = (path: number[]): string | undefined => {
+    const cached = formattedPathCache.get(JSON.stringify(path));
+    return cached && Date.now() - cached.cachedAt < FORMATTED_PATH_TTL ? cached.text : undefined;
+};
+
+/** Formats paths (in order), resolving only those not formatted recently. */
+export const resolveFormattedSignaturePaths = async (paths: number[][], token: string): Promise<string[]> => {
+    const missing = paths.filter(path => getFormattedPath(path) === undefined);
+    if (missing.length > 0) {
+        const resolved = await resolveSignaturePaths(missing, token);
+        const cachedAt = Date.now();
+        missing.forEach((path, i) => formattedPathCache.set(JSON.stringify(path), { text: formatSignaturePath(resolved[i], path), cachedAt }));
+    }
+    return paths.map(path => getFormattedPath(path) ?? formatSignaturePath(path.map(() => null), path));
+};
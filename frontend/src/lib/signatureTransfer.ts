@@ -0,0 +1,193 @@
+import { z } from 'zod';
+import api from './api';
+import { SignatureComponentIndexType, type SignatureComponent } from '../../../backend/src/functionalities/signature/component/models';
+import type { SignatureElement } from '../../../backend/src/functionalities/signature/element/models';
+import type { SupportedLanguage } from '@/translations/models';
+import { t } from '@/translations/utils';
+
+// Moves signature components with their elements between instances as a JSON file.
+// IDs differ between instances, so parent elements are referenced by component name + element name
+// and resolved again on import: first among the imported elements, then among the ones already there.
+
+export const SIGNATURE_EXPORT_FORMAT = 'jezarch-signature-components';
+export const SIGNATURE_EXPORT_VERSION = 1;
+
+const parentRefSchema = z.object({
+    component: z.string().min(1),
+    element: z.string().min(1),
+});
+
+const exportedElementSchema = z.object({
+    name: z.string().min(1).max(100),
+    description: z.string().max(500).nullable().optional(),
+    index: z.string().max(255).nullable().optional(),
+    parents: z.array(parentRefSchema).optional().default([]),
+});
+
+const exportedComponentSchema = z.object({
+    name: z.string().min(1).max(100),
+    description: z.string().max(500).nullable().optional(),
+    index_type: SignatureComponentIndexType.optional().default('dec'),
+    elements: z.array(exportedElementSchema).default([]),
+});
+
+export const signatureExportSchema = z.object({
+    format: z.literal(SIGNATURE_EXPORT_FORMAT),
+    version: z.literal(SIGNATURE_EXPORT_VERSION),
+    exportedOn: z.string().optional(),
+    components: z.array(exportedComponentSchema),
+});
+
+export type SignatureParentRef = z.infer<typeof parentRefSchema>;
+export type SignatureExport = z.infer<typeof signatureExportSchema>;
+
+export interface SignatureImportFailure {
+    item: string; // "Component" or "Component / Element"
+    message: string;
+}
+
+export interface SignatureImportResult {
+    createdComponents: number;
+    createdElements: number;
+    failures: SignatureImportFailure[];
+}
+
+const elementKey = (componentName: string, elementName: string) => `${componentName}\u0000${elementName}`;
+
+/** Fetches the components' elements (with parents) and builds the export document. */
+export const buildSignatureExport = async (components: SignatureComponent[], token: string): Promise<SignatureExport> => {
+    // Parents may live in components that aren't exported, so names come from the full list
+    const componentNames = new Map((await api.getAllSignatureComponents(token)).map(c => [c.signatureComponentId!, c.name]));
+    const exported: SignatureExport['components'] = [];
+    for (const component of components) {
+        const elements = await api.getElementsByComponent(component.signatureComponentId!, token, ['parents']);
+        exported.push({
+            name: component.name,
+            description: component.description ?? null,
+            index_type: component.index_type,
+            elements: elements.map((element: SignatureElement) => ({
+                name: element.name,
+                description: element.description ?? null,
+                index: element.index ?? null,
+                parents: (element.parentElements ?? []).map(parent => ({
+                    component: componentNames.get(parent.signatureComponentId) ?? String(parent.signatureComponentId),
+                    element: parent.name,
+                })),
+            })),
+        });
+    }
+    return { format: SIGNATURE_EXPORT_FORMAT, version: SIGNATURE_EXPORT_VERSION, exportedOn: new Date().toISOString(), components: exported };
+};
+
+/** Parses an export file; throws with a readable message when the content isn't a valid export. */
+export const parseSignatureExport = (text: string, lang: SupportedLanguage): SignatureExport => {
+    let raw: unknown;
+    try {
+        raw = JSON.parse(text);
+    } catch {
+        throw new Error(t('signatureImportInvalidJson', lang));
+    }
+    const parsed = signatureExportSchema.safeParse(raw);
+    if (!parsed.success) {
+        const issue = parsed.error.issues[0];
+        throw new Error(t('signatureImportInvalidFile', lang, { path: issue.path.join('.') || '-', message: issue.message }));
+    }
+    return parsed.data;
+};
+
+/** Number of create requests an import makes (components + elements), used for progress. */
+export const countSignatureImportSteps = (data: SignatureExport) =>
+    data.components.reduce((sum, c) => sum + 1 + c.elements.length, 0);
+
+/**
+ * Creates the exported components and elements, then links parents to the new IDs.
+ * Components whose name already exists are skipped rather than duplicated. Every failure is collected
+ * and the import carries on, so one bad entry doesn't leave the rest half-imported.
+ */
+export const importSignatureExport = async (
+    data: SignatureExport,
+    token: string,
+    onProgress: (done: number, total: number) => void,
+    lang: SupportedLanguage,
+): Promise<SignatureImportResult> => {
+    const failures: SignatureImportFailure[] = [];
+    const total = countSignatureImportSteps(data);
+    let done = 0;
+    const step = (count = 1) => { done += count; onProgress(done, total); };
+
+    const existingComponents = await api.getAllSignatureComponents(token);
+    const existingByName = new Map(existingComponents.map(c => [c.name, c]));
+    const createdIds = new Map<string, number>(); // elementKey -> new element ID
+    const pendingParents: { elementId: number, item: string, parents: SignatureParentRef[] }[] = [];
+    let createdComponents = 0, createdElements = 0;
+
+    for (const component of data.components) {
+        if (existingByName.has(component.name)) {
+            failures.push({ item: component.name, message: t('signatureImportComponentExists', lang) });
+            step(1 + component.elements.length);
+            continue;
+        }
+        let componentId: number;
+        try {
+            const created = await api.createSignatureComponent({
+                name: component.name,
+                description: component.description ?? undefined,
+                index_type: component.index_type,
+            }, token);
+            componentId = created.signatureComponentId!;
+            createdComponents++;
+        } catch (err: any) {
+            failures.push({ item: component.name, message: err.message || t('componentSaveFailedError', lang) });
+            step(1 + component.elements.length);
+            continue;
+        }
+        step();
+
+        for (const element of component.elements) {
+            const item = `${component.name} / ${element.name}`;
+            try {
+                const created = await api.createSignatureElement({
+                    signatureComponentId: componentId,
+                    name: element.name,
+                    description: element.description ?? undefined,
+                    index: element.index ?? undefined,
+                    parentIds: [],
+                }, token);
+                createdIds.set(elementKey(component.name, element.name), created.signatureElementId!);
+                createdElements++;
+                if (element.parents.length > 0) pendingParents.push({ elementId: created.signatureElementId!, item, parents: element.parents });
+            } catch (err: any) {
+                failures.push({ item, message: err.message || t('signatureImportElementFailed', lang) });
+            }
+            step();
+        }
+    }
+
+    // Parents outside the import are looked up in the existing components, one fetch per component
+    const existingElements = new Map<string, Promise<SignatureElement[]>>();
+    const resolveParent = async (ref: SignatureParentRef): Promise<number | undefined> => {
+        const imported = createdIds.get(elementKey(ref.component, ref.element));
+        if (imported) return imported;
+        const component = existingByName.get(ref.component);
+        if (!component) return undefined;
+        if (!existingElements.has(ref.component)) existingElements.set(ref.component, api.getElementsByComponent(component.signatureComponentId!, token));
+        return (await existingElements.get(ref.component)!).find(e => e.name === ref.element)?.signatureElementId;
+    };
+
+    for (const { elementId, item, parents } of pendingParents) {
+        const parentIds: number[] = [];
+        for (const ref of parents) {
+            const parentId = await resolveParent(ref).catch(() => undefined);
+            if (parentId) parentIds.push(parentId);
+            else failures.push({ item, message: t('signatureImportParentNotFound', lang, { parent: `${ref.component} / ${ref.element}` }) });
+        }
+        if (parentIds.length === 0) continue;
+        try {
+            await api.updateSignatureElement(elementId, { parentIds }, token);
+        } catch (err: any) {
+            failures.push({ item, message: err.message || t('signatureImportParentsFailed', lang) });
+        }
+    }
+
+    return { createdComponents, createdElements, failures };
+};
@@ -0,0 +1,15 @@
+import { toast as sonnerToast } from 'sonner';
+
+// The app's toast: sonner's, except that error toasts stay on screen longer. The Toaster only takes a
+// single default duration, so the error one is injected here unless the caller passes its own.
+let errorToastDuration: number | undefined;
+
+export const setErrorToastDuration = (duration: number) => { errorToastDuration = duration; };
+
+export const toast: typeof sonnerToast = Object.assign(
+    (...args: Parameters<typeof sonnerToast>) => sonnerToast(...args),
+    sonnerToast,
+    {
+        error: ((message, data) => sonnerToast.error(message, { duration: errorToastDuration, ...data })) as typeof sonnerToast.error,
+    },
+);
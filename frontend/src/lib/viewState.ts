@@ -0,0 +1,70 @@
+// Where the user left off (admin tab, list pages, archive unit, list filters, view modes), persisted in localStorage
+// so reopening the app comes back to the same place. Kept per user, so whoever logs in next on the same browser
+// starts from their own state. Values are validated on read - anything missing or malformed falls back to the caller's default.
+
+const VIEW_STATE_STORAGE_KEY = 'viewState';
+
+// The session's user id is in localStorage or sessionStorage, depending on "Remember me"
+const storageKey = (): string => {
+    const userId = localStorage.getItem('authUserId') ?? sessionStorage.getItem('authUserId');
+    return userId ? `${VIEW_STATE_STORAGE_KEY}:${userId}` : VIEW_STATE_STORAGE_KEY;
+};
+
+export type TimestampFormat = 'relative' | 'absolute';
+export type UsersRoleFilter = 'all' | 'admin' | 'employee' | 'user' | 'none';
+const USERS_ROLE_FILTERS: UsersRoleFilter[] = ['all', 'admin', 'employee', 'user', 'none'];
+
+export interface ViewState {
+    adminTab: string;
+    notesPage: number;
+    archiveUnitId: number | null; // null = archive root
+    archivePage: number;
+    componentsFilter: string;
+    componentsSort: { field: 'name' | 'index_count', direction: 'ASC' | 'DESC' };
+    elementsViewMode: 'list' | 'tree';
+    timestampFormat: TimestampFormat;
+    archiveFormCollapsedSections: string[];
+    usersFilter: string;
+    usersRoleFilter: UsersRoleFilter;
+}
+
+const readStored = (): Record<string, unknown> => {
+    try {
+        const parsed = JSON.parse(localStorage.getItem(storageKey()) || '{}');
+        return parsed && typeof parsed === 'object' && !Array.isArray(parsed) ? parsed : {};
+    } catch {
+        return {};
+    }
+};
+
+const isPositiveInt = (value: unknown): value is number => Number.isInteger(value) && (value as number) > 0;
+
+export const loadViewState = (): Partial<ViewState> => {
+    const stored = readStored();
+    const state: Partial<ViewState> = {};
+    if (typeof stored.adminTab === 'string') state.adminTab = stored.adminTab;
+    if (isPositiveInt(stored.notesPage)) state.notesPage = stored.notesPage;
+    if (stored.archiveUnitId === null || isPositiveInt(stored.archiveUnitId)) state.archiveUnitId = stored.archiveUnitId;
+    if (isPositiveInt(stored.archivePage)) state.archivePage = stored.archivePage;
+    if (typeof stored.componentsFilter === 'string') state.componentsFilter = stored.componentsFilter;
+    const sort = stored.componentsSort as Record<string, unknown> | undefined;
+    if (sort && (sort.field === 'name' || sort.field === 'index_count') && (sort.direction === 'ASC' || sort.direction === 'DESC')) {
+        state.componentsSort = { field: sort.field, direction: sort.direction };
+    }
+    if (stored.elementsViewMode === 'list' || stored.elementsViewMode === 'tree') state.elementsViewMode = stored.elementsViewMode;
+    if (stored.timestampFormat === 'relative' || stored.timestampFormat === 'absolute') state.timestampFormat = stored.timestampFormat;
+    if (Array.isArray(stored.archiveFormCollapsedSections) && stored.archiveFormCollapsedSections.every(id => typeof id === 'string')) {
+        state.archiveFormCollapsedSections = stored.archiveFormCollapsedSections;
+    }
+    if (typeof stored.usersFilter === 'string') state.usersFilter = stored.usersFilter;
+    if (USERS_ROLE_FILTERS.includes(stored.usersRoleFilter as UsersRoleFilter)) state.usersRoleFilter = stored.usersRoleFilter as UsersRoleFilter;
+    return state;
+};
+
+export const saveViewState = (changes: Partial<ViewState>) => {
+    try {
+        localStorage.setItem(storageKey(), JSON.stringify({ ...readStored(), ...changes }));
+    } catch (err) {
+        console.error("Failed to persist view state:", err);
+    }
+};
@@ -3,8 +3,10 @@ import type { SignatureComponentIndexType } from '../../../backend/src/functiona
 import type { ArchiveDocumentType } from '../../../backend/src/functionalities/archive/document/models';
 import { AppConfigKeys } from '../../../backend/src/functionalities/config/models';
 import { searchRequestSchema as backendSearchRequestSchema } from '../../../backend/src/utils/search_validation';
+import { PASSWORD_MIN_LENGTH } from './passwordStrength';
 // --- UPDATED: Import supportedLanguages and correct type ---
 import { supportedLanguages, type SupportedLanguage as BackendSupportedLanguage } from '../../../backend/src/functionalities/user/models'; // Import supportedLanguages
+import { NOTE_CONTENT_MAX_LENGTH } from '../../../backend/src/functionalities/note/models';
 // --- Use the imported type ---
 type SupportedLanguage = BackendSupportedLanguage;
 // -----------------------------
@@ -16,7 +18,7 @@ export const loginSchema = z.object({
 });
 export type LoginFormData = z.infer<typeof loginSchema>;
 
-export const passwordSchema = z.string().min(8, "Password must be at least 8 characters")
+export const passwordSchema = z.string().min(PASSWORD_MIN_LENGTH, `Password must be at least ${PASSWORD_MIN_LENGTH} characters`)
     .regex(/[A-Z]/, "Must contain at least one uppercase letter")
     .regex(/[a-z]/, "Must contain at least one lowercase letter")
     .regex(/[0-9]/, "Must contain at least one number");
@@ -89,7 +91,7 @@ export type TagFormData = z.infer<typeof tagFormSchema>;
 // --- Note ---
 export const noteFormSchema = z.object({
     title: z.string().min(1, "Title cannot be empty"),
-    content: z.string().optional().nullable(),
+    content: z.string().max(NOTE_CONTENT_MAX_LENGTH, `Content cannot exceed ${NOTE_CONTENT_MAX_LENGTH} characters`).optional().nullable(),
     shared: z.boolean().optional().default(false),
     tagIds: z.array(z.number().int().positive()).optional().default([]),
 });
@@ -113,6 +115,15 @@ export const elementFormSchema = z.object({
 export type ElementFormData = z.infer<typeof elementFormSchema>;
 
 // --- Archive Document ---
+export const isHttpUrl = (value: string): boolean => {
+    try {
+        const url = new URL(value);
+        return url.protocol === 'http:' || url.protocol === 'https:';
+    } catch {
+        return false;
+    }
+};
+
 export const createArchiveDocumentFormSchema = z.object({
     parentUnitArchiveDocumentId: z.preprocess(
         (val) => (val === "" || val === null || val === undefined ? null : Number(val)),
@@ -124,7 +135,11 @@ export const createArchiveDocumentFormSchema = z.object({
     title: z.string().min(1, "Title cannot be empty"),
     creator: z.string().min(1, "Creator cannot be empty"),
     creationDate: z.string().min(1, "Creation date cannot be empty"),
-    numberOfPages: z.string().max(50).optional().nullable(),
+    // Stored as text, but must be a whole number when given (empty = not specified)
+    numberOfPages: z.preprocess(
+        (val) => (typeof val === "string" ? (val.trim() === "" ? null : val.trim()) : val),
+        z.string().max(50).regex(/^\d+$/, "Number of pages must be a non-negative whole number").nullable().optional()
+    ),
     documentType: z.string().max(100).optional().nullable(),
     dimensions: z.string().max(100).optional().nullable(),
     binding: z.string().max(100).optional().nullable(),
@@ -138,13 +153,18 @@ export const createArchiveDocumentFormSchema = z.object({
     relatedDocumentsReferences: z.string().max(500).optional().nullable(),
     isDigitized: z.boolean().optional().default(false),
     digitizedVersionLink: z.preprocess(
-        (val) => (val === "" ? null : val),
-        z.string().url("Invalid URL format").nullable().optional()
+        (val) => (typeof val === "string" ? (val.trim() === "" ? null : val.trim()) : val),
+        z.string().nullable().optional()
     ),
     tagIds: z.array(z.number().int().positive()).optional().default([]),
-}).refine(data => data.isDigitized || !data.digitizedVersionLink, {
-    message: "Digitized link requires 'Is Digitized' to be checked",
-    path: ["digitizedVersionLink"],
+}).superRefine((data, ctx) => {
+    // Single refinement step, so innerType() still exposes the field shapes (see BulkEditFieldDialog)
+    if (!data.digitizedVersionLink) return;
+    if (!data.isDigitized) {
+        ctx.addIssue({ code: z.ZodIssueCode.custom, message: "Digitized link requires 'Is Digitized' to be checked", path: ["digitizedVersionLink"] });
+    } else if (!isHttpUrl(data.digitizedVersionLink)) {
+        ctx.addIssue({ code: z.ZodIssueCode.custom, message: "Invalid URL format (must start with http:// or https://)", path: ["digitizedVersionLink"] });
+    }
 });
 export type CreateArchiveDocumentFormData = z.infer<typeof createArchiveDocumentFormSchema>;
 
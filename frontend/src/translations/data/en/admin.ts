@@ -112,4 +112,50 @@ export const adminTranslationsEN: AdminTranslationSet = {
   setPasswordButton: 'Set Password',
   createdBySearchLabel: 'Created By',
   updatedBySearchLabel: 'Updated By',
+  settingsLoadFailedError: 'Failed to load settings: {keys}',
+  settingsRetryButton: 'Retry',
+  settingsAutoRetrying: 'Retrying automatically ({attempt}/{max})…',
+  accessDeniedBackButton: 'Back to dashboard',
+  accessDeniedRoleMessage: 'You do not have the necessary permissions to view this page. Required role: {roles}.',
+  roleChangeRevertedError: 'Role change failed and was reverted: {message}',
+  settingsValueNotSetHint: 'Not set on the server - the default shown here is only saved if you change it.',
+  settingsNoChangesToSave: 'No settings were changed.',
+  logExportButton: 'Export logs',
+  logExportCsv: 'CSV ({count} entries)',
+  logExportJson: 'JSON ({count} entries)',
+  logExportProgress: 'Exporting {done} / {total}…',
+  logExportLargeConfirm: '{count} log entries match the current filters. Export all of them?',
+  logExportCappedConfirm: '{count} log entries match the current filters. Only the newest {max} will be exported - narrow the filters to export the rest.',
+  logExportCancelled: 'Log export cancelled.',
+  logExportSuccess: 'Exported {count} log entries to {fileName}.',
+  logExportFailed: 'Failed to export logs.',
+  logLevelFilterAll: 'All levels',
+  logMessageFilterPlaceholder: 'Filter by message...',
+  logAutoRefreshLabel: 'Auto-refresh',
+  logAutoRefreshOff: 'Auto-refresh: off',
+  logAutoRefreshEvery: 'Refresh every {seconds}s',
+  logLastRefreshed: 'Updated {time}',
+  logRefreshNow: 'Refresh now',
+  userFilterPlaceholder: 'Filter by login...',
+  userRoleFilterLabel: 'Filter by role',
+  userRoleFilterAll: 'All roles',
+  userFilterCount: 'Showing {shown} of {total} users',
+  userTotalCount: '{total, plural, one {# user} other {# users}}',
+  userFilterNoMatches: 'No users match the filter.',
+  settingsUnsavedChanges: 'Unsaved changes',
+  settingsFieldSaved: 'Saved',
+  settingsFieldSaveFailed: 'Not saved: {message}',
+  settingsPartialSaveError: '{failed} of {total} settings could not be saved - see the fields below',
+  logUserFilterPlaceholder: 'User login...',
+  logsActionColumn: 'Action',
+  logsTargetColumn: 'Target',
+  logActionFilterAll: 'All actions',
+  logActionCreate: 'Created',
+  logActionUpdate: 'Updated',
+  logActionDelete: 'Deleted',
+  logActionMove: 'Moved / merged',
+  logActionReindex: 'Re-indexed',
+  logViewModeLabel: 'Log view',
+  logViewModeEntries: 'All entries',
+  logViewModeActivity: 'User activity',
 };
\ No newline at end of file
@@ -72,7 +72,6 @@ export const archiveTranslationsEN: ArchiveTranslationSet = {
   archivePreviewAdditionalInfoLabel: 'Additional Info',
   archivePreviewRelatedDocsLabel: 'Related Docs',
   archivePreviewDigitizedLabel: 'Digitized',
-  archivePreviewDigitizedYesLink: 'Yes - Link:',
   archivePreviewDigitizedYes: 'Yes',
   archivePreviewDigitizedNo: 'No',
   archivePreviewEmptyContent: 'No content.',
@@ -136,16 +135,115 @@ export const archiveTranslationsEN: ArchiveTranslationSet = {
   unitLabel: 'Unit',
   archiveBrowsingUnit: 'Browsing items within "{unitTitle}".',
   archiveDescriptionUser: 'Search documents based on your assigned tags.',
-  archiveClickCreateHint: 'Click "Create Item" to start.',
   archivePreviewBy: 'By', // Used for original document creator
   archivePreviewOn: 'on', // Used for original document creation date
-  archivePreviewInvalidDate: 'Invalid Date',
-  archivePreviewErrorDate: 'Error',
-  archivePreviewNotApplicable: 'N/A',
   added: 'Added', // For batch tag success message
   removed: 'Removed', // For batch tag success message
   thisUnit: 'this unit',
   createRootItemButton: 'Create Item',
   createdBySearchLabel: 'Created By',
   updatedBySearchLabel: 'Updated By ',
+  archiveParentUnitDisabledWarning: 'The unit "{unitTitle}" is disabled. Documents filed into it will be hidden from regular users.',
+  archiveParentUnitDisabledConfirm: 'The unit "{unitTitle}" is disabled. Save into it anyway?',
+  archiveReenableUnitButton: 'Re-enable unit',
+  archiveChooseOtherParentButton: 'Choose another unit',
+  archiveParentUnitReenabledSuccess: 'Unit "{unitTitle}" re-enabled.',
+  archiveOpenContainingUnitButton: 'Open containing unit',
+  archiveOpenRootButton: 'Open archive root',
+  archiveDigitizedOnlyFilter: 'Digitized only',
+  archiveDigitizedIndicatorTooltip: 'Digitized',
+  archiveDigitizedOpenLinkTooltip: 'Digitized - open digitized version',
+  archiveImportCsvButton: 'Import CSV',
+  archiveImportTitle: 'Import archive items from CSV',
+  archiveImportDescription: 'The first row must name the columns (required: {columns}). Validate the file first - nothing is created until you run the import.',
+  archiveImportRowsRead: '{count, plural, one {# data row} other {# data rows}} read. Click "Validate only" to check them.',
+  archiveImportValidateButton: 'Validate only',
+  archiveImportButton: 'Import {count, plural, one {# row} other {# rows}}',
+  archiveImportValidTitle: 'Validation passed',
+  archiveImportValidText: 'All {count, plural, one {# row is} other {# rows are}} valid and ready to import.',
+  archiveImportIssuesTitle: '{count, plural, one {# problem found} other {# problems found}}',
+  archiveImportIssuesText: '{valid} of {total} rows are valid. Fix the file and validate again, or import only the valid rows.',
+  archiveImportRowColumn: 'Row',
+  archiveImportFieldColumn: 'Field',
+  archiveImportMessageColumn: 'Problem',
+  archiveImportMoreIssues: '...and {count} more in the downloadable report.',
+  archiveImportDownloadReport: 'Download error report',
+  archiveImportSkipInvalid: 'Skip rows with errors and import the valid ones',
+  archiveImportProgress: 'Importing {done} of {total}...',
+  archiveImportSuccess: 'Imported {count, plural, one {# item} other {# items}}.',
+  archiveImportRowsFailed: '{count, plural, one {# row} other {# rows}} could not be created (rows: {rows}).',
+  archiveImportUnknownColumn: 'Unknown column, it will be ignored',
+  archiveImportMissingColumn: 'Required column is missing',
+  archiveImportUnknownTag: 'Tag with ID {id} does not exist',
+  archiveImportParentNotFound: 'Parent unit with ID {id} does not exist',
+  archiveTypeFilterLabel: 'Item type',
+  archiveTypeFilterAll: 'All',
+  archiveTypeFilterUnits: 'Units',
+  archiveTypeFilterDocuments: 'Documents',
+  archiveBatchTagsLargeConfirm: 'This batch operation will change the tags of {count, plural, one {# item} other {# items}} matching the current filters.',
+  archiveBulkEditButton: 'Bulk edit',
+  archiveBulkEditTooltip: 'Change one field in all items matching the current filters, after previewing the changes',
+  archiveBulkEditTitle: 'Bulk edit field',
+  archiveBulkEditDescription: 'Change a field in the {count} items matching the current filters. The changes are shown for review before anything is saved.',
+  archiveBulkEditPreviewDescription: '{count} items will change "{field}" ({unchanged} already have the resulting value). Deselect rows to leave them unchanged.',
+  archiveBulkEditFieldLabel: 'Field',
+  archiveBulkEditModeSet: 'Set value',
+  archiveBulkEditModeReplace: 'Find and replace',
+  archiveBulkEditFindLabel: 'Find text',
+  archiveBulkEditFindRequired: 'Enter the text to find.',
+  archiveBulkEditNewValueLabel: 'New value',
+  archiveBulkEditReplaceWithLabel: 'Replace with',
+  archiveBulkEditEmptyClearsHint: 'Leave empty to clear the field.',
+  archiveBulkEditCapNote: 'Only the first {max} matching items are included - narrow the filters to edit the rest.',
+  archiveBulkEditPreviewButton: 'Preview changes',
+  archiveBulkEditPreviewFailed: 'Failed to load items for the preview.',
+  archiveBulkEditNothingChanges: 'No item would change.',
+  archiveBulkEditSelectAll: 'Select all changes',
+  archiveBulkEditOldValueColumn: 'Current value',
+  archiveBulkEditNewValueColumn: 'New value',
+  archiveBulkEditEmptyValue: '(empty)',
+  archiveBulkEditInvalidValue: 'Invalid value',
+  archiveBulkEditBackButton: 'Back',
+  archiveBulkEditApplyButton: 'Apply to {count} items',
+  archiveBulkEditConfirm: 'Change "{field}" in {count} items?',
+  archiveBulkEditProgress: 'Saving {done} / {total}…',
+  archiveBulkEditSummary: 'Updated {updated} of {total} items.',
+  archiveBulkEditFailures: 'Failed to update {failed}: {titles}',
+  archiveUnitChildCountTooltip: '{count, plural, =0 {Empty unit} one {Contains # item} other {Contains # items}}',
+  archivePreviewIdLabel: 'ID',
+  archivePreviewSignaturePathLabel: 'Signature path',
+  archiveShowDisabledFilter: 'Show disabled',
+  archiveDisabledBadge: 'Disabled',
+  archiveRestoreButton: 'Restore',
+  archiveRestoreSuccess: '"{title}" restored.',
+  archiveRestoreFailed: 'Restore failed: {message}',
+  archiveQuickSearchPlaceholder: 'Search title or creator...',
+  archiveQuickTagFilter: 'Tags',
+  archiveQuickTagFilterActive: 'Tags ({count})',
+  archiveQuickTagFilterClear: 'Clear tag filter',
+  archiveExportCsvButton: 'Export CSV',
+  archiveExportCsvTooltip: 'Export all items matching the current view and filters',
+  archiveExportProgress: 'Exporting {done} / {total}…',
+  archiveExportLargeConfirm: '{count} items match the current view. Export all of them?',
+  archiveExportCappedConfirm: '{count} items match the current view. Only the first {max} will be exported - narrow the filters to export the rest.',
+  archiveExportCancelled: 'Archive export cancelled.',
+  archiveExportSuccess: 'Exported {count} items to {fileName}.',
+  archiveExportFailed: 'Failed to export the archive.',
+  archiveDuplicateButton: 'Duplicate',
+  archiveDuplicateTitle: '{title} (copy)',
+  archiveDuplicateDialogTitle: 'Duplicate "{title}"',
+  archiveFormSectionFilledCount: '({count, plural, one {# field filled} other {# fields filled}})',
+  archiveFormExpandAllSections: 'Expand all sections',
+  archiveFormCollapseAllSections: 'Collapse optional sections',
+  archiveEmptyDescription: 'Units group documents, the way boxes and folders do on the shelf.',
+  archiveCreateFirstButton: 'Create the first item',
+  archivePreviewCanEditBadge: 'You can edit',
+  archivePreviewReadOnlyBadge: 'Read-only',
+  archivePreviewReadOnlyReason: 'Your role allows viewing archive items only. Editing, disabling and duplicating require the employee or admin role.',
+  archiveBreadcrumbsLabel: 'Unit path',
+  archiveGoToParentUnitButton: 'Go to parent unit',
+  archivePreviewDigitizedMissingLink: 'Marked as digitized, but no link to the digitized version is set.',
+  archivePreviewDigitizedInvalidLink: 'The link is not a valid http(s) address, so it can\'t be opened.',
+  archivePreviewDigitizedOpenImage: 'Open the full image in a new tab',
+  archivePreviewDigitizedThumbnailAlt: 'Digitized version preview',
 };
\ No newline at end of file
@@ -19,4 +19,13 @@ export const authTranslationsEN: AuthTranslationSet = {
   hasAccountPrompt: 'Already have an account?',
   loginLink: 'Login',
   languagePickerLabel: 'Language',
+  rememberMeLabel: 'Remember me',
+  sessionExpiredMessage: 'Session expired, please log in again.',
+  passwordStrengthLabel: 'Strength: {level}',
+  passwordStrengthWeak: 'weak',
+  passwordStrengthFair: 'fair',
+  passwordStrengthGood: 'good',
+  passwordStrengthStrong: 'strong',
+  passwordStrengthRequirements: 'use at least 8 characters with an uppercase letter, a lowercase letter and a number',
+  passwordsMismatchWarning: 'Passwords don\'t match',
 };
\ No newline at end of file
@@ -138,7 +138,6 @@ export const commonTranslationsEN: CommonTranslationSet = {
   thisUnit: 'this unit',
   notLabel: 'NOT',
   enterValuesPlaceholder: 'value1, value2...',
-  clickToCreate: 'Click {item}.',
   insufficientPermissionsError: 'Insufficient permissions.',
   notAvailableAbbr: 'N/A',
   unknown: 'unknown',
@@ -154,4 +153,109 @@ export const commonTranslationsEN: CommonTranslationSet = {
   updatedByLabel: 'Updated By',
   lastModifiedLabel: 'Last Modified', // Can be used instead of 'Updated By' depending on context
   ownerLabel: 'Owner', // Keep if needed elsewhere, otherwise remove/replace
+  notificationsLabel: 'Notifications',
+  notificationsPositionLabel: 'Position',
+  notificationsPositionTopRight: 'Top right',
+  notificationsPositionTopCenter: 'Top center',
+  notificationsPositionTopLeft: 'Top left',
+  notificationsPositionBottomRight: 'Bottom right',
+  notificationsPositionBottomCenter: 'Bottom center',
+  notificationsPositionBottomLeft: 'Bottom left',
+  notificationsDurationLabel: 'Auto-dismiss after',
+  notificationsDurationSeconds: '{seconds} s',
+  notificationsErrorDurationHint: 'Error notifications stay twice as long.',
+  previousPage: 'Previous page',
+  nextPage: 'Next page',
+  unitSelectorPageIndicator: 'Page {page} of {totalPages}',
+  pendingChangesTooltip: '{count, plural, one {# operation in progress} other {# operations in progress}}',
+  pendingChangesTitle: 'Operations in progress',
+  pendingChangesCreate: 'Creating',
+  pendingChangesUpdate: 'Updating',
+  pendingChangesDelete: 'Deleting',
+  pendingChangesAction: 'Processing',
+  copyFieldTooltip: 'Copy {label}',
+  copiedToClipboard: '{label} copied to clipboard.',
+  copyToClipboardFailed: 'Could not copy to clipboard.',
+  resetAppDataMenuItem: 'Reset app data',
+  resetAppDataDialogTitle: 'Reset app data',
+  resetAppDataDialogDescription: 'Use this if the app behaves unexpectedly, e.g. shows stale data or broken settings.',
+  resetAppDataWarning: 'All cached data and local preferences (e.g. notification settings) will be cleared and the app will reload. Data stored on the server is not affected.',
+  resetAppDataAlsoLogout: 'Also log out',
+  resetAppDataConfirmButton: 'Reset and reload',
+  markdownPreviewButton: 'Preview',
+  markdownEditButton: 'Edit',
+  markdownPreviewEmpty: 'Nothing to preview.',
+  markdownSupportedHint: 'Supports basic markdown: **bold**, *italic*, lists, [links](https://…).',
+  pasteCollapsedToSingleLine: 'Line breaks and tabs were removed from the pasted text.',
+  firstPage: 'First page',
+  lastPage: 'Last page',
+  loadingStatusLoading: 'Loading {label}…',
+  loadingStatusLoaded: '{label}: loaded {count, plural, one {# item} other {# items}}',
+  loadingStatusDone: '{label}: loading finished',
+  helpOverlayTitle: 'Help for this view',
+  helpOverlayCloseHint: 'press ? or Esc (or click anywhere) to close',
+  helpNavigation: 'Switch between the main sections of the application.',
+  helpUserMenu: 'Language, notifications, password change, app data reset and logout.',
+  helpSearch: 'Combine search criteria (field, condition, value) and press Search to filter the list.',
+  helpPagination: 'Move between result pages; the double arrows jump to the first and last page.',
+  helpArchiveDigitizedFilter: 'Show only items that have been digitized.',
+  helpArchiveBatchTags: 'Add or remove tags on all items matching the current search at once.',
+  helpArchiveCreate: 'Create a new unit or document in the current location. Shortcut: Alt+N.',
+  helpArchiveList: 'Click a unit to open it, or a document to preview it.',
+  helpNotesCreate: 'Write a new note; it can be kept private or shared with everyone. Shortcut: Alt+N.',
+  helpNotesList: 'Your notes and notes shared by others. Click a row to preview it.',
+  helpComponentsCreate: 'Create a signature component - a named list of elements (admins only). Shortcut: Alt+N.',
+  helpComponentsList: 'Click a component to manage its elements.',
+  helpElementsJumpToIndex: 'Type an element index and press Enter to jump to its row.',
+  helpElementsCreate: 'Add a new element to this component. Shortcut: Alt+N.',
+  helpElementsList: 'Elements of this component. Select rows to delete several at once.',
+  helpTagsCreate: 'Create a tag for organizing notes and archive documents. Shortcut: Alt+N.',
+  helpTagsList: 'All tags; edit or delete them from the row actions.',
+  helpAdminTabs: 'Users, application settings, database backups and system logs.',
+  confirmDialogDefaultTitle: 'Are you sure?',
+  serverAddressMenuItem: 'Server address',
+  serverAddressDialogTitle: 'Server address',
+  serverAddressDialogDescription: 'The address of the JezArch API this client talks to. Default: {defaultUrl} (the server this page was loaded from).',
+  serverAddressLabel: 'API base URL',
+  serverAddressHint: 'A full http(s) URL or a path on this server. The server must accept requests from this page. The app reloads after saving.',
+  serverAddressResetButton: 'Use default',
+  helpArchiveTypeFilter: 'Quickly narrow the list to units or documents only.',
+  confirmDialogTypeCountLabel: 'This affects {count} items. Type {count} to confirm.',
+  bulkConfirmThresholdLabel: 'Bulk action safety',
+  bulkConfirmThresholdOption: 'Above {count} items',
+  bulkConfirmThresholdHint: 'Bulk actions affecting more items than this require typing the item count.',
+  paginationSummary: 'Showing {from}–{to} of {total}',
+  themeLabel: 'Theme',
+  themeLight: 'Light',
+  themeDark: 'Dark',
+  themeSystem: 'System',
+  conditionIsEmpty: 'is empty',
+  conditionIsNotEmpty: 'is not empty',
+  helpArchiveShowDisabled: 'Include disabled items in the list so they can be restored.',
+  refreshViewTooltip: 'Refresh (reload this view from the server)',
+  helpRefresh: 'Reload the current view with fresh data from the server.',
+  helpArchiveQuickSearch: 'Quickly find items by a part of their title or creator.',
+  tableSortByTooltip: 'Sort by {column}',
+  connectivityUnknown: 'Server connection not checked yet',
+  connectivityOnline: 'Connected to the server',
+  connectivitySlow: 'Server is responding slowly ({latency} ms)',
+  connectivityOffline: 'Server unreachable',
+  connectivityCheckedAt: 'Last checked: {time}',
+  connectivityLastError: 'Last error ({time}): {message}',
+  timeJustNow: 'just now',
+  timestampToggleHint: 'Click to switch between relative and exact dates',
+  zoomLabel: 'Zoom',
+  zoomInTooltip: 'Zoom in (Ctrl+Plus)',
+  zoomOutTooltip: 'Zoom out (Ctrl+Minus)',
+  zoomResetTooltip: 'Reset zoom (Ctrl+0)',
+  searchMatchLabel: 'Match',
+  searchMatchAll: 'all criteria (AND)',
+  searchMatchAny: 'any criterion (OR)',
+  saveInProgressCloseBlocked: 'Saving... The editor can be closed once the save has finished.',
+  deleteModeLabel: 'Deleting items',
+  deleteModeConfirm: 'Ask for confirmation',
+  deleteModeUndo: 'Delete at once, allow undo',
+  deleteModeHint: 'Applies to notes and tags. With undo, the item is removed after {seconds} seconds unless you click Undo.',
+  undoDeleteToast: 'Deleted "{name}"',
+  undoButton: 'Undo',
 };
\ No newline at end of file
@@ -7,7 +7,6 @@ export const notesTranslationsEN: NotesTranslationSet = {
   notesCreateTitle: 'Create Note',
   notesEditTitle: 'Edit Note',
   notesNoNotesFound: 'No notes found.',
-  notesClickCreateHint: 'Click "Create Note" to add one.',
   notesContentColumn: 'Content',
   notesAuthorColumn: 'Author',
   notesModifiedColumn: 'Modified',
@@ -29,6 +28,49 @@ export const notesTranslationsEN: NotesTranslationSet = {
   notesSaveFailed: 'Failed to save note: {message}',
   notesPreviewTitle: 'Note Preview',
   notesPreviewBy: 'By',
-  notesPreviewOn: 'on',
-  notesNoContentPlaceholder: 'No content.', // Added Key
+  notesNoContentPlaceholder: '(no content)', // Added Key
+  notesAudienceLabel: 'Audience',
+  notesAudiencePrivate: 'Private (only me)',
+  notesAudienceEveryone: 'Shared with everyone',
+  notesAudienceLimitedHint: 'Sharing with specific users or roles is not supported by the server yet.',
+  notesContentLength: '{length} / {max} characters',
+  notesContentLongWarning: 'This note is longer than {threshold} characters and may be slow to display.',
+  notesContentTooLongError: 'Content exceeds the maximum of {max} characters allowed by the server. Shorten it to save.',
+  notesPreviewModified: 'modified',
+  notesPrivateBadge: 'Private',
+  notesExportButton: 'Export',
+  notesExportCurrentPage: 'Current page ({count})',
+  notesExportAllPages: 'All results ({count})',
+  notesExportMarkdown: 'Markdown (.md)',
+  notesExportJson: 'JSON (.json)',
+  notesExportHeader: '{count, plural, one {# note} other {# notes}} exported on {date}',
+  notesExportNoContent: '(no content)',
+  notesExportNothing: 'There are no notes to export.',
+  notesExportSuccess: 'Exported {count, plural, one {# note} other {# notes}} to {fileName}.',
+  notesExportFailed: 'Failed to export notes.',
+  notesSelectPageLabel: 'Select all notes on this page',
+  notesSelectNoteLabel: 'Select note "{title}"',
+  notesSelectedCount: '{count, plural, one {# note selected} other {# notes selected}}',
+  notesBatchTagAddButton: 'Add tags to selected',
+  notesBatchTagRemoveButton: 'Remove tags from selected',
+  notesClearSelectionButton: 'Clear selection',
+  notesBatchTagAddTitle: 'Add Tags to Selected Notes',
+  notesBatchTagRemoveTitle: 'Remove Tags from Selected Notes',
+  notesBatchTagDescription: '{action, select, add {The chosen tags will be added to} other {The chosen tags will be removed from}} {count, plural, one {# selected note} other {# selected notes}}.',
+  notesBatchTagConfirmAdd: 'Add Tags ({count})',
+  notesBatchTagConfirmRemove: 'Remove Tags ({count})',
+  notesBatchTagProgress: '{done}/{total} updated',
+  notesBatchTagSummary: '{action, select, add {Tags added} other {Tags removed}}: {updated, plural, one {# note updated} other {# notes updated}}, {unchanged} unchanged.',
+  notesBatchTagFailures: '{count, plural, one {# note} other {# notes}} could not be updated: {titles}',
+  notesContentPreviewColumn: 'Preview',
+  notesEmptyDescription: 'Notes keep your research findings and reminders in one place. You can tag them and share them with others.',
+  notesCreateFirstButton: 'Create your first note',
+  notesFilterLabel: 'Show notes',
+  notesFilterAll: 'All',
+  notesFilterMine: 'Mine',
+  notesFilterShared: 'Shared with me',
+  notesPreviewViewLabel: 'Content view',
+  notesPreviewRendered: 'Formatted',
+  notesPreviewSource: 'Source',
+  notesContentMarkdownHint: 'Markdown is supported: # headings, **bold**, *italics*, lists, > quotes, ``` code blocks and links.',
 };
\ No newline at end of file
@@ -25,7 +25,6 @@ export const signatureTranslationsEN: SignatureTranslationSet = {
   confirmDeleteComponentTitle: 'Confirm Component Deletion',
   confirmDeleteComponentMessage: 'WARNING: Deleting a component will also delete ALL its elements and potentially break references. This cannot be undone. Are you sure?',
   confirmReindexComponentTitle: 'Confirm Re-index',
-  confirmReindexComponentMessage: 'Re-indexing will recalculate indices for all elements in component ID {componentId}. Continue?',
   componentCreatedSuccess: 'Component created successfully.',
   componentUpdatedSuccess: 'Component updated successfully.',
   componentDeletedSuccess: 'Component deleted successfully.',
@@ -122,4 +121,117 @@ export const signatureTranslationsEN: SignatureTranslationSet = {
   elementLoadFailedError: 'Failed to load elements',
   componentNoChangesDetected: 'No changes detected.', // Added Key
   elementBrowserSelectComponentFirst: 'Select a component first to see elements.', // Added missing key
+  moveElementButtonTooltip: 'Move to component…',
+  moveElementDialogTitle: 'Move Element to Component',
+  moveElementDialogDescription: 'Move "{name}" out of "{componentName}" into another component.',
+  moveElementTargetComponentLabel: 'Target Component',
+  moveElementIndexWarning: 'The index "{index}" is kept as is and may need to be reassigned (or the target component reindexed) after the move.',
+  moveElementParentsWarning: 'Parent relationships will be affected: elements of the old component that point to this element as a parent keep the link, and parents from the target component become invalid.',
+  moveElementIncompatibleParentsLabel: '{count, plural, one {# parent belongs} other {# parents belong}} to the target component:',
+  moveElementClearParentsLabel: 'Remove these parents during the move',
+  moveElementConfirmButton: 'Move Element',
+  elementMovedSuccess: 'Element "{name}" moved to "{componentName}".',
+  elementMoveFailedError: 'Failed to move element',
+  elementAncestryLabel: 'Ancestry',
+  elementAncestryHint: 'Full path from the root to each selected parent (read-only).',
+  elementAncestryMultipleParentsHint: '(has more parents, first shown)',
+  elementAncestryResolveError: 'Could not resolve ancestry of element ID {id}.',
+  elementJumpToIndexLabel: 'Jump to index',
+  elementJumpToIndexPlaceholder: 'Jump to index…',
+  elementJumpNotFound: 'No element with index "{index}" in this component.',
+  elementJumpFilteredOut: 'Element with index "{index}" is hidden by the current search filters.',
+  elementJumpFailedError: 'Failed to jump to index.',
+  elementSearchEverywhereLabel: 'Search everywhere',
+  elementSearchEverywherePlaceholder: 'Search all components by name or index…',
+  elementSearchEverywhereHint: 'Type a name or index to search elements across all components.',
+  elementSearchEverywhereNoResults: 'No matching elements in any component.',
+  elementSearchEverywhereTooManyResults: 'Showing the first {count} matches. Refine your search.',
+  elementSearchEverywhereUnknownComponent: 'Component ID {id}',
+  elementSelectorBrowseByComponent: 'Browse by component',
+  elementBrowserModeEverywhere: 'Everywhere',
+  elementBrowserModeEverywhereHint: 'Search all components at once and append the chosen element to the path.',
+  elementSelectAllOnPage: 'Select all elements on this page',
+  elementSelectRow: 'Select {name}',
+  elementDeleteSelectedButton: 'Delete selected ({count})',
+  elementBulkDeleteProgress: 'Deleting {done}/{total}…',
+  elementClearSelectionButton: 'Clear selection',
+  elementBulkDeleteConfirm: 'Delete {count, plural, one {# selected element} other {# selected elements}}? This cannot be undone.',
+  elementBulkDeleteUsageWarning: '{usedCount} of {count} selected elements are used by documents ({documentCount, plural, one {# reference} other {# references}}). Those documents will keep references to deleted elements.',
+  elementBulkDeleteUnusedNote: 'None of the selected elements are used by documents.',
+  elementBulkDeleteSummary: 'Deleted {deleted} of {total} elements.',
+  elementBulkDeleteFailures: '{failed, plural, one {# element} other {# elements}} could not be deleted: {names}',
+  elementUsageCheckFailed: 'Could not check element usage: {message}',
+  elementFindDuplicatesButton: 'Find duplicates',
+  elementDuplicatesDialogTitle: 'Merge duplicate elements',
+  elementDuplicatesDialogDescription: 'Elements of "{componentName}" sharing a name or an index. Merging moves the documents, children and parents of the duplicates to the kept element and deletes the duplicates.',
+  elementDuplicatesLoadError: 'Failed to load elements for duplicate detection.',
+  elementDuplicatesNoneFound: 'No likely duplicates found in this component.',
+  elementDuplicatesSameName: 'Same name: {value}',
+  elementDuplicatesSameIndex: 'Same index: {value}',
+  elementDuplicatesKeepHint: 'Select the element to keep.',
+  elementDuplicatesDocumentCount: '{count, plural, one {# document} other {# documents}}',
+  elementDuplicatesKeptBadge: 'Kept',
+  elementMergeButton: 'Merge',
+  elementMergeConfirmTitle: 'Merge elements?',
+  elementMergeConfirmMessage: '{count, plural, one {Element} other {# elements}} ({names}) will be merged into "{keptName}" and deleted. {documentCount, plural, =0 {No documents reference them.} one {# document will be updated.} other {# documents will be updated.}} This cannot be undone.',
+  elementMergeSummary: 'Merged {merged} into "{keptName}", {documentCount} documents updated.',
+  elementMergeFailures: 'Failed to merge {failed}: {names}',
+  componentFilterPlaceholder: 'Filter by name or description…',
+  componentFilterNoMatches: 'No components match "{filter}".',
+  componentSortByTooltip: 'Sort by {column}',
+  elementParentsNotLoadedError: 'Parent elements could not be loaded. Saving is blocked so existing parents are not removed.',
+  elementParentsRetryButton: 'Retry',
+  elementViewModeLabel: 'Elements view',
+  elementViewModeList: 'List',
+  elementViewModeTree: 'Tree',
+  elementTreeViewLabel: 'Element hierarchy',
+  elementTreeExpand: 'Expand {name}',
+  elementTreeCollapse: 'Collapse {name}',
+  elementTreeLoadFailed: 'Failed to load elements of this level.',
+  signaturePathCopyLabel: 'Signature path',
+  elementBrowserTruncatePathTooltip: 'Go back to "{name}"',
+  elementBrowserPathAdded: 'Added path: {path}',
+  signatureDragHandleLabel: 'Drag to reorder',
+  elementBrowserKeyboardHint: '↑/↓ to move, Enter to pick, Backspace to step back, Ctrl+Enter to add the path',
+  componentsEmptyDescription: 'Components are the dictionaries (e.g. places, people, subjects) whose elements build descriptive signatures.',
+  componentsCreateFirstButton: 'Create your first component',
+  elementsEmptyDescription: 'Elements are the entries of this component used in descriptive signatures.',
+  elementsCreateFirstButton: 'Create the first element',
+  reindexPreviewDialogTitle: 'Preview re-indexing',
+  reindexPreviewDialogDescription: 'Elements of "{componentName}" are numbered by name using the {indexType} index type. Review the new indexes before applying them.',
+  reindexPreviewLoadError: 'Failed to load elements for the preview',
+  reindexPreviewNoElements: 'This component has no elements to re-index.',
+  reindexPreviewChangedCount: '{changed, plural, =0 {No index changes} one {# of {total} indexes changes} other {# of {total} indexes change}}',
+  reindexPreviewCurrentIndex: 'Current index',
+  reindexPreviewNewIndex: 'New index',
+  reindexPreviewConfirmButton: 'Re-index',
+  reindexAllComponentsButton: 'Re-index all',
+  reindexAllComponentsTooltip: 'Re-index the elements of every component',
+  reindexAllComponentsProgress: 'Re-indexing {done} / {total}…',
+  confirmReindexAllComponentsMessage: 'Re-indexing will recalculate indexes for all elements in {count, plural, one {# component} other {# components}}. Continue?',
+  reindexAllComponentsSummary: 'Re-indexed {count, plural, one {# component} other {# components}}',
+  reindexAllComponentsFailures: 'Failed to re-index {failed, plural, one {# component} other {# components}}: {names}',
+  exportComponentButtonTooltip: 'Export component with its elements (JSON)',
+  exportAllComponentsButton: 'Export all',
+  exportAllComponentsTooltip: 'Export all components with their elements to a JSON file',
+  componentsExportedSuccess: 'Exported {components, plural, one {# component} other {# components}} with {elements, plural, one {# element} other {# elements}}.',
+  componentsExportFailedError: 'Failed to export components',
+  importComponentsButton: 'Import',
+  signatureImportTitle: 'Import signature components',
+  signatureImportDescription: 'Choose a JSON file exported from the components list. Components are created with their elements, and parent elements are linked by component and element name. Components whose name already exists are skipped.',
+  signatureImportReadyTitle: 'File ready to import',
+  signatureImportReadyText: '{components, plural, one {# component} other {# components}} with {elements, plural, one {# element} other {# elements}} will be created.',
+  signatureImportButton: 'Import',
+  signatureImportProgress: 'Importing {done} of {total}...',
+  signatureImportSuccess: 'Created {components, plural, one {# component} other {# components}} and {elements, plural, one {# element} other {# elements}}.',
+  signatureImportFailuresTitle: '{count, plural, one {# problem} other {# problems}} during import',
+  signatureImportItemColumn: 'Item',
+  signatureImportMessageColumn: 'Problem',
+  signatureImportFailedError: 'Import failed',
+  signatureImportInvalidJson: 'The file is not valid JSON.',
+  signatureImportInvalidFile: 'Not a signature components export ({path}: {message}).',
+  signatureImportComponentExists: 'A component with this name already exists - skipped.',
+  signatureImportElementFailed: 'Failed to create element',
+  signatureImportParentNotFound: 'Parent element "{parent}" not found.',
+  signatureImportParentsFailed: 'Failed to link parent elements',
 };
\ No newline at end of file
@@ -7,7 +7,6 @@ export const tagsTranslationsEN: TagsTranslationSet = {
   tagsCreateTitle: 'Create Tag',
   tagsEditTitle: 'Edit Tag',
   tagsNoTagsFound: 'No tags found.',
-  tagsClickCreateHint: 'Click "Create Tag" to add one.',
   tagLabelSingular: 'Tag', // Singular form
   tagsEditDialogDescription: 'Edit the tag "{tagName}".',
   tagsCreateDialogDescription: 'Create a new tag to organize content.',
@@ -21,4 +20,11 @@ export const tagsTranslationsEN: TagsTranslationSet = {
   tagLoadFailedError: 'Failed to load tags',
   tagSaveSuccess: 'Tag {action} successfully.',
   tagsLoadErrorPlaceholder: 'Could not load tags. Please try again later.',
+  tagInlineRenameHint: 'Double-click to rename',
+  tagInlineRenameLabel: 'New name for tag "{tagName}"',
+  tagInlineRenameSuccess: 'Tag "{oldName}" renamed to "{newName}".',
+  tagCreatedByLabel: 'Created by',
+  tagCreatedOnLabel: 'Created',
+  tagsEmptyDescription: 'Tags group notes and archive items by topic, so they are easy to find later.',
+  tagsCreateFirstButton: 'Create your first tag',
 };
\ No newline at end of file
@@ -112,4 +112,50 @@ export const adminTranslationsPL: AdminTranslationSet = {
   setPasswordButton: 'Ustaw Hasło',
   createdBySearchLabel: 'Utworzone przez',
   updatedBySearchLabel: 'Zaktualizowane przez',
+  settingsLoadFailedError: 'Nie udało się wczytać ustawień: {keys}',
+  settingsRetryButton: 'Ponów',
+  settingsAutoRetrying: 'Automatyczna ponowna próba ({attempt}/{max})…',
+  accessDeniedBackButton: 'Wróć do pulpitu',
+  accessDeniedRoleMessage: 'Nie masz uprawnień do wyświetlenia tej strony. Wymagana rola: {roles}.',
+  roleChangeRevertedError: 'Zmiana roli nie powiodła się i została cofnięta: {message}',
+  settingsValueNotSetHint: 'Brak wartości na serwerze - pokazana wartość domyślna zostanie zapisana tylko po jej zmianie.',
+  settingsNoChangesToSave: 'Nie zmieniono żadnych ustawień.',
+  logExportButton: 'Eksportuj logi',
+  logExportCsv: 'CSV (wpisów: {count})',
+  logExportJson: 'JSON (wpisów: {count})',
+  logExportProgress: 'Eksportowanie {done} / {total}…',
+  logExportLargeConfirm: 'Bieżącym filtrom odpowiada {count} wpisów logu. Wyeksportować wszystkie?',
+  logExportCappedConfirm: 'Bieżącym filtrom odpowiada {count} wpisów logu. Wyeksportowanych zostanie tylko {max} najnowszych - zawęź filtry, aby wyeksportować pozostałe.',
+  logExportCancelled: 'Anulowano eksport logów.',
+  logExportSuccess: 'Wyeksportowano wpisy logu ({count}) do {fileName}.',
+  logExportFailed: 'Nie udało się wyeksportować logów.',
+  logLevelFilterAll: 'Wszystkie poziomy',
+  logMessageFilterPlaceholder: 'Filtruj po treści...',
+  logAutoRefreshLabel: 'Automatyczne odświeżanie',
+  logAutoRefreshOff: 'Odświeżanie: wył.',
+  logAutoRefreshEvery: 'Odświeżaj co {seconds}s',
+  logLastRefreshed: 'Zaktualizowano {time}',
+  logRefreshNow: 'Odśwież teraz',
+  userFilterPlaceholder: 'Filtruj po loginie...',
+  userRoleFilterLabel: 'Filtruj po roli',
+  userRoleFilterAll: 'Wszystkie role',
+  userFilterCount: 'Wyświetlono {shown} z {total} użytkowników',
+  userTotalCount: '{total, plural, one {# użytkownik} few {# użytkownicy} many {# użytkowników} other {# użytkownika}}',
+  userFilterNoMatches: 'Żaden użytkownik nie pasuje do filtra.',
+  settingsUnsavedChanges: 'Niezapisane zmiany',
+  settingsFieldSaved: 'Zapisano',
+  settingsFieldSaveFailed: 'Nie zapisano: {message}',
+  settingsPartialSaveError: 'Nie udało się zapisać {failed} z {total} ustawień - szczegóły przy polach poniżej',
+  logUserFilterPlaceholder: 'Login użytkownika...',
+  logsActionColumn: 'Akcja',
+  logsTargetColumn: 'Obiekt',
+  logActionFilterAll: 'Wszystkie akcje',
+  logActionCreate: 'Utworzenie',
+  logActionUpdate: 'Zmiana',
+  logActionDelete: 'Usunięcie',
+  logActionMove: 'Przeniesienie / scalenie',
+  logActionReindex: 'Przeindeksowanie',
+  logViewModeLabel: 'Widok logów',
+  logViewModeEntries: 'Wszystkie wpisy',
+  logViewModeActivity: 'Aktywność użytkowników',
 };
\ No newline at end of file
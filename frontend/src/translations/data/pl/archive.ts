@@ -72,7 +72,6 @@ export const archiveTranslationsPL: ArchiveTranslationSet = {
   archivePreviewAdditionalInfoLabel: 'Dodatkowe Informacje',
   archivePreviewRelatedDocsLabel: 'Powiązane Dok.',
   archivePreviewDigitizedLabel: 'Zdigitalizowano',
-  archivePreviewDigitizedYesLink: 'Tak - Link:',
   archivePreviewDigitizedYes: 'Tak',
   archivePreviewDigitizedNo: 'Nie',
   archivePreviewEmptyContent: 'Brak treści.',
@@ -136,12 +135,8 @@ export const archiveTranslationsPL: ArchiveTranslationSet = {
   unitLabel: 'Jednostka',
   archiveBrowsingUnit: 'Przeglądanie pozycji w jednostce "{unitTitle}".',
   archiveDescriptionUser: 'Przeszukuj dokumenty na podstawie przypisanych tagów.',
-  archiveClickCreateHint: 'Kliknij "Utwórz Pozycję", aby rozpocząć.',
   archivePreviewBy: 'Autor:', // Used for original document creator
   archivePreviewOn: 'dnia', // Used for original document creation date
-  archivePreviewInvalidDate: 'Nieprawidłowa Data',
-  archivePreviewErrorDate: 'Błąd Daty',
-  archivePreviewNotApplicable: 'Nie dotyczy', // Translation for N/A
   added: 'Dodano', // For batch tags success
   removed: 'Usunięto', // For batch tags success
   thisUnit: 'tę jednostkę',
@@ -151,4 +146,107 @@ export const archiveTranslationsPL: ArchiveTranslationSet = {
   // --- NEW: createdBy/updatedBy search field labels ---
   createdBySearchLabel: 'Utworzone przez',
   updatedBySearchLabel: 'Zaktualizowane przez',
+  archiveParentUnitDisabledWarning: 'Jednostka "{unitTitle}" jest wyłączona. Dokumenty w niej umieszczone będą ukryte przed zwykłymi użytkownikami.',
+  archiveParentUnitDisabledConfirm: 'Jednostka "{unitTitle}" jest wyłączona. Czy mimo to zapisać w niej?',
+  archiveReenableUnitButton: 'Włącz ponownie jednostkę',
+  archiveChooseOtherParentButton: 'Wybierz inną jednostkę',
+  archiveParentUnitReenabledSuccess: 'Jednostka "{unitTitle}" została ponownie włączona.',
+  archiveOpenContainingUnitButton: 'Otwórz jednostkę nadrzędną',
+  archiveOpenRootButton: 'Otwórz katalog główny archiwum',
+  archiveDigitizedOnlyFilter: 'Tylko zdigitalizowane',
+  archiveDigitizedIndicatorTooltip: 'Zdigitalizowany',
+  archiveDigitizedOpenLinkTooltip: 'Zdigitalizowany - otwórz wersję cyfrową',
+  archiveImportCsvButton: 'Importuj CSV',
+  archiveImportTitle: 'Import pozycji archiwum z CSV',
+  archiveImportDescription: 'Pierwszy wiersz musi zawierać nazwy kolumn (wymagane: {columns}). Najpierw zweryfikuj plik - nic nie zostanie utworzone przed uruchomieniem importu.',
+  archiveImportRowsRead: 'Wczytano {count, plural, one {# wiersz danych} few {# wiersze danych} many {# wierszy danych} other {# wierszy danych}}. Kliknij "Tylko weryfikuj", aby je sprawdzić.',
+  archiveImportValidateButton: 'Tylko weryfikuj',
+  archiveImportButton: 'Importuj {count, plural, one {# wiersz} few {# wiersze} many {# wierszy} other {# wierszy}}',
+  archiveImportValidTitle: 'Weryfikacja zakończona pomyślnie',
+  archiveImportValidText: 'Wszystkie wiersze ({count}) są poprawne i gotowe do importu.',
+  archiveImportIssuesTitle: 'Znaleziono problemy: {count}',
+  archiveImportIssuesText: 'Poprawne wiersze: {valid} z {total}. Popraw plik i zweryfikuj ponownie lub zaimportuj tylko poprawne wiersze.',
+  archiveImportRowColumn: 'Wiersz',
+  archiveImportFieldColumn: 'Pole',
+  archiveImportMessageColumn: 'Problem',
+  archiveImportMoreIssues: '...oraz {count} więcej w raporcie do pobrania.',
+  archiveImportDownloadReport: 'Pobierz raport błędów',
+  archiveImportSkipInvalid: 'Pomiń błędne wiersze i zaimportuj poprawne',
+  archiveImportProgress: 'Importowanie {done} z {total}...',
+  archiveImportSuccess: 'Zaimportowano pozycje: {count}.',
+  archiveImportRowsFailed: 'Nie udało się utworzyć wierszy: {count} (wiersze: {rows}).',
+  archiveImportUnknownColumn: 'Nieznana kolumna, zostanie pominięta',
+  archiveImportMissingColumn: 'Brak wymaganej kolumny',
+  archiveImportUnknownTag: 'Tag o ID {id} nie istnieje',
+  archiveImportParentNotFound: 'Jednostka nadrzędna o ID {id} nie istnieje',
+  archiveTypeFilterLabel: 'Typ pozycji',
+  archiveTypeFilterAll: 'Wszystkie',
+  archiveTypeFilterUnits: 'Jednostki',
+  archiveTypeFilterDocuments: 'Dokumenty',
+  archiveBatchTagsLargeConfirm: 'Ta operacja zbiorcza zmieni tagi {count, plural, one {# pozycji} other {# pozycji}} pasujących do obecnych filtrów.',
+  archiveBulkEditButton: 'Edycja zbiorcza',
+  archiveBulkEditTooltip: 'Zmień jedno pole we wszystkich pozycjach pasujących do bieżących filtrów, po podglądzie zmian',
+  archiveBulkEditTitle: 'Zbiorcza edycja pola',
+  archiveBulkEditDescription: 'Zmień pole w {count} pozycjach pasujących do bieżących filtrów. Zmiany zostaną pokazane do przejrzenia przed zapisaniem.',
+  archiveBulkEditPreviewDescription: 'W {count} pozycjach zmieni się "{field}" ({unchanged} ma już docelową wartość). Odznacz wiersze, których nie chcesz zmieniać.',
+  archiveBulkEditFieldLabel: 'Pole',
+  archiveBulkEditModeSet: 'Ustaw wartość',
+  archiveBulkEditModeReplace: 'Znajdź i zamień',
+  archiveBulkEditFindLabel: 'Szukany tekst',
+  archiveBulkEditFindRequired: 'Wpisz szukany tekst.',
+  archiveBulkEditNewValueLabel: 'Nowa wartość',
+  archiveBulkEditReplaceWithLabel: 'Zamień na',
+  archiveBulkEditEmptyClearsHint: 'Pozostaw puste, aby wyczyścić pole.',
+  archiveBulkEditCapNote: 'Uwzględnionych zostanie tylko pierwszych {max} pasujących pozycji - zawęź filtry, aby edytować pozostałe.',
+  archiveBulkEditPreviewButton: 'Podgląd zmian',
+  archiveBulkEditPreviewFailed: 'Nie udało się wczytać pozycji do podglądu.',
+  archiveBulkEditNothingChanges: 'Żadna pozycja nie zostałaby zmieniona.',
+  archiveBulkEditSelectAll: 'Zaznacz wszystkie zmiany',
+  archiveBulkEditOldValueColumn: 'Obecna wartość',
+  archiveBulkEditNewValueColumn: 'Nowa wartość',
+  archiveBulkEditEmptyValue: '(puste)',
+  archiveBulkEditInvalidValue: 'Nieprawidłowa wartość',
+  archiveBulkEditBackButton: 'Wstecz',
+  archiveBulkEditApplyButton: 'Zastosuj do {count} pozycji',
+  archiveBulkEditConfirm: 'Zmienić "{field}" w {count} pozycjach?',
+  archiveBulkEditProgress: 'Zapisywanie {done} / {total}…',
+  archiveBulkEditSummary: 'Zaktualizowano {updated} z {total} pozycji.',
+  archiveBulkEditFailures: 'Nie udało się zaktualizować {failed}: {titles}',
+  archiveUnitChildCountTooltip: '{count, plural, =0 {Pusta jednostka} one {Zawiera # element} few {Zawiera # elementy} other {Zawiera # elementów}}',
+  archivePreviewIdLabel: 'ID',
+  archivePreviewSignaturePathLabel: 'Ścieżka sygnatury',
+  archiveShowDisabledFilter: 'Pokaż wyłączone',
+  archiveDisabledBadge: 'Wyłączona',
+  archiveRestoreButton: 'Przywróć',
+  archiveRestoreSuccess: 'Przywrócono "{title}".',
+  archiveRestoreFailed: 'Przywracanie nie powiodło się: {message}',
+  archiveQuickSearchPlaceholder: 'Szukaj w tytule lub twórcy...',
+  archiveQuickTagFilter: 'Tagi',
+  archiveQuickTagFilterActive: 'Tagi ({count})',
+  archiveQuickTagFilterClear: 'Wyczyść filtr tagów',
+  archiveExportCsvButton: 'Eksportuj CSV',
+  archiveExportCsvTooltip: 'Eksportuj wszystkie elementy pasujące do bieżącego widoku i filtrów',
+  archiveExportProgress: 'Eksportowanie {done} / {total}…',
+  archiveExportLargeConfirm: 'Bieżącemu widokowi odpowiada {count} elementów. Wyeksportować wszystkie?',
+  archiveExportCappedConfirm: 'Bieżącemu widokowi odpowiada {count} elementów. Wyeksportowanych zostanie tylko {max} pierwszych - zawęź filtry, aby wyeksportować pozostałe.',
+  archiveExportCancelled: 'Anulowano eksport archiwum.',
+  archiveExportSuccess: 'Wyeksportowano elementy ({count}) do {fileName}.',
+  archiveExportFailed: 'Nie udało się wyeksportować archiwum.',
+  archiveDuplicateButton: 'Duplikuj',
+  archiveDuplicateTitle: '{title} (kopia)',
+  archiveDuplicateDialogTitle: 'Duplikuj „{title}”',
+  archiveFormSectionFilledCount: '({count, plural, one {# wypełnione pole} few {# wypełnione pola} other {# wypełnionych pól}})',
+  archiveFormExpandAllSections: 'Rozwiń wszystkie sekcje',
+  archiveFormCollapseAllSections: 'Zwiń sekcje opcjonalne',
+  archiveEmptyDescription: 'Jednostki grupują dokumenty, tak jak pudła i teczki na półce.',
+  archiveCreateFirstButton: 'Utwórz pierwszą pozycję',
+  archivePreviewCanEditBadge: 'Możesz edytować',
+  archivePreviewReadOnlyBadge: 'Tylko do odczytu',
+  archivePreviewReadOnlyReason: 'Twoja rola pozwala tylko na przeglądanie elementów archiwum. Edycja, wyłączanie i duplikowanie wymagają roli pracownika lub administratora.',
+  archiveBreadcrumbsLabel: 'Ścieżka jednostki',
+  archiveGoToParentUnitButton: 'Przejdź do jednostki nadrzędnej',
+  archivePreviewDigitizedMissingLink: 'Oznaczono jako zdigitalizowany, ale brak linku do wersji cyfrowej.',
+  archivePreviewDigitizedInvalidLink: 'Link nie jest poprawnym adresem http(s), więc nie można go otworzyć.',
+  archivePreviewDigitizedOpenImage: 'Otwórz pełny obraz w nowej karcie',
+  archivePreviewDigitizedThumbnailAlt: 'Podgląd wersji cyfrowej',
 };
\ No newline at end of file
@@ -19,4 +19,13 @@ export const authTranslationsPL: AuthTranslationSet = {
   hasAccountPrompt: 'Masz już konto?',
   loginLink: 'Zaloguj się',
   languagePickerLabel: 'Język',
+  rememberMeLabel: 'Zapamiętaj mnie',
+  sessionExpiredMessage: 'Sesja wygasła, zaloguj się ponownie.',
+  passwordStrengthLabel: 'Siła hasła: {level}',
+  passwordStrengthWeak: 'słabe',
+  passwordStrengthFair: 'średnie',
+  passwordStrengthGood: 'dobre',
+  passwordStrengthStrong: 'silne',
+  passwordStrengthRequirements: 'użyj co najmniej 8 znaków, w tym wielkiej litery, małej litery i cyfry',
+  passwordsMismatchWarning: 'Hasła nie są zgodne',
 };
\ No newline at end of file
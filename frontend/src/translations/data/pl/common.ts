@@ -138,7 +138,6 @@ export const commonTranslationsPL: CommonTranslationSet = {
   thisUnit: 'tę jednostkę',
   notLabel: 'NIE',
   enterValuesPlaceholder: 'wartość1, wartość2...',
-  clickToCreate: 'Kliknij {item}.',
   insufficientPermissionsError: 'Niewystarczające uprawnienia.',
   notAvailableAbbr: 'B/D', // Brak Danych
   unknown: 'nieznany',
@@ -154,4 +153,109 @@ export const commonTranslationsPL: CommonTranslationSet = {
   updatedByLabel: 'Zaktualizowane przez',
   lastModifiedLabel: 'Ostatnia modyfikacja', // Can be used instead of 'Updated By' depending on context
   ownerLabel: 'Właściciel', // Keep if needed elsewhere, otherwise remove/replace
+  notificationsLabel: 'Powiadomienia',
+  notificationsPositionLabel: 'Położenie',
+  notificationsPositionTopRight: 'Prawy górny róg',
+  notificationsPositionTopCenter: 'Góra, środek',
+  notificationsPositionTopLeft: 'Lewy górny róg',
+  notificationsPositionBottomRight: 'Prawy dolny róg',
+  notificationsPositionBottomCenter: 'Dół, środek',
+  notificationsPositionBottomLeft: 'Lewy dolny róg',
+  notificationsDurationLabel: 'Ukryj automatycznie po',
+  notificationsDurationSeconds: '{seconds} s',
+  notificationsErrorDurationHint: 'Powiadomienia o błędach są wyświetlane dwa razy dłużej.',
+  previousPage: 'Poprzednia strona',
+  nextPage: 'Następna strona',
+  unitSelectorPageIndicator: 'Strona {page} z {totalPages}',
+  pendingChangesTooltip: '{count, plural, one {# operacja w toku} few {# operacje w toku} other {# operacji w toku}}',
+  pendingChangesTitle: 'Operacje w toku',
+  pendingChangesCreate: 'Tworzenie',
+  pendingChangesUpdate: 'Aktualizacja',
+  pendingChangesDelete: 'Usuwanie',
+  pendingChangesAction: 'Przetwarzanie',
+  copyFieldTooltip: 'Kopiuj: {label}',
+  copiedToClipboard: 'Skopiowano do schowka: {label}.',
+  copyToClipboardFailed: 'Nie udało się skopiować do schowka.',
+  resetAppDataMenuItem: 'Resetuj dane aplikacji',
+  resetAppDataDialogTitle: 'Resetuj dane aplikacji',
+  resetAppDataDialogDescription: 'Użyj tej opcji, jeśli aplikacja działa nieprawidłowo, np. pokazuje nieaktualne dane lub błędne ustawienia.',
+  resetAppDataWarning: 'Wszystkie dane w pamięci podręcznej i lokalne preferencje (np. ustawienia powiadomień) zostaną wyczyszczone, a aplikacja zostanie przeładowana. Dane zapisane na serwerze nie zostaną naruszone.',
+  resetAppDataAlsoLogout: 'Wyloguj również',
+  resetAppDataConfirmButton: 'Resetuj i przeładuj',
+  markdownPreviewButton: 'Podgląd',
+  markdownEditButton: 'Edytuj',
+  markdownPreviewEmpty: 'Brak treści do podglądu.',
+  markdownSupportedHint: 'Obsługuje podstawowy markdown: **pogrubienie**, *kursywa*, listy, [linki](https://…).',
+  pasteCollapsedToSingleLine: 'Z wklejonego tekstu usunięto znaki nowej linii i tabulacji.',
+  firstPage: 'Pierwsza strona',
+  lastPage: 'Ostatnia strona',
+  loadingStatusLoading: 'Wczytywanie: {label}…',
+  loadingStatusLoaded: '{label}: wczytano {count, plural, one {# element} few {# elementy} other {# elementów}}',
+  loadingStatusDone: '{label}: wczytywanie zakończone',
+  helpOverlayTitle: 'Pomoc dla tego widoku',
+  helpOverlayCloseHint: 'naciśnij ? lub Esc (albo kliknij gdziekolwiek), aby zamknąć',
+  helpNavigation: 'Przełączaj się między głównymi sekcjami aplikacji.',
+  helpUserMenu: 'Język, powiadomienia, zmiana hasła, reset danych aplikacji i wylogowanie.',
+  helpSearch: 'Łącz kryteria wyszukiwania (pole, warunek, wartość) i naciśnij Szukaj, aby przefiltrować listę.',
+  helpPagination: 'Przechodź między stronami wyników; podwójne strzałki przenoszą na pierwszą i ostatnią stronę.',
+  helpArchiveDigitizedFilter: 'Pokaż tylko zdigitalizowane pozycje.',
+  helpArchiveBatchTags: 'Dodaj lub usuń tagi we wszystkich pozycjach pasujących do bieżącego wyszukiwania naraz.',
+  helpArchiveCreate: 'Utwórz nową jednostkę lub dokument w bieżącym miejscu. Skrót: Alt+N.',
+  helpArchiveList: 'Kliknij jednostkę, aby ją otworzyć, lub dokument, aby wyświetlić podgląd.',
+  helpNotesCreate: 'Napisz nową notatkę; może pozostać prywatna lub być udostępniona wszystkim. Skrót: Alt+N.',
+  helpNotesList: 'Twoje notatki i notatki udostępnione przez innych. Kliknij wiersz, aby wyświetlić podgląd.',
+  helpComponentsCreate: 'Utwórz komponent sygnatury - nazwaną listę elementów (tylko administratorzy). Skrót: Alt+N.',
+  helpComponentsList: 'Kliknij komponent, aby zarządzać jego elementami.',
+  helpElementsJumpToIndex: 'Wpisz indeks elementu i naciśnij Enter, aby przejść do jego wiersza.',
+  helpElementsCreate: 'Dodaj nowy element do tego komponentu. Skrót: Alt+N.',
+  helpElementsList: 'Elementy tego komponentu. Zaznacz wiersze, aby usunąć kilka naraz.',
+  helpTagsCreate: 'Utwórz tag do porządkowania notatek i dokumentów archiwum. Skrót: Alt+N.',
+  helpTagsList: 'Wszystkie tagi; edytuj je lub usuwaj za pomocą akcji w wierszu.',
+  helpAdminTabs: 'Użytkownicy, ustawienia aplikacji, kopie zapasowe bazy danych i logi systemowe.',
+  confirmDialogDefaultTitle: 'Czy na pewno?',
+  serverAddressMenuItem: 'Adres serwera',
+  serverAddressDialogTitle: 'Adres serwera',
+  serverAddressDialogDescription: 'Adres API JezArch, z którym łączy się ten klient. Domyślnie: {defaultUrl} (serwer, z którego wczytano tę stronę).',
+  serverAddressLabel: 'Bazowy adres URL API',
+  serverAddressHint: 'Pełny adres http(s) lub ścieżka na tym serwerze. Serwer musi akceptować żądania z tej strony. Po zapisaniu aplikacja zostanie przeładowana.',
+  serverAddressResetButton: 'Użyj domyślnego',
+  helpArchiveTypeFilter: 'Szybko zawęź listę tylko do jednostek lub dokumentów.',
+  confirmDialogTypeCountLabel: 'Dotyczy to {count} pozycji. Wpisz {count}, aby potwierdzić.',
+  bulkConfirmThresholdLabel: 'Bezpieczeństwo operacji zbiorczych',
+  bulkConfirmThresholdOption: 'Powyżej {count} pozycji',
+  bulkConfirmThresholdHint: 'Operacje zbiorcze obejmujące więcej pozycji wymagają wpisania ich liczby.',
+  paginationSummary: 'Pozycje {from}–{to} z {total}',
+  themeLabel: 'Motyw',
+  themeLight: 'Jasny',
+  themeDark: 'Ciemny',
+  themeSystem: 'Systemowy',
+  conditionIsEmpty: 'jest puste',
+  conditionIsNotEmpty: 'nie jest puste',
+  helpArchiveShowDisabled: 'Uwzględnij wyłączone pozycje na liście, aby można je było przywrócić.',
+  refreshViewTooltip: 'Odśwież (wczytaj ten widok ponownie z serwera)',
+  helpRefresh: 'Wczytaj bieżący widok ponownie z aktualnymi danymi z serwera.',
+  helpArchiveQuickSearch: 'Szybko znajdź pozycje po fragmencie tytułu lub twórcy.',
+  tableSortByTooltip: 'Sortuj według: {column}',
+  connectivityUnknown: 'Połączenie z serwerem nie zostało jeszcze sprawdzone',
+  connectivityOnline: 'Połączono z serwerem',
+  connectivitySlow: 'Serwer odpowiada wolno ({latency} ms)',
+  connectivityOffline: 'Serwer jest nieosiągalny',
+  connectivityCheckedAt: 'Ostatnie sprawdzenie: {time}',
+  connectivityLastError: 'Ostatni błąd ({time}): {message}',
+  timeJustNow: 'przed chwilą',
+  timestampToggleHint: 'Kliknij, aby przełączyć między datami względnymi a dokładnymi',
+  zoomLabel: 'Powiększenie',
+  zoomInTooltip: 'Powiększ (Ctrl+Plus)',
+  zoomOutTooltip: 'Pomniejsz (Ctrl+Minus)',
+  zoomResetTooltip: 'Przywróć domyślne powiększenie (Ctrl+0)',
+  searchMatchLabel: 'Dopasuj',
+  searchMatchAll: 'wszystkie kryteria (AND)',
+  searchMatchAny: 'dowolne kryterium (OR)',
+  saveInProgressCloseBlocked: 'Trwa zapisywanie... Edytor można zamknąć po zakończeniu zapisu.',
+  deleteModeLabel: 'Usuwanie elementów',
+  deleteModeConfirm: 'Pytaj o potwierdzenie',
+  deleteModeUndo: 'Usuwaj od razu z możliwością cofnięcia',
+  deleteModeHint: 'Dotyczy notatek i tagów. Przy cofaniu element jest usuwany po {seconds} s, chyba że klikniesz Cofnij.',
+  undoDeleteToast: 'Usunięto "{name}"',
+  undoButton: 'Cofnij',
 };
\ No newline at end of file
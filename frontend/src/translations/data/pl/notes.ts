@@ -7,7 +7,6 @@ export const notesTranslationsPL: NotesTranslationSet = {
   notesCreateTitle: 'Utwórz Notatkę',
   notesEditTitle: 'Edytuj Notatkę',
   notesNoNotesFound: 'Nie znaleziono notatek.',
-  notesClickCreateHint: 'Kliknij "Utwórz Notatkę", aby dodać nową.',
   notesContentColumn: 'Treść',
   notesAuthorColumn: 'Autor',
   notesModifiedColumn: 'Zmodyfikowano',
@@ -31,8 +30,50 @@ export const notesTranslationsPL: NotesTranslationSet = {
   notesSaveFailed: 'Nie udało się zapisać notatki: {message}',
   notesPreviewTitle: 'Podgląd Notatki',
   notesPreviewBy: 'Autor:',
-  notesPreviewOn: 'dnia',
-  notesNoContentPlaceholder: 'Brak treści.', // Added Key
+  notesNoContentPlaceholder: '(brak treści)', // Added Key
   notesSharedBadge: 'Udostępniona', // Added Key
   notesPrivateBadge: 'Prywatna', // Added Key
+  notesAudienceLabel: 'Odbiorcy',
+  notesAudiencePrivate: 'Prywatna (tylko ja)',
+  notesAudienceEveryone: 'Udostępniona wszystkim',
+  notesAudienceLimitedHint: 'Udostępnianie wybranym użytkownikom lub rolom nie jest jeszcze obsługiwane przez serwer.',
+  notesContentLength: '{length} / {max} znaków',
+  notesContentLongWarning: 'Ta notatka ma ponad {threshold} znaków i może wyświetlać się wolno.',
+  notesContentTooLongError: 'Treść przekracza maksymalną liczbę {max} znaków dozwoloną przez serwer. Skróć ją, aby zapisać.',
+  notesPreviewModified: 'zmodyfikowano',
+  notesExportButton: 'Eksportuj',
+  notesExportCurrentPage: 'Bieżąca strona ({count})',
+  notesExportAllPages: 'Wszystkie wyniki ({count})',
+  notesExportMarkdown: 'Markdown (.md)',
+  notesExportJson: 'JSON (.json)',
+  notesExportHeader: 'Wyeksportowano {count, plural, one {# notatkę} few {# notatki} many {# notatek} other {# notatki}}: {date}',
+  notesExportNoContent: '(brak treści)',
+  notesExportNothing: 'Brak notatek do eksportu.',
+  notesExportSuccess: 'Wyeksportowano {count, plural, one {# notatkę} few {# notatki} many {# notatek} other {# notatki}} do {fileName}.',
+  notesExportFailed: 'Nie udało się wyeksportować notatek.',
+  notesSelectPageLabel: 'Zaznacz wszystkie notatki na tej stronie',
+  notesSelectNoteLabel: 'Zaznacz notatkę "{title}"',
+  notesSelectedCount: '{count, plural, one {Zaznaczono # notatkę} few {Zaznaczono # notatki} other {Zaznaczono # notatek}}',
+  notesBatchTagAddButton: 'Dodaj tagi do zaznaczonych',
+  notesBatchTagRemoveButton: 'Usuń tagi z zaznaczonych',
+  notesClearSelectionButton: 'Wyczyść zaznaczenie',
+  notesBatchTagAddTitle: 'Dodaj tagi do zaznaczonych notatek',
+  notesBatchTagRemoveTitle: 'Usuń tagi z zaznaczonych notatek',
+  notesBatchTagDescription: '{action, select, add {Wybrane tagi zostaną dodane do} other {Wybrane tagi zostaną usunięte z}} {count, plural, one {# zaznaczonej notatki} other {# zaznaczonych notatek}}.',
+  notesBatchTagConfirmAdd: 'Dodaj tagi ({count})',
+  notesBatchTagConfirmRemove: 'Usuń tagi ({count})',
+  notesBatchTagProgress: 'Zaktualizowano {done}/{total}',
+  notesBatchTagSummary: '{action, select, add {Dodano tagi} other {Usunięto tagi}}: zaktualizowano {updated}, bez zmian {unchanged}.',
+  notesBatchTagFailures: 'Nie udało się zaktualizować ({count}): {titles}',
+  notesContentPreviewColumn: 'Podgląd',
+  notesEmptyDescription: 'Notatki pozwalają trzymać ustalenia i przypomnienia w jednym miejscu. Można je tagować i udostępniać innym.',
+  notesCreateFirstButton: 'Utwórz pierwszą notatkę',
+  notesFilterLabel: 'Pokaż notatki',
+  notesFilterAll: 'Wszystkie',
+  notesFilterMine: 'Moje',
+  notesFilterShared: 'Udostępnione mi',
+  notesPreviewViewLabel: 'Widok treści',
+  notesPreviewRendered: 'Sformatowany',
+  notesPreviewSource: 'Źródło',
+  notesContentMarkdownHint: 'Obsługiwany jest Markdown: # nagłówki, **pogrubienie**, *kursywa*, listy, > cytaty, bloki kodu ``` i linki.',
 };
\ No newline at end of file
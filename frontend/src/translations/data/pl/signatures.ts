@@ -25,7 +25,6 @@ export const signatureTranslationsPL: SignatureTranslationSet = {
   confirmDeleteComponentTitle: 'Potwierdź Usunięcie Komponentu',
   confirmDeleteComponentMessage: 'OSTRZEŻENIE: Usunięcie komponentu spowoduje również usunięcie WSZYSTKICH jego elementów i może przerwać istniejące odwołania. Tej akcji nie można cofnąć. Czy jesteś pewien?',
   confirmReindexComponentTitle: 'Potwierdź Reindeksację',
-  confirmReindexComponentMessage: 'Reindeksacja przeliczy indeksy dla wszystkich elementów w komponencie ID {componentId}. Kontynuować?',
   componentCreatedSuccess: 'Komponent utworzony pomyślnie.',
   componentUpdatedSuccess: 'Komponent zaktualizowany pomyślnie.',
   componentDeletedSuccess: 'Komponent usunięty pomyślnie.',
@@ -122,4 +121,117 @@ export const signatureTranslationsPL: SignatureTranslationSet = {
   elementLoadFailedError: 'Nie udało się załadować elementów',
   componentNoChangesDetected: 'Nie wykryto żadnych zmian.', // Added Key
   elementBrowserSelectComponentFirst: 'Najpierw wybierz komponent, aby zobaczyć elementy.', // Added missing key
+  moveElementButtonTooltip: 'Przenieś do komponentu…',
+  moveElementDialogTitle: 'Przenieś element do komponentu',
+  moveElementDialogDescription: 'Przenieś "{name}" z "{componentName}" do innego komponentu.',
+  moveElementTargetComponentLabel: 'Komponent docelowy',
+  moveElementIndexWarning: 'Indeks "{index}" zostanie zachowany i może wymagać ponownego przypisania (lub reindeksacji komponentu docelowego) po przeniesieniu.',
+  moveElementParentsWarning: 'Relacje nadrzędne zostaną naruszone: elementy starego komponentu wskazujące ten element jako nadrzędny zachowają powiązanie, a elementy nadrzędne z komponentu docelowego staną się nieprawidłowe.',
+  moveElementIncompatibleParentsLabel: '{count, plural, one {# element nadrzędny należy} few {# elementy nadrzędne należą} other {# elementów nadrzędnych należy}} do komponentu docelowego:',
+  moveElementClearParentsLabel: 'Usuń te elementy nadrzędne podczas przenoszenia',
+  moveElementConfirmButton: 'Przenieś element',
+  elementMovedSuccess: 'Element "{name}" przeniesiono do "{componentName}".',
+  elementMoveFailedError: 'Nie udało się przenieść elementu',
+  elementAncestryLabel: 'Pochodzenie',
+  elementAncestryHint: 'Pełna ścieżka od korzenia do każdego wybranego elementu nadrzędnego (tylko do odczytu).',
+  elementAncestryMultipleParentsHint: '(ma więcej elementów nadrzędnych, pokazano pierwszy)',
+  elementAncestryResolveError: 'Nie udało się ustalić pochodzenia elementu o ID {id}.',
+  elementJumpToIndexLabel: 'Przejdź do indeksu',
+  elementJumpToIndexPlaceholder: 'Przejdź do indeksu…',
+  elementJumpNotFound: 'Brak elementu o indeksie "{index}" w tym komponencie.',
+  elementJumpFilteredOut: 'Element o indeksie "{index}" jest ukryty przez bieżące filtry wyszukiwania.',
+  elementJumpFailedError: 'Nie udało się przejść do indeksu.',
+  elementSearchEverywhereLabel: 'Szukaj wszędzie',
+  elementSearchEverywherePlaceholder: 'Szukaj we wszystkich komponentach po nazwie lub indeksie…',
+  elementSearchEverywhereHint: 'Wpisz nazwę lub indeks, aby wyszukać elementy we wszystkich komponentach.',
+  elementSearchEverywhereNoResults: 'Brak pasujących elementów w żadnym komponencie.',
+  elementSearchEverywhereTooManyResults: 'Wyświetlono pierwsze {count} wyników. Zawęź wyszukiwanie.',
+  elementSearchEverywhereUnknownComponent: 'Komponent ID {id}',
+  elementSelectorBrowseByComponent: 'Przeglądaj według komponentu',
+  elementBrowserModeEverywhere: 'Wszędzie',
+  elementBrowserModeEverywhereHint: 'Przeszukaj wszystkie komponenty naraz i dołącz wybrany element do ścieżki.',
+  elementSelectAllOnPage: 'Zaznacz wszystkie elementy na tej stronie',
+  elementSelectRow: 'Zaznacz {name}',
+  elementDeleteSelectedButton: 'Usuń zaznaczone ({count})',
+  elementBulkDeleteProgress: 'Usuwanie {done}/{total}…',
+  elementClearSelectionButton: 'Wyczyść zaznaczenie',
+  elementBulkDeleteConfirm: 'Usunąć zaznaczone elementy ({count})? Tej operacji nie można cofnąć.',
+  elementBulkDeleteUsageWarning: '{usedCount} z {count} zaznaczonych elementów jest używanych w dokumentach (odwołania: {documentCount}). Te dokumenty zachowają odwołania do usuniętych elementów.',
+  elementBulkDeleteUnusedNote: 'Żaden z zaznaczonych elementów nie jest używany w dokumentach.',
+  elementBulkDeleteSummary: 'Usunięto {deleted} z {total} elementów.',
+  elementBulkDeleteFailures: 'Nie udało się usunąć elementów ({failed}): {names}',
+  elementUsageCheckFailed: 'Nie udało się sprawdzić użycia elementów: {message}',
+  elementFindDuplicatesButton: 'Znajdź duplikaty',
+  elementDuplicatesDialogTitle: 'Scal zduplikowane elementy',
+  elementDuplicatesDialogDescription: 'Elementy komponentu "{componentName}" o tej samej nazwie lub indeksie. Scalenie przenosi dokumenty, elementy podrzędne i nadrzędne duplikatów do zachowanego elementu i usuwa duplikaty.',
+  elementDuplicatesLoadError: 'Nie udało się wczytać elementów do wykrywania duplikatów.',
+  elementDuplicatesNoneFound: 'Nie znaleziono prawdopodobnych duplikatów w tym komponencie.',
+  elementDuplicatesSameName: 'Ta sama nazwa: {value}',
+  elementDuplicatesSameIndex: 'Ten sam indeks: {value}',
+  elementDuplicatesKeepHint: 'Wybierz element do zachowania.',
+  elementDuplicatesDocumentCount: '{count, plural, one {# dokument} few {# dokumenty} many {# dokumentów} other {# dokumentu}}',
+  elementDuplicatesKeptBadge: 'Zachowany',
+  elementMergeButton: 'Scal',
+  elementMergeConfirmTitle: 'Scalić elementy?',
+  elementMergeConfirmMessage: '{count, plural, one {Element} few {# elementy} many {# elementów} other {# elementu}} ({names}) zostanie scalonych z "{keptName}" i usuniętych. {documentCount, plural, =0 {Żaden dokument się do nich nie odwołuje.} one {Zostanie zaktualizowany # dokument.} few {Zostaną zaktualizowane # dokumenty.} many {Zostanie zaktualizowanych # dokumentów.} other {Zostanie zaktualizowane # dokumentu.}} Tej operacji nie można cofnąć.',
+  elementMergeSummary: 'Scalono {merged} z "{keptName}", zaktualizowano dokumentów: {documentCount}.',
+  elementMergeFailures: 'Nie udało się scalić {failed}: {names}',
+  componentFilterPlaceholder: 'Filtruj po nazwie lub opisie…',
+  componentFilterNoMatches: 'Żaden komponent nie pasuje do "{filter}".',
+  componentSortByTooltip: 'Sortuj według: {column}',
+  elementParentsNotLoadedError: 'Nie udało się wczytać elementów nadrzędnych. Zapis jest zablokowany, aby nie usunąć istniejących powiązań.',
+  elementParentsRetryButton: 'Ponów',
+  elementViewModeLabel: 'Widok elementów',
+  elementViewModeList: 'Lista',
+  elementViewModeTree: 'Drzewo',
+  elementTreeViewLabel: 'Hierarchia elementów',
+  elementTreeExpand: 'Rozwiń {name}',
+  elementTreeCollapse: 'Zwiń {name}',
+  elementTreeLoadFailed: 'Nie udało się wczytać elementów tego poziomu.',
+  signaturePathCopyLabel: 'Ścieżka sygnatury',
+  elementBrowserTruncatePathTooltip: 'Wróć do „{name}”',
+  elementBrowserPathAdded: 'Dodano ścieżkę: {path}',
+  signatureDragHandleLabel: 'Przeciągnij, aby zmienić kolejność',
+  elementBrowserKeyboardHint: '↑/↓ – przechodzenie, Enter – wybór, Backspace – krok wstecz, Ctrl+Enter – dodanie ścieżki',
+  componentsEmptyDescription: 'Komponenty to słowniki (np. miejsca, osoby, tematy), z których elementów buduje się sygnatury opisowe.',
+  componentsCreateFirstButton: 'Utwórz pierwszy komponent',
+  elementsEmptyDescription: 'Elementy to pozycje tego komponentu używane w sygnaturach opisowych.',
+  elementsCreateFirstButton: 'Utwórz pierwszy element',
+  reindexPreviewDialogTitle: 'Podgląd reindeksacji',
+  reindexPreviewDialogDescription: 'Elementy komponentu "{componentName}" są numerowane według nazwy w formacie: {indexType}. Sprawdź nowe indeksy przed ich zastosowaniem.',
+  reindexPreviewLoadError: 'Nie udało się wczytać elementów do podglądu',
+  reindexPreviewNoElements: 'Ten komponent nie ma elementów do reindeksacji.',
+  reindexPreviewChangedCount: '{changed, plural, =0 {Żaden indeks się nie zmieni} one {Zmieni się # z {total} indeksów} few {Zmienią się # z {total} indeksów} many {Zmieni się # z {total} indeksów} other {Zmieni się # z {total} indeksów}}',
+  reindexPreviewCurrentIndex: 'Obecny indeks',
+  reindexPreviewNewIndex: 'Nowy indeks',
+  reindexPreviewConfirmButton: 'Reindeksuj',
+  reindexAllComponentsButton: 'Reindeksuj wszystkie',
+  reindexAllComponentsTooltip: 'Reindeksuj elementy wszystkich komponentów',
+  reindexAllComponentsProgress: 'Reindeksacja {done} / {total}…',
+  confirmReindexAllComponentsMessage: 'Reindeksacja przeliczy indeksy wszystkich elementów w {count, plural, one {# komponencie} other {# komponentach}}. Kontynuować?',
+  reindexAllComponentsSummary: 'Zreindeksowano {count, plural, one {# komponent} few {# komponenty} many {# komponentów} other {# komponentu}}',
+  reindexAllComponentsFailures: 'Nie udało się zreindeksować {failed, plural, one {# komponentu} other {# komponentów}}: {names}',
+  exportComponentButtonTooltip: 'Eksportuj komponent wraz z elementami (JSON)',
+  exportAllComponentsButton: 'Eksportuj wszystkie',
+  exportAllComponentsTooltip: 'Eksportuj wszystkie komponenty wraz z elementami do pliku JSON',
+  componentsExportedSuccess: 'Wyeksportowano komponenty: {components}, elementy: {elements}.',
+  componentsExportFailedError: 'Nie udało się wyeksportować komponentów',
+  importComponentsButton: 'Importuj',
+  signatureImportTitle: 'Import komponentów sygnatur',
+  signatureImportDescription: 'Wybierz plik JSON wyeksportowany z listy komponentów. Komponenty zostaną utworzone wraz z elementami, a elementy nadrzędne powiązane według nazwy komponentu i elementu. Komponenty o istniejącej już nazwie zostaną pominięte.',
+  signatureImportReadyTitle: 'Plik gotowy do importu',
+  signatureImportReadyText: 'Zostaną utworzone komponenty: {components}, elementy: {elements}.',
+  signatureImportButton: 'Importuj',
+  signatureImportProgress: 'Importowanie {done} z {total}...',
+  signatureImportSuccess: 'Utworzono komponenty: {components}, elementy: {elements}.',
+  signatureImportFailuresTitle: 'Problemy podczas importu: {count}',
+  signatureImportItemColumn: 'Pozycja',
+  signatureImportMessageColumn: 'Problem',
+  signatureImportFailedError: 'Import nie powiódł się',
+  signatureImportInvalidJson: 'Plik nie zawiera poprawnego JSON.',
+  signatureImportInvalidFile: 'To nie jest eksport komponentów sygnatur ({path}: {message}).',
+  signatureImportComponentExists: 'Komponent o tej nazwie już istnieje - pominięto.',
+  signatureImportElementFailed: 'Nie udało się utworzyć elementu',
+  signatureImportParentNotFound: 'Nie znaleziono elementu nadrzędnego "{parent}".',
+  signatureImportParentsFailed: 'Nie udało się powiązać elementów nadrzędnych',
 };
\ No newline at end of file
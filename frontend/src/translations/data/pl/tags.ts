@@ -7,7 +7,6 @@ export const tagsTranslationsPL: TagsTranslationSet = {
   tagsCreateTitle: 'Utwórz Tag',
   tagsEditTitle: 'Edytuj Tag',
   tagsNoTagsFound: 'Nie znaleziono tagów.',
-  tagsClickCreateHint: 'Kliknij "Utwórz Tag", aby dodać nowy.',
   tagLabelSingular: 'Tag', // Singular form
   tagsEditDialogDescription: 'Edytuj tag "{tagName}".',
   tagsCreateDialogDescription: 'Utwórz nowy tag do organizacji treści.',
@@ -21,4 +20,11 @@ export const tagsTranslationsPL: TagsTranslationSet = {
   tagLoadFailedError: 'Nie udało się załadować tagów',
   tagSaveSuccess: 'Tag {action} pomyślnie.', // action: utworzono/zaktualizowano
   tagsLoadErrorPlaceholder: 'Nie udało się załadować tagów. Spróbuj ponownie później.',
+  tagInlineRenameHint: 'Kliknij dwukrotnie, aby zmienić nazwę',
+  tagInlineRenameLabel: 'Nowa nazwa tagu "{tagName}"',
+  tagInlineRenameSuccess: 'Zmieniono nazwę tagu "{oldName}" na "{newName}".',
+  tagCreatedByLabel: 'Utworzony przez',
+  tagCreatedOnLabel: 'Utworzono',
+  tagsEmptyDescription: 'Tagi grupują notatki i pozycje archiwum według tematu, dzięki czemu łatwo je później odnaleźć.',
+  tagsCreateFirstButton: 'Utwórz pierwszy tag',
 };
\ No newline at end of file
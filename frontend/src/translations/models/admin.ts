@@ -112,6 +112,52 @@ export type AdminTranslationKey =
   | 'setPasswordButton'
   | 'createdBySearchLabel' // New
   | 'updatedBySearchLabel' // New
+  | 'settingsLoadFailedError'
+  | 'settingsRetryButton'
+  | 'settingsAutoRetrying'
+  | 'accessDeniedBackButton'
+  | 'accessDeniedRoleMessage'
+  | 'roleChangeRevertedError'
+  | 'settingsValueNotSetHint'
+  | 'settingsNoChangesToSave'
+  | 'logExportButton'
+  | 'logExportCsv'
+  | 'logExportJson'
+  | 'logExportProgress'
+  | 'logExportLargeConfirm'
+  | 'logExportCappedConfirm'
+  | 'logExportCancelled'
+  | 'logExportSuccess'
+  | 'logExportFailed'
+  | 'logLevelFilterAll'
+  | 'logMessageFilterPlaceholder'
+  | 'logAutoRefreshLabel'
+  | 'logAutoRefreshOff'
+  | 'logAutoRefreshEvery'
+  | 'logLastRefreshed'
+  | 'logRefreshNow'
+  | 'userFilterPlaceholder'
+  | 'userRoleFilterLabel'
+  | 'userRoleFilterAll'
+  | 'userFilterCount'
+  | 'userTotalCount'
+  | 'userFilterNoMatches'
+  | 'settingsUnsavedChanges'
+  | 'settingsFieldSaved'
+  | 'settingsFieldSaveFailed'
+  | 'settingsPartialSaveError'
+  | 'logUserFilterPlaceholder'
+  | 'logsActionColumn'
+  | 'logsTargetColumn'
+  | 'logActionFilterAll'
+  | 'logActionCreate'
+  | 'logActionUpdate'
+  | 'logActionDelete'
+  | 'logActionMove'
+  | 'logActionReindex'
+  | 'logViewModeLabel'
+  | 'logViewModeEntries'
+  | 'logViewModeActivity'
   ;
 
 export type AdminTranslationSet = TranslationSet<AdminTranslationKey>;
\ No newline at end of file
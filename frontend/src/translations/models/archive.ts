@@ -70,7 +70,6 @@ export type ArchiveTranslationKey =
   | 'archivePreviewAdditionalInfoLabel'
   | 'archivePreviewRelatedDocsLabel'
   | 'archivePreviewDigitizedLabel'
-  | 'archivePreviewDigitizedYesLink'
   | 'archivePreviewDigitizedYes'
   | 'archivePreviewDigitizedNo'
   | 'archivePreviewEmptyContent'
@@ -134,12 +133,8 @@ export type ArchiveTranslationKey =
   | 'unitLabel' // Singular "Unit"
   | 'archiveBrowsingUnit' // e.g., "Browsing items within "{unitTitle}"."
   | 'archiveDescriptionUser' // e.g., "Search documents based on your assigned tags."
-  | 'archiveClickCreateHint' // e.g., 'Click "Create Item" to start.'
   | 'archivePreviewBy' // Used for original document creator
   | 'archivePreviewOn' // Used for original document creation date
-  | 'archivePreviewInvalidDate'
-  | 'archivePreviewErrorDate'
-  | 'archivePreviewNotApplicable'
   | 'added' // For batch tag success message
   | 'removed' // For batch tag success message
   | 'thisUnit' // Placeholder for parentUnitId context
@@ -147,6 +142,109 @@ export type ArchiveTranslationKey =
   | 'ownerUserIdSearchLabel' // Keep key for reference, but update label in translation files
   | 'createdBySearchLabel' // New key
   | 'updatedBySearchLabel' // New key
+  | 'archiveParentUnitDisabledWarning'
+  | 'archiveParentUnitDisabledConfirm'
+  | 'archiveReenableUnitButton'
+  | 'archiveChooseOtherParentButton'
+  | 'archiveParentUnitReenabledSuccess'
+  | 'archiveOpenContainingUnitButton'
+  | 'archiveOpenRootButton'
+  | 'archiveDigitizedOnlyFilter'
+  | 'archiveDigitizedIndicatorTooltip'
+  | 'archiveDigitizedOpenLinkTooltip'
+  | 'archiveImportCsvButton'
+  | 'archiveImportTitle'
+  | 'archiveImportDescription'
+  | 'archiveImportRowsRead'
+  | 'archiveImportValidateButton'
+  | 'archiveImportButton'
+  | 'archiveImportValidTitle'
+  | 'archiveImportValidText'
+  | 'archiveImportIssuesTitle'
+  | 'archiveImportIssuesText'
+  | 'archiveImportRowColumn'
+  | 'archiveImportFieldColumn'
+  | 'archiveImportMessageColumn'
+  | 'archiveImportMoreIssues'
+  | 'archiveImportDownloadReport'
+  | 'archiveImportSkipInvalid'
+  | 'archiveImportProgress'
+  | 'archiveImportSuccess'
+  | 'archiveImportRowsFailed'
+  | 'archiveImportUnknownColumn'
+  | 'archiveImportMissingColumn'
+  | 'archiveImportUnknownTag'
+  | 'archiveImportParentNotFound'
+  | 'archiveTypeFilterLabel'
+  | 'archiveTypeFilterAll'
+  | 'archiveTypeFilterUnits'
+  | 'archiveTypeFilterDocuments'
+  | 'archiveBatchTagsLargeConfirm'
+  | 'archiveBulkEditButton'
+  | 'archiveBulkEditTooltip'
+  | 'archiveBulkEditTitle'
+  | 'archiveBulkEditDescription'
+  | 'archiveBulkEditPreviewDescription'
+  | 'archiveBulkEditFieldLabel'
+  | 'archiveBulkEditModeSet'
+  | 'archiveBulkEditModeReplace'
+  | 'archiveBulkEditFindLabel'
+  | 'archiveBulkEditFindRequired'
+  | 'archiveBulkEditNewValueLabel'
+  | 'archiveBulkEditReplaceWithLabel'
+  | 'archiveBulkEditEmptyClearsHint'
+  | 'archiveBulkEditCapNote'
+  | 'archiveBulkEditPreviewButton'
+  | 'archiveBulkEditPreviewFailed'
+  | 'archiveBulkEditNothingChanges'
+  | 'archiveBulkEditSelectAll'
+  | 'archiveBulkEditOldValueColumn'
+  | 'archiveBulkEditNewValueColumn'
+  | 'archiveBulkEditEmptyValue'
+  | 'archiveBulkEditInvalidValue'
+  | 'archiveBulkEditBackButton'
+  | 'archiveBulkEditApplyButton'
+  | 'archiveBulkEditConfirm'
+  | 'archiveBulkEditProgress'
+  | 'archiveBulkEditSummary'
+  | 'archiveBulkEditFailures'
+  | 'archiveUnitChildCountTooltip'
+  | 'archivePreviewIdLabel'
+  | 'archivePreviewSignaturePathLabel'
+  | 'archiveShowDisabledFilter'
+  | 'archiveDisabledBadge'
+  | 'archiveRestoreButton'
+  | 'archiveRestoreSuccess'
+  | 'archiveRestoreFailed'
+  | 'archiveQuickSearchPlaceholder'
+  | 'archiveQuickTagFilter'
+  | 'archiveQuickTagFilterActive'
+  | 'archiveQuickTagFilterClear'
+  | 'archiveExportCsvButton'
+  | 'archiveExportCsvTooltip'
+  | 'archiveExportProgress'
+  | 'archiveExportLargeConfirm'
+  | 'archiveExportCappedConfirm'
+  | 'archiveExportCancelled'
+  | 'archiveExportSuccess'
+  | 'archiveExportFailed'
+  | 'archiveDuplicateButton'
+  | 'archiveDuplicateTitle'
+  | 'archiveDuplicateDialogTitle'
+  | 'archiveFormSectionFilledCount'
+  | 'archiveFormExpandAllSections'
+  | 'archiveFormCollapseAllSections'
+  | 'archiveEmptyDescription'
+  | 'archiveCreateFirstButton'
+  | 'archivePreviewCanEditBadge'
+  | 'archivePreviewReadOnlyBadge'
+  | 'archivePreviewReadOnlyReason'
+  | 'archiveBreadcrumbsLabel'
+  | 'archiveGoToParentUnitButton'
+  | 'archivePreviewDigitizedMissingLink'
+  | 'archivePreviewDigitizedInvalidLink'
+  | 'archivePreviewDigitizedOpenImage'
+  | 'archivePreviewDigitizedThumbnailAlt'
   ;
 
 export type ArchiveTranslationSet = TranslationSet<ArchiveTranslationKey>;
\ No newline at end of file
@@ -20,7 +20,16 @@ export type AuthTranslationKey =
   | 'createAccountButton'
   | 'hasAccountPrompt'
   | 'loginLink'
-  | 'languagePickerLabel';
+  | 'languagePickerLabel'
+  | 'rememberMeLabel'
+  | 'sessionExpiredMessage'
+  | 'passwordStrengthLabel'
+  | 'passwordStrengthWeak'
+  | 'passwordStrengthFair'
+  | 'passwordStrengthGood'
+  | 'passwordStrengthStrong'
+  | 'passwordStrengthRequirements'
+  | 'passwordsMismatchWarning';
 
 
 export type AuthTranslationSet = TranslationSet<AuthTranslationKey> 
@@ -138,7 +138,6 @@ export type CommonTranslationKey =
   | 'thisUnit'
   | 'notLabel'
   | 'enterValuesPlaceholder'
-  | 'clickToCreate'
   | 'insufficientPermissionsError'
   | 'notAvailableAbbr'
   | 'unknown'
@@ -153,6 +152,111 @@ export type CommonTranslationKey =
   | 'updatedByLabel' // New
   | 'lastModifiedLabel' // New/Alternative
   | 'ownerLabel' // Keep? Remove? -> Keeping for now if used elsewhere
+  | 'notificationsLabel'
+  | 'notificationsPositionLabel'
+  | 'notificationsPositionTopRight'
+  | 'notificationsPositionTopCenter'
+  | 'notificationsPositionTopLeft'
+  | 'notificationsPositionBottomRight'
+  | 'notificationsPositionBottomCenter'
+  | 'notificationsPositionBottomLeft'
+  | 'notificationsDurationLabel'
+  | 'notificationsDurationSeconds'
+  | 'notificationsErrorDurationHint'
+  | 'previousPage'
+  | 'nextPage'
+  | 'unitSelectorPageIndicator'
+  | 'pendingChangesTooltip'
+  | 'pendingChangesTitle'
+  | 'pendingChangesCreate'
+  | 'pendingChangesUpdate'
+  | 'pendingChangesDelete'
+  | 'pendingChangesAction'
+  | 'copyFieldTooltip'
+  | 'copiedToClipboard'
+  | 'copyToClipboardFailed'
+  | 'resetAppDataMenuItem'
+  | 'resetAppDataDialogTitle'
+  | 'resetAppDataDialogDescription'
+  | 'resetAppDataWarning'
+  | 'resetAppDataAlsoLogout'
+  | 'resetAppDataConfirmButton'
+  | 'markdownPreviewButton'
+  | 'markdownEditButton'
+  | 'markdownPreviewEmpty'
+  | 'markdownSupportedHint'
+  | 'pasteCollapsedToSingleLine'
+  | 'firstPage'
+  | 'lastPage'
+  | 'loadingStatusLoading'
+  | 'loadingStatusLoaded'
+  | 'loadingStatusDone'
+  | 'helpOverlayTitle'
+  | 'helpOverlayCloseHint'
+  | 'helpNavigation'
+  | 'helpUserMenu'
+  | 'helpSearch'
+  | 'helpPagination'
+  | 'helpArchiveDigitizedFilter'
+  | 'helpArchiveBatchTags'
+  | 'helpArchiveCreate'
+  | 'helpArchiveList'
+  | 'helpNotesCreate'
+  | 'helpNotesList'
+  | 'helpComponentsCreate'
+  | 'helpComponentsList'
+  | 'helpElementsJumpToIndex'
+  | 'helpElementsCreate'
+  | 'helpElementsList'
+  | 'helpTagsCreate'
+  | 'helpTagsList'
+  | 'helpAdminTabs'
+  | 'confirmDialogDefaultTitle'
+  | 'serverAddressMenuItem'
+  | 'serverAddressDialogTitle'
+  | 'serverAddressDialogDescription'
+  | 'serverAddressLabel'
+  | 'serverAddressHint'
+  | 'serverAddressResetButton'
+  | 'helpArchiveTypeFilter'
+  | 'confirmDialogTypeCountLabel'
+  | 'bulkConfirmThresholdLabel'
+  | 'bulkConfirmThresholdOption'
+  | 'bulkConfirmThresholdHint'
+  | 'paginationSummary'
+  | 'themeLabel'
+  | 'themeLight'
+  | 'themeDark'
+  | 'themeSystem'
+  | 'conditionIsEmpty'
+  | 'conditionIsNotEmpty'
+  | 'helpArchiveShowDisabled'
+  | 'refreshViewTooltip'
+  | 'helpRefresh'
+  | 'helpArchiveQuickSearch'
+  | 'tableSortByTooltip'
+  | 'connectivityUnknown'
+  | 'connectivityOnline'
+  | 'connectivitySlow'
+  | 'connectivityOffline'
+  | 'connectivityCheckedAt'
+  | 'connectivityLastError'
+  | 'timeJustNow'
+  | 'timestampToggleHint'
+  | 'zoomLabel'
+  | 'zoomInTooltip'
+  | 'zoomOutTooltip'
+  | 'zoomResetTooltip'
+  | 'searchMatchLabel'
+  | 'searchMatchAll'
+  | 'searchMatchAny'
+  | 'saveInProgressCloseBlocked'
+  | 'deleteModeLabel'
+  | 'deleteModeConfirm'
+  | 'deleteModeUndo'
+  | 'deleteModeHint'
+  | 'undoDeleteToast'
+  | 'undoButton'
   ;
 
 export type CommonTranslationSet = TranslationSet<CommonTranslationKey>;
\ No newline at end of file
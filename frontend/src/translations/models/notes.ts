@@ -8,7 +8,6 @@ export type NotesTranslationKey =
   | 'notesCreateTitle'
   | 'notesEditTitle'
   | 'notesNoNotesFound' // Used in NotesPage
-  | 'notesClickCreateHint' // Used in NotesPage
   | 'notesContentColumn' // Used in NotesPage (SearchBar)
   | 'notesAuthorColumn' // Used in NoteList
   | 'notesModifiedColumn' // Used in NoteList
@@ -30,10 +29,52 @@ export type NotesTranslationKey =
   | 'notesSaveFailed'
   | 'notesPreviewTitle'
   | 'notesPreviewBy'
-  | 'notesPreviewOn'
   | 'notesNoContentPlaceholder' // Added missing key
   | 'notesSharedBadge' // Added missing key
   | 'notesPrivateBadge' // Added missing key
+  | 'notesAudienceLabel'
+  | 'notesAudiencePrivate'
+  | 'notesAudienceEveryone'
+  | 'notesAudienceLimitedHint'
+  | 'notesContentLength'
+  | 'notesContentLongWarning'
+  | 'notesContentTooLongError'
+  | 'notesPreviewModified'
+  | 'notesExportButton'
+  | 'notesExportCurrentPage'
+  | 'notesExportAllPages'
+  | 'notesExportMarkdown'
+  | 'notesExportJson'
+  | 'notesExportHeader'
+  | 'notesExportNoContent'
+  | 'notesExportNothing'
+  | 'notesExportSuccess'
+  | 'notesExportFailed'
+  | 'notesSelectPageLabel'
+  | 'notesSelectNoteLabel'
+  | 'notesSelectedCount'
+  | 'notesBatchTagAddButton'
+  | 'notesBatchTagRemoveButton'
+  | 'notesClearSelectionButton'
+  | 'notesBatchTagAddTitle'
+  | 'notesBatchTagRemoveTitle'
+  | 'notesBatchTagDescription'
+  | 'notesBatchTagConfirmAdd'
+  | 'notesBatchTagConfirmRemove'
+  | 'notesBatchTagProgress'
+  | 'notesBatchTagSummary'
+  | 'notesBatchTagFailures'
+  | 'notesContentPreviewColumn'
+  | 'notesEmptyDescription'
+  | 'notesCreateFirstButton'
+  | 'notesFilterLabel'
+  | 'notesFilterAll'
+  | 'notesFilterMine'
+  | 'notesFilterShared'
+  | 'notesPreviewViewLabel'
+  | 'notesPreviewRendered'
+  | 'notesPreviewSource'
+  | 'notesContentMarkdownHint'
   ;
 
 export type NotesTranslationSet = TranslationSet<NotesTranslationKey>;
\ No newline at end of file
@@ -25,7 +25,6 @@ export type SignatureTranslationKey =
   | 'confirmDeleteComponentTitle'
   | 'confirmDeleteComponentMessage'
   | 'confirmReindexComponentTitle'
-  | 'confirmReindexComponentMessage'
   | 'componentCreatedSuccess'
   | 'componentUpdatedSuccess'
   | 'componentDeletedSuccess'
@@ -122,6 +121,119 @@ export type SignatureTranslationKey =
   | 'elementLoadFailedError'
   | 'componentNoChangesDetected' // Added Key
   | 'elementBrowserSelectComponentFirst' // Added missing key
+  | 'moveElementButtonTooltip'
+  | 'moveElementDialogTitle'
+  | 'moveElementDialogDescription'
+  | 'moveElementTargetComponentLabel'
+  | 'moveElementIndexWarning'
+  | 'moveElementParentsWarning'
+  | 'moveElementIncompatibleParentsLabel'
+  | 'moveElementClearParentsLabel'
+  | 'moveElementConfirmButton'
+  | 'elementMovedSuccess'
+  | 'elementMoveFailedError'
+  | 'elementAncestryLabel'
+  | 'elementAncestryHint'
+  | 'elementAncestryMultipleParentsHint'
+  | 'elementAncestryResolveError'
+  | 'elementJumpToIndexLabel'
+  | 'elementJumpToIndexPlaceholder'
+  | 'elementJumpNotFound'
+  | 'elementJumpFilteredOut'
+  | 'elementJumpFailedError'
+  | 'elementSearchEverywhereLabel'
+  | 'elementSearchEverywherePlaceholder'
+  | 'elementSearchEverywhereHint'
+  | 'elementSearchEverywhereNoResults'
+  | 'elementSearchEverywhereTooManyResults'
+  | 'elementSearchEverywhereUnknownComponent'
+  | 'elementSelectorBrowseByComponent'
+  | 'elementBrowserModeEverywhere'
+  | 'elementBrowserModeEverywhereHint'
+  | 'elementSelectAllOnPage'
+  | 'elementSelectRow'
+  | 'elementDeleteSelectedButton'
+  | 'elementBulkDeleteProgress'
+  | 'elementClearSelectionButton'
+  | 'elementBulkDeleteConfirm'
+  | 'elementBulkDeleteUsageWarning'
+  | 'elementBulkDeleteUnusedNote'
+  | 'elementBulkDeleteSummary'
+  | 'elementBulkDeleteFailures'
+  | 'elementUsageCheckFailed'
+  | 'elementFindDuplicatesButton'
+  | 'elementDuplicatesDialogTitle'
+  | 'elementDuplicatesDialogDescription'
+  | 'elementDuplicatesLoadError'
+  | 'elementDuplicatesNoneFound'
+  | 'elementDuplicatesSameName'
+  | 'elementDuplicatesSameIndex'
+  | 'elementDuplicatesKeepHint'
+  | 'elementDuplicatesDocumentCount'
+  | 'elementDuplicatesKeptBadge'
+  | 'elementMergeButton'
+  | 'elementMergeConfirmTitle'
+  | 'elementMergeConfirmMessage'
+  | 'elementMergeSummary'
+  | 'elementMergeFailures'
+  | 'componentFilterPlaceholder'
+  | 'componentFilterNoMatches'
+  | 'componentSortByTooltip'
+  | 'elementParentsNotLoadedError'
+  | 'elementParentsRetryButton'
+  | 'elementViewModeLabel'
+  | 'elementViewModeList'
+  | 'elementViewModeTree'
+  | 'elementTreeViewLabel'
+  | 'elementTreeExpand'
+  | 'elementTreeCollapse'
+  | 'elementTreeLoadFailed'
+  | 'signaturePathCopyLabel'
+  | 'elementBrowserTruncatePathTooltip'
+  | 'elementBrowserPathAdded'
+  | 'signatureDragHandleLabel'
+  | 'elementBrowserKeyboardHint'
+  | 'componentsEmptyDescription'
+  | 'componentsCreateFirstButton'
+  | 'elementsEmptyDescription'
+  | 'elementsCreateFirstButton'
+  | 'reindexPreviewDialogTitle'
+  | 'reindexPreviewDialogDescription'
+  | 'reindexPreviewLoadError'
+  | 'reindexPreviewNoElements'
+  | 'reindexPreviewChangedCount'
+  | 'reindexPreviewCurrentIndex'
+  | 'reindexPreviewNewIndex'
+  | 'reindexPreviewConfirmButton'
+  | 'reindexAllComponentsButton'
+  | 'reindexAllComponentsTooltip'
+  | 'reindexAllComponentsProgress'
+  | 'confirmReindexAllComponentsMessage'
+  | 'reindexAllComponentsSummary'
+  | 'reindexAllComponentsFailures'
+  | 'exportComponentButtonTooltip'
+  | 'exportAllComponentsButton'
+  | 'exportAllComponentsTooltip'
+  | 'componentsExportedSuccess'
+  | 'componentsExportFailedError'
+  | 'importComponentsButton'
+  | 'signatureImportTitle'
+  | 'signatureImportDescription'
+  | 'signatureImportReadyTitle'
+  | 'signatureImportReadyText'
+  | 'signatureImportButton'
+  | 'signatureImportProgress'
+  | 'signatureImportSuccess'
+  | 'signatureImportFailuresTitle'
+  | 'signatureImportItemColumn'
+  | 'signatureImportMessageColumn'
+  | 'signatureImportFailedError'
+  | 'signatureImportInvalidJson'
+  | 'signatureImportInvalidFile'
+  | 'signatureImportComponentExists'
+  | 'signatureImportElementFailed'
+  | 'signatureImportParentNotFound'
+  | 'signatureImportParentsFailed'
   ;
 
 
@@ -8,7 +8,6 @@ export type TagsTranslationKey =
   | 'tagsCreateTitle'
   | 'tagsEditTitle'
   | 'tagsNoTagsFound'
-  | 'tagsClickCreateHint'
   | 'tagLabelSingular' // New key for singular form
   | 'tagsEditDialogDescription' // e.g., Edit the tag "{tagName}".
   | 'tagsCreateDialogDescription' // e.g., Create a new tag to organize content.
@@ -21,6 +20,13 @@ export type TagsTranslationKey =
   | 'tagLoadFailedError' // Added Key
   | 'tagSaveSuccess' // Added Key
   | 'tagsLoadErrorPlaceholder' // Added Key
+  | 'tagInlineRenameHint'
+  | 'tagInlineRenameLabel'
+  | 'tagInlineRenameSuccess'
+  | 'tagCreatedByLabel'
+  | 'tagCreatedOnLabel'
+  | 'tagsEmptyDescription'
+  | 'tagsCreateFirstButton'
   ;
 
 export type TagsTranslationSet = TranslationSet<TagsTranslationKey>;